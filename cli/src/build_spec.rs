@@ -6,15 +6,16 @@ use core::{Context, CoreFlavor, Flavor, RelativePath, Reporter, Resolved, Resolv
            Source, Version};
 use manifest::{self as m, read_manifest, read_manifest_preamble, Lang, Language, Manifest,
                ManifestFile, ManifestPreamble, NoLang, Publish};
-use repository::{index_from_path, index_from_url, objects_from_path, objects_from_url, Index,
-                 IndexConfig, NoIndex, NoObjects, Objects, ObjectsConfig, Paths, Repository,
-                 Resolvers};
+use repository::{index_from_path, index_from_url, objects_from_path, objects_from_url,
+                 read_lockfile, Index, IndexConfig, Locked, Lockfile, NoIndex, NoObjects, Objects,
+                 ObjectsConfig, Overrides, Paths, Repository};
 use repository_http;
 use semck;
+use std::cell::RefCell;
 use std::collections::HashMap;
 use std::fmt;
 use std::fs::File;
-use std::path::Path;
+use std::path::{Path, PathBuf};
 use std::rc::Rc;
 use std::time::Duration;
 use trans::Environment;
@@ -22,8 +23,15 @@ use url;
 
 pub const DEFAULT_INDEX: &'static str = "git+https://github.com/reproto/reproto-index";
 pub const MANIFEST_NAME: &'static str = "reproto.toml";
-
-fn load_index(base: &Path, url: &str, publishing: bool, config: IndexConfig) -> Result<Box<Index>> {
+pub const LOCKFILE_NAME: &'static str = "reproto.lock";
+
+fn load_index(
+    base: &Path,
+    url: &str,
+    publishing: bool,
+    config: IndexConfig,
+    token: Option<&String>,
+) -> Result<Box<Index>> {
     let index_path = Path::new(url);
 
     if index_path.is_dir() {
@@ -41,7 +49,16 @@ fn load_index(base: &Path, url: &str, publishing: bool, config: IndexConfig) ->
             index_from_path(&path).map_err(Into::into)
         }
         Err(e) => return Err(e.into()),
-        Ok(url) => index_from_url(config, &url, publishing).map_err(Into::into),
+        Ok(url) => index_from_url(
+            config,
+            &url,
+            |_, scheme, url| match scheme {
+                "http" => Ok(Some(repository_http::index_from_url(url, token.cloned())?)),
+                "https" => Ok(Some(repository_http::index_from_url(url, token.cloned())?)),
+                _ => Ok(None),
+            },
+            publishing,
+        ).map_err(Into::into),
     }
 }
 
@@ -51,6 +68,7 @@ fn load_objects(
     index_url: &str,
     objects: Option<String>,
     config: ObjectsConfig,
+    token: Option<&String>,
 ) -> Result<Box<Objects>> {
     let (objects_url, publishing) = if let Some(ref objects) = objects {
         (objects.as_ref(), true)
@@ -83,8 +101,16 @@ fn load_objects(
             config,
             &url,
             |config, scheme, url| match scheme {
-                "http" => Ok(Some(repository_http::objects_from_url(config, url)?)),
-                "https" => Ok(Some(repository_http::objects_from_url(config, url)?)),
+                "http" => Ok(Some(repository_http::objects_from_url(
+                    config,
+                    url,
+                    token.cloned(),
+                )?)),
+                "https" => Ok(Some(repository_http::objects_from_url(
+                    config,
+                    url,
+                    token.cloned(),
+                )?)),
                 _ => Ok(None),
             },
             publishing,
@@ -109,12 +135,14 @@ pub fn repository(manifest: &Manifest) -> Result<Repository> {
     let mut cache_home = None;
     let mut index = repository.index.clone();
     let mut objects = repository.objects.clone();
+    let mut token = None;
 
     if let Some(config_env) = ConfigEnv::new()? {
         repo_dir = Some(config_env.repo_dir);
         cache_home = Some(config_env.cache_home);
         index = index.or(config_env.index.clone());
         objects = objects.or(config_env.objects.clone());
+        token = config_env.token.clone();
     }
 
     let repo_dir = repo_dir.ok_or_else(|| "repo_dir: must be specified")?;
@@ -128,7 +156,13 @@ pub fn repository(manifest: &Manifest) -> Result<Repository> {
         repo_dir: repo_dir.clone(),
     };
 
-    let index = load_index(base, index_url.as_str(), index_publishing, index_config)?;
+    let index = load_index(
+        base,
+        index_url.as_str(),
+        index_publishing,
+        index_config,
+        token.as_ref(),
+    )?;
 
     let objects_config = ObjectsConfig {
         repo_dir,
@@ -142,6 +176,7 @@ pub fn repository(manifest: &Manifest) -> Result<Repository> {
         index_url.as_str(),
         objects,
         objects_config,
+        token.as_ref(),
     )?;
 
     Ok(Repository::new(index, objects))
@@ -164,16 +199,62 @@ pub fn path_resolver(manifest: &Manifest) -> Result<Option<Box<Resolver>>> {
     ))))
 }
 
+/// Path to the lockfile, which is stored next to the manifest.
+pub fn lockfile_path(manifest: &Manifest) -> Option<PathBuf> {
+    manifest
+        .path
+        .as_ref()
+        .and_then(|p| p.parent())
+        .map(|p| p.join(LOCKFILE_NAME))
+}
+
 pub fn resolvers(manifest: &Manifest) -> Result<Box<Resolver>> {
-    let mut resolvers: Vec<Box<Resolver>> = Vec::new();
+    let mut lockfile = Lockfile::default();
+
+    // NB: nothing is resolved from the repository if it is disabled.
+    if !manifest.repository.no_repository {
+        let existing = match lockfile_path(manifest) {
+            Some(path) => read_lockfile(&path)?,
+            None => None,
+        };
+
+        match existing {
+            Some(existing) => lockfile = existing,
+            None if manifest.locked => {
+                return Err(format!("{}: missing, but resolution is locked", LOCKFILE_NAME).into());
+            }
+            None => {}
+        }
+    }
 
-    resolvers.push(Box::new(repository(manifest)?));
+    let resolved = Rc::new(RefCell::new(Lockfile::default()));
+    resolvers_with_lockfile(manifest, lockfile, manifest.locked, resolved)
+}
 
-    if let Some(resolver) = path_resolver(manifest)? {
-        resolvers.push(resolver);
+/// Setup resolvers, where packages from the repository are resolved according to `lockfile`.
+///
+/// If `locked` is set, resolving a package which is not in the lockfile is an error.
+/// Every package resolved from the repository is recorded in `resolved`.
+///
+/// Packages available from paths take precedence, so they are neither recorded nor checked
+/// against the lockfile.
+fn resolvers_with_lockfile(
+    manifest: &Manifest,
+    lockfile: Lockfile,
+    locked: bool,
+    resolved: Rc<RefCell<Lockfile>>,
+) -> Result<Box<Resolver>> {
+    let locked: Box<Resolver> = Box::new(Locked::new(
+        repository(manifest)?,
+        lockfile,
+        locked,
+        resolved,
+    ));
+
+    match path_resolver(manifest)? {
+        Some(paths) => Ok(Box::new(Overrides::new(paths, locked))),
+        None => Ok(locked),
     }
-
-    Ok(Box::new(Resolvers::new(resolvers)))
 }
 
 /// Read the first part of the manifest, to determine the language used.
@@ -233,6 +314,37 @@ where
     F: Fn(&Path) -> Result<()>,
 {
     let resolvers = resolvers(manifest)?;
+    environment_with_resolvers(lang, ctx, manifest, resolvers, path_hook)
+}
+
+/// Resolve all packages required by the manifest, honoring the versions pinned in `lockfile`.
+///
+/// Resolves everything from scratch if `lockfile` is empty.
+/// Returns a new lockfile with every package that was resolved from the repository.
+pub fn resolve_lockfile(
+    lang: &Lang,
+    ctx: Rc<Context>,
+    manifest: &Manifest,
+    lockfile: Lockfile,
+) -> Result<Lockfile> {
+    let resolved = Rc::new(RefCell::new(Lockfile::default()));
+    let resolvers = resolvers_with_lockfile(manifest, lockfile, manifest.locked, resolved.clone())?;
+    environment_with_resolvers(lang, ctx, manifest, resolvers, |_| Ok(()))?;
+    let lockfile = resolved.borrow().clone();
+    Ok(lockfile)
+}
+
+/// Setup environment with the given resolvers.
+fn environment_with_resolvers<F: 'static>(
+    lang: &Lang,
+    ctx: Rc<Context>,
+    manifest: &Manifest,
+    resolvers: Box<Resolver>,
+    path_hook: F,
+) -> Result<Environment<CoreFlavor>>
+where
+    F: Fn(&Path) -> Result<()>,
+{
     let package_prefix = manifest.package_prefix.clone();
 
     let mut env = lang.into_env(ctx, package_prefix, resolvers)
@@ -357,6 +469,8 @@ fn manifest_from_matches(lang: &Lang, manifest: &mut Manifest, matches: &ArgMatc
         manifest.output = Some(out.to_owned());
     }
 
+    manifest.locked = matches.is_present("locked");
+
     repository_from_matches(&mut manifest.repository, matches)?;
     Ok(())
 }
@@ -435,12 +549,17 @@ where
     Ok(results)
 }
 
+/// Check that the given match doesn't violate semantic versioning.
+///
+/// If `wire_only` is set, only violations which break the serialized representation of values are
+/// reported.
 pub fn semck_check(
     ctx: &Context,
     errors: &mut Vec<Error>,
     repository: &mut Repository,
     env: &mut Environment<CoreFlavor>,
     m: &Match,
+    wire_only: bool,
 ) -> Result<()> {
     let Match(ref version, ref source, ref package) = *m;
 
@@ -468,6 +587,11 @@ pub fn semck_check(
 
         let violations = semck::check((&d.version, &file_from), (&version, &file_to))?;
 
+        let violations = violations
+            .into_iter()
+            .filter(|v| !wire_only || v.breaks() == semck::Breaks::Wire)
+            .collect::<Vec<_>>();
+
         if !violations.is_empty() {
             errors.push(Error::new(format!(
                 "Encountered {} semck violation(s)",
@@ -485,9 +609,34 @@ pub fn semck_check(
     return Ok(());
 
     fn handle_violation(report: &mut Reporter, violation: semck::Violation) -> Result<()> {
+        use semck::Component::Major;
         use semck::Violation::*;
 
         match violation {
+            DeclRemoved(Major, reg) => {
+                report.err(
+                    reg,
+                    "major change violation: declaration removed without being deprecated",
+                );
+            }
+            RemoveField(Major, field) => {
+                report.err(
+                    field,
+                    "major change violation: field removed without being deprecated",
+                );
+            }
+            RemoveVariant(Major, variant) => {
+                report.err(
+                    variant,
+                    "major change violation: variant removed without being deprecated",
+                );
+            }
+            RemoveEndpoint(Major, span) => {
+                report.err(
+                    span,
+                    "major change violation: endpoint removed without being deprecated",
+                );
+            }
             DeclRemoved(c, reg) => {
                 report.err(reg, format!("{}: declaration removed", c.describe()));
             }
@@ -506,20 +655,51 @@ pub fn semck_check(
             AddVariant(c, field) => {
                 report.err(field, format!("{}: variant added", c.describe()));
             }
-            FieldTypeChange(c, from_type, from, to_type, to) => {
+            FieldTypeChange(c, breaks, from_type, from, to_type, to) => {
                 report.err(
                     to,
-                    format!("{}: type changed to `{}`", c.describe(), to_type),
+                    format!(
+                        "{} ({}): type changed to `{}`",
+                        c.describe(),
+                        breaks.describe(),
+                        to_type
+                    ),
                 );
                 report.err(from, format!("from `{}`", from_type));
             }
             FieldNameChange(c, from_name, from, to_name, to) => {
                 report.err(
                     to,
-                    format!("{}: name changed to `{}`", c.describe(), to_name),
+                    format!(
+                        "{} (wire-breaking): serialized name changed to `{}`",
+                        c.describe(),
+                        to_name
+                    ),
                 );
                 report.err(from, format!("from `{}`", from_name));
             }
+            FieldIdentChange(c, from_ident, from, to_ident, to) => {
+                report.err(
+                    to,
+                    format!(
+                        "{} (source-breaking): identifier changed to `{}`",
+                        c.describe(),
+                        to_ident
+                    ),
+                );
+                report.err(from, format!("from `{}`", from_ident));
+            }
+            FieldIndexChange(c, from_index, from, to_index, to) => {
+                report.err(
+                    to,
+                    format!(
+                        "{} (wire-breaking): index in tuple changed to `{}`",
+                        c.describe(),
+                        to_index
+                    ),
+                );
+                report.err(from, format!("from `{}`", from_index));
+            }
             VariantOrdinalChange(c, from_ordinal, from, to_ordinal, to) => {
                 report.err(
                     to,
@@ -575,6 +755,58 @@ pub fn semck_check(
                     format!("from `{}`", FmtChannel(from_channel.as_ref())),
                 );
             }
+            EndpointHttpPathChange(c, from_path, from, to_path, to) => {
+                report.err(
+                    to,
+                    format!(
+                        "{}: HTTP path changed to `{}`",
+                        c.describe(),
+                        to_path.as_ref().map(String::as_str).unwrap_or("*empty*")
+                    ),
+                );
+                report.err(
+                    from,
+                    format!(
+                        "from `{}`",
+                        from_path.as_ref().map(String::as_str).unwrap_or("*empty*")
+                    ),
+                );
+            }
+            EndpointHttpMethodChange(c, from_method, from, to_method, to) => {
+                report.err(
+                    to,
+                    format!(
+                        "{}: HTTP method changed to `{}`",
+                        c.describe(),
+                        to_method.as_str()
+                    ),
+                );
+                report.err(from, format!("from `{}`", from_method.as_str()));
+            }
+            EnumTypeChange(c, from_type, from, to_type, to) => {
+                report.err(
+                    to,
+                    format!("{}: enum type changed to `{}`", c.describe(), to_type),
+                );
+                report.err(from, format!("from `{}`", from_type));
+            }
+            AddSubType(c, span) => {
+                report.err(span, format!("{}: sub-type added", c.describe()));
+            }
+            RemoveSubType(c, span) => {
+                report.err(span, format!("{}: sub-type removed", c.describe()));
+            }
+            SubTypeStrategyChange(c, from_strategy, from, to_strategy, to) => {
+                report.err(
+                    to,
+                    format!(
+                        "{}: sub-type strategy changed to {}",
+                        c.describe(),
+                        to_strategy
+                    ),
+                );
+                report.err(from, format!("from {}", from_strategy));
+            }
         }
 
         return Ok(());
@@ -608,13 +840,35 @@ pub fn convert_lang(input: Language) -> Box<Lang> {
         Java => Box::new(::java::JavaLang),
         Js => Box::new(::js::JsLang),
         Json => Box::new(::json::JsonLang),
+        JsonSchema => Box::new(::jsonschema::JsonSchemaLang),
+        Kotlin => Box::new(::kotlin::KotlinLang),
+        OpenApi => Box::new(::openapi::OpenApiLang),
+        Proto => Box::new(::proto::ProtoLang),
         Python => Box::new(::python::PythonLang),
         Reproto => Box::new(::reproto::ReprotoLang),
         Rust => Box::new(::rust::RustLang),
         Swift => Box::new(::swift::SwiftLang),
+        TypeScript => Box::new(::ts::TypeScriptLang),
     }
 }
 
+/// Parse a package with an exact version, like `io.reproto.toystore@1.0.0`.
+pub fn parse_versioned(input: &str) -> Result<RpVersionedPackage> {
+    let mut it = input.splitn(2, '@');
+
+    let package = it.next()
+        .map(RpPackage::parse)
+        .unwrap_or_else(RpPackage::empty);
+
+    let version = it.next()
+        .ok_or_else(|| format!("expected a version, like `{}@1.0.0`", input))?;
+
+    let version =
+        Version::parse(version).map_err(|e| format!("bad version: {}: {}", version, e))?;
+
+    Ok(RpVersionedPackage::new(package, Some(version)))
+}
+
 /// Setup a basic environment falling back to `NoLang` unless one is specified.
 pub fn simple_config(
     ctx: &Rc<Context>,
@@ -14,6 +14,8 @@ pub struct Repository {
     /// URL to objects source.
     /// FIXME: Can't use Url type directly here with `url_serde`, since it's not seen as optional.
     pub objects: Option<String>,
+    /// Token used to authenticate against the repository, when publishing.
+    pub token: Option<String>,
 }
 
 impl Default for Repository {
@@ -21,6 +23,7 @@ impl Default for Repository {
         Repository {
             index: None,
             objects: None,
+            token: None,
         }
     }
 }
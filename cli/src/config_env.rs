@@ -53,6 +53,7 @@ pub struct ConfigEnv {
     pub bin_home: PathBuf,
     pub index: Option<String>,
     pub objects: Option<String>,
+    pub token: Option<String>,
 }
 
 impl ConfigEnv {
@@ -84,6 +85,7 @@ impl ConfigEnv {
 
         let mut index = None;
         let mut objects = None;
+        let mut token = None;
 
         if config.is_file() {
             let config = read_config(&config)?;
@@ -92,6 +94,7 @@ impl ConfigEnv {
                 // set values from configuration (if not already set).
                 index = index.or(repository.index);
                 objects = objects.or(repository.objects);
+                token = token.or(repository.token);
             }
 
             if let Some(out) = config.cache_home {
@@ -113,6 +116,7 @@ impl ConfigEnv {
             bin_home,
             index,
             objects,
+            token,
         }));
     }
 }
@@ -20,10 +20,15 @@ extern crate reproto_backend_go as go;
 extern crate reproto_backend_java as java;
 extern crate reproto_backend_js as js;
 extern crate reproto_backend_json as json;
+extern crate reproto_backend_jsonschema as jsonschema;
+extern crate reproto_backend_kotlin as kotlin;
+extern crate reproto_backend_openapi as openapi;
+extern crate reproto_backend_proto as proto;
 extern crate reproto_backend_python as python;
 extern crate reproto_backend_reproto as reproto;
 extern crate reproto_backend_rust as rust;
 extern crate reproto_backend_swift as swift;
+extern crate reproto_backend_ts as ts;
 extern crate reproto_compile as compile;
 extern crate reproto_core as core;
 extern crate reproto_derive as derive;
@@ -14,6 +14,12 @@ pub fn options<'a, 'b>() -> App<'a, 'b> {
             .help("Override published version with argument"),
     );
 
+    let out = out.arg(
+        Arg::with_name("wire-only")
+            .long("wire-only")
+            .help("Only check for changes which break the serialized representation of values"),
+    );
+
     let out = out.arg(Arg::with_name("package").multiple(true));
 
     out
@@ -51,12 +57,14 @@ pub fn entry(ctx: Rc<Context>, m: &ArgMatches) -> Result<()> {
         &packages,
     )?);
 
+    let wire_only = m.is_present("wire-only");
+
     let mut repository = repository(&manifest)?;
 
     let mut errors = Vec::new();
 
     for m in results {
-        semck_check(&ctx, &mut errors, &mut repository, &mut env, &m)?;
+        semck_check(&ctx, &mut errors, &mut repository, &mut env, &m, wire_only)?;
     }
 
     if errors.len() > 0 {
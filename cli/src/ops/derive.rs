@@ -45,7 +45,7 @@ pub fn options<'a, 'b>() -> App<'a, 'b> {
             .long("format")
             .short("F")
             .takes_value(true)
-            .help("Format to decode, valid values: json, yaml"),
+            .help("Format to decode, valid values: json, yaml, json-schema, proto"),
     );
 
     let out = out.arg(
@@ -72,31 +72,42 @@ pub fn entry(_ctx: Rc<Context>, matches: &ArgMatches) -> Result<()> {
         Some(name) => name.to_string(),
     };
 
-    let package_prefix = match matches.value_of("package-prefix") {
-        None => RpPackage::parse("io.github.reproto"),
-        Some(name) => RpPackage::parse(name),
-    };
-
-    let format: Box<derive::Format> = match matches.value_of("format") {
-        None | Some("json") => Box::new(derive::Json),
-        Some("yaml") => Box::new(derive::Yaml),
-        Some(value) => return Err(format!("Unsupported format: {}", value).into()),
-    };
+    let package_prefix = matches.value_of("package-prefix").map(RpPackage::parse);
 
     let source = match matches.value_of("file") {
         Some(file) => Source::from_path(file),
         None => Source::stdin(),
     };
 
-    let derive = derive::Derive::new(root_name, format, Some(package_prefix.clone()));
+    let (package, decls) = match matches.value_of("format") {
+        // JSON Schema describes declarations, instead of being derived from a sample.
+        Some("json-schema") => (None, derive::import_json_schema(root_name, &source)?),
+        Some("proto") => {
+            let proto = derive::import_proto(&source)?;
+            (proto.package, proto.decls)
+        }
+        format => {
+            let format: Box<derive::Format> = match format {
+                None | Some("json") => Box::new(derive::Json),
+                Some("yaml") => Box::new(derive::Yaml),
+                Some(value) => return Err(format!("Unsupported format: {}", value).into()),
+            };
+
+            let derive = derive::Derive::new(root_name, format, package_prefix.clone());
+            (None, vec![derive::derive(derive, &source)?])
+        }
+    };
 
-    let decl = derive::derive(derive, &source)?;
+    // NB: the package declared in the input is used, unless one is specified.
+    let package_prefix = package_prefix
+        .or(package)
+        .unwrap_or_else(|| RpPackage::parse("io.github.reproto"));
 
     let file = ast::File {
         comment: vec!["Generated from reproto derive CLI".to_string().into()],
         attributes: vec![],
         uses: vec![],
-        decls: vec![decl],
+        decls: decls,
     };
 
     let input = compile::Input::File(
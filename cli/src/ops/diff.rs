@@ -0,0 +1,155 @@
+//! Diff action that lists the changes between two published versions of a package.
+
+use build_spec::{parse_versioned, repository, simple_config};
+use clap::{App, Arg, ArgMatches, SubCommand};
+use core::errors::*;
+use core::flavored::RpFile;
+use core::{Context, CoreFlavor, RpPackageFormat, RpVersionedPackage};
+use repository::Repository;
+use semck;
+use serde_json;
+use std::io::{self, Write};
+use std::rc::Rc;
+use trans::Environment;
+
+/// Changes between two versions, as emitted by `--format json`.
+#[derive(Serialize)]
+struct Diff<'a> {
+    from: String,
+    to: String,
+    changes: &'a [semck::Change],
+}
+
+pub fn options<'a, 'b>() -> App<'a, 'b> {
+    let out = SubCommand::with_name("diff")
+        .about("List changes between two published versions of a package");
+
+    let out = out.arg(
+        Arg::with_name("from")
+            .required(true)
+            .help("Package and version to compare from, like `io.reproto.toystore@1.0.0`"),
+    );
+
+    let out = out.arg(
+        Arg::with_name("to")
+            .required(true)
+            .help("Package and version to compare to, like `io.reproto.toystore@1.1.0`"),
+    );
+
+    let out = out.arg(
+        Arg::with_name("format")
+            .long("format")
+            .takes_value(true)
+            .possible_values(&["human", "json"])
+            .help("Format to list changes in (default: human)."),
+    );
+
+    out
+}
+
+/// Load the published object for the given package.
+fn load(
+    repository: &mut Repository,
+    env: &mut Environment<CoreFlavor>,
+    package: &RpVersionedPackage,
+) -> Result<RpFile> {
+    let deployment = repository
+        .all(&package.package)?
+        .into_iter()
+        .find(|d| Some(&d.version) == package.version.as_ref())
+        .ok_or_else(|| format!("no published version found for: {}", package))?;
+
+    let object = repository
+        .get_object(&deployment)?
+        .ok_or_else(|| format!("No object found for deployment: {:?}", deployment))?;
+
+    let name = RpPackageFormat(&package.package, Some(&deployment.version)).to_string();
+    env.load_object(&object.with_name(name), package)
+}
+
+pub fn entry(ctx: Rc<Context>, m: &ArgMatches) -> Result<()> {
+    let (manifest, mut env) = simple_config(&ctx, m)?;
+
+    let from = parse_versioned(m.value_of("from").ok_or_else(|| "missing <from>")?)?;
+    let to = parse_versioned(m.value_of("to").ok_or_else(|| "missing <to>")?)?;
+
+    let mut repository = repository(&manifest)?;
+
+    let from_file = load(&mut repository, &mut env, &from)?;
+    let to_file = load(&mut repository, &mut env, &to)?;
+
+    let changes = semck::diff(&from_file, &to_file)?;
+
+    let stdout = io::stdout();
+    let mut stdout = stdout.lock();
+
+    match m.value_of("format") {
+        Some("json") => {
+            let diff = Diff {
+                from: from.to_string(),
+                to: to.to_string(),
+                changes: &changes,
+            };
+
+            serde_json::to_writer_pretty(&mut stdout, &diff)?;
+            writeln!(stdout)?;
+        }
+        _ => {
+            for change in &changes {
+                writeln!(
+                    stdout,
+                    "{} {} {}",
+                    change.kind.symbol(),
+                    change.element.describe(),
+                    change.path
+                )?;
+
+                for detail in &change.details {
+                    writeln!(stdout, "    {}", detail)?;
+                }
+            }
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Diff;
+    use semck::{Change, ChangeKind, Element};
+    use serde_json;
+
+    #[test]
+    fn test_json() {
+        let changes = vec![
+            Change {
+                kind: ChangeKind::Added,
+                element: Element::Field,
+                path: "Toy.color".to_string(),
+                details: vec![],
+            },
+            Change {
+                kind: ChangeKind::Changed,
+                element: Element::Field,
+                path: "Toy.name".to_string(),
+                details: vec!["changed to be optional".to_string()],
+            },
+        ];
+
+        let diff = Diff {
+            from: "io.reproto.toystore-1.0.0".to_string(),
+            to: "io.reproto.toystore-1.1.0".to_string(),
+            changes: &changes,
+        };
+
+        let expected = concat!(
+            r#"{"from":"io.reproto.toystore-1.0.0","to":"io.reproto.toystore-1.1.0","changes":["#,
+            r#"{"kind":"added","element":"field","path":"Toy.color"},"#,
+            r#"{"kind":"changed","element":"field","path":"Toy.name","#,
+            r#""details":["changed to be optional"]}]}"#
+        );
+
+        assert_eq!(expected, serde_json::to_string(&diff).unwrap());
+    }
+}
@@ -0,0 +1,71 @@
+//! Info action that shows the published versions of a package.
+
+use build_spec::{repository, simple_config};
+use clap::{App, Arg, ArgMatches, SubCommand};
+use core::errors::*;
+use core::{Context, RpPackage, RpPackageFormat, RpVersionedPackage};
+use std::io::{self, Write};
+use std::rc::Rc;
+
+pub fn options<'a, 'b>() -> App<'a, 'b> {
+    let out = SubCommand::with_name("info").about("Show information about a published package");
+
+    let out = out.arg(
+        Arg::with_name("package")
+            .required(true)
+            .help("Package to show information about, like `io.reproto.toystore`"),
+    );
+
+    out
+}
+
+pub fn entry(ctx: Rc<Context>, m: &ArgMatches) -> Result<()> {
+    let (manifest, mut env) = simple_config(&ctx, m)?;
+
+    let package = RpPackage::parse(m.value_of("package").ok_or_else(|| "missing <package>")?);
+
+    let mut repository = repository(&manifest)?;
+
+    let deployments = repository.all(&package)?;
+
+    let latest = deployments
+        .iter()
+        .filter(|d| !d.yanked)
+        .last()
+        .cloned()
+        .ok_or_else(|| format!("no published versions found for: {}", package))?;
+
+    let stdout = io::stdout();
+    let mut stdout = stdout.lock();
+
+    writeln!(stdout, "{}", package)?;
+    writeln!(stdout, "versions:")?;
+
+    for deployment in &deployments {
+        if deployment.yanked {
+            writeln!(
+                stdout,
+                "  {} {} (yanked)",
+                deployment.version, deployment.object
+            )?;
+        } else {
+            writeln!(stdout, "  {} {}", deployment.version, deployment.object)?;
+        }
+    }
+
+    let object = repository
+        .get_object(&latest)?
+        .ok_or_else(|| format!("No object found for deployment: {:?}", latest))?;
+
+    let name = RpPackageFormat(&package, Some(&latest.version)).to_string();
+    let versioned = RpVersionedPackage::new(package, Some(latest.version.clone()));
+    let file = env.load_object(&object.with_name(name), &versioned)?;
+
+    writeln!(stdout, "declarations ({}):", latest.version)?;
+
+    for decl in file.for_each_decl() {
+        writeln!(stdout, "  {}", decl)?;
+    }
+
+    Ok(())
+}
@@ -1,13 +1,17 @@
 mod build;
 mod check;
 mod derive;
+mod diff;
 mod doc;
+mod info;
 mod init;
 mod publish;
 mod repo;
+mod search;
 mod self_update;
 mod update;
 mod watch;
+mod yank;
 
 use clap::{App, Arg, ArgMatches};
 use core::errors::*;
@@ -31,6 +35,13 @@ pub fn base_args<'a, 'b>(out: App<'a, 'b>) -> App<'a, 'b> {
             .help("Completely disable repository operations"),
     );
 
+    let out = out.arg(
+        Arg::with_name("locked")
+            .long("locked")
+            .takes_value(false)
+            .help("Require that packages are resolved exactly as recorded in reproto.lock"),
+    );
+
     let out = out.arg(
         Arg::with_name("objects")
             .long("objects")
@@ -121,10 +132,14 @@ pub fn options<'a, 'b>(out: App<'a, 'b>) -> App<'a, 'b> {
     let out = out.subcommand(build_args(doc::options()));
     let out = out.subcommand(build_args(watch::options()));
     let out = out.subcommand(base_args(check::options()));
+    let out = out.subcommand(base_args(diff::options()));
     let out = out.subcommand(base_args(publish::options()));
     let out = out.subcommand(base_args(update::options()));
     let out = out.subcommand(base_args(self_update::options()));
     let out = out.subcommand(base_args(repo::options()));
+    let out = out.subcommand(base_args(search::options()));
+    let out = out.subcommand(base_args(info::options()));
+    let out = out.subcommand(base_args(yank::options()));
     let out = out.subcommand(derive::options());
     let out = out.subcommand(init::options());
     out
@@ -138,13 +153,17 @@ pub fn entry(ctx: Rc<Context>, matches: &ArgMatches, output: &Output) -> Result<
         "build" => return build::entry(ctx, matches),
         "check" => return check::entry(ctx, matches),
         "derive" => return derive::entry(ctx, matches),
+        "diff" => return diff::entry(ctx, matches),
         "doc" => return doc::entry(ctx, matches),
+        "info" => return info::entry(ctx, matches),
         "init" => return init::entry(ctx, matches),
         "publish" => return publish::entry(ctx, matches),
         "repo" => return repo::entry(ctx, matches),
+        "search" => return search::entry(ctx, matches),
         "self-update" => return self_update::entry(ctx, matches),
         "update" => return update::entry(ctx, matches),
         "watch" => return watch::entry(ctx, matches, output),
+        "yank" => return yank::entry(ctx, matches),
         _ => {}
     }
 
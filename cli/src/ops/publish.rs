@@ -33,6 +33,12 @@ pub fn options<'a, 'b>() -> App<'a, 'b> {
             .help("Override published version with argument"),
     );
 
+    let out = out.arg(
+        Arg::with_name("wire-only")
+            .long("wire-only")
+            .help("Only check for changes which break the serialized representation of values"),
+    );
+
     let out = out.arg(Arg::with_name("package").multiple(true));
 
     out
@@ -75,6 +81,7 @@ pub fn entry(ctx: Rc<Context>, m: &ArgMatches) -> Result<()> {
     let force = m.is_present("force");
     let pretend = m.is_present("pretend");
     let no_semck = m.is_present("no-semck");
+    let wire_only = m.is_present("wire-only");
 
     let mut repository = repository(&manifest)?;
 
@@ -82,7 +89,14 @@ pub fn entry(ctx: Rc<Context>, m: &ArgMatches) -> Result<()> {
     let mut semck_errors = Vec::new();
 
     for m in &results {
-        semck_check(&ctx, &mut semck_errors, &mut repository, &mut env, &m)?;
+        semck_check(
+            &ctx,
+            &mut semck_errors,
+            &mut repository,
+            &mut env,
+            &m,
+            wire_only,
+        )?;
     }
 
     if semck_errors.len() > 0 {
@@ -0,0 +1,48 @@
+//! Search action that lists packages in the repository matching a query.
+
+use build_spec::{repository, simple_config};
+use clap::{App, Arg, ArgMatches, SubCommand};
+use core::errors::*;
+use core::Context;
+use std::io::{self, Write};
+use std::rc::Rc;
+
+pub fn options<'a, 'b>() -> App<'a, 'b> {
+    let out = SubCommand::with_name("search").about("Search for packages in the repository");
+
+    let out = out.arg(
+        Arg::with_name("query")
+            .required(true)
+            .help("Query to search for, matching any part of the package name"),
+    );
+
+    out
+}
+
+pub fn entry(ctx: Rc<Context>, m: &ArgMatches) -> Result<()> {
+    let (manifest, _) = simple_config(&ctx, m)?;
+
+    let query = m.value_of("query")
+        .ok_or_else(|| "missing <query>")?
+        .to_lowercase();
+
+    let repository = repository(&manifest)?;
+
+    let stdout = io::stdout();
+    let mut stdout = stdout.lock();
+
+    for package in repository.packages()? {
+        if !package.to_string().to_lowercase().contains(query.as_str()) {
+            continue;
+        }
+
+        let deployments = repository.all(&package)?;
+
+        match deployments.iter().filter(|d| !d.yanked).last() {
+            Some(latest) => writeln!(stdout, "{}@{}", package, latest.version)?,
+            None => writeln!(stdout, "{}", package)?,
+        }
+    }
+
+    Ok(())
+}
@@ -1,20 +1,21 @@
-//! Update action that synchronizes all repositories.
+//! Update action that synchronizes all repositories, and refreshes the lockfile.
 
-use build_spec::{convert_lang, manifest, manifest_preamble, repository};
+use build_spec::{convert_lang, lockfile_path, manifest, manifest_preamble, repository,
+                 resolve_lockfile};
 use clap::{App, ArgMatches, SubCommand};
 use core::errors::*;
 use core::Context;
 use manifest::NoLang;
-use repository::Update;
+use repository::{read_lockfile, write_lockfile, Lockfile, Update};
 use std::collections::HashSet;
 use std::rc::Rc;
 
 pub fn options<'a, 'b>() -> App<'a, 'b> {
-    let out = SubCommand::with_name("update").about("Update local repository");
+    let out = SubCommand::with_name("update").about("Update local repository and lockfile");
     out
 }
 
-pub fn entry(_ctx: Rc<Context>, matches: &ArgMatches) -> Result<()> {
+pub fn entry(ctx: Rc<Context>, matches: &ArgMatches) -> Result<()> {
     let preamble = manifest_preamble(matches)?;
 
     let lang = preamble
@@ -32,5 +33,33 @@ pub fn entry(_ctx: Rc<Context>, matches: &ArgMatches) -> Result<()> {
         update.update()?;
     }
 
+    // Only projects with a manifest have a lockfile.
+    let has_manifest = manifest.path.as_ref().map(|p| p.is_file()).unwrap_or(false);
+
+    if let Some(path) = lockfile_path(&manifest) {
+        if has_manifest && !manifest.repository.no_repository {
+            if manifest.locked {
+                let existing = read_lockfile(&path)?.ok_or_else(|| {
+                    format!("{}: missing, but resolution is locked", path.display())
+                })?;
+
+                // fails if any package is not pinned, or if a pinned version is unavailable.
+                let lockfile = resolve_lockfile(lang.as_ref(), ctx, &manifest, existing.clone())?;
+
+                if lockfile != existing {
+                    return Err(format!(
+                        "{}: resolution would change, but resolution is locked",
+                        path.display()
+                    ).into());
+                }
+            } else {
+                let lockfile =
+                    resolve_lockfile(lang.as_ref(), ctx, &manifest, Lockfile::default())?;
+                write_lockfile(&path, &lockfile)?;
+                info!("updated: {}", path.display());
+            }
+        }
+    }
+
     Ok(())
 }
@@ -0,0 +1,37 @@
+//! Yank action that marks a published version of a package as yanked.
+
+use build_spec::{parse_versioned, repository, simple_config};
+use clap::{App, Arg, ArgMatches, SubCommand};
+use core::errors::*;
+use core::Context;
+use std::rc::Rc;
+
+pub fn options<'a, 'b>() -> App<'a, 'b> {
+    let out = SubCommand::with_name("yank")
+        .about("Yank a published version, preventing it from being used in new resolutions");
+
+    let out = out.arg(
+        Arg::with_name("package")
+            .required(true)
+            .help("Package and version to yank, like `io.reproto.toystore@1.0.0`"),
+    );
+
+    out
+}
+
+pub fn entry(ctx: Rc<Context>, m: &ArgMatches) -> Result<()> {
+    let (manifest, _) = simple_config(&ctx, m)?;
+
+    let package = parse_versioned(m.value_of("package").ok_or_else(|| "missing <package>")?)?;
+
+    let version = package
+        .version
+        .as_ref()
+        .ok_or_else(|| format!("expected a version: {}", package))?;
+
+    let mut repository = repository(&manifest)?;
+
+    info!("yanking: {}", package);
+    repository.yank(&package.package, version)?;
+    Ok(())
+}
@@ -28,7 +28,7 @@ var reprotoHighlightRules = function() {
     { token: 'comment.line.double-slash.reproto',
       regex: '//.*$' },
     { token: 'storage.type.reproto',
-      regex: '\\b(any|float|double|boolean|string|bytes|datetime|u32|u64|i32|i64)\\b' },
+      regex: '\\b(any|float|double|boolean|string|bytes|datetime|date|time|duration|uuid|u32|u64|i32|i64)\\b' },
     { token: 'storage.modifier.reproto',
       regex: '\\b(?:stream)\\b' },
     { token : "paren.lparen", regex : /[\[({]/ },
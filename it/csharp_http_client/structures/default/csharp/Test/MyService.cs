@@ -0,0 +1,131 @@
+using Newtonsoft.Json;
+using System;
+using System.Net.Http;
+using System.Text;
+using System.Threading.Tasks;
+
+namespace Test {
+  public abstract class MyService {
+    /// <summary>
+    /// UNKNOWN
+    /// </summary>
+    public abstract void Unknown(UInt32 id);
+
+    /// <summary>
+    /// UNKNOWN
+    /// </summary>
+    public abstract Task<Entry> UnknownReturn(UInt32 id);
+
+    /// <summary>
+    /// UNKNOWN
+    /// </summary>
+    public abstract void UnknownArgument(Entry request, UInt32 id);
+
+    /// <summary>
+    /// UNARY
+    /// </summary>
+    public abstract Task<Entry> Unary(Entry request, UInt32 id);
+
+    /// <summary>
+    /// SERVER_STREMAING (ignored)
+    /// </summary>
+    public abstract Task<Entry> ServerStreaming(Entry request);
+
+    /// <summary>
+    /// CLIENT_STREAMING (ignored)
+    /// </summary>
+    public abstract Task<Entry> ClientStreaming(Entry request);
+
+    /// <summary>
+    /// BIDI_STREAMING (ignored)
+    /// </summary>
+    public abstract Task<Entry> BidiStreaming(Entry request);
+
+    public class HttpClient {
+      private System.Net.Http.HttpClient client;
+      private Uri baseUrl;
+
+      public HttpClient(
+        System.Net.Http.HttpClient client,
+        Uri baseUrl
+      ) {
+        this.client = client;
+        this.baseUrl = baseUrl ?? new Uri("http://example.com");
+      }
+
+      /// <summary>
+      /// UNKNOWN
+      /// </summary>
+      public async Task Unknown(UInt32 id) {
+        var path_ = new StringBuilder(this.baseUrl.ToString().TrimEnd('/'));
+        path_.Append("/");
+        path_.Append("unknown");
+        path_.Append("/");
+        path_.Append(Uri.EscapeDataString(id.ToString()));
+
+        var req_ = new HttpRequestMessage(new HttpMethod("GET"), path_.ToString());
+
+        using (var res_ = await this.client.SendAsync(req_)) {
+          res_.EnsureSuccessStatusCode();
+        }
+      }
+
+      /// <summary>
+      /// UNKNOWN
+      /// </summary>
+      public async Task<Entry> UnknownReturn(UInt32 id) {
+        var path_ = new StringBuilder(this.baseUrl.ToString().TrimEnd('/'));
+        path_.Append("/");
+        path_.Append("unknown-return");
+        path_.Append("/");
+        path_.Append(Uri.EscapeDataString(id.ToString()));
+
+        var req_ = new HttpRequestMessage(new HttpMethod("GET"), path_.ToString());
+
+        using (var res_ = await this.client.SendAsync(req_)) {
+          res_.EnsureSuccessStatusCode();
+          var body_ = await res_.Content.ReadAsStringAsync();
+          return JsonConvert.DeserializeObject<Entry>(body_);
+        }
+      }
+
+      /// <summary>
+      /// UNKNOWN
+      /// </summary>
+      public async Task UnknownArgument(Entry request, UInt32 id) {
+        var path_ = new StringBuilder(this.baseUrl.ToString().TrimEnd('/'));
+        path_.Append("/");
+        path_.Append("unknown-argument");
+        path_.Append("/");
+        path_.Append(Uri.EscapeDataString(id.ToString()));
+
+        var req_ = new HttpRequestMessage(new HttpMethod("GET"), path_.ToString());
+        req_.Content = new StringContent(JsonConvert.SerializeObject(request), Encoding.UTF8, "application/json");
+
+        using (var res_ = await this.client.SendAsync(req_)) {
+          res_.EnsureSuccessStatusCode();
+        }
+      }
+
+      /// <summary>
+      /// UNARY
+      /// </summary>
+      public async Task<Entry> Unary(Entry request, UInt32 id) {
+        var path_ = new StringBuilder(this.baseUrl.ToString().TrimEnd('/'));
+        path_.Append("/");
+        path_.Append("unary");
+        path_.Append("/");
+        path_.Append(Uri.EscapeDataString(id.ToString()));
+
+        var req_ = new HttpRequestMessage(new HttpMethod("GET"), path_.ToString());
+        req_.Content = new StringContent(JsonConvert.SerializeObject(request), Encoding.UTF8, "application/json");
+
+        using (var res_ = await this.client.SendAsync(req_)) {
+          res_.EnsureSuccessStatusCode();
+          var body_ = await res_.Content.ReadAsStringAsync();
+          return JsonConvert.DeserializeObject<Entry>(body_);
+        }
+      }
+    }
+  }
+}
@@ -0,0 +1,84 @@
+import datetime
+import uuid
+
+class Entry:
+  def __init__(self, date_type, time_type, duration_type, uuid_type):
+    self.date_type = date_type
+    self.time_type = time_type
+    self.duration_type = duration_type
+    self.uuid_type = uuid_type
+
+  def get_date_type(self):
+    return self.date_type
+
+  def get_time_type(self):
+    return self.time_type
+
+  def get_duration_type(self):
+    return self.duration_type
+
+  def get_uuid_type(self):
+    return self.uuid_type
+
+  @staticmethod
+  def decode(data):
+    f_date_type = None
+
+    if "date_type" in data:
+      f_date_type = data["date_type"]
+
+      if f_date_type is not None:
+        if not isinstance(f_date_type, unicode):
+          raise Exception("not a string")
+        f_date_type = datetime.datetime.strptime(f_date_type, "%Y-%m-%d").date()
+
+    f_time_type = None
+
+    if "time_type" in data:
+      f_time_type = data["time_type"]
+
+      if f_time_type is not None:
+        if not isinstance(f_time_type, unicode):
+          raise Exception("not a string")
+        f_time_type = datetime.datetime.strptime(f_time_type, "%H:%M:%S.%f" if "." in f_time_type else "%H:%M:%S").time()
+
+    f_duration_type = None
+
+    if "duration_type" in data:
+      f_duration_type = data["duration_type"]
+
+      if f_duration_type is not None:
+        if not isinstance(f_duration_type, unicode):
+          raise Exception("not a string")
+
+    f_uuid_type = None
+
+    if "uuid_type" in data:
+      f_uuid_type = data["uuid_type"]
+
+      if f_uuid_type is not None:
+        if not isinstance(f_uuid_type, unicode):
+          raise Exception("not a string")
+        f_uuid_type = uuid.UUID(f_uuid_type)
+
+    return Entry(f_date_type, f_time_type, f_duration_type, f_uuid_type)
+
+  def encode(self):
+    data = dict()
+
+    if self.date_type is not None:
+      data["date_type"] = self.date_type.isoformat()
+
+    if self.time_type is not None:
+      data["time_type"] = self.time_type.isoformat()
+
+    if self.duration_type is not None:
+      data["duration_type"] = self.duration_type
+
+    if self.uuid_type is not None:
+      data["uuid_type"] = str(self.uuid_type)
+
+    return data
+
+  def __repr__(self):
+    return "<Entry date_type:{!r}, time_type:{!r}, duration_type:{!r}, uuid_type:{!r}>".format(self.date_type, self.time_type, self.duration_type, self.uuid_type)
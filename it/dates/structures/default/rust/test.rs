@@ -0,0 +1,17 @@
+use chrono;
+use uuid;
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Entry {
+  #[serde(skip_serializing_if="Option::is_none")]
+  pub date_type: Option<chrono::NaiveDate>,
+
+  #[serde(skip_serializing_if="Option::is_none")]
+  pub time_type: Option<chrono::NaiveTime>,
+
+  #[serde(skip_serializing_if="Option::is_none")]
+  pub duration_type: Option<String>,
+
+  #[serde(skip_serializing_if="Option::is_none")]
+  pub uuid_type: Option<uuid::Uuid>,
+}
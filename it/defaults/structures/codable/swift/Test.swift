@@ -0,0 +1,19 @@
+public struct Test_Entry: Codable {
+  let limit: UInt32?
+  let name: String?
+
+  enum CodingKeys: String, CodingKey {
+    case limit = "limit"
+    case name = "name"
+  }
+}
+
+public extension Test_Entry {
+  init(from decoder: Decoder) throws {
+    let values = try decoder.container(keyedBy: CodingKeys.self)
+
+    self.limit = try values.decodeIfPresent(UInt32.self, forKey: .limit) ?? 10
+
+    self.name = try values.decodeIfPresent(String.self, forKey: .name) ?? "all"
+  }
+}
@@ -0,0 +1,75 @@
+using Newtonsoft.Json;
+using System;
+using System.ComponentModel;
+using System.Text;
+
+namespace Test {
+  [JsonObject(ItemNullValueHandling = NullValueHandling.Ignore)]
+  public class Entry {
+    [JsonProperty("limit", DefaultValueHandling = DefaultValueHandling.Populate)]
+    [DefaultValue(10U)]
+    public UInt32? limit {
+      get;
+    }
+    [JsonProperty("name", DefaultValueHandling = DefaultValueHandling.Populate)]
+    [DefaultValue("all")]
+    public String name {
+      get;
+    }
+
+    [JsonConstructor]
+    public Entry(
+      [JsonProperty("limit", DefaultValueHandling = DefaultValueHandling.Populate)] [DefaultValue(10U)] UInt32? limit,
+      [JsonProperty("name", DefaultValueHandling = DefaultValueHandling.Populate)] [DefaultValue("all")] String name
+    ) {
+      this.limit = limit;
+      this.name = name;
+    }
+
+    public override Int32 GetHashCode() {
+      Int32 result = 1;
+      result = result * 31 + this.limit.GetHashCode();
+      result = result * 31 + this.name.GetHashCode();
+      return result;
+    }
+
+    public override Boolean Equals(Object other) {
+      Entry o = other as Entry;
+
+      if (o == null) {
+        return false;
+      }
+
+      if (!this.limit.Equals(o.limit)) {
+        return false;
+      }
+
+      if (this.name == null) {
+        if (o.name != null) {
+          return false;
+        }
+      } else {
+        if (!this.name.Equals(o.name)) {
+          return false;
+        }
+      }
+
+      return true;
+    }
+
+    public override String ToString() {
+      StringBuilder b = new StringBuilder();
+
+      b.Append("Entry");
+      b.Append("(");
+      b.Append("limit=");
+      b.Append(this.limit);
+      b.Append(", ");
+      b.Append("name=");
+      b.Append(this.name);
+      b.Append(")");
+
+      return b.ToString();
+    }
+  }
+}
@@ -0,0 +1,26 @@
+package test
+
+import "encoding/json"
+
+type Entry struct {
+  Limit *uint32 `json:"limit,omitempty"`
+
+  Name *string `json:"name,omitempty"`
+}
+
+func (this *Entry) UnmarshalJSON(b []byte) error {
+  type alias Entry
+  data := alias{}
+
+  v0 := uint32(10)
+  data.Limit = &v0
+  v1 := string("all")
+  data.Name = &v1
+
+  if err := json.Unmarshal(b, &data); err != nil {
+    return err
+  }
+
+  *this = Entry(data)
+  return nil
+}
@@ -0,0 +1,104 @@
+package test;
+
+import com.fasterxml.jackson.annotation.JsonCreator;
+import com.fasterxml.jackson.annotation.JsonProperty;
+import java.util.Objects;
+import java.util.Optional;
+
+public class Entry {
+  @JsonProperty("limit")
+  private final Optional<Integer> limit;
+  @JsonProperty("name")
+  private final Optional<String> name;
+
+  @JsonCreator
+  public Entry(
+    @JsonProperty("limit") final Optional<Integer> limit,
+    @JsonProperty("name") final Optional<String> name
+  ) {
+    Objects.requireNonNull(limit, "limit");
+    this.limit = limit.isPresent() ? limit : Optional.of(10);
+    Objects.requireNonNull(name, "name");
+    this.name = name.isPresent() ? name : Optional.of("all");
+  }
+
+  @JsonProperty("limit")
+  public Optional<Integer> getLimit() {
+    return this.limit;
+  }
+
+  @JsonProperty("name")
+  public Optional<String> getName() {
+    return this.name;
+  }
+
+  @Override
+  public int hashCode() {
+    int result = 1;
+    result = result * 31 + this.limit.hashCode();
+    result = result * 31 + this.name.hashCode();
+    return result;
+  }
+
+  @Override
+  public boolean equals(final Object other) {
+    if (other == null) {
+      return false;
+    }
+
+    if (!(other instanceof Entry)) {
+      return false;
+    }
+
+    @SuppressWarnings("unchecked")
+    final Entry o = (Entry) other;
+
+    if (!this.limit.equals(o.limit)) {
+      return false;
+    }
+
+    if (!this.name.equals(o.name)) {
+      return false;
+    }
+
+    return true;
+  }
+
+  @Override
+  public String toString() {
+    final StringBuilder b = new StringBuilder();
+
+    b.append("Entry");
+    b.append("(");
+    b.append("limit=");
+    b.append(this.limit.toString());
+    b.append(", ");
+    b.append("name=");
+    b.append(this.name.toString());
+    b.append(")");
+
+    return b.toString();
+  }
+
+  public static class Builder {
+    private Optional<Integer> limit = Optional.empty();
+    private Optional<String> name = Optional.empty();
+
+    public Builder limit(final int limit) {
+      this.limit = Optional.of(limit);
+      return this;
+    }
+
+    public Builder name(final String name) {
+      this.name = Optional.of(name);
+      return this;
+    }
+
+    public Entry build() {
+      final Optional<Integer> limit = this.limit;
+      final Optional<String> name = this.name;
+
+      return new Entry(limit, name);
+    }
+  }
+}
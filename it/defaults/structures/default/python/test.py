@@ -0,0 +1,46 @@
+class Entry:
+  def __init__(self, limit, name):
+    self.limit = limit
+    self.name = name
+
+  def get_limit(self):
+    return self.limit
+
+  def get_name(self):
+    return self.name
+
+  @staticmethod
+  def decode(data):
+    f_limit = 10
+
+    if "limit" in data:
+      f_limit = data["limit"]
+
+      if f_limit is not None:
+        if not isinstance(f_limit, int):
+          raise Exception("not an integer")
+
+    f_name = "all"
+
+    if "name" in data:
+      f_name = data["name"]
+
+      if f_name is not None:
+        if not isinstance(f_name, unicode):
+          raise Exception("not a string")
+
+    return Entry(f_limit, f_name)
+
+  def encode(self):
+    data = dict()
+
+    if self.limit is not None:
+      data["limit"] = self.limit
+
+    if self.name is not None:
+      data["name"] = self.name
+
+    return data
+
+  def __repr__(self):
+    return "<Entry limit:{!r}, name:{!r}>".format(self.limit, self.name)
@@ -0,0 +1,20 @@
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Entry {
+  #[serde(default = "Entry::default_limit")]
+  #[serde(skip_serializing_if="Option::is_none")]
+  pub limit: Option<u32>,
+
+  #[serde(default = "Entry::default_name")]
+  #[serde(skip_serializing_if="Option::is_none")]
+  pub name: Option<String>,
+}
+
+impl Entry {
+  fn default_limit() -> Option<u32> {
+    Some(10)
+  }
+
+  fn default_name() -> Option<String> {
+    Some("all".to_string())
+  }
+}
@@ -0,0 +1,37 @@
+public struct Test_Entry {
+  let limit: UInt32?
+  let name: String?
+}
+
+public extension Test_Entry {
+  static func decode(json: Any) throws -> Test_Entry {
+    let json = try decode_value(json as? [String: Any])
+
+    var limit: UInt32? = Optional.some(10)
+
+    if let value = json["limit"] {
+      limit = Optional.some(try decode_name(unbox(value, as: UInt32.self), name: "limit"))
+    }
+
+    var name: String? = Optional.some("all")
+
+    if let value = json["name"] {
+      name = Optional.some(try decode_name(unbox(value, as: String.self), name: "name"))
+    }
+
+    return Test_Entry(limit: limit, name: name)
+  }
+
+  func encode() throws -> [String: Any] {
+    var json = [String: Any]()
+
+    if let value = self.limit {
+      json["limit"] = value
+    }
+    if let value = self.name {
+      json["name"] = value
+    }
+
+    return json
+  }
+}
@@ -0,0 +1,334 @@
+package test
+
+import "bytes"
+import "encoding/json"
+import "errors"
+import "fmt"
+import "net/http"
+import "net/url"
+import "regexp"
+import "strings"
+
+type Entry struct {
+}
+
+// HTTP client for MyService.
+type MyService_Client struct {
+  client *http.Client
+  url string
+}
+
+func NewMyService_Client(client *http.Client, url string) *MyService_Client {
+  if url == "" {
+    url = "http://example.com"
+  }
+  return &MyService_Client{client: client, url: url}
+}
+
+// UNKNOWN
+func (this *MyService_Client) Unknown(id uint32) error {
+  var path bytes.Buffer
+  path.WriteString(this.url)
+  path.WriteString("/")
+  path.WriteString("unknown")
+  path.WriteString("/")
+  path.WriteString(url.PathEscape(fmt.Sprint(id)))
+
+  req, err := http.NewRequest("GET", path.String(), nil)
+  if err != nil {
+    return err
+  }
+
+  res, err := this.client.Do(req)
+  if err != nil {
+    return err
+  }
+
+  defer res.Body.Close()
+
+  if res.StatusCode < 200 || res.StatusCode >= 300 {
+    return errors.New("bad response: " + res.Status)
+  }
+
+  return nil
+}
+
+// UNKNOWN
+func (this *MyService_Client) UnknownReturn(id uint32) (*Entry, error) {
+  var path bytes.Buffer
+  path.WriteString(this.url)
+  path.WriteString("/")
+  path.WriteString("unknown-return")
+  path.WriteString("/")
+  path.WriteString(url.PathEscape(fmt.Sprint(id)))
+
+  req, err := http.NewRequest("GET", path.String(), nil)
+  if err != nil {
+    return nil, err
+  }
+
+  res, err := this.client.Do(req)
+  if err != nil {
+    return nil, err
+  }
+
+  defer res.Body.Close()
+
+  if res.StatusCode < 200 || res.StatusCode >= 300 {
+    return nil, errors.New("bad response: " + res.Status)
+  }
+
+  var response Entry
+
+  if err := json.NewDecoder(res.Body).Decode(&response); err != nil {
+    return nil, err
+  }
+
+  return &response, nil
+}
+
+// UNKNOWN
+func (this *MyService_Client) UnknownArgument(request Entry, id uint32) error {
+  var path bytes.Buffer
+  path.WriteString(this.url)
+  path.WriteString("/")
+  path.WriteString("unknown-argument")
+  path.WriteString("/")
+  path.WriteString(url.PathEscape(fmt.Sprint(id)))
+
+  body, err := json.Marshal(request)
+  if err != nil {
+    return err
+  }
+
+  req, err := http.NewRequest("GET", path.String(), bytes.NewReader(body))
+  if err != nil {
+    return err
+  }
+
+  req.Header.Set("Content-Type", "application/json")
+
+  res, err := this.client.Do(req)
+  if err != nil {
+    return err
+  }
+
+  defer res.Body.Close()
+
+  if res.StatusCode < 200 || res.StatusCode >= 300 {
+    return errors.New("bad response: " + res.Status)
+  }
+
+  return nil
+}
+
+// UNARY
+func (this *MyService_Client) Unary(request Entry, id uint32) (*Entry, error) {
+  var path bytes.Buffer
+  path.WriteString(this.url)
+  path.WriteString("/")
+  path.WriteString("unary")
+  path.WriteString("/")
+  path.WriteString(url.PathEscape(fmt.Sprint(id)))
+
+  body, err := json.Marshal(request)
+  if err != nil {
+    return nil, err
+  }
+
+  req, err := http.NewRequest("GET", path.String(), bytes.NewReader(body))
+  if err != nil {
+    return nil, err
+  }
+
+  req.Header.Set("Content-Type", "application/json")
+
+  res, err := this.client.Do(req)
+  if err != nil {
+    return nil, err
+  }
+
+  defer res.Body.Close()
+
+  if res.StatusCode < 200 || res.StatusCode >= 300 {
+    return nil, errors.New("bad response: " + res.Status)
+  }
+
+  var response Entry
+
+  if err := json.NewDecoder(res.Body).Decode(&response); err != nil {
+    return nil, err
+  }
+
+  return &response, nil
+}
+
+// Server-side handler for MyService.
+type MyService_Handler interface {
+  // UNKNOWN
+  Unknown(id uint32) error
+
+  // UNKNOWN
+  UnknownReturn(id uint32) (*Entry, error)
+
+  // UNKNOWN
+  UnknownArgument(request Entry, id uint32) error
+
+  // UNARY
+  Unary(request Entry, id uint32) (*Entry, error)
+}
+
+// Router for MyService which implements http.Handler.
+type MyService_Router struct {
+  handler MyService_Handler
+  prefix string
+  pathUnknown *regexp.Regexp
+  pathUnknownReturn *regexp.Regexp
+  pathUnknownArgument *regexp.Regexp
+  pathUnary *regexp.Regexp
+}
+
+func NewMyService_Router(handler MyService_Handler, prefix string) *MyService_Router {
+  return &MyService_Router{
+    handler: handler,
+    prefix: prefix,
+    pathUnknown: regexp.MustCompile("^/unknown/([^/]+)$"),
+    pathUnknownReturn: regexp.MustCompile("^/unknown-return/([^/]+)$"),
+    pathUnknownArgument: regexp.MustCompile("^/unknown-argument/([^/]+)$"),
+    pathUnary: regexp.MustCompile("^/unary/([^/]+)$"),
+  }
+}
+
+func (this *MyService_Router) ServeHTTP(w http.ResponseWriter, r *http.Request) {
+  if !strings.HasPrefix(r.URL.Path, this.prefix) {
+    http.NotFound(w, r)
+    return
+  }
+
+  path := r.URL.Path[len(this.prefix):]
+  var allow []string
+
+  if m := this.pathUnknown.FindStringSubmatch(path); m != nil {
+    if r.Method == "GET" {
+      this.serveUnknown(w, r, m)
+      return
+    }
+
+    allow = append(allow, "GET")
+  }
+
+  if m := this.pathUnknownReturn.FindStringSubmatch(path); m != nil {
+    if r.Method == "GET" {
+      this.serveUnknownReturn(w, r, m)
+      return
+    }
+
+    allow = append(allow, "GET")
+  }
+
+  if m := this.pathUnknownArgument.FindStringSubmatch(path); m != nil {
+    if r.Method == "GET" {
+      this.serveUnknownArgument(w, r, m)
+      return
+    }
+
+    allow = append(allow, "GET")
+  }
+
+  if m := this.pathUnary.FindStringSubmatch(path); m != nil {
+    if r.Method == "GET" {
+      this.serveUnary(w, r, m)
+      return
+    }
+
+    allow = append(allow, "GET")
+  }
+
+  if len(allow) > 0 {
+    w.Header().Set("Allow", strings.Join(allow, ", "))
+    http.Error(w, http.StatusText(http.StatusMethodNotAllowed), http.StatusMethodNotAllowed)
+    return
+  }
+
+  http.NotFound(w, r)
+}
+
+func (this *MyService_Router) serveUnknown(w http.ResponseWriter, r *http.Request, m []string) {
+  var id uint32
+  if _, err := fmt.Sscan(m[1], &id); err != nil {
+    http.Error(w, err.Error(), http.StatusBadRequest)
+    return
+  }
+
+  err := this.handler.Unknown(id)
+  if err != nil {
+    http.Error(w, err.Error(), http.StatusInternalServerError)
+    return
+  }
+}
+
+func (this *MyService_Router) serveUnknownReturn(w http.ResponseWriter, r *http.Request, m []string) {
+  var id uint32
+  if _, err := fmt.Sscan(m[1], &id); err != nil {
+    http.Error(w, err.Error(), http.StatusBadRequest)
+    return
+  }
+
+  response, err := this.handler.UnknownReturn(id)
+  if err != nil {
+    http.Error(w, err.Error(), http.StatusInternalServerError)
+    return
+  }
+
+  w.Header().Set("Content-Type", "application/json")
+
+  if err := json.NewEncoder(w).Encode(response); err != nil {
+    http.Error(w, err.Error(), http.StatusInternalServerError)
+  }
+}
+
+func (this *MyService_Router) serveUnknownArgument(w http.ResponseWriter, r *http.Request, m []string) {
+  var id uint32
+  if _, err := fmt.Sscan(m[1], &id); err != nil {
+    http.Error(w, err.Error(), http.StatusBadRequest)
+    return
+  }
+
+  var request Entry
+  if err := json.NewDecoder(r.Body).Decode(&request); err != nil {
+    http.Error(w, err.Error(), http.StatusBadRequest)
+    return
+  }
+
+  err := this.handler.UnknownArgument(request, id)
+  if err != nil {
+    http.Error(w, err.Error(), http.StatusInternalServerError)
+    return
+  }
+}
+
+func (this *MyService_Router) serveUnary(w http.ResponseWriter, r *http.Request, m []string) {
+  var id uint32
+  if _, err := fmt.Sscan(m[1], &id); err != nil {
+    http.Error(w, err.Error(), http.StatusBadRequest)
+    return
+  }
+
+  var request Entry
+  if err := json.NewDecoder(r.Body).Decode(&request); err != nil {
+    http.Error(w, err.Error(), http.StatusBadRequest)
+    return
+  }
+
+  response, err := this.handler.Unary(request, id)
+  if err != nil {
+    http.Error(w, err.Error(), http.StatusInternalServerError)
+    return
+  }
+
+  w.Header().Set("Content-Type", "application/json")
+
+  if err := json.NewEncoder(w).Encode(response); err != nil {
+    http.Error(w, err.Error(), http.StatusInternalServerError)
+  }
+}
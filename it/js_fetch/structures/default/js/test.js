@@ -0,0 +1,116 @@
+export class Entry {
+  constructor() {
+  }
+
+  static decode(data) {
+    return new Entry();
+  }
+
+  encode() {
+    const data = {};
+
+    return data;
+  }
+}
+
+export class MyService_Fetch {
+  constructor(url) {
+    if (url === null || url === undefined) {
+      url = "http://example.com";
+    }
+
+    this.url = url;
+  }
+
+  /**
+   * UNKNOWN
+   */
+  unknown(id) {
+    const path = [this.url];
+    path.push("/");
+    path.push("unknown");
+    path.push("/");
+    path.push(encodeURIComponent(id));
+
+    const url = path.join("");
+
+    const init = {method: "GET"};
+
+    return fetch(url, init).then(function(r) {
+      if (!r.ok) {
+        throw new Error("bad response: " + r.status);
+      }
+    });
+  }
+
+  /**
+   * UNKNOWN
+   */
+  unknown_return(id) {
+    const path = [this.url];
+    path.push("/");
+    path.push("unknown-return");
+    path.push("/");
+    path.push(encodeURIComponent(id));
+
+    const url = path.join("");
+
+    const init = {method: "GET"};
+
+    return fetch(url, init).then(function(r) {
+      if (!r.ok) {
+        throw new Error("bad response: " + r.status);
+      }
+
+      return r.json().then(function(data) {
+        return Entry.decode(data);
+      });
+    });
+  }
+
+  /**
+   * UNKNOWN
+   */
+  unknown_argument(request, id) {
+    const path = [this.url];
+    path.push("/");
+    path.push("unknown-argument");
+    path.push("/");
+    path.push(encodeURIComponent(id));
+
+    const url = path.join("");
+
+    const init = {method: "GET", headers: {"Content-Type": "application/json"}, body: JSON.stringify(request.encode())};
+
+    return fetch(url, init).then(function(r) {
+      if (!r.ok) {
+        throw new Error("bad response: " + r.status);
+      }
+    });
+  }
+
+  /**
+   * UNARY
+   */
+  unary(request, id) {
+    const path = [this.url];
+    path.push("/");
+    path.push("unary");
+    path.push("/");
+    path.push(encodeURIComponent(id));
+
+    const url = path.join("");
+
+    const init = {method: "GET", headers: {"Content-Type": "application/json"}, body: JSON.stringify(request.encode())};
+
+    return fetch(url, init).then(function(r) {
+      if (!r.ok) {
+        throw new Error("bad response: " + r.status);
+      }
+
+      return r.json().then(function(data) {
+        return Entry.decode(data);
+      });
+    });
+  }
+}
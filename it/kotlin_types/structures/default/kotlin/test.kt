@@ -0,0 +1,133 @@
+package test
+
+import kotlinx.serialization.*
+import kotlinx.serialization.descriptors.*
+import kotlinx.serialization.encoding.*
+import kotlinx.serialization.json.*
+
+@Serializable
+data class Entry(
+  val tagged: Tagged? = null,
+  val untagged: Untagged? = null,
+  val explicit: EnumExplicit? = null,
+  @SerialName("enum_u32")
+  val enumU32: EnumU32? = null,
+  val point: Point? = null,
+  val strings: List<String>? = null,
+  val points: Map<String, Point>? = null
+)
+
+/**
+ * A point in space.
+ */
+@Serializable(with = Point.Serializer::class)
+data class Point(
+  val x: Double,
+  val y: Double
+) {
+  object Serializer : KSerializer<Point> {
+    override val descriptor: SerialDescriptor = JsonArray.serializer().descriptor
+
+    override fun serialize(encoder: Encoder, value: Point) {
+      val output = encoder as JsonEncoder
+
+      val array = buildJsonArray {
+        add(output.json.encodeToJsonElement(value.x))
+        add(output.json.encodeToJsonElement(value.y))
+      }
+
+      output.encodeJsonElement(array)
+    }
+
+    override fun deserialize(decoder: Decoder): Point {
+      val input = decoder as JsonDecoder
+      val array = input.decodeJsonElement().jsonArray
+
+      return Point(
+        input.json.decodeFromJsonElement<Double>(array[0]),
+        input.json.decodeFromJsonElement<Double>(array[1])
+      )
+    }
+  }
+}
+
+@Serializable
+enum class EnumExplicit(val value: String) {
+  @SerialName("foo")
+  A("foo"),
+  @SerialName("bar")
+  B("bar");
+}
+
+@Serializable(with = EnumU32.Serializer::class)
+enum class EnumU32(val value: Int) {
+  Min(0),
+  Max(2147483647);
+
+  object Serializer : KSerializer<EnumU32> {
+    override val descriptor: SerialDescriptor = PrimitiveSerialDescriptor("test.EnumU32", PrimitiveKind.INT)
+
+    override fun serialize(encoder: Encoder, value: EnumU32) {
+      encoder.encodeInt(value.value)
+    }
+
+    override fun deserialize(decoder: Decoder): EnumU32 {
+      val value = decoder.decodeInt()
+      return EnumU32.values().find { it.value == value } ?: throw SerializationException("no matching value: " + value)
+    }
+  }
+}
+
+@Serializable
+@OptIn(ExperimentalSerializationApi::class)
+@JsonClassDiscriminator("@type")
+sealed class Tagged {
+  abstract val shared: String
+}
+
+@Serializable
+@SerialName("foo")
+data class Tagged_A(
+  override val shared: String
+) : Tagged()
+
+@Serializable
+@SerialName("b")
+data class Tagged_B(
+  override val shared: String,
+  val b: String
+) : Tagged()
+
+@Serializable(with = Untagged.Serializer::class)
+sealed class Untagged {
+  abstract val shared: String
+
+  object Serializer : JsonContentPolymorphicSerializer<Untagged>(Untagged::class) {
+    override fun selectDeserializer(element: JsonElement): DeserializationStrategy<out Untagged> {
+      val keys = element.jsonObject.keys
+
+      if (keys.containsAll(listOf("a", "b"))) {
+        return Untagged_A.serializer()
+      }
+
+      if (keys.containsAll(listOf("a"))) {
+        return Untagged_B.serializer()
+      }
+
+      throw SerializationException("no legal field combinations found")
+    }
+  }
+}
+
+@Serializable
+data class Untagged_A(
+  override val shared: String,
+  val a: String,
+  val b: String
+) : Untagged()
+
+@Serializable
+data class Untagged_B(
+  override val shared: String,
+  val a: String
+) : Untagged()
@@ -0,0 +1,91 @@
+import re
+
+class Entry:
+  def __init__(self, name, age, roles):
+    self.name = name
+    self.age = age
+    self.roles = roles
+
+  def get_name(self):
+    return self.name
+
+  def get_age(self):
+    return self.age
+
+  def get_roles(self):
+    return self.roles
+
+  @staticmethod
+  def decode(data):
+    f_name = None
+
+    if "name" in data:
+      f_name = data["name"]
+
+      if f_name is not None:
+        if not isinstance(f_name, unicode):
+          raise Exception("not a string")
+
+        if len(f_name) < 3:
+          raise Exception("length must be at least 3")
+
+        if len(f_name) > 32:
+          raise Exception("length must be at most 32")
+
+        if not re.search("^[a-z][a-z0-9_]*$", f_name):
+          raise Exception("does not match pattern: ^[a-z][a-z0-9_]*$")
+
+    f_age = None
+
+    if "age" in data:
+      f_age = data["age"]
+
+      if f_age is not None:
+        if not isinstance(f_age, int):
+          raise Exception("not an integer")
+
+        if f_age < 0:
+          raise Exception("value must be at least 0")
+
+        if f_age > 150:
+          raise Exception("value must be at most 150")
+
+    f_roles = None
+
+    if "roles" in data:
+      f_roles = data["roles"]
+
+      if f_roles is not None:
+        if not isinstance(f_roles, list):
+          raise Exception("not an array")
+
+        _a0 = []
+
+        for _v0 in f_roles:
+          if not isinstance(_v0, unicode):
+            raise Exception("not a string")
+          _a0.append(_v0)
+
+        f_roles = _a0
+
+        if len(f_roles) > 2:
+          raise Exception("number of items must be at most 2")
+
+    return Entry(f_name, f_age, f_roles)
+
+  def encode(self):
+    data = dict()
+
+    if self.name is not None:
+      data["name"] = self.name
+
+    if self.age is not None:
+      data["age"] = self.age
+
+    if self.roles is not None:
+      data["roles"] = self.roles
+
+    return data
+
+  def __repr__(self):
+    return "<Entry name:{!r}, age:{!r}, roles:{!r}>".format(self.name, self.age, self.roles)
@@ -0,0 +1,2 @@
+pub mod reproto;
+pub mod test;
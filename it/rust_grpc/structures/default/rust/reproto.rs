@@ -0,0 +1,34 @@
+use grpc;
+use grpc::marshall;
+use serde;
+use serde::de;
+use serde_json;
+
+/// Marshaller for JSON-encoded gRPC messages.
+pub struct JsonMarshaller;
+
+impl<T> marshall::Marshaller<T> for JsonMarshaller
+where
+  T: serde::Serialize + de::DeserializeOwned + Send + Sync + 'static,
+{
+  fn write(&self, m: &T) -> grpc::Result<Vec<u8>> {
+    serde_json::to_vec(m).map_err(|e| grpc::Error::Panic(e.to_string()))
+  }
+
+  fn read(&self, bytes: &[u8]) -> grpc::Result<T> {
+    serde_json::from_slice(bytes).map_err(|e| grpc::Error::Panic(e.to_string()))
+  }
+}
+
+/// Marshaller for empty gRPC messages.
+pub struct VoidMarshaller;
+
+impl marshall::Marshaller<()> for VoidMarshaller {
+  fn write(&self, _: &()) -> grpc::Result<Vec<u8>> {
+    Ok(Vec::new())
+  }
+
+  fn read(&self, _: &[u8]) -> grpc::Result<()> {
+    Ok(())
+  }
+}
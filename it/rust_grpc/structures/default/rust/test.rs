@@ -0,0 +1,217 @@
+use grpc;
+use grpc::rt;
+use reproto;
+use std::sync::Arc;
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Entry {
+}
+
+pub trait MyService_Grpc {
+  /// UNKNOWN
+  fn unknown(&self, o: grpc::RequestOptions, request: ()) -> grpc::SingleResponse<()>;
+
+  /// UNKNOWN
+  fn unknown_return(&self, o: grpc::RequestOptions, request: ()) -> grpc::SingleResponse<Entry>;
+
+  /// UNKNOWN
+  fn unknown_argument(&self, o: grpc::RequestOptions, request: Entry) -> grpc::SingleResponse<()>;
+
+  /// UNARY
+  fn unary(&self, o: grpc::RequestOptions, request: Entry) -> grpc::SingleResponse<Entry>;
+
+  /// SERVER_STREMAING
+  fn server_streaming(&self, o: grpc::RequestOptions, request: Entry) -> grpc::StreamingResponse<Entry>;
+
+  /// CLIENT_STREAMING
+  fn client_streaming(&self, o: grpc::RequestOptions, request: grpc::StreamingRequest<Entry>) -> grpc::SingleResponse<Entry>;
+
+  /// BIDI_STREAMING
+  fn bidi_streaming(&self, o: grpc::RequestOptions, request: grpc::StreamingRequest<Entry>) -> grpc::StreamingResponse<Entry>;
+}
+
+pub struct MyService_GrpcClient {
+  grpc_client: Arc<grpc::Client>,
+  method_unknown: Arc<rt::MethodDescriptor<(), ()>>,
+  method_unknown_return: Arc<rt::MethodDescriptor<(), Entry>>,
+  method_unknown_argument: Arc<rt::MethodDescriptor<Entry, ()>>,
+  method_unary: Arc<rt::MethodDescriptor<Entry, Entry>>,
+  method_server_streaming: Arc<rt::MethodDescriptor<Entry, Entry>>,
+  method_client_streaming: Arc<rt::MethodDescriptor<Entry, Entry>>,
+  method_bidi_streaming: Arc<rt::MethodDescriptor<Entry, Entry>>,
+}
+
+impl grpc::ClientStub for MyService_GrpcClient {
+  fn with_client(grpc_client: Arc<grpc::Client>) -> Self {
+    Self {
+      grpc_client,
+      method_unknown: Arc::new(rt::MethodDescriptor {
+        name: "/test.MyService/unknown".to_string(),
+        streaming: rt::GrpcStreaming::Unary,
+        req_marshaller: Box::new(reproto::VoidMarshaller),
+        resp_marshaller: Box::new(reproto::VoidMarshaller),
+      }),
+      method_unknown_return: Arc::new(rt::MethodDescriptor {
+        name: "/test.MyService/unknown_return".to_string(),
+        streaming: rt::GrpcStreaming::Unary,
+        req_marshaller: Box::new(reproto::VoidMarshaller),
+        resp_marshaller: Box::new(reproto::JsonMarshaller),
+      }),
+      method_unknown_argument: Arc::new(rt::MethodDescriptor {
+        name: "/test.MyService/unknown_argument".to_string(),
+        streaming: rt::GrpcStreaming::Unary,
+        req_marshaller: Box::new(reproto::JsonMarshaller),
+        resp_marshaller: Box::new(reproto::VoidMarshaller),
+      }),
+      method_unary: Arc::new(rt::MethodDescriptor {
+        name: "/test.MyService/unary".to_string(),
+        streaming: rt::GrpcStreaming::Unary,
+        req_marshaller: Box::new(reproto::JsonMarshaller),
+        resp_marshaller: Box::new(reproto::JsonMarshaller),
+      }),
+      method_server_streaming: Arc::new(rt::MethodDescriptor {
+        name: "/test.MyService/server_streaming".to_string(),
+        streaming: rt::GrpcStreaming::ServerStreaming,
+        req_marshaller: Box::new(reproto::JsonMarshaller),
+        resp_marshaller: Box::new(reproto::JsonMarshaller),
+      }),
+      method_client_streaming: Arc::new(rt::MethodDescriptor {
+        name: "/test.MyService/client_streaming".to_string(),
+        streaming: rt::GrpcStreaming::ClientStreaming,
+        req_marshaller: Box::new(reproto::JsonMarshaller),
+        resp_marshaller: Box::new(reproto::JsonMarshaller),
+      }),
+      method_bidi_streaming: Arc::new(rt::MethodDescriptor {
+        name: "/test.MyService/bidi_streaming".to_string(),
+        streaming: rt::GrpcStreaming::Bidi,
+        req_marshaller: Box::new(reproto::JsonMarshaller),
+        resp_marshaller: Box::new(reproto::JsonMarshaller),
+      }),
+    }
+  }
+}
+
+impl MyService_Grpc for MyService_GrpcClient {
+  fn unknown(&self, o: grpc::RequestOptions, request: ()) -> grpc::SingleResponse<()> {
+    self.grpc_client.call_unary(o, request, self.method_unknown.clone())
+  }
+
+  fn unknown_return(&self, o: grpc::RequestOptions, request: ()) -> grpc::SingleResponse<Entry> {
+    self.grpc_client.call_unary(o, request, self.method_unknown_return.clone())
+  }
+
+  fn unknown_argument(&self, o: grpc::RequestOptions, request: Entry) -> grpc::SingleResponse<()> {
+    self.grpc_client.call_unary(o, request, self.method_unknown_argument.clone())
+  }
+
+  fn unary(&self, o: grpc::RequestOptions, request: Entry) -> grpc::SingleResponse<Entry> {
+    self.grpc_client.call_unary(o, request, self.method_unary.clone())
+  }
+
+  fn server_streaming(&self, o: grpc::RequestOptions, request: Entry) -> grpc::StreamingResponse<Entry> {
+    self.grpc_client.call_server_streaming(o, request, self.method_server_streaming.clone())
+  }
+
+  fn client_streaming(&self, o: grpc::RequestOptions, request: grpc::StreamingRequest<Entry>) -> grpc::SingleResponse<Entry> {
+    self.grpc_client.call_client_streaming(o, request, self.method_client_streaming.clone())
+  }
+
+  fn bidi_streaming(&self, o: grpc::RequestOptions, request: grpc::StreamingRequest<Entry>) -> grpc::StreamingResponse<Entry> {
+    self.grpc_client.call_bidi(o, request, self.method_bidi_streaming.clone())
+  }
+}
+
+pub struct MyService_GrpcServer;
+
+impl MyService_GrpcServer {
+  pub fn new_service_def<H: MyService_Grpc + Send + Sync + 'static>(handler: H) -> rt::ServerServiceDefinition {
+    let handler = Arc::new(handler);
+
+    rt::ServerServiceDefinition::new("/test.MyService", vec![
+      rt::ServerMethod::new(
+        Arc::new(rt::MethodDescriptor {
+          name: "/test.MyService/unknown".to_string(),
+          streaming: rt::GrpcStreaming::Unary,
+          req_marshaller: Box::new(reproto::VoidMarshaller),
+          resp_marshaller: Box::new(reproto::VoidMarshaller),
+        }),
+        {
+          let handler = handler.clone();
+          rt::MethodHandlerUnary::new(move |o, p| handler.unknown(o, p))
+        },
+      ),
+      rt::ServerMethod::new(
+        Arc::new(rt::MethodDescriptor {
+          name: "/test.MyService/unknown_return".to_string(),
+          streaming: rt::GrpcStreaming::Unary,
+          req_marshaller: Box::new(reproto::VoidMarshaller),
+          resp_marshaller: Box::new(reproto::JsonMarshaller),
+        }),
+        {
+          let handler = handler.clone();
+          rt::MethodHandlerUnary::new(move |o, p| handler.unknown_return(o, p))
+        },
+      ),
+      rt::ServerMethod::new(
+        Arc::new(rt::MethodDescriptor {
+          name: "/test.MyService/unknown_argument".to_string(),
+          streaming: rt::GrpcStreaming::Unary,
+          req_marshaller: Box::new(reproto::JsonMarshaller),
+          resp_marshaller: Box::new(reproto::VoidMarshaller),
+        }),
+        {
+          let handler = handler.clone();
+          rt::MethodHandlerUnary::new(move |o, p| handler.unknown_argument(o, p))
+        },
+      ),
+      rt::ServerMethod::new(
+        Arc::new(rt::MethodDescriptor {
+          name: "/test.MyService/unary".to_string(),
+          streaming: rt::GrpcStreaming::Unary,
+          req_marshaller: Box::new(reproto::JsonMarshaller),
+          resp_marshaller: Box::new(reproto::JsonMarshaller),
+        }),
+        {
+          let handler = handler.clone();
+          rt::MethodHandlerUnary::new(move |o, p| handler.unary(o, p))
+        },
+      ),
+      rt::ServerMethod::new(
+        Arc::new(rt::MethodDescriptor {
+          name: "/test.MyService/server_streaming".to_string(),
+          streaming: rt::GrpcStreaming::ServerStreaming,
+          req_marshaller: Box::new(reproto::JsonMarshaller),
+          resp_marshaller: Box::new(reproto::JsonMarshaller),
+        }),
+        {
+          let handler = handler.clone();
+          rt::MethodHandlerServerStreaming::new(move |o, p| handler.server_streaming(o, p))
+        },
+      ),
+      rt::ServerMethod::new(
+        Arc::new(rt::MethodDescriptor {
+          name: "/test.MyService/client_streaming".to_string(),
+          streaming: rt::GrpcStreaming::ClientStreaming,
+          req_marshaller: Box::new(reproto::JsonMarshaller),
+          resp_marshaller: Box::new(reproto::JsonMarshaller),
+        }),
+        {
+          let handler = handler.clone();
+          rt::MethodHandlerClientStreaming::new(move |o, p| handler.client_streaming(o, p))
+        },
+      ),
+      rt::ServerMethod::new(
+        Arc::new(rt::MethodDescriptor {
+          name: "/test.MyService/bidi_streaming".to_string(),
+          streaming: rt::GrpcStreaming::Bidi,
+          req_marshaller: Box::new(reproto::JsonMarshaller),
+          resp_marshaller: Box::new(reproto::JsonMarshaller),
+        }),
+        {
+          let handler = handler.clone();
+          rt::MethodHandlerBidi::new(move |o, p| handler.bidi_streaming(o, p))
+        },
+      ),
+    ])
+  }
+}
@@ -0,0 +1,44 @@
+import Foundation
+import SwiftGRPC
+
+func grpc_decode<T>(_ data: Data?, decode: (Data) throws -> T) throws -> T? {
+  guard let data = data else {
+    return nil
+  }
+
+  return try decode(data)
+}
+
+public class GrpcCall<Request, Response> {
+  let call: Call
+  let encode: (Request) throws -> Data
+  let decode: (Data) throws -> Response
+
+  init(call: Call, encode: @escaping (Request) throws -> Data, decode: @escaping (Data) throws -> Response) {
+    self.call = call
+    self.encode = encode
+    self.decode = decode
+  }
+
+  public func send(_ message: Request, completion: ((Error?) -> Void)? = nil) throws {
+    try self.call.sendMessage(data: try self.encode(message), completion: completion)
+  }
+
+  public func receive(completion: @escaping (Response?, Error?, CallResult) -> Void) throws {
+    try self.call.receiveMessage { result in
+      do {
+        completion(try grpc_decode(result.resultData, decode: self.decode), nil, result)
+      } catch {
+        completion(nil, error, result)
+      }
+    }
+  }
+
+  public func close(completion: (() -> Void)? = nil) throws {
+    try self.call.close(completion: completion)
+  }
+
+  public func cancel() {
+    self.call.cancel()
+  }
+}
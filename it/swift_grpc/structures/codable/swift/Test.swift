@@ -0,0 +1,83 @@
+import Foundation
+import SwiftGRPC
+
+public struct Test_Entry: Codable {
+}
+
+public class Test_MyService_GrpcClient {
+  let channel: Channel
+  public var metadata: Metadata
+
+  public init(channel: Channel, metadata: Metadata = Metadata()) {
+    self.channel = channel
+    self.metadata = metadata
+  }
+
+  // UNKNOWN
+  public func unknown(completion: @escaping (CallResult) -> Void) throws -> Call {
+    let call = self.channel.makeCall("/test.MyService/unknown")
+    try call.start(.unary, metadata: self.metadata, message: Data()) { result in
+      completion(result)
+    }
+    return call
+  }
+
+  // UNKNOWN
+  public func unknown_return(completion: @escaping (Test_Entry?, Error?, CallResult) -> Void) throws -> Call {
+    let call = self.channel.makeCall("/test.MyService/unknown_return")
+    try call.start(.unary, metadata: self.metadata, message: Data()) { result in
+      do {
+        completion(try grpc_decode(result.resultData, decode: { data in try JSONDecoder().decode(Test_Entry.self, from: data) }), nil, result)
+      } catch {
+        completion(nil, error, result)
+      }
+    }
+    return call
+  }
+
+  // UNKNOWN
+  public func unknown_argument(_ request: Test_Entry, completion: @escaping (CallResult) -> Void) throws -> Call {
+    let call = self.channel.makeCall("/test.MyService/unknown_argument")
+    let message = try JSONEncoder().encode(request)
+    try call.start(.unary, metadata: self.metadata, message: message) { result in
+      completion(result)
+    }
+    return call
+  }
+
+  // UNARY
+  public func unary(_ request: Test_Entry, completion: @escaping (Test_Entry?, Error?, CallResult) -> Void) throws -> Call {
+    let call = self.channel.makeCall("/test.MyService/unary")
+    let message = try JSONEncoder().encode(request)
+    try call.start(.unary, metadata: self.metadata, message: message) { result in
+      do {
+        completion(try grpc_decode(result.resultData, decode: { data in try JSONDecoder().decode(Test_Entry.self, from: data) }), nil, result)
+      } catch {
+        completion(nil, error, result)
+      }
+    }
+    return call
+  }
+
+  // SERVER_STREMAING
+  public func server_streaming(_ request: Test_Entry) throws -> GrpcCall<Void, Test_Entry> {
+    let call = self.channel.makeCall("/test.MyService/server_streaming")
+    let message = try JSONEncoder().encode(request)
+    try call.start(.serverStreaming, metadata: self.metadata, message: message, completion: nil)
+    return GrpcCall(call: call, encode: { _ in Data() }, decode: { data in try JSONDecoder().decode(Test_Entry.self, from: data) })
+  }
+
+  // CLIENT_STREAMING
+  public func client_streaming() throws -> GrpcCall<Test_Entry, Test_Entry> {
+    let call = self.channel.makeCall("/test.MyService/client_streaming")
+    try call.start(.clientStreaming, metadata: self.metadata, message: nil, completion: nil)
+    return GrpcCall(call: call, encode: { value in try JSONEncoder().encode(value) }, decode: { data in try JSONDecoder().decode(Test_Entry.self, from: data) })
+  }
+
+  // BIDI_STREAMING
+  public func bidi_streaming() throws -> GrpcCall<Test_Entry, Test_Entry> {
+    let call = self.channel.makeCall("/test.MyService/bidi_streaming")
+    try call.start(.bidiStreaming, metadata: self.metadata, message: nil, completion: nil)
+    return GrpcCall(call: call, encode: { value in try JSONEncoder().encode(value) }, decode: { data in try JSONDecoder().decode(Test_Entry.self, from: data) })
+  }
+}
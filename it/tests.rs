@@ -11,12 +11,28 @@ define!{
     },
     code => {
     },
+    dates => {
+        dates.include(Rust);
+        dates.include(Python);
+        dates.arg(Rust, &["-m", "chrono", "-m", "uuid"]);
+    },
+    defaults => {
+        defaults.include(Java);
+        defaults.include(Rust);
+        defaults.include(Python);
+        defaults.include(Swift);
+        defaults.include(Csharp);
+        defaults.include(Go);
+    },
     enum_ => {
     },
     inner => {
     },
     interfaces => {
     },
+    go_net_http => {
+        go_net_http.include(Go);
+    },
     java_grpc => {
         java_grpc.include(Java);
     },
@@ -26,9 +42,15 @@ define!{
     csharp_keywords => {
         csharp_keywords.include(Csharp);
     },
+    csharp_http_client => {
+        csharp_http_client.include(Csharp);
+    },
     swift_keywords => {
         swift_keywords.include(Swift);
     },
+    swift_grpc => {
+        swift_grpc.include(Swift);
+    },
     java_okhttp1 => {
         java_okhttp1.include(Java);
     },
@@ -38,12 +60,30 @@ define!{
     js_keywords => {
         js_keywords.include(JavaScript);
     },
+    js_fetch => {
+        js_fetch.include(JavaScript);
+    },
+    jsonschema => {
+        jsonschema.include(JsonSchema);
+    },
+    kotlin_types => {
+        kotlin_types.include(Kotlin);
+    },
+    openapi => {
+        openapi.include(OpenApi);
+    },
+    protobuf => {
+        protobuf.include(Proto);
+    },
     python_keywords => {
         python_keywords.include(Python);
     },
     python_requests => {
         python_requests.include(Python);
     },
+    python_validate => {
+        python_validate.include(Python);
+    },
     service => {
         service.package("service");
         service.arg(Java, &["-m", "grpc"]);
@@ -53,9 +93,15 @@ define!{
     rust_keywords => {
         rust_keywords.include(Rust);
     },
+    rust_grpc => {
+        rust_grpc.include(Rust);
+    },
     rust_reqwest => {
         rust_reqwest.include(Rust);
     },
+    ts_types => {
+        ts_types.include(TypeScript);
+    },
     tuple => {},
     versions => {},
     default_naming => {},
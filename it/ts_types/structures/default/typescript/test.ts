@@ -0,0 +1,390 @@
+export interface Entry {
+  tagged?: Tagged;
+  untagged?: Untagged;
+  explicit?: EnumExplicit;
+  enum_u32?: EnumU32;
+  point?: Point;
+  strings?: Array<string>;
+  points?: {[key: string]: Point};
+}
+
+export namespace Entry {
+  export function decode(data: any): Entry {
+    let v_tagged: Tagged | undefined = undefined;
+
+    if (data["tagged"] !== null && data["tagged"] !== undefined) {
+      v_tagged = Tagged.decode(data["tagged"]);
+    }
+
+    let v_untagged: Untagged | undefined = undefined;
+
+    if (data["untagged"] !== null && data["untagged"] !== undefined) {
+      v_untagged = Untagged.decode(data["untagged"]);
+    }
+
+    let v_explicit: EnumExplicit | undefined = undefined;
+
+    if (data["explicit"] !== null && data["explicit"] !== undefined) {
+      v_explicit = EnumExplicit.decode(data["explicit"]);
+    }
+
+    let v_enum_u32: EnumU32 | undefined = undefined;
+
+    if (data["enum_u32"] !== null && data["enum_u32"] !== undefined) {
+      v_enum_u32 = EnumU32.decode(data["enum_u32"]);
+    }
+
+    let v_point: Point | undefined = undefined;
+
+    if (data["point"] !== null && data["point"] !== undefined) {
+      v_point = Point.decode(data["point"]);
+    }
+
+    let v_strings: Array<string> | undefined = undefined;
+
+    if (data["strings"] !== null && data["strings"] !== undefined) {
+      v_strings = data["strings"];
+    }
+
+    let v_points: {[key: string]: Point} | undefined = undefined;
+
+    if (data["points"] !== null && data["points"] !== undefined) {
+      v_points = ((data: any) => { const o: {[key: string]: Point} = {}; for (const k in data) { o[k] = Point.decode(data[k]); } return o; })(data["points"]);
+    }
+
+    return {
+      tagged: v_tagged,
+      untagged: v_untagged,
+      explicit: v_explicit,
+      enum_u32: v_enum_u32,
+      point: v_point,
+      strings: v_strings,
+      points: v_points,
+    };
+  }
+
+  export function encode(value: Entry): any {
+    const data: any = {};
+
+    if (value.tagged !== null && value.tagged !== undefined) {
+      data["tagged"] = Tagged.encode(value.tagged);
+    }
+
+    if (value.untagged !== null && value.untagged !== undefined) {
+      data["untagged"] = Untagged.encode(value.untagged);
+    }
+
+    if (value.explicit !== null && value.explicit !== undefined) {
+      data["explicit"] = EnumExplicit.encode(value.explicit);
+    }
+
+    if (value.enum_u32 !== null && value.enum_u32 !== undefined) {
+      data["enum_u32"] = EnumU32.encode(value.enum_u32);
+    }
+
+    if (value.point !== null && value.point !== undefined) {
+      data["point"] = Point.encode(value.point);
+    }
+
+    if (value.strings !== null && value.strings !== undefined) {
+      data["strings"] = value.strings;
+    }
+
+    if (value.points !== null && value.points !== undefined) {
+      data["points"] = ((data: {[key: string]: Point}) => { const o: any = {}; for (const k in data) { o[k] = Point.encode(data[k]); } return o; })(value.points);
+    }
+
+    return data;
+  }
+}
+
+/**
+ * A point in space.
+ */
+export interface Point {
+  x: number;
+  y: number;
+}
+
+export namespace Point {
+  export function decode(data: any): Point {
+    if (data[0] === null || data[0] === undefined) {
+      throw new Error("x" + ": required field");
+    }
+
+    const v_x: number = data[0];
+
+    if (data[1] === null || data[1] === undefined) {
+      throw new Error("y" + ": required field");
+    }
+
+    const v_y: number = data[1];
+
+    return {
+      x: v_x,
+      y: v_y,
+    };
+  }
+
+  export function encode(value: Point): any {
+    return [value.x, value.y];
+  }
+}
+
+export type EnumExplicit = "foo" | "bar";
+
+export namespace EnumExplicit {
+  export const A: EnumExplicit = "foo";
+  export const B: EnumExplicit = "bar";
+
+  export const values: Array<EnumExplicit> = [A, B];
+
+  export function decode(data: any): EnumExplicit {
+    for (const member of values) {
+      if (member === data) {
+        return member;
+      }
+    }
+
+    throw new Error("no matching value: " + data);
+  }
+
+  export function encode(value: EnumExplicit): any {
+    return value;
+  }
+}
+
+export type EnumU32 = 0 | 2147483647;
+
+export namespace EnumU32 {
+  export const Min: EnumU32 = 0;
+  export const Max: EnumU32 = 2147483647;
+
+  export const values: Array<EnumU32> = [Min, Max];
+
+  export function decode(data: any): EnumU32 {
+    for (const member of values) {
+      if (member === data) {
+        return member;
+      }
+    }
+
+    throw new Error("no matching value: " + data);
+  }
+
+  export function encode(value: EnumU32): any {
+    return value;
+  }
+}
+
+export type Tagged = Tagged_A | Tagged_B;
+
+export namespace Tagged {
+  export function decode(data: any): Tagged {
+    const f_tag = data["@type"];
+
+    if (f_tag === "foo") {
+      return Tagged_A.decode(data);
+    }
+
+    if (f_tag === "b") {
+      return Tagged_B.decode(data);
+    }
+
+    throw new Error("bad type: " + f_tag);
+  }
+
+  export function encode(value: Tagged): any {
+    const f_tag = value["@type"];
+
+    if (f_tag === "foo") {
+      return Tagged_A.encode(value as Tagged_A);
+    }
+
+    if (f_tag === "b") {
+      return Tagged_B.encode(value as Tagged_B);
+    }
+
+    throw new Error("bad type: " + f_tag);
+  }
+}
+
+export interface Tagged_A {
+  "@type": "foo";
+  shared: string;
+}
+
+export namespace Tagged_A {
+  export function decode(data: any): Tagged_A {
+    if (data["shared"] === null || data["shared"] === undefined) {
+      throw new Error("shared" + ": required field");
+    }
+
+    const v_shared: string = data["shared"];
+
+    return {
+      "@type": "foo",
+      shared: v_shared,
+    };
+  }
+
+  export function encode(value: Tagged_A): any {
+    const data: any = {};
+
+    data["@type"] = "foo";
+
+    data["shared"] = value.shared;
+
+    return data;
+  }
+}
+
+export interface Tagged_B {
+  "@type": "b";
+  shared: string;
+  b: string;
+}
+
+export namespace Tagged_B {
+  export function decode(data: any): Tagged_B {
+    if (data["shared"] === null || data["shared"] === undefined) {
+      throw new Error("shared" + ": required field");
+    }
+
+    const v_shared: string = data["shared"];
+
+    if (data["b"] === null || data["b"] === undefined) {
+      throw new Error("b" + ": required field");
+    }
+
+    const v_b: string = data["b"];
+
+    return {
+      "@type": "b",
+      shared: v_shared,
+      b: v_b,
+    };
+  }
+
+  export function encode(value: Tagged_B): any {
+    const data: any = {};
+
+    data["@type"] = "b";
+
+    data["shared"] = value.shared;
+
+    data["b"] = value.b;
+
+    return data;
+  }
+}
+
+export type Untagged = Untagged_A | Untagged_B;
+
+export namespace Untagged {
+  export function decode(data: any): Untagged {
+    if (("a" in data) && ("b" in data)) {
+      return Untagged_A.decode(data);
+    }
+
+    if (("a" in data)) {
+      return Untagged_B.decode(data);
+    }
+
+    throw new Error("no legal field combinations found");
+  }
+
+  export function encode(value: Untagged): any {
+    if (("a" in value) && ("b" in value)) {
+      return Untagged_A.encode(value as Untagged_A);
+    }
+
+    if (("a" in value)) {
+      return Untagged_B.encode(value as Untagged_B);
+    }
+
+    throw new Error("no legal field combinations found");
+  }
+}
+
+export interface Untagged_A {
+  shared: string;
+  a: string;
+  b: string;
+}
+
+export namespace Untagged_A {
+  export function decode(data: any): Untagged_A {
+    if (data["shared"] === null || data["shared"] === undefined) {
+      throw new Error("shared" + ": required field");
+    }
+
+    const v_shared: string = data["shared"];
+
+    if (data["a"] === null || data["a"] === undefined) {
+      throw new Error("a" + ": required field");
+    }
+
+    const v_a: string = data["a"];
+
+    if (data["b"] === null || data["b"] === undefined) {
+      throw new Error("b" + ": required field");
+    }
+
+    const v_b: string = data["b"];
+
+    return {
+      shared: v_shared,
+      a: v_a,
+      b: v_b,
+    };
+  }
+
+  export function encode(value: Untagged_A): any {
+    const data: any = {};
+
+    data["shared"] = value.shared;
+
+    data["a"] = value.a;
+
+    data["b"] = value.b;
+
+    return data;
+  }
+}
+
+export interface Untagged_B {
+  shared: string;
+  a: string;
+}
+
+export namespace Untagged_B {
+  export function decode(data: any): Untagged_B {
+    if (data["shared"] === null || data["shared"] === undefined) {
+      throw new Error("shared" + ": required field");
+    }
+
+    const v_shared: string = data["shared"];
+
+    if (data["a"] === null || data["a"] === undefined) {
+      throw new Error("a" + ": required field");
+    }
+
+    const v_a: string = data["a"];
+
+    return {
+      shared: v_shared,
+      a: v_a,
+    };
+  }
+
+  export function encode(value: Untagged_B): any {
+    const data: any = {};
+
+    data["shared"] = value.shared;
+
+    data["a"] = value.a;
+
+    return data;
+  }
+}
@@ -0,0 +1,12 @@
+import kotlinx.serialization.json.Json
+import kotlinx.serialization.decodeFromString
+import kotlinx.serialization.encodeToString
+import test.Entry
+
+fun main() {
+  while (true) {
+    val line = readLine() ?: break
+    val entry = Json.decodeFromString<Entry>(line)
+    println(Json.encodeToString(entry))
+  }
+}
@@ -5,6 +5,8 @@ extern crate serde_json;
 #[macro_use]
 extern crate serde_derive;
 extern crate chrono;
+extern crate grpc;
 extern crate reqwest;
+extern crate uuid;
 
 pub mod generated;
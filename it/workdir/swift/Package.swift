@@ -11,5 +11,8 @@ let package = Package(
             name: "Models",
             dependencies: []
         ),
+    ],
+    dependencies: [
+        .Package(url: "https://github.com/grpc/grpc-swift.git", majorVersion: 0),
     ]
 )
@@ -0,0 +1,18 @@
+import {Entry} from '../generated/test';
+
+declare const require: any;
+declare const process: any;
+
+const readline = require('readline');
+
+const rl = readline.createInterface({
+  input: process.stdin,
+  output: process.stdout,
+  terminal: false
+});
+
+rl.on('line', function(line: string) {
+  const data = JSON.parse(line);
+  const decoded: Entry = Entry.decode(data);
+  console.log(JSON.stringify(Entry.encode(decoded)));
+});
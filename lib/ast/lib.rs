@@ -41,7 +41,7 @@ impl<T> From<T> for ErrorRecovery<T> {
 /// #[foo(value = "hello")]
 /// <item>
 /// ```
-#[derive(Debug, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub struct Item<'input, T> {
     pub comment: Vec<Cow<'input, str>>,
     pub attributes: Vec<Loc<Attribute<'input>>>,
@@ -79,7 +79,7 @@ impl<'input, T> Item<'input, T> {
 /// ```ignore
 /// #[attribute(name = <value>)]
 /// ```
-#[derive(Debug, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub enum AttributeItem<'input> {
     Word(Loc<Value<'input>>),
     NameValue {
@@ -101,7 +101,7 @@ pub enum AttributeItem<'input> {
 /// ```ignore
 /// #[name_value(foo = <value>, bar = <value>)]
 /// ```
-#[derive(Debug, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub enum Attribute<'input> {
     Word(Loc<Cow<'input, str>>),
     List(Loc<Cow<'input, str>>, Vec<AttributeItem<'input>>),
@@ -126,8 +126,18 @@ pub enum Type {
     Any,
     /// ISO-8601 for date and time.
     DateTime,
+    /// ISO-8601 for a date, like `2018-01-31`.
+    Date,
+    /// ISO-8601 for a time of day, like `13:37:00`.
+    Time,
+    /// ISO-8601 for a duration, like `PT1H30M`.
+    Duration,
+    /// A universally unique identifier.
+    Uuid,
+    /// A named type, with type arguments if it refers to a generic declaration.
     Name {
         name: Name,
+        arguments: Vec<Loc<Type>>,
     },
     Array {
         inner: Box<Type>,
@@ -139,7 +149,7 @@ pub enum Type {
 }
 
 /// Any kind of declaration.
-#[derive(Debug, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub enum Decl<'input> {
     Type(Item<'input, TypeBody<'input>>),
     Tuple(Item<'input, TupleBody<'input>>),
@@ -160,6 +170,17 @@ impl<'input> Decl<'input> {
             Service(ref body) => &body.name,
         }
     }
+
+    /// Type parameters of the declaration, empty unless the declaration is generic.
+    pub fn generics(&self) -> &[Loc<Cow<'input, str>>] {
+        use self::Decl::*;
+
+        match *self {
+            Type(ref body) => &body.generics,
+            Interface(ref body) => &body.generics,
+            _ => &[],
+        }
+    }
 }
 
 /// The body of an enum declaration.
@@ -173,7 +194,7 @@ impl<'input> Decl<'input> {
 /// ```
 ///
 /// Note: members must only be options.
-#[derive(Debug, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub struct EnumBody<'input> {
     pub name: Cow<'input, str>,
     pub ty: Loc<Type>,
@@ -181,14 +202,14 @@ pub struct EnumBody<'input> {
     pub members: Vec<EnumMember<'input>>,
 }
 
-#[derive(Debug, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub struct EnumVariant<'input> {
     pub name: Loc<Cow<'input, str>>,
     pub argument: Option<Loc<Value<'input>>>,
 }
 
 /// A member in a tuple, type, or interface.
-#[derive(Debug, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub enum EnumMember<'input> {
     Code(Loc<Code<'input>>),
 }
@@ -198,12 +219,14 @@ pub enum EnumMember<'input> {
 /// ```ignore
 /// <name><modifier>: <ty> as <field_as>
 /// ```
-#[derive(Debug, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub struct Field<'input> {
     pub required: bool,
     pub name: Cow<'input, str>,
     pub ty: Loc<ErrorRecovery<Type>>,
     pub field_as: Option<String>,
+    /// Default value of the field, like `= 50`.
+    pub default: Option<Loc<Value<'input>>>,
 }
 
 /// A file.
@@ -215,7 +238,7 @@ pub struct Field<'input> {
 ///
 /// <decls>
 /// ```
-#[derive(Debug, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub struct File<'input> {
     pub comment: Vec<Cow<'input, str>>,
     pub attributes: Vec<Loc<Attribute<'input>>>,
@@ -258,20 +281,21 @@ pub enum Name {
 /// The body of an interface declaration
 ///
 /// ```ignore
-/// interface <name> {
+/// interface <name><<generics>> {
 ///   <members>
 ///   <sub_types>
 /// }
 /// ```
-#[derive(Debug, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub struct InterfaceBody<'input> {
     pub name: Cow<'input, str>,
+    pub generics: Vec<Loc<Cow<'input, str>>>,
     pub members: Vec<TypeMember<'input>>,
     pub sub_types: Vec<Item<'input, SubType<'input>>>,
 }
 
 /// A contextual code-block.
-#[derive(Debug, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub struct Code<'input> {
     pub attributes: Vec<Loc<Attribute<'input>>>,
     pub context: Loc<Cow<'input, str>>,
@@ -279,7 +303,7 @@ pub struct Code<'input> {
 }
 
 /// A member in a tuple, type, or interface.
-#[derive(Debug, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub enum TypeMember<'input> {
     Field(Item<'input, Field<'input>>),
     Code(Loc<Code<'input>>),
@@ -293,21 +317,21 @@ pub enum TypeMember<'input> {
 ///   <members>
 /// }
 /// ```
-#[derive(Debug, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub struct ServiceBody<'input> {
     pub name: Cow<'input, str>,
     pub members: Vec<ServiceMember<'input>>,
 }
 
 /// A member of a service declaration.
-#[derive(Debug, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub enum ServiceMember<'input> {
     Endpoint(Item<'input, Endpoint<'input>>),
     InnerDecl(Decl<'input>),
 }
 
 /// The argument in and endpoint.
-#[derive(Debug, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub struct EndpointArgument<'input> {
     pub ident: Loc<Cow<'input, str>>,
     pub channel: Loc<Channel>,
@@ -320,7 +344,7 @@ pub struct EndpointArgument<'input> {
 ///   <options>
 /// }
 /// ```
-#[derive(Debug, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub struct Endpoint<'input> {
     pub id: Loc<Cow<'input, str>>,
     pub alias: Option<String>,
@@ -334,7 +358,7 @@ pub struct Endpoint<'input> {
 /// Unary(stream <ty>)
 /// Streaming(<ty>)
 /// ```
-#[derive(Debug, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub enum Channel {
     /// Single send.
     Unary { ty: Type },
@@ -350,7 +374,7 @@ pub enum Channel {
 /// }
 /// ```
 /// Sub-types in interface declarations.
-#[derive(Debug, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub struct SubType<'input> {
     pub name: Loc<Cow<'input, str>>,
     pub members: Vec<TypeMember<'input>>,
@@ -364,7 +388,7 @@ pub struct SubType<'input> {
 ///     <members>
 /// }
 /// ```
-#[derive(Debug, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub struct TupleBody<'input> {
     pub name: Cow<'input, str>,
     pub members: Vec<TypeMember<'input>>,
@@ -373,13 +397,14 @@ pub struct TupleBody<'input> {
 /// The body of a type
 ///
 /// ```ignore
-/// type <name> {
+/// type <name><<generics>> {
 ///     <members>
 /// }
 /// ```
-#[derive(Debug, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub struct TypeBody<'input> {
     pub name: Cow<'input, str>,
+    pub generics: Vec<Loc<Cow<'input, str>>>,
     pub members: Vec<TypeMember<'input>>,
 }
 
@@ -388,7 +413,7 @@ pub struct TypeBody<'input> {
 /// ```ignore
 /// use <package> "<version req> as <alias>
 /// ```
-#[derive(Debug, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub struct UseDecl<'input> {
     pub package: Loc<RpPackage>,
     pub range: Option<Loc<String>>,
@@ -398,7 +423,7 @@ pub struct UseDecl<'input> {
 /// A literal value
 ///
 /// For example, `"string"`, `42.0`, and `foo`.
-#[derive(Debug, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub enum Value<'input> {
     String(String),
     Number(RpNumber),
@@ -407,20 +432,20 @@ pub enum Value<'input> {
 }
 
 /// A part of a step.
-#[derive(Debug, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub enum PathPart<'input> {
     Variable(Cow<'input, str>),
     Segment(String),
 }
 
 /// A step in a path specification.
-#[derive(Debug, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub struct PathStep<'input> {
     pub parts: Vec<PathPart<'input>>,
 }
 
 /// A path specification.
-#[derive(Debug, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub struct PathSpec<'input> {
     pub steps: Vec<PathStep<'input>>,
 }
@@ -4,18 +4,20 @@ use Options;
 use codegen::{ClassAdded, EndpointExtra, EnumAdded, InterfaceAdded, ServiceAdded, TupleAdded,
               TypeField, TypeFieldAdded};
 use core::errors::*;
-use core::{self, ForEachLoc, Handle, Loc, RpContext, RpSubTypeStrategy, WithSpan};
+use core::{self, ForEachLoc, Handle, Loc, RpContext, RpDeprecated, RpSubTypeStrategy,
+           WithSpan};
 use csharp_field::CsharpField;
 use csharp_file::CsharpFile;
 use flavored::{CsharpFlavor, RpDecl, RpEnumBody, RpField, RpInterfaceBody, RpServiceBody,
                RpTupleBody, RpTypeBody};
 use genco::csharp::{self, local, optional, using, Argument, Class, Constructor, Enum, Field,
                     INT32, Method, Modifier, BOOLEAN};
-use genco::{Cons, Csharp, Element, Quoted, Tokens};
+use genco::{Cons, Csharp, Element, IntoTokens, Quoted, Tokens};
 use naming::{self, Naming};
 use processor::Processor;
 use std::rc::Rc;
 use trans::Translated;
+use utils::Obsolete;
 
 pub struct Compiler {
     env: Rc<Translated<CsharpFlavor>>,
@@ -259,9 +261,25 @@ impl Compiler {
         Ok(())
     }
 
+    /// Mark the given enum variant as obsolete if it is deprecated.
+    fn variant<'el>(
+        &self,
+        deprecated: Option<&RpDeprecated>,
+        value: Tokens<'el, Csharp<'el>>,
+    ) -> Tokens<'el, Csharp<'el>> {
+        match deprecated {
+            Some(deprecated) => toks![Obsolete(deprecated).into_tokens(), " ", value],
+            None => value,
+        }
+    }
+
     fn process_enum<'el>(&self, body: &'el RpEnumBody) -> Result<Enum<'el>> {
         let mut spec = Enum::new(body.ident.clone());
 
+        if let Some(ref deprecated) = body.deprecated {
+            spec.attribute(Obsolete(deprecated));
+        }
+
         let mut names = None;
 
         match body.enum_type {
@@ -278,7 +296,7 @@ impl Compiler {
                 for v in variants {
                     let name = Rc::new(self.variant_naming.convert(v.ident()));
                     local_names.push(v.value.to_string().into());
-                    spec.variants.append(toks![name]);
+                    spec.variants.append(self.variant(v.deprecated.as_ref(), toks![name]));
                 }
 
                 names = Some(local_names);
@@ -292,7 +310,8 @@ impl Compiler {
                         _ => v.value.to_string(),
                     };
 
-                    spec.variants.append(toks![name, " = ", value]);
+                    let value = toks![name, " = ", value];
+                    spec.variants.append(self.variant(v.deprecated.as_ref(), value));
                 }
             }
         }
@@ -313,6 +332,10 @@ impl Compiler {
     fn process_tuple<'el>(&self, body: &'el RpTupleBody) -> Result<Class<'el>> {
         let mut spec = Class::new(body.ident.clone());
 
+        if let Some(ref deprecated) = body.deprecated {
+            spec.attribute(Obsolete(deprecated));
+        }
+
         let fields = self.fields(&body.fields)?;
 
         self.add_class(
@@ -338,6 +361,10 @@ impl Compiler {
 
     fn process_type<'el>(&self, body: &'el RpTypeBody) -> Result<Class<'el>> {
         let mut spec = Class::new(body.ident.clone());
+
+        if let Some(ref deprecated) = body.deprecated {
+            spec.attribute(Obsolete(deprecated));
+        }
         let fields = self.fields(&body.fields)?;
         let names: Vec<_> = fields.iter().map(|f| f.name.clone()).collect();
 
@@ -373,6 +400,10 @@ impl Compiler {
         body: &'el RpInterfaceBody,
     ) -> Result<Class<'el>> {
         let mut spec = Class::new(body.ident.clone());
+
+        if let Some(ref deprecated) = body.deprecated {
+            spec.attribute(Obsolete(deprecated));
+        }
         spec.modifiers = vec![Modifier::Abstract, Modifier::Public];
         let interface_fields = self.fields(&body.fields)?;
 
@@ -423,6 +454,10 @@ impl Compiler {
             let mut class = Class::new(sub_type.ident.clone());
             class.modifiers = vec![Modifier::Public];
 
+            if let Some(ref deprecated) = sub_type.deprecated {
+                class.attribute(Obsolete(deprecated));
+            }
+
             let sub_type_fields = self.fields(&sub_type.fields)?;
 
             class
@@ -476,6 +511,10 @@ impl Compiler {
         let mut spec = Class::new(body.ident.as_str());
         spec.modifiers = vec![Modifier::Abstract, Modifier::Public];
 
+        if let Some(ref deprecated) = body.deprecated {
+            spec.attribute(Obsolete(deprecated));
+        }
+
         let mut extra: Vec<EndpointExtra> = Vec::new();
 
         for endpoint in &body.endpoints {
@@ -521,6 +560,10 @@ impl Compiler {
                     method.comments.push("</summary>".into());
                 }
 
+                if let Some(ref deprecated) = endpoint.deprecated {
+                    method.attribute(Obsolete(deprecated));
+                }
+
                 method.arguments.extend(arguments.iter().cloned());
 
                 method.returns = response_ty.clone();
@@ -564,6 +607,10 @@ impl Compiler {
             spec.comments.push("</summary>".into());
         }
 
+        if let Some(ref deprecated) = field.deprecated {
+            spec.attribute(Obsolete(deprecated));
+        }
+
         let mut block = Tokens::new();
 
         if self.options.build_getters {
@@ -579,6 +626,7 @@ impl Compiler {
             ident: ident,
             spec: spec,
             optional: field.is_optional(),
+            default: field.default.clone(),
         })
     }
 
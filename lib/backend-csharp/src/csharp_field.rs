@@ -1,4 +1,5 @@
 pub use super::*;
+use core::RpDefault;
 use genco::csharp::Field;
 use genco::Cons;
 use std::rc::Rc;
@@ -10,6 +11,8 @@ pub struct CsharpField<'el> {
     pub ident: Rc<String>,
     pub spec: Field<'el>,
     pub optional: bool,
+    /// Default value to use when the field is absent.
+    pub default: Option<RpDefault>,
 }
 
 impl<'el> CsharpField<'el> {
@@ -32,6 +32,7 @@ pub struct CsharpFlavorTranslator {
     dictionary: Csharp<'static>,
     string: Csharp<'static>,
     date_time: Csharp<'static>,
+    guid: Csharp<'static>,
     object: Csharp<'static>,
     pub void: Csharp<'static>,
     to_upper_camel: naming::ToUpperCamel,
@@ -45,6 +46,7 @@ impl CsharpFlavorTranslator {
             dictionary: using("System.Collections.Generic", "Dictionary"),
             string: using("System", "String"),
             date_time: struct_(using("System", "DateTime")),
+            guid: struct_(using("System", "Guid")),
             object: using("System", "Object"),
             void: using("java.lang", "Void"),
             to_upper_camel: naming::to_upper_camel(),
@@ -94,6 +96,22 @@ impl FlavorTranslator for CsharpFlavorTranslator {
         Ok(self.date_time.clone())
     }
 
+    fn translate_date(&self) -> Result<Csharp<'static>> {
+        Ok(self.string.clone())
+    }
+
+    fn translate_time(&self) -> Result<Csharp<'static>> {
+        Ok(self.string.clone())
+    }
+
+    fn translate_duration(&self) -> Result<Csharp<'static>> {
+        Ok(self.string.clone())
+    }
+
+    fn translate_uuid(&self) -> Result<Csharp<'static>> {
+        Ok(self.guid.clone())
+    }
+
     fn translate_array(&self, inner: Csharp<'static>) -> Result<Csharp<'static>> {
         Ok(self.list.with_arguments(vec![inner]).into())
     }
@@ -141,6 +141,7 @@ impl Lang for CsharpLang {
 #[derive(Debug)]
 pub enum CsharpModule {
     JsonNet,
+    HttpClient,
 }
 
 impl TryFromToml for CsharpModule {
@@ -149,6 +150,7 @@ impl TryFromToml for CsharpModule {
 
         let result = match id {
             "Json.NET" => JsonNet,
+            "http_client" => HttpClient,
             _ => return NoModule::illegal(path, id, value),
         };
 
@@ -160,6 +162,7 @@ impl TryFromToml for CsharpModule {
 
         let result = match id {
             "Json.NET" => JsonNet,
+            "http_client" => HttpClient,
             _ => return NoModule::illegal(path, id, value),
         };
 
@@ -179,6 +182,7 @@ fn setup_options<'a>(modules: Vec<CsharpModule>) -> Options {
 
         match module {
             JsonNet => module::JsonNet.initialize(c),
+            HttpClient => module::HttpClient.initialize(c),
         };
     }
 
@@ -0,0 +1,268 @@
+//! Module that generates HTTP clients using System.Net.Http.HttpClient.
+
+use codegen::{Configure, EndpointExtra, ServiceAdded, ServiceCodegen};
+use core;
+use core::errors::Result;
+use flavored::{RpEndpoint, RpEndpointHttp1, RpPathSpec};
+use genco::csharp::{using, Argument, Class, Constructor, Field, Method, Modifier};
+use genco::{Csharp, Quoted, Tokens};
+use naming::{self, Naming};
+use std::rc::Rc;
+
+pub struct Module;
+
+impl Module {
+    pub fn initialize(self, e: Configure) {
+        e.options
+            .service_generators
+            .push(Box::new(HttpClientServiceCodegen::new()));
+    }
+}
+
+struct HttpClientServiceCodegen {
+    to_upper_camel: naming::ToUpperCamel,
+    http_client: Csharp<'static>,
+    http_method: Csharp<'static>,
+    http_request_message: Csharp<'static>,
+    string_content: Csharp<'static>,
+    string_builder: Csharp<'static>,
+    encoding: Csharp<'static>,
+    json_convert: Csharp<'static>,
+    task: Csharp<'static>,
+    uri: Csharp<'static>,
+}
+
+impl HttpClientServiceCodegen {
+    pub fn new() -> Self {
+        Self {
+            to_upper_camel: naming::to_upper_camel(),
+            http_client: using("System.Net.Http", "HttpClient").qualified(),
+            http_method: using("System.Net.Http", "HttpMethod"),
+            http_request_message: using("System.Net.Http", "HttpRequestMessage"),
+            string_content: using("System.Net.Http", "StringContent"),
+            string_builder: using("System.Text", "StringBuilder"),
+            encoding: using("System.Text", "Encoding"),
+            json_convert: using("Newtonsoft.Json", "JsonConvert"),
+            task: using("System.Threading.Tasks", "Task"),
+            uri: using("System", "Uri"),
+        }
+    }
+
+    /// Build the method for a single endpoint.
+    fn endpoint<'el>(
+        &self,
+        e: &'el RpEndpoint,
+        extra: &EndpointExtra<'el>,
+        path: &'el RpPathSpec,
+        client: &Field<'el>,
+        base_url: &Field<'el>,
+    ) -> Result<Method<'el>> {
+        let name = Rc::new(self.to_upper_camel.convert(extra.name.as_ref()));
+
+        let mut m = Method::new(name);
+        m.modifiers = vec![Modifier::Public, Modifier::Async];
+        m.arguments.extend(extra.arguments.iter().cloned());
+
+        if !e.comment.is_empty() {
+            m.comments.push("<summary>".into());
+            m.comments.extend(e.comment.iter().cloned().map(Into::into));
+            m.comments.push("</summary>".into());
+        }
+
+        m.returns = match e.response {
+            Some(ref res) => self.task.with_arguments(vec![res.ty().clone()]),
+            None => self.task.clone(),
+        };
+
+        m.body.push({
+            let mut t = Tokens::new();
+
+            push!(
+                t,
+                "var path_ = new ",
+                self.string_builder,
+                "(this.",
+                base_url.var(),
+                ".ToString().TrimEnd('/'));"
+            );
+
+            for step in &path.steps {
+                push!(t, "path_.Append(", "/".quoted(), ");");
+
+                for part in &step.parts {
+                    let var = match *part {
+                        core::RpPathPart::Variable(ref arg) => toks![
+                            self.uri.clone(),
+                            ".EscapeDataString(",
+                            arg.safe_ident(),
+                            ".ToString())"
+                        ],
+                        core::RpPathPart::Segment(ref s) => toks![s.as_str().quoted()],
+                    };
+
+                    push!(t, "path_.Append(", var, ");");
+                }
+            }
+
+            t
+        });
+
+        m.body.push({
+            let mut t = Tokens::new();
+
+            let method = e.http
+                .method
+                .as_ref()
+                .unwrap_or(&core::RpHttpMethod::Get)
+                .as_str();
+
+            push!(
+                t,
+                "var req_ = new ",
+                self.http_request_message,
+                "(new ",
+                self.http_method,
+                "(",
+                method.quoted(),
+                "), path_.ToString());"
+            );
+
+            if let Some(ref req) = e.request {
+                push!(
+                    t,
+                    "req_.Content = new ",
+                    self.string_content,
+                    "(",
+                    self.json_convert,
+                    ".SerializeObject(",
+                    req.safe_ident(),
+                    "), ",
+                    self.encoding,
+                    ".UTF8, ",
+                    "application/json".quoted(),
+                    ");"
+                );
+            }
+
+            t
+        });
+
+        m.body.push({
+            let mut t = Tokens::new();
+
+            push!(
+                t,
+                "using (var res_ = await this.",
+                client.var(),
+                ".SendAsync(req_)) {"
+            );
+
+            t.nested({
+                let mut t = Tokens::new();
+
+                t.push("res_.EnsureSuccessStatusCode();");
+
+                if let Some(ref res) = e.response {
+                    t.push("var body_ = await res_.Content.ReadAsStringAsync();");
+
+                    match e.http.accept {
+                        core::RpAccept::Json => {
+                            push!(
+                                t,
+                                "return ",
+                                self.json_convert,
+                                ".DeserializeObject<",
+                                res.ty().clone(),
+                                ">(body_);"
+                            );
+                        }
+                        core::RpAccept::Text => {
+                            t.push("return body_;");
+                        }
+                    }
+                }
+
+                t
+            });
+
+            t.push("}");
+            t
+        });
+
+        m.body = m.body.join_line_spacing();
+        Ok(m)
+    }
+}
+
+impl ServiceCodegen for HttpClientServiceCodegen {
+    fn generate(&self, e: ServiceAdded) -> Result<()> {
+        let ServiceAdded {
+            body, extra, spec, ..
+        } = e;
+
+        let mut client = Field::new(self.http_client.clone(), "client");
+        client.modifiers = vec![Modifier::Private];
+
+        let mut base_url = Field::new(self.uri.clone(), "baseUrl");
+        base_url.modifiers = vec![Modifier::Private];
+
+        let mut c = Class::new("HttpClient");
+        c.modifiers = vec![Modifier::Public];
+
+        c.constructors.push({
+            let mut c = Constructor::new();
+
+            let client_arg = Argument::new(client.ty(), client.var());
+            let base_url_arg = Argument::new(base_url.ty(), base_url.var());
+
+            push!(c.body, "this.", client.var(), " = ", client_arg.var(), ";");
+
+            // Use default URL if available.
+            if let Some(ref url) = body.http.url {
+                push!(
+                    c.body,
+                    "this.",
+                    base_url.var(),
+                    " = ",
+                    base_url_arg.var(),
+                    " ?? new ",
+                    self.uri,
+                    "(",
+                    url.as_str().quoted(),
+                    ");"
+                );
+            } else {
+                push!(
+                    c.body,
+                    "this.",
+                    base_url.var(),
+                    " = ",
+                    base_url_arg.var(),
+                    ";"
+                );
+            }
+
+            c.arguments.push(client_arg);
+            c.arguments.push(base_url_arg);
+            c
+        });
+
+        for (e, extra) in body.endpoints.iter().zip(extra.iter()) {
+            // Only endpoints which can be expressed as HTTP/1.1 are supported.
+            if RpEndpointHttp1::from_endpoint(e).is_none() {
+                continue;
+            }
+
+            if let Some(ref path) = e.http.path {
+                c.methods
+                    .push(self.endpoint(e, extra, path, &client, &base_url)?);
+            }
+        }
+
+        c.fields.push(client);
+        c.fields.push(base_url);
+
+        spec.body.push(c);
+        Ok(())
+    }
+}
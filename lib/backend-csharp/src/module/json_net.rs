@@ -2,7 +2,7 @@ use codegen::{ClassAdded, ClassCodegen, Configure, EnumAdded, EnumCodegen, Inter
               InterfaceCodegen, TupleAdded, TupleCodegen, TypeField, TypeFieldAdded,
               TypeFieldCodegen};
 use core::errors::Result;
-use core::{self, RpSubTypeStrategy};
+use core::{self, RpDefault, RpSubTypeStrategy};
 use flavored::RpInterfaceBody;
 use genco::csharp::{self, using, Argument};
 use genco::{Cons, Csharp, Element, IntoTokens, Quoted, Tokens};
@@ -89,7 +89,11 @@ impl ClassCodegen for JsonNet {
                     Required::DisallowNull
                 };
 
-                argument.attribute(JsonProperty(name.clone(), required));
+                argument.attribute(JsonProperty(name.clone(), required, field.default.is_some()));
+
+                if let Some(ref default) = field.default {
+                    argument.attribute(DefaultValue(default.clone()));
+                }
             }
 
             // Modify the class to deserialize, and pass type field into the super class.
@@ -99,7 +103,7 @@ impl ClassCodegen for JsonNet {
             }) = type_field.as_mut()
             {
                 let mut a = Argument::new(field.ty(), field.var());
-                a.attribute(JsonProperty(tag.clone(), Required::DisallowNull));
+                a.attribute(JsonProperty(tag.clone(), Required::DisallowNull, false));
                 c.arguments.insert(0, a);
                 c.base = Some(toks!["base(", field.var(), ")"]);
             }
@@ -113,7 +117,11 @@ impl ClassCodegen for JsonNet {
                 Required::DisallowNull
             };
 
-            spec.attribute(JsonProperty(name.clone(), required));
+            spec.attribute(JsonProperty(name.clone(), required, field.default.is_some()));
+
+            if let Some(ref default) = field.default {
+                spec.attribute(DefaultValue(default.clone()));
+            }
         }
 
         Ok(())
@@ -369,7 +377,7 @@ impl InterfaceCodegen for JsonNet {
 
 impl TypeFieldCodegen for JsonNet {
     fn generate(&self, TypeFieldAdded { field, tag }: TypeFieldAdded) -> Result<()> {
-        field.attribute(JsonProperty(tag.clone(), Required::DisallowNull));
+        field.attribute(JsonProperty(tag.clone(), Required::DisallowNull, false));
         Ok(())
     }
 }
@@ -608,7 +616,10 @@ impl<'el> IntoTokens<'el, Csharp<'el>> for JsonObject {
 }
 
 /// [JsonProperty(..)] attribute
-pub struct JsonProperty<'el>(Cons<'el>, Required);
+///
+/// The last argument indicates if the default value of the property should be populated when it
+/// is absent.
+pub struct JsonProperty<'el>(Cons<'el>, Required, bool);
 
 impl<'el> IntoTokens<'el, Csharp<'el>> for JsonProperty<'el> {
     fn into_tokens(self) -> Tokens<'el, Csharp<'el>> {
@@ -623,10 +634,43 @@ impl<'el> IntoTokens<'el, Csharp<'el>> for JsonProperty<'el> {
             other => args.append(toks!["Required = ", other.into_tokens()]),
         }
 
+        if self.2 {
+            let handling = using("Newtonsoft.Json", "DefaultValueHandling");
+            args.append(toks!["DefaultValueHandling = ", handling, ".Populate"]);
+        }
+
         toks!["[", property, "(", args.join(", "), ")]"]
     }
 }
 
+/// [DefaultValue(..)] attribute
+pub struct DefaultValue(RpDefault);
+
+impl<'el> IntoTokens<'el, Csharp<'el>> for DefaultValue {
+    fn into_tokens(self) -> Tokens<'el, Csharp<'el>> {
+        use self::RpDefault::*;
+
+        let default_value = using("System.ComponentModel", "DefaultValue");
+
+        let value: Tokens<'el, Csharp<'el>> = match self.0 {
+            Boolean(value) => toks![value.to_string()],
+            Signed { size, value } => match size {
+                32 => toks![value.to_string()],
+                _ => toks![format!("{}L", value)],
+            },
+            Unsigned { size, value } => match size {
+                32 => toks![format!("{}U", value)],
+                _ => toks![format!("{}UL", value)],
+            },
+            Float(value) => toks![format!("{}F", value)],
+            Double(value) => toks![format!("{}D", value)],
+            String(value) => toks![value.quoted()],
+        };
+
+        toks!["[", default_value, "(", value, ")]"]
+    }
+}
+
 /// [JsonConstructor] attribute
 pub struct JsonConstructor;
 
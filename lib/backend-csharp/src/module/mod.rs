@@ -1,3 +1,5 @@
+mod http_client;
 mod json_net;
 
+pub use self::http_client::Module as HttpClient;
 pub use self::json_net::Module as JsonNet;
@@ -1,5 +1,7 @@
+use core::RpDeprecated;
 use genco::csharp::using;
 use genco::{Cons, Csharp, IntoTokens, Quoted, Tokens};
+use std::rc::Rc;
 
 /// [DataMember(..)] attribute
 #[allow(unused)]
@@ -33,3 +35,20 @@ impl<'el> IntoTokens<'el, Csharp<'el>> for DataMember<'el> {
         toks!["[", data_member, "(", args.join(", "), ")]"]
     }
 }
+
+/// [Obsolete(..)] attribute
+pub struct Obsolete<'a>(pub &'a RpDeprecated);
+
+impl<'a, 'el> IntoTokens<'el, Csharp<'el>> for Obsolete<'a> {
+    fn into_tokens(self) -> Tokens<'el, Csharp<'el>> {
+        let obsolete = using("System", "Obsolete");
+
+        match self.0.message {
+            Some(ref message) => {
+                let message = Rc::new(message.to_string());
+                toks!["[", obsolete, "(", message.quoted(), ")]"]
+            }
+            None => toks!["[", obsolete, "]"],
+        }
+    }
+}
@@ -14,7 +14,7 @@ define_processor!(EnumProcessor, RpEnumBody, self,
             let id = self.body.name.join("_");
 
             html!(self, section {id => &id, class => "section-content section-enum"} => {
-                self.section_title("enum", &self.body.name)?;
+                self.section_title("enum", &self.body.name, self.body.deprecated.as_ref())?;
                 self.doc(&self.body.comment)?;
                 self.variants(self.body.variants.iter())?;
                 self.nested_decls(self.body.decls.iter())?;
@@ -56,6 +56,8 @@ impl<'p> EnumProcessor<'p> {
                             Escape(number.to_string().as_str()));
                     }
                 }
+
+                self.deprecated(variant.deprecated.as_ref())?;
             });
 
             self.doc(variant.comment)?;
@@ -12,7 +12,7 @@ define_processor!(InterfaceProcessor, RpInterfaceBody, self,
             let id = self.body.name.join("_");
 
             html!(self, section {id => &id, class => "section-content section-interface"} => {
-                self.section_title("interface", &self.body.name)?;
+                self.section_title("interface", &self.body.name, self.body.deprecated.as_ref())?;
 
                 self.doc(&self.body.comment)?;
 
@@ -37,6 +37,7 @@ impl<'p> InterfaceProcessor<'p> {
         html!(self, h2 {id => id, class => "sub-type-title"} => {
             html!(self, span {class => "kind"} ~ "subtype");
             self.full_name_without_package(&sub_type.name)?;
+            self.deprecated(sub_type.deprecated.as_ref())?;
         });
 
         self.doc(&self.body.comment)?;
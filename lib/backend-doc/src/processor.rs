@@ -3,7 +3,7 @@
 use super::{DOC_CSS_NAME, NORMALIZE_CSS_NAME};
 use core::errors::*;
 use core::flavored::{RpDecl, RpField, RpName, RpType, RpVersionedPackage};
-use core::{self, AsPackage, CoreFlavor, ForEachLoc, Loc, WithSpan};
+use core::{self, AsPackage, CoreFlavor, ForEachLoc, Loc, RpDeprecated, WithSpan};
 use doc_builder::DocBuilder;
 use escape::Escape;
 use macros::FormatAttribute;
@@ -115,6 +115,10 @@ pub trait Processor<'env> {
             Boolean => self.primitive("boolean")?,
             String => self.primitive("string")?,
             DateTime => self.primitive("datetime")?,
+            Date => self.primitive("date")?,
+            Time => self.primitive("time")?,
+            Duration => self.primitive("duration")?,
+            Uuid => self.primitive("uuid")?,
             Bytes => self.primitive("bytes")?,
             Any => self.primitive("any")?,
             Signed { ref size } => self.primitive(format!("i{}", size).as_str())?,
@@ -174,6 +178,18 @@ pub trait Processor<'env> {
                 html!(self, span {class => "keyword"} ~ "as");
                 html!(self, span {class => "field-name"} ~ Escape(field.name()));
             }
+
+            if let Some(ref default) = field.default {
+                html!(self, span {} ~ "=");
+                html!(self, span {class => "field-default"} ~ Escape(&default.to_string()));
+            }
+
+            if let Some(ref validate) = field.validate {
+                html!(self, span {class => "keyword"} ~ "validate");
+                html!(self, span {class => "field-validate"} ~ Escape(&validate.to_string()));
+            }
+
+            self.deprecated(field.deprecated.as_ref())?;
         });
 
         self.doc(&field.comment)?;
@@ -213,15 +229,31 @@ pub trait Processor<'env> {
     }
 
     /// Write a section title.
-    fn section_title(&self, kind: &str, name: &RpName) -> Result<()> {
+    fn section_title(
+        &self,
+        kind: &str,
+        name: &RpName,
+        deprecated: Option<&RpDeprecated>,
+    ) -> Result<()> {
         html!(self, h1 {class => "section-title"} => {
             html!(self, span {class => "kind"} ~ kind);
             self.full_name(name, Some(name))?;
+            self.deprecated(deprecated)?;
         });
 
         Ok(())
     }
 
+    /// Write a badge if the item is deprecated, with the deprecation message as its title.
+    fn deprecated(&self, deprecated: Option<&RpDeprecated>) -> Result<()> {
+        if let Some(deprecated) = deprecated {
+            let title = Escape(deprecated.message()).to_string();
+            html!(self, span {class => "badge deprecated", title => title} ~ "deprecated");
+        }
+
+        Ok(())
+    }
+
     /// Write a complete HTML document.
     fn write_doc<Body>(&self, body: Body) -> Result<()>
     where
@@ -14,7 +14,7 @@ define_processor!(ServiceProcessor, RpServiceBody, self,
             let id = self.body.name.join("_");
 
             html!(self, section {id => &id, class => "section-content section-service"} => {
-                self.section_title("service", &self.body.name)?;
+                self.section_title("service", &self.body.name, self.body.deprecated.as_ref())?;
 
                 self.doc(&self.body.comment)?;
 
@@ -81,6 +81,8 @@ impl<'p> ServiceProcessor<'p> {
                 html!(self, span {class => "keyword"} ~ Escape("as"));
                 html!(self, span {} ~ Escape(endpoint.name()));
             }
+
+            self.deprecated(endpoint.deprecated.as_ref())?;
         });
 
         self.doc(&endpoint.comment)?;
@@ -12,7 +12,7 @@ define_processor!(TupleProcessor, RpTupleBody, self,
             let id = self.body.name.join("_");
 
             html!(self, section {id => &id, class => "section-content section-tuple"} => {
-                self.section_title("tuple", &self.body.name)?;
+                self.section_title("tuple", &self.body.name, self.body.deprecated.as_ref())?;
 
                 html!(self, div {class => "section-body"} => {
                     self.doc(&self.body.comment)?;
@@ -12,7 +12,7 @@ define_processor!(TypeProcessor, RpTypeBody, self,
             let id = self.body.name.join("_");
 
             html!(self, section {id => &id, class => "section-content section-type"} => {
-                self.section_title("type", &self.body.name)?;
+                self.section_title("type", &self.body.name, self.body.deprecated.as_ref())?;
 
                 html!(self, div {class => "section-body"} => {
                     self.doc(&self.body.comment)?;
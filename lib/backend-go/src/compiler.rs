@@ -2,13 +2,14 @@
 
 use backend::PackageProcessor;
 use core::errors::*;
-use core::{Handle, Loc, RelativePathBuf};
-use flavored::{GoFlavor, GoName, RpEnumBody, RpField, RpInterfaceBody, RpPackage, RpTupleBody,
-               RpTypeBody};
+use core::{Handle, Loc, RelativePathBuf, RpDeprecated};
+use flavored::{GoFlavor, GoName, RpEnumBody, RpField, RpInterfaceBody, RpPackage, RpServiceBody,
+               RpTupleBody, RpTypeBody};
 use genco::go::Go;
 use genco::{IntoTokens, Tokens};
 use trans::{self, Translated};
-use {EnumAdded, FieldAdded, FileSpec, InterfaceAdded, Options, Tags, TupleAdded, EXT};
+use {EnumAdded, FieldAdded, FileSpec, InterfaceAdded, Options, ServiceAdded, StructAdded, Tags,
+     TupleAdded, EXT};
 
 /// Documentation comments.
 pub struct Comments<'el, S: 'el>(pub &'el [S]);
@@ -25,6 +26,18 @@ impl<'el, S: 'el + AsRef<str>> IntoTokens<'el, Go<'el>> for Comments<'el, S> {
     }
 }
 
+/// Deprecation notice, using the `Deprecated:` convention recognized by Go tooling.
+pub struct Deprecated<'el>(pub &'el Option<RpDeprecated>);
+
+impl<'el> IntoTokens<'el, Go<'el>> for Deprecated<'el> {
+    fn into_tokens(self) -> Tokens<'el, Go<'el>> {
+        match *self.0 {
+            Some(ref deprecated) => toks!["// Deprecated: ", deprecated.message()],
+            None => toks![],
+        }
+    }
+}
+
 pub struct Compiler<'el> {
     pub env: &'el Translated<GoFlavor>,
     options: Options,
@@ -50,20 +63,24 @@ impl<'el> Compiler<'el> {
         &self,
         name: &'el GoName,
         comment: &'el [String],
+        deprecated: &'el Option<RpDeprecated>,
         fields: I,
     ) -> Result<Tokens<'el, Go<'el>>>
     where
         I: IntoIterator<Item = &'el RpField>,
     {
+        let fields = fields.into_iter().collect::<Vec<_>>();
+
         let mut t = Tokens::new();
 
         t.push(Comments(comment));
+        t.push_unless_empty(Deprecated(deprecated));
         t.push(toks!["type ", name, " struct {"]);
 
         t.nested({
             let mut t = Tokens::new();
 
-            for f in fields.into_iter() {
+            for f in fields.iter().cloned() {
                 let ty = if f.is_optional() {
                     toks!["*", f.ty.clone()]
                 } else {
@@ -84,6 +101,7 @@ impl<'el> Compiler<'el> {
 
                 t.push_into(|t| {
                     t.push(Comments(&f.comment));
+                    t.push_unless_empty(Deprecated(&f.deprecated));
                     t.push(base.join_spacing());
                 });
             }
@@ -92,7 +110,19 @@ impl<'el> Compiler<'el> {
         });
 
         t.push("}");
-        Ok(t)
+
+        let mut container = Tokens::new();
+        container.push(t);
+
+        for g in &self.options.struct_gens {
+            g.generate(StructAdded {
+                container: &mut container,
+                name: name,
+                fields: &fields,
+            })?;
+        }
+
+        Ok(container.join_line_spacing())
     }
 
     pub fn compile(&self) -> Result<()> {
@@ -131,6 +161,7 @@ impl<'el> PackageProcessor<'el, GoFlavor, GoName> for Compiler<'el> {
         out.0.push(self.process_struct(
             &body.name,
             &body.comment,
+            &body.deprecated,
             body.fields.iter().map(Loc::value),
         )?);
 
@@ -140,6 +171,7 @@ impl<'el> PackageProcessor<'el, GoFlavor, GoName> for Compiler<'el> {
     fn process_tuple(&self, out: &mut Self::Out, body: &'el RpTupleBody) -> Result<()> {
         out.0.try_push_into::<Error, _>(|t| {
             t.push(Comments(&body.comment));
+            t.push_unless_empty(Deprecated(&body.deprecated));
             t.push(toks!["type ", &body.name, " struct {"]);
 
             t.nested({
@@ -159,6 +191,7 @@ impl<'el> PackageProcessor<'el, GoFlavor, GoName> for Compiler<'el> {
 
                     t.push_into(|t| {
                         t.push(Comments(&f.comment));
+                        t.push_unless_empty(Deprecated(&f.deprecated));
                         t.push(base.join_spacing());
                     });
                 }
@@ -187,6 +220,7 @@ impl<'el> PackageProcessor<'el, GoFlavor, GoName> for Compiler<'el> {
 
             t.push_into(|t| {
                 t.push(Comments(&body.comment));
+                t.push_unless_empty(Deprecated(&body.deprecated));
                 t.push(toks!["type ", &body.name, " int"])
             });
 
@@ -233,6 +267,7 @@ impl<'el> PackageProcessor<'el, GoFlavor, GoName> for Compiler<'el> {
 
             t.try_push_into::<Error, _>(|t| {
                 t.push_unless_empty(Comments(&body.comment));
+                t.push_unless_empty(Deprecated(&body.deprecated));
                 push!(t, "type ", &body.name, " struct {");
 
                 t.nested_into(|t| {
@@ -252,6 +287,7 @@ impl<'el> PackageProcessor<'el, GoFlavor, GoName> for Compiler<'el> {
                     t.push(self.process_struct(
                         &sub_type.name,
                         &sub_type.comment,
+                        &sub_type.deprecated,
                         body.fields
                             .iter()
                             .chain(sub_type.fields.iter())
@@ -280,4 +316,16 @@ impl<'el> PackageProcessor<'el, GoFlavor, GoName> for Compiler<'el> {
 
         Ok(())
     }
+
+    fn process_service(&self, out: &mut Self::Out, body: &'el RpServiceBody) -> Result<()> {
+        for g in &self.options.service_gens {
+            g.generate(ServiceAdded {
+                container: &mut out.0,
+                name: &body.name,
+                body: body,
+            })?;
+        }
+
+        Ok(())
+    }
 }
@@ -104,6 +104,22 @@ impl FlavorTranslator for GoFlavorTranslator {
         Ok(local("string"))
     }
 
+    fn translate_date(&self) -> Result<Go<'static>> {
+        Ok(local("string"))
+    }
+
+    fn translate_time(&self) -> Result<Go<'static>> {
+        Ok(local("string"))
+    }
+
+    fn translate_duration(&self) -> Result<Go<'static>> {
+        Ok(local("string"))
+    }
+
+    fn translate_uuid(&self) -> Result<Go<'static>> {
+        Ok(local("string"))
+    }
+
     fn translate_array(&self, argument: Go<'static>) -> Result<Go<'static>> {
         Ok(array(argument))
     }
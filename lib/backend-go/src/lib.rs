@@ -23,7 +23,8 @@ use backend::{Initializer, IntoBytes};
 use compiler::Compiler;
 use core::errors::Result;
 use core::{Context, CoreFlavor};
-use flavored::{GoName, RpEnumBody, RpField, RpInterfaceBody, RpPackage, RpTupleBody};
+use flavored::{GoName, RpEnumBody, RpField, RpInterfaceBody, RpPackage, RpServiceBody,
+               RpTupleBody};
 use genco::go::{self, Go};
 use genco::{Element, IntoTokens, Tokens};
 use manifest::{Lang, Manifest, NoModule, TryFromToml};
@@ -63,6 +64,7 @@ impl Lang for GoLang {
 #[derive(Debug)]
 pub enum GoModule {
     EncodingJson,
+    NetHttp,
 }
 
 impl TryFromToml for GoModule {
@@ -71,6 +73,7 @@ impl TryFromToml for GoModule {
 
         let result = match id {
             "encoding/json" => EncodingJson,
+            "net/http" => NetHttp,
             _ => return NoModule::illegal(path, id, value),
         };
 
@@ -82,6 +85,7 @@ impl TryFromToml for GoModule {
 
         let result = match id {
             "encoding/json" => EncodingJson,
+            "net/http" => NetHttp,
             _ => return NoModule::illegal(path, id, value),
         };
 
@@ -91,18 +95,22 @@ impl TryFromToml for GoModule {
 
 pub struct Options {
     pub field_gens: Vec<Box<FieldCodegen>>,
+    pub struct_gens: Vec<Box<StructCodegen>>,
     pub enum_gens: Vec<Box<EnumCodegen>>,
     pub tuple_gens: Vec<Box<TupleCodegen>>,
     pub interface_gens: Vec<Box<InterfaceCodegen>>,
+    pub service_gens: Vec<Box<ServiceCodegen>>,
 }
 
 impl Options {
     pub fn new() -> Options {
         Options {
             field_gens: Vec::new(),
+            struct_gens: Vec::new(),
             enum_gens: Vec::new(),
             tuple_gens: Vec::new(),
             interface_gens: Vec::new(),
+            service_gens: Vec::new(),
         }
     }
 }
@@ -117,6 +125,7 @@ pub fn options(modules: Vec<GoModule>) -> Result<Options> {
 
         let initializer: Box<Initializer<Options = Options>> = match m {
             EncodingJson => Box::new(module::EncodingJson::new()),
+            NetHttp => Box::new(module::NetHttp::new()),
         };
 
         initializer.initialize(&mut options)?;
@@ -167,6 +176,15 @@ pub struct FieldAdded<'a, 'el: 'a> {
 
 codegen!(FieldCodegen, FieldAdded);
 
+/// Event emitted when a struct has been added.
+pub struct StructAdded<'a, 'el: 'a> {
+    pub container: &'a mut Tokens<'el, Go<'el>>,
+    pub name: &'el GoName,
+    pub fields: &'a [&'el RpField],
+}
+
+codegen!(StructCodegen, StructAdded);
+
 /// Event emitted when an enum has been added
 pub struct EnumAdded<'a, 'el: 'a> {
     pub container: &'a mut Tokens<'el, Go<'el>>,
@@ -194,6 +212,15 @@ pub struct InterfaceAdded<'a, 'el: 'a> {
 
 codegen!(InterfaceCodegen, InterfaceAdded);
 
+/// Event emitted when a service has been added.
+pub struct ServiceAdded<'a, 'el: 'a> {
+    pub container: &'a mut Tokens<'el, Go<'el>>,
+    pub name: &'el GoName,
+    pub body: &'el RpServiceBody,
+}
+
+codegen!(ServiceCodegen, ServiceAdded);
+
 pub enum TagValue {
     String(String),
 }
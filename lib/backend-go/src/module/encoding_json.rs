@@ -1,14 +1,14 @@
 //! encoding/json module for Go
 
 use backend::Initializer;
-use core;
 use core::errors::{Error, Result};
-use flavored::{GoName, RpEnumBody, RpInterfaceBody, RpSubType, RpTupleBody};
+use core::{self, RpDefault};
+use flavored::{GoName, RpEnumBody, RpField, RpInterfaceBody, RpSubType, RpTupleBody};
 use genco::go::{imported, Go};
 use genco::{Quoted, Tokens};
 use std::rc::Rc;
 use {EnumAdded, EnumCodegen, FieldAdded, FieldCodegen, InterfaceAdded, InterfaceCodegen, Options,
-     TupleAdded, TupleCodegen};
+     StructAdded, StructCodegen, TupleAdded, TupleCodegen};
 
 pub struct Module {}
 
@@ -24,6 +24,7 @@ impl Initializer for Module {
     fn initialize(&self, options: &mut Self::Options) -> Result<()> {
         let codegen = Rc::new(Codegen::new());
         options.field_gens.push(Box::new(codegen.clone()));
+        options.struct_gens.push(Box::new(codegen.clone()));
         options.enum_gens.push(Box::new(codegen.clone()));
         options.tuple_gens.push(Box::new(codegen.clone()));
         options.interface_gens.push(Box::new(codegen.clone()));
@@ -63,6 +64,91 @@ impl FieldCodegen for Codegen {
     }
 }
 
+impl StructCodegen for Codegen {
+    fn generate(&self, e: StructAdded) -> Result<()> {
+        let StructAdded {
+            container,
+            name,
+            fields,
+            ..
+        } = e;
+
+        let defaults = fields
+            .iter()
+            .cloned()
+            .filter_map(|f| f.default.as_ref().map(|d| (f, d)))
+            .collect::<Vec<_>>();
+
+        // only needed to populate default values for absent fields.
+        if defaults.is_empty() {
+            return Ok(());
+        }
+
+        container.push(unmarshal_json(self, name, defaults));
+        return Ok(());
+
+        fn unmarshal_json<'el>(
+            c: &Codegen,
+            name: &'el GoName,
+            defaults: Vec<(&'el RpField, &'el RpDefault)>,
+        ) -> Tokens<'el, Go<'el>> {
+            let mut t = Tokens::new();
+
+            push!(t, "func (this *", name, ") UnmarshalJSON(b []byte) error {");
+
+            t.nested({
+                let mut t = Tokens::new();
+
+                // alias without methods, to avoid recursing into this function.
+                push!(t, "type alias ", name);
+                push!(t, "data := alias{}");
+
+                t.push({
+                    let mut t = Tokens::new();
+
+                    for (i, (field, default)) in defaults.into_iter().enumerate() {
+                        let var = format!("v{}", i);
+                        let value = toks![field.ty.clone(), "(", literal(default), ")"];
+
+                        push!(t, var.clone(), " := ", value);
+                        push!(t, "data.", field.safe_ident(), " = &", var);
+                    }
+
+                    t
+                });
+
+                t.push_into(|t| {
+                    push!(t, "if err := ", c.unmarshal, "(b, &data); err != nil {");
+                    nested!(t, "return err");
+                    push!(t, "}");
+                });
+
+                t.push_into(|t| {
+                    push!(t, "*this = ", name, "(data)");
+                    push!(t, "return nil");
+                });
+
+                t.join_line_spacing()
+            });
+
+            t.push("}");
+
+            t
+        }
+
+        fn literal<'el>(default: &'el RpDefault) -> Tokens<'el, Go<'el>> {
+            use self::RpDefault::*;
+
+            match *default {
+                Boolean(value) => toks![value.to_string()],
+                Signed { ref value, .. } | Unsigned { ref value, .. } => toks![value.to_string()],
+                Float(ref value) | Double(ref value) => toks![value.to_string()],
+                String(ref value) => toks![value.as_str().quoted()],
+            }
+        }
+    }
+}
+
 impl EnumCodegen for Codegen {
     fn generate(&self, e: EnumAdded) -> Result<()> {
         let EnumAdded {
@@ -1,3 +1,5 @@
 mod encoding_json;
+mod net_http;
 
 pub use self::encoding_json::Module as EncodingJson;
+pub use self::net_http::Module as NetHttp;
@@ -0,0 +1,772 @@
+//! net/http module for Go
+
+use backend::Initializer;
+use compiler::Comments;
+use core::errors::Result;
+use core::{self, Loc};
+use flavored::{GoName, RpEndpoint, RpEndpointHttp1, RpPathSpec, RpServiceBody};
+use genco::go::{imported, local, Go};
+use genco::{Cons, Quoted, Tokens};
+use naming::{self, Naming};
+use std::rc::Rc;
+use {Options, ServiceAdded, ServiceCodegen};
+
+pub struct Module {}
+
+impl Module {
+    pub fn new() -> Module {
+        Module {}
+    }
+}
+
+impl Initializer for Module {
+    type Options = Options;
+
+    fn initialize(&self, options: &mut Self::Options) -> Result<()> {
+        let codegen = Rc::new(Codegen::new());
+        options.service_gens.push(Box::new(codegen.clone()));
+        Ok(())
+    }
+}
+
+/// An endpoint which can be expressed over HTTP/1.1.
+struct Endpoint<'el> {
+    e: &'el RpEndpoint,
+    path: &'el RpPathSpec,
+    method: &'el str,
+    /// Exported name of the endpoint.
+    ident: Rc<String>,
+}
+
+struct Codegen {
+    to_upper_camel: naming::ToUpperCamel,
+    string: Go<'static>,
+    client: Go<'static>,
+    new_request: Go<'static>,
+    handler: Go<'static>,
+    response_writer: Go<'static>,
+    request: Go<'static>,
+    http_error: Go<'static>,
+    not_found: Go<'static>,
+    status_text: Go<'static>,
+    method_not_allowed: Go<'static>,
+    bad_request: Go<'static>,
+    internal_server_error: Go<'static>,
+    buffer: Go<'static>,
+    new_reader: Go<'static>,
+    path_escape: Go<'static>,
+    sprint: Go<'static>,
+    sscan: Go<'static>,
+    marshal: Go<'static>,
+    new_decoder: Go<'static>,
+    new_encoder: Go<'static>,
+    read_all: Go<'static>,
+    write_string: Go<'static>,
+    new_error: Go<'static>,
+    regexp: Go<'static>,
+    must_compile: Go<'static>,
+    has_prefix: Go<'static>,
+    join: Go<'static>,
+}
+
+impl Codegen {
+    pub fn new() -> Codegen {
+        Self {
+            to_upper_camel: naming::to_upper_camel(),
+            string: local("string"),
+            client: imported("net/http", "Client"),
+            new_request: imported("net/http", "NewRequest"),
+            handler: imported("net/http", "Handler"),
+            response_writer: imported("net/http", "ResponseWriter"),
+            request: imported("net/http", "Request"),
+            http_error: imported("net/http", "Error"),
+            not_found: imported("net/http", "NotFound"),
+            status_text: imported("net/http", "StatusText"),
+            method_not_allowed: imported("net/http", "StatusMethodNotAllowed"),
+            bad_request: imported("net/http", "StatusBadRequest"),
+            internal_server_error: imported("net/http", "StatusInternalServerError"),
+            buffer: imported("bytes", "Buffer"),
+            new_reader: imported("bytes", "NewReader"),
+            path_escape: imported("net/url", "PathEscape"),
+            sprint: imported("fmt", "Sprint"),
+            sscan: imported("fmt", "Sscan"),
+            marshal: imported("encoding/json", "Marshal"),
+            new_decoder: imported("encoding/json", "NewDecoder"),
+            new_encoder: imported("encoding/json", "NewEncoder"),
+            read_all: imported("io/ioutil", "ReadAll"),
+            write_string: imported("io", "WriteString"),
+            new_error: imported("errors", "New"),
+            regexp: imported("regexp", "Regexp"),
+            must_compile: imported("regexp", "MustCompile"),
+            has_prefix: imported("strings", "HasPrefix"),
+            join: imported("strings", "Join"),
+        }
+    }
+
+    /// Collect all endpoints which can be expressed over HTTP/1.1.
+    fn endpoints<'el>(&self, body: &'el RpServiceBody) -> Vec<Endpoint<'el>> {
+        let mut out = Vec::new();
+
+        for e in &body.endpoints {
+            if RpEndpointHttp1::from_endpoint(e).is_none() {
+                continue;
+            }
+
+            let path = match e.http.path {
+                Some(ref path) => path,
+                None => continue,
+            };
+
+            let method = e.http
+                .method
+                .as_ref()
+                .unwrap_or(&core::RpHttpMethod::Get)
+                .as_str();
+
+            out.push(Endpoint {
+                e: Loc::value(e),
+                path: path,
+                method: method,
+                ident: Rc::new(self.to_upper_camel.convert(e.ident())),
+            });
+        }
+
+        out
+    }
+
+    /// Method signature shared by the client and the handler interface.
+    fn signature<'el>(&self, e: &Endpoint<'el>) -> Tokens<'el, Go<'el>> {
+        let mut args = Tokens::new();
+
+        for a in &e.e.arguments {
+            args.append(toks![a.safe_ident(), " ", a.channel.ty().clone()]);
+        }
+
+        let ret = match e.e.response {
+            Some(ref res) => toks!["(*", res.ty().clone(), ", error)"],
+            None => toks!["error"],
+        };
+
+        toks![e.ident.clone(), "(", args.join(", "), ") ", ret]
+    }
+
+    /// The value to return in case of errors.
+    fn error_return<'el>(&self, e: &Endpoint<'el>) -> Tokens<'el, Go<'el>> {
+        match e.e.response {
+            Some(_) => toks!["return nil, err"],
+            None => toks!["return err"],
+        }
+    }
+
+    /// Build the typed client for a service.
+    fn client<'el>(
+        &self,
+        name: &'el GoName,
+        body: &'el RpServiceBody,
+        endpoints: &[Endpoint<'el>],
+    ) -> Tokens<'el, Go<'el>> {
+        let client_name = Rc::new(format!("{}_Client", name));
+
+        let mut t = Tokens::new();
+
+        t.push_into(|t| {
+            push!(t, "// HTTP client for ", name, ".");
+            push!(t, "type ", client_name, " struct {");
+            nested!(t, "client *", self.client);
+            nested!(t, "url string");
+            push!(t, "}");
+        });
+
+        t.push_into(|t| {
+            push!(
+                t,
+                "func New",
+                client_name,
+                "(client *",
+                self.client,
+                ", url string) *",
+                client_name,
+                " {"
+            );
+
+            t.nested_into(|t| {
+                // Use default URL if available.
+                if let Some(ref url) = body.http.url {
+                    t.push_into(|t| {
+                        push!(t, "if url == ", "".quoted(), " {");
+                        nested!(t, "url = ", url.as_str().quoted());
+                        push!(t, "}");
+                    });
+                }
+
+                push!(t, "return &", client_name, "{client: client, url: url}");
+            });
+
+            push!(t, "}");
+        });
+
+        for e in endpoints {
+            t.push(self.client_endpoint(&client_name, e));
+        }
+
+        t.join_line_spacing()
+    }
+
+    /// Build a single client method.
+    fn client_endpoint<'el>(
+        &self,
+        client_name: &Rc<String>,
+        e: &Endpoint<'el>,
+    ) -> Tokens<'el, Go<'el>> {
+        let err = self.error_return(e);
+
+        let mut t = Tokens::new();
+
+        t.push_unless_empty(Comments(&e.e.comment));
+        push!(
+            t,
+            "func (this *",
+            client_name.clone(),
+            ") ",
+            self.signature(e),
+            " {"
+        );
+
+        t.nested({
+            let mut t = Tokens::new();
+
+            t.push_into(|t| {
+                push!(t, "var path ", self.buffer);
+                push!(t, "path.WriteString(this.url)");
+
+                for step in &e.path.steps {
+                    push!(t, "path.WriteString(", "/".quoted(), ")");
+
+                    for part in &step.parts {
+                        match *part {
+                            core::RpPathPart::Variable(ref arg) => {
+                                let value = toks![self.sprint.clone(), "(", arg.safe_ident(), ")"];
+                                let value = toks![self.path_escape.clone(), "(", value, ")"];
+                                push!(t, "path.WriteString(", value, ")");
+                            }
+                            core::RpPathPart::Segment(ref s) => {
+                                push!(t, "path.WriteString(", s.as_str().quoted(), ")");
+                            }
+                        }
+                    }
+                }
+            });
+
+            let body = match e.e.request {
+                Some(ref req) => {
+                    t.push_into(|t| {
+                        push!(t, "body, err := ", self.marshal, "(", req.safe_ident(), ")");
+                        push!(t, "if err != nil {");
+                        nested!(t, err.clone());
+                        push!(t, "}");
+                    });
+
+                    toks![self.new_reader.clone(), "(body)"]
+                }
+                None => toks!["nil"],
+            };
+
+            t.push_into(|t| {
+                push!(
+                    t,
+                    "req, err := ",
+                    self.new_request,
+                    "(",
+                    e.method.quoted(),
+                    ", path.String(), ",
+                    body,
+                    ")"
+                );
+                push!(t, "if err != nil {");
+                nested!(t, err.clone());
+                push!(t, "}");
+            });
+
+            if e.e.request.is_some() {
+                push!(
+                    t,
+                    "req.Header.Set(",
+                    "Content-Type".quoted(),
+                    ", ",
+                    "application/json".quoted(),
+                    ")"
+                );
+            }
+
+            t.push_into(|t| {
+                push!(t, "res, err := this.client.Do(req)");
+                push!(t, "if err != nil {");
+                nested!(t, err.clone());
+                push!(t, "}");
+            });
+
+            push!(t, "defer res.Body.Close()");
+
+            let bad_response = toks![
+                self.new_error.clone(),
+                "(",
+                "bad response: ".quoted(),
+                " + res.Status)"
+            ];
+
+            t.push_into(|t| {
+                push!(t, "if res.StatusCode < 200 || res.StatusCode >= 300 {");
+
+                match e.e.response {
+                    Some(_) => nested!(t, "return nil, ", bad_response),
+                    None => nested!(t, "return ", bad_response),
+                }
+
+                push!(t, "}");
+            });
+
+            match e.e.response {
+                Some(ref res) => match e.e.http.accept {
+                    core::RpAccept::Json => {
+                        push!(t, "var response ", res.ty().clone());
+
+                        t.push_into(|t| {
+                            push!(
+                                t,
+                                "if err := ",
+                                self.new_decoder,
+                                "(res.Body).Decode(&response); err != nil {"
+                            );
+                            nested!(t, err.clone());
+                            push!(t, "}");
+                        });
+
+                        push!(t, "return &response, nil");
+                    }
+                    core::RpAccept::Text => {
+                        t.push_into(|t| {
+                            push!(t, "b, err := ", self.read_all, "(res.Body)");
+                            push!(t, "if err != nil {");
+                            nested!(t, err.clone());
+                            push!(t, "}");
+                        });
+
+                        push!(t, "response := string(b)");
+                        push!(t, "return &response, nil");
+                    }
+                },
+                None => {
+                    push!(t, "return nil");
+                }
+            }
+
+            t.join_line_spacing()
+        });
+
+        push!(t, "}");
+        t
+    }
+
+    /// Build the handler interface for a service.
+    fn handler<'el>(&self, name: &'el GoName, endpoints: &[Endpoint<'el>]) -> Tokens<'el, Go<'el>> {
+        let mut t = Tokens::new();
+
+        push!(t, "// Server-side handler for ", name, ".");
+        push!(t, "type ", name, "_Handler interface {");
+
+        t.nested({
+            let mut t = Tokens::new();
+
+            for e in endpoints {
+                t.push_into(|t| {
+                    t.push_unless_empty(Comments(&e.e.comment));
+                    t.push(self.signature(e));
+                });
+            }
+
+            t.join_line_spacing()
+        });
+
+        push!(t, "}");
+        t
+    }
+
+    /// Build the router for a service, which dispatches requests to a handler.
+    ///
+    /// The router is mounted under a prefix, which is stripped from the path of every request
+    /// before it is matched.
+    fn router<'el>(&self, name: &'el GoName, endpoints: &[Endpoint<'el>]) -> Tokens<'el, Go<'el>> {
+        let router_name = Rc::new(format!("{}_Router", name));
+
+        let mut t = Tokens::new();
+
+        t.push_into(|t| {
+            push!(t, "// Router for ", name, " which implements ", self.handler, ".");
+            push!(t, "type ", router_name, " struct {");
+
+            t.nested_into(|t| {
+                push!(t, "handler ", name, "_Handler");
+                push!(t, "prefix string");
+
+                for e in endpoints {
+                    push!(t, path_field(e), " *", self.regexp);
+                }
+            });
+
+            push!(t, "}");
+        });
+
+        t.push_into(|t| {
+            push!(
+                t,
+                "func New",
+                router_name,
+                "(handler ",
+                name,
+                "_Handler, prefix string) *",
+                router_name,
+                " {"
+            );
+
+            t.nested_into(|t| {
+                push!(t, "return &", router_name, "{");
+
+                t.nested_into(|t| {
+                    push!(t, "handler: handler,");
+                    push!(t, "prefix: prefix,");
+
+                    for e in endpoints {
+                        let re = path_regex(e.path).quoted();
+                        push!(t, path_field(e), ": ", self.must_compile, "(", re, "),");
+                    }
+                });
+
+                push!(t, "}");
+            });
+
+            push!(t, "}");
+        });
+
+        t.push_into(|t| {
+            push!(
+                t,
+                "func (this *",
+                router_name,
+                ") ServeHTTP(w ",
+                self.response_writer,
+                ", r *",
+                self.request,
+                ") {"
+            );
+
+            t.nested({
+                let mut t = Tokens::new();
+
+                t.push_into(|t| {
+                    push!(t, "if !", self.has_prefix, "(r.URL.Path, this.prefix) {");
+                    nested!(t, self.not_found, "(w, r)");
+                    nested!(t, "return");
+                    push!(t, "}");
+                });
+
+                t.push_into(|t| {
+                    push!(t, "path := r.URL.Path[len(this.prefix):]");
+                    push!(t, "var allow []string");
+                });
+
+                for e in endpoints {
+                    t.push_into(|t| {
+                        let matches = toks!["this.", path_field(e), ".FindStringSubmatch(path)"];
+
+                        push!(t, "if m := ", matches, "; m != nil {");
+
+                        t.nested({
+                            let mut t = Tokens::new();
+
+                            t.push_into(|t| {
+                                push!(t, "if r.Method == ", e.method.quoted(), " {");
+                                nested!(t, "this.serve", e.ident.clone(), "(w, r, m)");
+                                nested!(t, "return");
+                                push!(t, "}");
+                            });
+
+                            push!(t, "allow = append(allow, ", e.method.quoted(), ")");
+
+                            t.join_line_spacing()
+                        });
+
+                        push!(t, "}");
+                    });
+                }
+
+                t.push_into(|t| {
+                    push!(t, "if len(allow) > 0 {");
+
+                    t.nested_into(|t| {
+                        push!(
+                            t,
+                            "w.Header().Set(",
+                            "Allow".quoted(),
+                            ", ",
+                            self.join,
+                            "(allow, ",
+                            ", ".quoted(),
+                            "))"
+                        );
+
+                        let status = self.method_not_allowed.clone();
+
+                        push!(
+                            t,
+                            self.http_error,
+                            "(w, ",
+                            self.status_text,
+                            "(",
+                            status,
+                            "), ",
+                            status,
+                            ")"
+                        );
+
+                        push!(t, "return");
+                    });
+
+                    push!(t, "}");
+                });
+
+                push!(t, self.not_found, "(w, r)");
+
+                t.join_line_spacing()
+            });
+
+            push!(t, "}");
+        });
+
+        for e in endpoints {
+            t.push(self.router_endpoint(&router_name, e));
+        }
+
+        t.join_line_spacing()
+    }
+
+    /// Build the method which serves a single endpoint.
+    fn router_endpoint<'el>(
+        &self,
+        router_name: &Rc<String>,
+        e: &Endpoint<'el>,
+    ) -> Tokens<'el, Go<'el>> {
+        let mut t = Tokens::new();
+
+        push!(
+            t,
+            "func (this *",
+            router_name.clone(),
+            ") serve",
+            e.ident.clone(),
+            "(w ",
+            self.response_writer,
+            ", r *",
+            self.request,
+            ", m []string) {"
+        );
+
+        t.nested({
+            let mut t = Tokens::new();
+
+            let mut index = 1;
+
+            for step in &e.path.steps {
+                for part in &step.parts {
+                    let arg = match *part {
+                        core::RpPathPart::Variable(ref arg) => arg,
+                        core::RpPathPart::Segment(_) => continue,
+                    };
+
+                    let m = toks!["m[", index.to_string(), "]"];
+                    index += 1;
+
+                    let ty = arg.channel.ty();
+
+                    if *ty == self.string {
+                        push!(t, arg.safe_ident(), " := ", m);
+                        continue;
+                    }
+
+                    t.push_into(|t| {
+                        push!(t, "var ", arg.safe_ident(), " ", ty.clone());
+                        push!(
+                            t,
+                            "if _, err := ",
+                            self.sscan,
+                            "(",
+                            m,
+                            ", &",
+                            arg.safe_ident(),
+                            "); err != nil {"
+                        );
+                        nested!(
+                            t,
+                            self.http_error,
+                            "(w, err.Error(), ",
+                            self.bad_request,
+                            ")"
+                        );
+                        nested!(t, "return");
+                        push!(t, "}");
+                    });
+                }
+            }
+
+            if let Some(ref req) = e.e.request {
+                t.push_into(|t| {
+                    push!(t, "var ", req.safe_ident(), " ", req.channel.ty().clone());
+                    push!(
+                        t,
+                        "if err := ",
+                        self.new_decoder,
+                        "(r.Body).Decode(&",
+                        req.safe_ident(),
+                        "); err != nil {"
+                    );
+                    nested!(
+                        t,
+                        self.http_error,
+                        "(w, err.Error(), ",
+                        self.bad_request,
+                        ")"
+                    );
+                    nested!(t, "return");
+                    push!(t, "}");
+                });
+            }
+
+            let mut args = Tokens::new();
+
+            for a in &e.e.arguments {
+                args.append(a.safe_ident());
+            }
+
+            let call = toks!["this.handler.", e.ident.clone(), "(", args.join(", "), ")"];
+
+            t.push_into(|t| {
+                match e.e.response {
+                    Some(_) => push!(t, "response, err := ", call),
+                    None => push!(t, "err := ", call),
+                }
+
+                push!(t, "if err != nil {");
+                nested!(
+                    t,
+                    self.http_error,
+                    "(w, err.Error(), ",
+                    self.internal_server_error,
+                    ")"
+                );
+                nested!(t, "return");
+                push!(t, "}");
+            });
+
+            if e.e.response.is_some() {
+                match e.e.http.accept {
+                    core::RpAccept::Json => {
+                        push!(
+                            t,
+                            "w.Header().Set(",
+                            "Content-Type".quoted(),
+                            ", ",
+                            "application/json".quoted(),
+                            ")"
+                        );
+
+                        t.push_into(|t| {
+                            push!(
+                                t,
+                                "if err := ",
+                                self.new_encoder,
+                                "(w).Encode(response); err != nil {"
+                            );
+                            nested!(
+                                t,
+                                self.http_error,
+                                "(w, err.Error(), ",
+                                self.internal_server_error,
+                                ")"
+                            );
+                            push!(t, "}");
+                        });
+                    }
+                    core::RpAccept::Text => {
+                        push!(
+                            t,
+                            "w.Header().Set(",
+                            "Content-Type".quoted(),
+                            ", ",
+                            "text/plain".quoted(),
+                            ")"
+                        );
+                        push!(t, self.write_string, "(w, *response)");
+                    }
+                }
+            }
+
+            t.join_line_spacing()
+        });
+
+        push!(t, "}");
+        t
+    }
+}
+
+impl ServiceCodegen for Codegen {
+    fn generate(&self, e: ServiceAdded) -> Result<()> {
+        let ServiceAdded {
+            container,
+            name,
+            body,
+            ..
+        } = e;
+
+        let endpoints = self.endpoints(body);
+
+        container.push(self.client(name, body, &endpoints));
+        container.push(self.handler(name, &endpoints));
+        container.push(self.router(name, &endpoints));
+
+        Ok(())
+    }
+}
+
+/// Name of the router field holding the path expression of an endpoint.
+fn path_field(e: &Endpoint) -> Cons<'static> {
+    Cons::from(format!("path{}", e.ident))
+}
+
+/// Build a regular expression matching the given path, capturing every variable.
+fn path_regex(path: &RpPathSpec) -> String {
+    let mut out = String::from("^");
+
+    for step in &path.steps {
+        out.push('/');
+
+        for part in &step.parts {
+            match *part {
+                core::RpPathPart::Variable(_) => out.push_str("([^/]+)"),
+                core::RpPathPart::Segment(ref s) => {
+                    for c in s.chars() {
+                        match c {
+                            '\\' | '.' | '+' | '*' | '?' | '(' | ')' | '|' | '[' | ']' | '{'
+                            | '}' | '^' | '$' => {
+                                out.push('\\');
+                                out.push(c);
+                            }
+                            c => out.push(c),
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    out.push('$');
+    out
+}
@@ -3,17 +3,17 @@
 use Options;
 use codegen::{ClassAdded, EnumAdded, GetterAdded, InterfaceAdded, ServiceAdded, TupleAdded};
 use core::errors::*;
-use core::{self, ForEachLoc, Handle, Loc, WithSpan};
+use core::{self, ForEachLoc, Handle, Loc, RpDefault, RpDeprecated, WithSpan};
 use flavored::{JavaField, JavaFlavor, RpCode, RpDecl, RpEnumBody, RpInterfaceBody, RpServiceBody,
                RpTupleBody, RpTypeBody};
 use genco::java::{self, imported, local, Argument, Class, Constructor, Enum, Field, Interface,
                   Method, Modifier, BOOLEAN, INTEGER};
-use genco::{Cons, Element, Java, Quoted, Tokens};
+use genco::{Cons, Element, IntoTokens, Java, Quoted, Tokens};
 use java_file::JavaFile;
 use naming::{self, Naming};
 use std::rc::Rc;
 use trans::{Packages, Translated};
-use utils::{Observer, Override};
+use utils::{Deprecated, Observer, Override};
 
 /// Helper macro to implement listeners opt loop.
 fn code<'el>(codes: &'el [Loc<RpCode>]) -> Tokens<'el, Java<'el>> {
@@ -145,12 +145,44 @@ impl<'el> Compiler<'el> {
 
             c.arguments.push(argument.clone());
 
-            push!(c.body, "this.", spec.var(), " = ", argument.var(), ";");
+            let value = match field.default {
+                Some(ref default) => toks![
+                    argument.var(),
+                    ".isPresent() ? ",
+                    argument.var(),
+                    " : ",
+                    self.optional.clone(),
+                    ".of(",
+                    self.default_literal(default),
+                    ")",
+                ],
+                None => toks![argument.var()],
+            };
+
+            push!(c.body, "this.", spec.var(), " = ", value, ";");
         }
 
         c
     }
 
+    /// Build the literal for the default value of a field.
+    fn default_literal(&self, default: &'el RpDefault) -> Tokens<'el, Java<'el>> {
+        use self::RpDefault::*;
+
+        let literal = match *default {
+            Boolean(value) => value.to_string(),
+            Signed { size, ref value } | Unsigned { size, ref value } => match size {
+                32 => value.to_string(),
+                _ => format!("{}L", value),
+            },
+            Float(ref value) => format!("{}f", value),
+            Double(ref value) => format!("{}d", value),
+            String(ref value) => return toks![value.as_str().quoted()],
+        };
+
+        toks![Rc::new(literal)]
+    }
+
     /// Build a require-non-null check.
     fn require_non_null(
         &self,
@@ -504,9 +536,25 @@ impl<'el> Compiler<'el> {
         to_value
     }
 
+    /// Annotate the variant if it is deprecated.
+    fn deprecated_variant(
+        &self,
+        deprecated: Option<&RpDeprecated>,
+        value: Tokens<'el, Java<'el>>,
+    ) -> Tokens<'el, Java<'el>> {
+        match deprecated {
+            Some(_) => toks![Deprecated.into_tokens(), " ", value],
+            None => value,
+        }
+    }
+
     fn process_enum(&self, body: &'el RpEnumBody) -> Result<Enum<'el>> {
         let mut spec = Enum::new(body.ident.clone());
 
+        if body.deprecated.is_some() {
+            spec.annotation(Deprecated);
+        }
+
         spec.fields
             .push(self.new_field_spec(&body.enum_type, "value"));
 
@@ -514,13 +562,8 @@ impl<'el> Compiler<'el> {
             core::RpVariants::String { ref variants } => {
                 for variant in variants {
                     let name = self.variant_naming.convert(variant.ident());
-                    push!(
-                        spec.variants,
-                        name,
-                        "(",
-                        variant.value.clone().quoted(),
-                        ")"
-                    );
+                    let value = toks![name, "(", variant.value.clone().quoted(), ")"];
+                    spec.variants.push(self.deprecated_variant(variant.deprecated.as_ref(), value));
                 }
             }
             core::RpVariants::Number { ref variants } => {
@@ -532,7 +575,8 @@ impl<'el> Compiler<'el> {
                         _ => variant.value.to_string(),
                     };
 
-                    push!(spec.variants, name, "(", value, ")");
+                    let value = toks![name, "(", value, ")"];
+                    spec.variants.push(self.deprecated_variant(variant.deprecated.as_ref(), value));
                 }
             }
         }
@@ -565,6 +609,10 @@ impl<'el> Compiler<'el> {
     fn process_tuple(&self, body: &'el RpTupleBody) -> Result<Class<'el>> {
         let mut spec = Class::new(body.ident.clone());
 
+        if body.deprecated.is_some() {
+            spec.annotation(Deprecated);
+        }
+
         self.add_class(
             spec.name(),
             &body.fields,
@@ -610,6 +658,10 @@ impl<'el> Compiler<'el> {
         let mut spec = Class::new(body.ident.clone());
         let names: Vec<_> = body.fields.iter().map(|f| f.name()).collect();
 
+        if body.deprecated.is_some() {
+            spec.annotation(Deprecated);
+        }
+
         for field in &body.fields {
             spec.fields.push(field.spec.clone());
 
@@ -662,6 +714,10 @@ impl<'el> Compiler<'el> {
         use self::Modifier::*;
         let mut spec = Interface::new(body.ident.clone());
 
+        if body.deprecated.is_some() {
+            spec.annotation(Deprecated);
+        }
+
         for field in &body.fields {
             let mut m = field.getter_without_body();
             m.modifiers = vec![];
@@ -674,6 +730,10 @@ impl<'el> Compiler<'el> {
             let mut class = Class::new(sub_type.ident.clone());
             class.modifiers = vec![Public, Static];
 
+            if sub_type.deprecated.is_some() {
+                class.annotation(Deprecated);
+            }
+
             class.body.push_unless_empty(code(&sub_type.codes));
 
             class.implements = vec![local(spec.name())];
@@ -768,6 +828,10 @@ impl<'el> Compiler<'el> {
     fn process_service(&self, body: &'el RpServiceBody) -> Result<Interface<'el>> {
         let mut spec = Interface::new(body.ident.as_str());
 
+        if body.deprecated.is_some() {
+            spec.annotation(Deprecated);
+        }
+
         for generator in &self.options.service_generators {
             generator.generate(ServiceAdded {
                 body: body,
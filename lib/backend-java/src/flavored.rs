@@ -15,6 +15,7 @@ use std::fmt;
 use std::ops::Deref;
 use std::rc::Rc;
 use trans::Packages;
+use utils::Deprecated;
 
 #[derive(Debug, Clone)]
 pub struct JavaHttp<'el> {
@@ -89,6 +90,11 @@ impl<'el> JavaField<'el> {
         let mut method = Method::new(Rc::new(format!("get{}", field_accessor)));
         method.comments = self.spec.comments.clone();
         method.returns = self.spec.ty().as_field();
+
+        if self.deprecated.is_some() {
+            method.annotation(Deprecated);
+        }
+
         method
     }
 
@@ -143,6 +149,10 @@ pub struct JavaFlavorTranslator {
     map: Java<'static>,
     string: Java<'static>,
     instant: Java<'static>,
+    local_date: Java<'static>,
+    local_time: Java<'static>,
+    duration: Java<'static>,
+    uuid: Java<'static>,
     object: Java<'static>,
     byte_buffer: Java<'static>,
     optional: Java<'static>,
@@ -158,6 +168,10 @@ impl JavaFlavorTranslator {
             map: java::imported("java.util", "Map"),
             string: java::imported("java.lang", "String"),
             instant: java::imported("java.time", "Instant"),
+            local_date: java::imported("java.time", "LocalDate"),
+            local_time: java::imported("java.time", "LocalTime"),
+            duration: java::imported("java.time", "Duration"),
+            uuid: java::imported("java.util", "UUID"),
             object: java::imported("java.lang", "Object"),
             byte_buffer: java::imported("java.nio", "ByteBuffer"),
             optional: java::imported("java.util", "Optional"),
@@ -209,6 +223,22 @@ impl FlavorTranslator for JavaFlavorTranslator {
         Ok(self.instant.clone().into())
     }
 
+    fn translate_date(&self) -> Result<Java<'static>> {
+        Ok(self.local_date.clone().into())
+    }
+
+    fn translate_time(&self) -> Result<Java<'static>> {
+        Ok(self.local_time.clone().into())
+    }
+
+    fn translate_duration(&self) -> Result<Java<'static>> {
+        Ok(self.duration.clone().into())
+    }
+
+    fn translate_uuid(&self) -> Result<Java<'static>> {
+        Ok(self.uuid.clone().into())
+    }
+
     fn translate_array(&self, argument: Java<'static>) -> Result<Java<'static>> {
         Ok(self.list.with_arguments(vec![argument]))
     }
@@ -261,6 +291,12 @@ impl FlavorTranslator for JavaFlavorTranslator {
             spec.comments.push("</pre>".into());
         }
 
+        if let Some(ref deprecated) = field.deprecated {
+            spec.comments
+                .push(Rc::new(format!("@deprecated {}", deprecated.message())).into());
+            spec.annotation(Deprecated);
+        }
+
         Ok(JavaField {
             field,
             field_accessor: field_accessor,
@@ -143,6 +143,9 @@ pub struct Jackson {
     token: Java<'static>,
     string: Java<'static>,
     instant: Java<'static>,
+    local_date: Java<'static>,
+    local_time: Java<'static>,
+    duration: Java<'static>,
     io_exception: Java<'static>,
 }
 
@@ -176,6 +179,9 @@ impl Jackson {
             token: java::imported("com.fasterxml.jackson.core", "JsonToken"),
             string: java::imported("java.lang", "String"),
             instant: java::imported("java.time", "Instant"),
+            local_date: java::imported("java.time", "LocalDate"),
+            local_time: java::imported("java.time", "LocalTime"),
+            duration: java::imported("java.time", "Duration"),
             io_exception: java::imported("java.io", "IOException"),
         }
     }
@@ -441,7 +447,13 @@ impl Jackson {
         for (field, name) in spec.fields.iter_mut().zip(names.iter().cloned()) {
             field.annotation(JsonProperty(name.into()));
 
-            if field.ty().as_value() == self.instant {
+            let ty = field.ty().as_value();
+
+            if ty == self.instant
+                || ty == self.local_date
+                || ty == self.local_time
+                || ty == self.duration
+            {
                 field.annotation(JsonFormat);
             }
         }
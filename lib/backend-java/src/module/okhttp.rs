@@ -7,7 +7,7 @@ use flavored::{JavaEndpoint, RpEndpointHttp1, RpPathStep};
 use genco::java::{self, Argument, Class, Constructor, Field, Method, Modifier, VOID};
 use genco::{Cons, IntoTokens, Java, Quoted, Tokens};
 use serialization::Serialization;
-use utils::Override;
+use utils::{Deprecated, Override};
 
 #[derive(Debug, Deserialize)]
 pub enum Version {
@@ -461,6 +461,11 @@ impl ServiceCodegen for OkHttpServiceCodegen {
             for e in &body.endpoints {
                 if let Some(http) = e.http1.as_ref() {
                     let mut m = Method::new(e.safe_ident());
+
+                    if e.deprecated.is_some() {
+                        m.annotation(Deprecated);
+                    }
+
                     m.returns = self.completable_future
                         .with_arguments(vec![http.response.as_ref().unwrap_or(&VOID).clone()]);
                     m.arguments.extend(e.arguments.iter().cloned());
@@ -10,6 +10,15 @@ impl<'el> IntoTokens<'el, Java<'el>> for Override {
     }
 }
 
+/// @Deprecated annotation
+pub struct Deprecated;
+
+impl<'el> IntoTokens<'el, Java<'el>> for Deprecated {
+    fn into_tokens(self) -> Tokens<'el, Java<'el>> {
+        toks!["@Deprecated"]
+    }
+}
+
 /// Observer interface used for bidirectional streaming communication.
 pub struct Observer;
 
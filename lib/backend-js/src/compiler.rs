@@ -1,8 +1,9 @@
 use backend::PackageProcessor;
+use codegen::{ServiceAdded, ServiceCodegen};
 use core::errors::*;
 use core::{self, ForEachLoc, Handle, Loc};
 use flavored::{JavaScriptFlavor, JavaScriptName, RpEnumBody, RpField, RpInterfaceBody,
-               RpTupleBody, RpTypeBody};
+               RpServiceBody, RpTupleBody, RpTypeBody};
 use genco::{Element, JavaScript, Quoted, Tokens};
 use naming::{self, Naming};
 use std::rc::Rc;
@@ -17,13 +18,14 @@ pub struct Compiler<'el> {
     to_lower_snake: naming::ToLowerSnake,
     values: Tokens<'static, JavaScript<'static>>,
     enum_name: Tokens<'static, JavaScript<'static>>,
+    service_generators: Vec<Box<ServiceCodegen>>,
 }
 
 impl<'el> Compiler<'el> {
     pub fn new(
         env: &'el Translated<JavaScriptFlavor>,
         variant_field: &'el Loc<RpField>,
-        _: Options,
+        options: Options,
         handle: &'el Handle,
     ) -> Compiler<'el> {
         Compiler {
@@ -33,6 +35,7 @@ impl<'el> Compiler<'el> {
             to_lower_snake: naming::to_lower_snake(),
             values: "values".into(),
             enum_name: "name".into(),
+            service_generators: options.service_generators,
         }
     }
 
@@ -443,6 +446,20 @@ impl<'el> PackageProcessor<'el, JavaScriptFlavor, JavaScriptName> for Compiler<'
         Ok(())
     }
 
+    fn process_service(&self, out: &mut Self::Out, body: &'el RpServiceBody) -> Result<()> {
+        let mut type_body = Tokens::new();
+
+        for g in &self.service_generators {
+            g.generate(ServiceAdded {
+                body: body,
+                type_body: &mut type_body,
+            })?;
+        }
+
+        out.0.push_unless_empty(type_body);
+        Ok(())
+    }
+
     fn process_interface(&self, out: &mut Self::Out, body: &'el RpInterfaceBody) -> Result<()> {
         let mut classes = Tokens::new();
 
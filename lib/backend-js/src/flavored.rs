@@ -95,7 +95,7 @@ impl<'el> JavaScriptType<'el> {
     }
 
     /// Check if the current type is completely native.
-    fn is_native(&self) -> bool {
+    pub fn is_native(&self) -> bool {
         use self::JavaScriptType::*;
 
         match *self {
@@ -196,6 +196,22 @@ impl FlavorTranslator for JavaScriptFlavorTranslator {
         Ok(JavaScriptType::Native)
     }
 
+    fn translate_date(&self) -> Result<JavaScriptType<'static>> {
+        Ok(JavaScriptType::Native)
+    }
+
+    fn translate_time(&self) -> Result<JavaScriptType<'static>> {
+        Ok(JavaScriptType::Native)
+    }
+
+    fn translate_duration(&self) -> Result<JavaScriptType<'static>> {
+        Ok(JavaScriptType::Native)
+    }
+
+    fn translate_uuid(&self) -> Result<JavaScriptType<'static>> {
+        Ok(JavaScriptType::Native)
+    }
+
     fn translate_array(
         &self,
         argument: JavaScriptType<'static>,
@@ -16,10 +16,13 @@ extern crate toml;
 
 #[macro_use]
 mod utils;
+mod codegen;
 mod compiler;
 mod flavored;
+pub mod module;
 
-use backend::IntoBytes;
+use backend::{Initializer, IntoBytes};
+use codegen::ServiceCodegen;
 use compiler::Compiler;
 use core::errors::Result;
 use core::{Context, CoreFlavor, Loc, RpField, RpPackage, RpType, Span, Translate};
@@ -118,21 +121,37 @@ impl Lang for JsLang {
 
 #[derive(Debug)]
 pub enum JsModule {
+    Fetch,
 }
 
 impl TryFromToml for JsModule {
     fn try_from_string(path: &Path, id: &str, value: String) -> Result<Self> {
-        NoModule::illegal(path, id, value)
+        use self::JsModule::*;
+
+        let result = match id {
+            "fetch" => Fetch,
+            _ => return NoModule::illegal(path, id, value),
+        };
+
+        Ok(result)
     }
 
     fn try_from_value(path: &Path, id: &str, value: toml::Value) -> Result<Self> {
-        NoModule::illegal(path, id, value)
+        use self::JsModule::*;
+
+        let result = match id {
+            "fetch" => Fetch,
+            _ => return NoModule::illegal(path, id, value),
+        };
+
+        Ok(result)
     }
 }
 
 pub struct Options {
     pub build_getters: bool,
     pub build_constructor: bool,
+    pub service_generators: Vec<Box<ServiceCodegen>>,
 }
 
 impl Options {
@@ -140,6 +159,7 @@ impl Options {
         Options {
             build_getters: false,
             build_constructor: true,
+            service_generators: Vec::new(),
         }
     }
 }
@@ -159,6 +179,22 @@ impl<'el> IntoBytes<Compiler<'el>> for FileSpec<'el> {
     }
 }
 
+pub fn setup_options(modules: Vec<JsModule>) -> Result<Options> {
+    use self::JsModule::*;
+
+    let mut options = Options::new();
+
+    for module in modules {
+        let initializer: Box<Initializer<Options = Options>> = match module {
+            Fetch => Box::new(module::Fetch::new()),
+        };
+
+        initializer.initialize(&mut options)?;
+    }
+
+    Ok(options)
+}
+
 fn compile(ctx: Rc<Context>, env: Environment<CoreFlavor>, manifest: Manifest) -> Result<()> {
     let packages = env.packages()?;
 
@@ -169,8 +205,8 @@ fn compile(ctx: Rc<Context>, env: Environment<CoreFlavor>, manifest: Manifest) -
 
     let env = env.translate(translator)?;
 
-    let _modules: Vec<JsModule> = manifest::checked_modules(manifest.modules)?;
-    let options = Options::new();
+    let modules = manifest::checked_modules(manifest.modules)?;
+    let options = setup_options(modules)?;
     let handle = ctx.filesystem(manifest.output.as_ref().map(AsRef::as_ref))?;
 
     Compiler::new(&env, &variant_field, options, handle.as_ref()).compile()
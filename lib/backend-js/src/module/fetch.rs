@@ -0,0 +1,211 @@
+//! Module that generates service clients using the fetch API.
+
+use backend::Initializer;
+use codegen::{ServiceAdded, ServiceCodegen};
+use core;
+use core::errors::Result;
+use flavored::{RpEndpoint, RpEndpointHttp1, RpPathSpec};
+use genco::{JavaScript, Quoted, Tokens};
+use utils::{is_not_defined, BlockComment};
+use Options;
+
+pub struct Module {}
+
+impl Module {
+    pub fn new() -> Module {
+        Module {}
+    }
+}
+
+impl Initializer for Module {
+    type Options = Options;
+
+    fn initialize(&self, options: &mut Options) -> Result<()> {
+        options
+            .service_generators
+            .push(Box::new(FetchServiceCodegen::new()));
+
+        Ok(())
+    }
+}
+
+struct FetchServiceCodegen {}
+
+impl FetchServiceCodegen {
+    pub fn new() -> FetchServiceCodegen {
+        Self {}
+    }
+
+    /// Build the method for a single endpoint.
+    fn endpoint<'el>(
+        &self,
+        e: &'el RpEndpoint,
+        path: &'el RpPathSpec,
+    ) -> Tokens<'el, JavaScript<'el>> {
+        let mut t = Tokens::new();
+
+        let mut args = Tokens::new();
+
+        for a in &e.arguments {
+            args.append(a.safe_ident());
+        }
+
+        t.push_unless_empty(BlockComment(&e.comment));
+        t.push(toks![e.safe_ident(), "(", args.join(", "), ") {"]);
+
+        t.nested({
+            let mut t = Tokens::new();
+
+            t.push({
+                let mut t = Tokens::new();
+
+                t.push("const path = [this.url];");
+
+                for step in &path.steps {
+                    t.push(toks!["path.push(", "/".quoted(), ");"]);
+
+                    for part in &step.parts {
+                        let var = match *part {
+                            core::RpPathPart::Variable(ref arg) => {
+                                toks!["encodeURIComponent(", arg.safe_ident(), ")"]
+                            }
+                            core::RpPathPart::Segment(ref s) => toks![s.as_str().quoted()],
+                        };
+
+                        t.push(toks!["path.push(", var, ");"]);
+                    }
+                }
+
+                t
+            });
+
+            t.push(toks!["const url = path.join(", "".quoted(), ");"]);
+
+            let method = e.http
+                .method
+                .as_ref()
+                .unwrap_or(&core::RpHttpMethod::Get)
+                .as_str();
+
+            let mut init = Tokens::new();
+            init.append(toks!["method: ", method.quoted()]);
+
+            if let Some(ref req) = e.request {
+                let content_type = toks![
+                    "Content-Type".quoted(),
+                    ": ",
+                    "application/json".quoted()
+                ];
+                init.append(toks!["headers: {", content_type, "}"]);
+
+                let body = req.channel.ty().encode(toks![req.safe_ident()]);
+                init.append(toks!["body: JSON.stringify(", body, ")"]);
+            }
+
+            t.push(toks!["const init = {", init.join(", "), "};"]);
+
+            t.push({
+                let mut t = Tokens::new();
+
+                t.push("return fetch(url, init).then(function(r) {");
+
+                t.nested({
+                    let mut t = Tokens::new();
+
+                    t.push({
+                        let mut t = Tokens::new();
+                        t.push("if (!r.ok) {");
+                        t.nested(toks![
+                            "throw new Error(",
+                            "bad response: ".quoted(),
+                            " + r.status);"
+                        ]);
+                        t.push("}");
+                        t
+                    });
+
+                    if let Some(ref res) = e.response {
+                        let res = res.ty();
+
+                        match e.http.accept {
+                            core::RpAccept::Json if res.is_native() => {
+                                t.push("return r.json();");
+                            }
+                            core::RpAccept::Json => {
+                                let mut j = Tokens::new();
+                                j.push("return r.json().then(function(data) {");
+                                j.nested(toks!["return ", res.decode("data".into()), ";"]);
+                                j.push("});");
+                                t.push(j);
+                            }
+                            core::RpAccept::Text => {
+                                t.push("return r.text();");
+                            }
+                        }
+                    }
+
+                    t.join_line_spacing()
+                });
+
+                t.push("});");
+                t
+            });
+
+            t.join_line_spacing()
+        });
+
+        t.push("}");
+        t
+    }
+}
+
+impl ServiceCodegen for FetchServiceCodegen {
+    fn generate(&self, ServiceAdded { body, type_body }: ServiceAdded) -> Result<()> {
+        type_body.push(toks!["export class ", &body.name, "_Fetch {"]);
+
+        type_body.nested({
+            let mut t = Tokens::new();
+
+            t.push({
+                let mut t = Tokens::new();
+
+                t.push("constructor(url) {");
+
+                t.nested({
+                    let mut t = Tokens::new();
+
+                    // Use default URL if available.
+                    if let Some(ref url) = body.http.url {
+                        t.push(js![if is_not_defined("url"),
+                                   toks!["url = ", url.as_str().quoted(), ";"]]);
+                    } else {
+                        t.push(js![if is_not_defined("url"),
+                                   js![throw "url: is a required argument".quoted()]]);
+                    }
+
+                    t.push("this.url = url;");
+                    t.join_line_spacing()
+                });
+
+                t.push("}");
+                t
+            });
+
+            for e in &body.endpoints {
+                // Only endpoints which can be expressed as HTTP/1.1 are supported.
+                if RpEndpointHttp1::from_endpoint(e).is_none() {
+                    continue;
+                }
+
+                if let Some(ref path) = e.http.path {
+                    t.push(self.endpoint(e, path));
+                }
+            }
+
+            t.join_line_spacing()
+        });
+
+        type_body.push("}");
+        Ok(())
+    }
+}
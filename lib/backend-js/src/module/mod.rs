@@ -0,0 +1,3 @@
+mod fetch;
+
+pub use self::fetch::Module as Fetch;
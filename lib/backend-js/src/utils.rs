@@ -1,3 +1,27 @@
+use genco::{IntoTokens, JavaScript, Tokens};
+
+/// Documentation comments.
+pub struct BlockComment<'el>(pub &'el [String]);
+
+impl<'el> IntoTokens<'el, JavaScript<'el>> for BlockComment<'el> {
+    fn into_tokens(self) -> Tokens<'el, JavaScript<'el>> {
+        let mut t = Tokens::new();
+
+        if self.0.is_empty() {
+            return t;
+        }
+
+        t.push("/**");
+
+        for c in self.0.iter() {
+            t.push(toks![" * ", c.as_str()]);
+        }
+
+        t.push(" */");
+        t
+    }
+}
+
 pub fn is_defined<'el, S>(toks: S) -> ::genco::Tokens<'el, ::genco::JavaScript<'el>>
 where
     S: Into<::genco::Tokens<'el, ::genco::JavaScript<'el>>>,
@@ -0,0 +1,75 @@
+#[macro_use]
+extern crate log;
+#[allow(unused)]
+#[macro_use]
+extern crate reproto_backend as backend;
+extern crate reproto_core as core;
+#[macro_use]
+extern crate reproto_manifest as manifest;
+extern crate reproto_trans as trans;
+extern crate serde;
+#[macro_use]
+extern crate serde_json;
+extern crate toml;
+
+mod schema;
+
+use core::errors::*;
+use core::{Context, CoreFlavor};
+use manifest::{Lang, Manifest, NoModule, TryFromToml};
+use std::any::Any;
+use std::path::Path;
+use std::rc::Rc;
+use trans::Environment;
+
+#[derive(Clone, Copy, Default, Debug)]
+pub struct JsonSchemaLang;
+
+impl Lang for JsonSchemaLang {
+    lang_base!(JsonSchemaModule, compile);
+}
+
+#[derive(Debug)]
+pub enum JsonSchemaModule {
+}
+
+impl TryFromToml for JsonSchemaModule {
+    fn try_from_string(path: &Path, id: &str, value: String) -> Result<Self> {
+        NoModule::illegal(path, id, value)
+    }
+
+    fn try_from_value(path: &Path, id: &str, value: toml::Value) -> Result<Self> {
+        NoModule::illegal(path, id, value)
+    }
+}
+
+/// Compile to one JSON Schema for each declaration.
+fn compile(ctx: Rc<Context>, env: Environment<CoreFlavor>, manifest: Manifest) -> Result<()> {
+    let env = env.translate_default()?;
+    let handle = ctx.filesystem(manifest.output.as_ref().map(AsRef::as_ref))?;
+
+    for decl in env.decl_iter() {
+        let path = schema::path(decl.name());
+
+        let value = match schema::decl(decl)? {
+            Some(value) => value,
+            None => continue,
+        };
+
+        if let Some(parent) = path.parent() {
+            if !handle.is_dir(parent) {
+                debug!("+dir: {}", parent.display());
+                handle.create_dir_all(parent)?;
+            }
+        }
+
+        debug!("+file: {}", path.display());
+        writeln!(
+            handle.create(&path)?,
+            "{}",
+            serde_json::to_string_pretty(&value)?,
+        )?;
+    }
+
+    Ok(())
+}
@@ -0,0 +1,231 @@
+//! Conversion of declarations into JSON Schema.
+
+use backend::json_schema::{default, description, enum_body, package_parts, validate};
+use core::errors::*;
+use core::flavored::{RpDecl, RpField, RpInterfaceBody, RpName, RpSubType, RpTupleBody, RpType,
+                     RpTypeBody};
+use core::{self, Loc, RelativePathBuf, RpSubTypeStrategy};
+use serde_json::{Map, Value};
+
+/// The version of JSON Schema that is generated.
+const SCHEMA: &str = "http://json-schema.org/draft-07/schema#";
+
+/// Path of the schema for the given name, relative to the output directory.
+///
+/// Every declaration is written to a separate file named after the declaration, in a directory
+/// named after its package.
+pub fn path(name: &RpName) -> RelativePathBuf {
+    let path = package_parts(&name.package)
+        .iter()
+        .fold(RelativePathBuf::new(), |path, part| path.join(part));
+
+    path.join(format!("{}.json", name.parts.join(".")))
+}
+
+/// Build a reference from the schema at `from` to the schema of the given name.
+fn reference(from: &RpName, to: &RpName) -> String {
+    let from = path(from);
+    let to = path(to);
+
+    if from.parent() == to.parent() {
+        if let Some(file_name) = to.file_name() {
+            return file_name.to_string();
+        }
+    }
+
+    let mut out = String::new();
+
+    if let Some(parent) = from.parent() {
+        for _ in parent.components() {
+            out.push_str("../");
+        }
+    }
+
+    out.push_str(to.as_str());
+    out
+}
+
+/// Build the schema for a single declaration.
+///
+/// Returns `None` for declarations which can't be represented as a schema, like services.
+pub fn decl(decl: &RpDecl) -> Result<Option<Value>> {
+    use core::RpDecl::*;
+
+    let body = match *decl {
+        Type(ref body) => type_body(body)?,
+        Tuple(ref body) => tuple_body(body)?,
+        Interface(ref body) => interface_body(body)?,
+        Enum(ref body) => enum_body(body)?,
+        Service(_) => return Ok(None),
+    };
+
+    let name = decl.name();
+
+    // NB: keys are emitted in insertion order, so that `$schema` and `$id` come first.
+    let mut schema = Map::new();
+    schema.insert("$schema".to_string(), json!(SCHEMA));
+    schema.insert("$id".to_string(), json!(path(name).as_str()));
+    schema.extend(body);
+    schema.insert("title".to_string(), json!(name.parts.join(".")));
+    description(&mut schema, decl.comment());
+
+    Ok(Some(Value::Object(schema)))
+}
+
+/// Build the schema for a type.
+fn ty(from: &RpName, input: &RpType) -> Result<Value> {
+    let value = match *input {
+        core::RpType::Double | core::RpType::Float => json!({"type": "number"}),
+        core::RpType::Signed { .. } => json!({"type": "integer"}),
+        core::RpType::Unsigned { .. } => json!({"type": "integer", "minimum": 0}),
+        core::RpType::Boolean => json!({"type": "boolean"}),
+        core::RpType::String => json!({"type": "string"}),
+        core::RpType::DateTime => json!({"type": "string", "format": "date-time"}),
+        core::RpType::Date => json!({"type": "string", "format": "date"}),
+        core::RpType::Time => json!({"type": "string", "format": "time"}),
+        core::RpType::Duration => json!({"type": "string", "format": "duration"}),
+        core::RpType::Uuid => json!({"type": "string", "format": "uuid"}),
+        core::RpType::Bytes => json!({"type": "string", "contentEncoding": "base64"}),
+        core::RpType::Any => json!({}),
+        core::RpType::Name { ref name } => json!({"$ref": reference(from, name)}),
+        core::RpType::Array { ref inner } => json!({"type": "array", "items": ty(from, inner)?}),
+        core::RpType::Map { ref value, .. } => json!({
+            "type": "object",
+            "additionalProperties": ty(from, value)?,
+        }),
+    };
+
+    Ok(value)
+}
+
+/// Build an object schema with the given fields.
+fn object<'a, I>(from: &RpName, fields: I) -> Result<Map<String, Value>>
+where
+    I: IntoIterator<Item = &'a Loc<RpField>>,
+{
+    let mut properties = Map::new();
+    let mut required = Vec::new();
+
+    for field in fields {
+        let mut property = match ty(from, &field.ty)? {
+            Value::Object(property) => property,
+            _ => return Err("expected object".into()),
+        };
+
+        description(&mut property, &field.comment);
+
+        if let Some(ref v) = field.validate {
+            validate(&mut property, &field.ty, v)?;
+        }
+
+        if let Some(ref d) = field.default {
+            property.insert("default".to_string(), default(d)?);
+        }
+
+        if !field.is_optional() {
+            required.push(json!(field.name()));
+        }
+
+        properties.insert(field.name().to_string(), Value::Object(property));
+    }
+
+    let mut schema = Map::new();
+    schema.insert("type".to_string(), json!("object"));
+    schema.insert("properties".to_string(), Value::Object(properties));
+
+    if !required.is_empty() {
+        schema.insert("required".to_string(), Value::Array(required));
+    }
+
+    Ok(schema)
+}
+
+fn type_body(body: &RpTypeBody) -> Result<Map<String, Value>> {
+    object(&body.name, &body.fields)
+}
+
+/// Tuples are encoded as arrays, where each field has a fixed position.
+fn tuple_body(body: &RpTupleBody) -> Result<Map<String, Value>> {
+    let mut items = Vec::new();
+
+    for field in &body.fields {
+        let mut item = match ty(&body.name, &field.ty)? {
+            Value::Object(item) => item,
+            _ => return Err("expected object".into()),
+        };
+
+        description(&mut item, &field.comment);
+
+        if let Some(ref v) = field.validate {
+            validate(&mut item, &field.ty, v)?;
+        }
+
+        items.push(Value::Object(item));
+    }
+
+    let min_items = body.fields.iter().filter(|f| !f.is_optional()).count();
+
+    let mut schema = Map::new();
+    schema.insert("type".to_string(), json!("array"));
+    schema.insert("minItems".to_string(), json!(min_items));
+    schema.insert("maxItems".to_string(), json!(items.len()));
+    schema.insert("items".to_string(), Value::Array(items));
+    schema.insert("additionalItems".to_string(), json!(false));
+    Ok(schema)
+}
+
+/// Interfaces are a choice between sub-types, each of which is kept in `definitions`.
+///
+/// Tagged sub-types can be told apart by their tag, so exactly one of them must match.
+/// Untagged sub-types might overlap, in which case the first matching one is used.
+fn interface_body(body: &RpInterfaceBody) -> Result<Map<String, Value>> {
+    let mut definitions = Map::new();
+    let mut choices = Vec::new();
+
+    for sub_type in &body.sub_types {
+        let schema = sub_type_schema(body, sub_type)?;
+        let reference = format!("#/definitions/{}", sub_type.ident);
+        choices.push(json!({"$ref": reference}));
+        definitions.insert(sub_type.ident.to_string(), Value::Object(schema));
+    }
+
+    let mut schema = Map::new();
+
+    if choices.is_empty() {
+        // NB: an interface without sub-types can't be matched by anything.
+        schema.insert("not".to_string(), json!({}));
+        return Ok(schema);
+    }
+
+    let keyword = match body.sub_type_strategy {
+        RpSubTypeStrategy::Tagged { .. } => "oneOf",
+        RpSubTypeStrategy::Untagged => "anyOf",
+    };
+
+    schema.insert(keyword.to_string(), Value::Array(choices));
+    schema.insert("definitions".to_string(), Value::Object(definitions));
+    Ok(schema)
+}
+
+fn sub_type_schema(body: &RpInterfaceBody, sub_type: &RpSubType) -> Result<Map<String, Value>> {
+    let fields = body.fields.iter().chain(sub_type.fields.iter());
+    let mut schema = object(&body.name, fields)?;
+
+    if let RpSubTypeStrategy::Tagged { ref tag, .. } = body.sub_type_strategy {
+        if let Some(&mut Value::Object(ref mut properties)) = schema.get_mut("properties") {
+            properties.insert(tag.to_string(), json!({"const": sub_type.name()}));
+        }
+
+        let mut required = vec![json!(tag.as_str())];
+
+        if let Some(Value::Array(rest)) = schema.remove("required") {
+            required.extend(rest);
+        }
+
+        schema.insert("required".to_string(), Value::Array(required));
+    }
+
+    schema.insert("title".to_string(), json!(sub_type.name.parts.join(".")));
+    description(&mut schema, &sub_type.comment);
+    Ok(schema)
+}
@@ -0,0 +1,571 @@
+use backend::PackageProcessor;
+use core::errors::*;
+use core::{self, ForEachLoc, Handle, Loc};
+use flavored::{KotlinFlavor, KotlinName, KotlinType, RpEnumBody, RpField, RpInterfaceBody,
+               RpServiceBody, RpSubType, RpTupleBody, RpTypeBody};
+use genco::{Quoted, Tokens};
+use trans::{self, Translated};
+use utils::{block, BlockComment, Deprecated};
+use {FileSpec, Kotlin, Options, EXT};
+
+const SERIALIZATION: &str = "kotlinx.serialization";
+const SERIALIZATION_JSON: &str = "kotlinx.serialization.json";
+const SERIALIZATION_DESCRIPTORS: &str = "kotlinx.serialization.descriptors";
+const SERIALIZATION_ENCODING: &str = "kotlinx.serialization.encoding";
+
+pub struct Compiler<'el> {
+    pub env: &'el Translated<KotlinFlavor>,
+    handle: &'el Handle,
+}
+
+impl<'el> Compiler<'el> {
+    pub fn new(
+        env: &'el Translated<KotlinFlavor>,
+        _: Options,
+        handle: &'el Handle,
+    ) -> Compiler<'el> {
+        Compiler { env, handle }
+    }
+
+    pub fn compile(&self) -> Result<()> {
+        let files = self.populate_files()?;
+        self.write_files(files)
+    }
+
+    /// Build the properties of a primary constructor.
+    ///
+    /// Fields marked as overriding implement an abstract property of a sealed class.
+    fn properties<I>(&self, fields: I) -> Tokens<'el, Kotlin>
+    where
+        I: IntoIterator<Item = (&'el Loc<RpField>, bool)>,
+    {
+        let fields = fields.into_iter().collect::<Vec<_>>();
+        let last = fields.len().saturating_sub(1);
+
+        let mut t = Tokens::new();
+
+        for (i, (field, overrides)) in fields.into_iter().enumerate() {
+            t.push_unless_empty(BlockComment(&field.comment));
+            t.push_unless_empty(Deprecated(&field.deprecated));
+
+            if field.name() != field.safe_ident() {
+                t.push(toks!["@SerialName(", field.name().quoted(), ")"]);
+            }
+
+            let mut property = Tokens::new();
+
+            if overrides {
+                property.append("override ");
+            }
+
+            property.append(toks!["val ", field.safe_ident(), ": ", field.ty.ty()]);
+
+            if field.is_optional() {
+                property.append("? = null");
+            }
+
+            if i != last {
+                property.append(",");
+            }
+
+            t.push(property);
+        }
+
+        t
+    }
+
+    /// Build a class with a primary constructor containing the given properties.
+    ///
+    /// Classes without properties can't be data classes, so a regular class is used instead.
+    fn class(
+        &self,
+        annotations: Tokens<'el, Kotlin>,
+        name: &'el KotlinName,
+        comment: &'el [String],
+        properties: Tokens<'el, Kotlin>,
+        extends: Option<Tokens<'el, Kotlin>>,
+        body: Tokens<'el, Kotlin>,
+    ) -> Tokens<'el, Kotlin> {
+        let mut t = Tokens::new();
+
+        t.push_unless_empty(BlockComment(comment));
+        t.push(annotations);
+
+        let mut close = Tokens::new();
+
+        if properties.is_empty() {
+            close.append(toks!["class ", name]);
+        } else {
+            t.push(toks!["data class ", name, "("]);
+            t.nested(properties);
+            close.append(")");
+        }
+
+        if let Some(extends) = extends {
+            close.append(toks![" : ", extends]);
+        }
+
+        if body.is_empty() {
+            t.push(close);
+        } else {
+            t.push(block(close, body.join_line_spacing()));
+        }
+
+        t
+    }
+
+    /// Build a serializer which encodes a tuple as a JSON array.
+    fn tuple_serializer(&self, body: &'el RpTupleBody) -> Tokens<'el, Kotlin> {
+        let name = &body.name;
+        let mut t = Tokens::new();
+
+        t.push(toks![
+            "override val descriptor: SerialDescriptor = ",
+            "JsonArray.serializer().descriptor"
+        ]);
+
+        t.push({
+            let mut elements = Tokens::new();
+
+            for field in &body.fields {
+                elements.push(toks![
+                    "add(output.json.encodeToJsonElement(value.",
+                    field.safe_ident(),
+                    "))"
+                ]);
+            }
+
+            let mut t = Tokens::new();
+            t.push("val output = encoder as JsonEncoder");
+            t.push(block("val array = buildJsonArray", elements));
+            t.push("output.encodeJsonElement(array)");
+
+            block(
+                toks!["override fun serialize(encoder: Encoder, value: ", name, ")"],
+                t.join_line_spacing(),
+            )
+        });
+
+        t.push({
+            let mut args = Tokens::new();
+            let last = body.fields.len().saturating_sub(1);
+
+            for (i, field) in body.fields.iter().enumerate() {
+                let mut arg = Tokens::new();
+
+                if field.is_optional() {
+                    arg.append(toks![
+                        "input.json.decodeFromJsonElement<",
+                        field.ty.ty(),
+                        "?>(array.getOrElse(",
+                        i.to_string(),
+                        ") { JsonNull })"
+                    ]);
+                } else {
+                    arg.append(toks![
+                        "input.json.decodeFromJsonElement<",
+                        field.ty.ty(),
+                        ">(array[",
+                        i.to_string(),
+                        "])"
+                    ]);
+                }
+
+                if i != last {
+                    arg.append(",");
+                }
+
+                args.push(arg);
+            }
+
+            let mut decode = Tokens::new();
+            decode.push("val input = decoder as JsonDecoder");
+            decode.push("val array = input.decodeJsonElement().jsonArray");
+
+            let mut t = Tokens::new();
+
+            t.push(decode);
+
+            if args.is_empty() {
+                t.push(toks!["return ", name, "()"]);
+            } else {
+                let mut ret = Tokens::new();
+                ret.push(toks!["return ", name, "("]);
+                ret.nested(args);
+                ret.push(")");
+                t.push(ret);
+            }
+
+            block(
+                toks!["override fun deserialize(decoder: Decoder): ", name],
+                t.join_line_spacing(),
+            )
+        });
+
+        block(
+            toks!["object Serializer : KSerializer<", name, ">"],
+            t.join_line_spacing(),
+        )
+    }
+
+    /// Build a serializer which encodes an enum as its primitive value.
+    fn enum_serializer(
+        &self,
+        body: &'el RpEnumBody,
+        kind: &'el str,
+        suffix: &'el str,
+    ) -> Tokens<'el, Kotlin> {
+        let name = &body.name;
+        let serial_name = format!("{}.{}", name.package.join("."), name.name);
+
+        let mut t = Tokens::new();
+
+        t.push(toks![
+            "override val descriptor: SerialDescriptor = PrimitiveSerialDescriptor(\"",
+            serial_name,
+            "\", PrimitiveKind.",
+            kind,
+            ")"
+        ]);
+
+        t.push(block(
+            toks!["override fun serialize(encoder: Encoder, value: ", name, ")"],
+            toks!["encoder.encode", suffix, "(value.value)"],
+        ));
+
+        t.push({
+            let mut body = Tokens::new();
+
+            body.push(toks!["val value = decoder.decode", suffix, "()"]);
+
+            body.push(toks![
+                "return ",
+                name,
+                ".values().find { it.value == value } ?: throw SerializationException(",
+                "no matching value: ".quoted(),
+                " + value)"
+            ]);
+
+            block(
+                toks!["override fun deserialize(decoder: Decoder): ", name],
+                body,
+            )
+        });
+
+        block(
+            toks!["object Serializer : KSerializer<", name, ">"],
+            t.join_line_spacing(),
+        )
+    }
+
+    /// Process a single sub-type of an interface.
+    fn process_sub_type(
+        &self,
+        out: &mut FileSpec<'el>,
+        body: &'el RpInterfaceBody,
+        sub_type: &'el RpSubType,
+    ) -> Result<()> {
+        let mut annotations = Tokens::new();
+        annotations.push("@Serializable");
+
+        if let core::RpSubTypeStrategy::Tagged { .. } = body.sub_type_strategy {
+            annotations.push(toks!["@SerialName(", sub_type.name().quoted(), ")"]);
+        }
+
+        annotations.push_unless_empty(Deprecated(&sub_type.deprecated));
+
+        let fields = body.fields
+            .iter()
+            .map(|f| (f, true))
+            .chain(sub_type.fields.iter().map(|f| (f, false)));
+
+        let properties = self.properties(fields);
+
+        let mut class_body = Tokens::new();
+        class_body.push_unless_empty(code!(&sub_type.codes, core::RpContext::Kotlin));
+
+        out.body.push(self.class(
+            annotations,
+            &sub_type.name,
+            &sub_type.comment,
+            properties,
+            Some(toks![&body.name, "()"]),
+            class_body,
+        ));
+
+        Ok(())
+    }
+}
+
+impl<'el> PackageProcessor<'el, KotlinFlavor, KotlinName> for Compiler<'el> {
+    type Out = FileSpec<'el>;
+    type DeclIter = trans::translated::DeclIter<'el, KotlinFlavor>;
+
+    fn ext(&self) -> &str {
+        EXT
+    }
+
+    fn decl_iter(&self) -> Self::DeclIter {
+        self.env.decl_iter()
+    }
+
+    fn handle(&self) -> &'el Handle {
+        self.handle
+    }
+
+    fn process_tuple(&self, out: &mut Self::Out, body: &'el RpTupleBody) -> Result<()> {
+        out.imports.insert(SERIALIZATION);
+        out.imports.insert(SERIALIZATION_JSON);
+        out.imports.insert(SERIALIZATION_DESCRIPTORS);
+        out.imports.insert(SERIALIZATION_ENCODING);
+
+        let mut annotations = Tokens::new();
+        annotations.push(toks![
+            "@Serializable(with = ",
+            &body.name,
+            ".Serializer::class)"
+        ]);
+        annotations.push_unless_empty(Deprecated(&body.deprecated));
+
+        let properties = self.properties(body.fields.iter().map(|f| (f, false)));
+
+        let mut class_body = Tokens::new();
+        class_body.push(self.tuple_serializer(body));
+        class_body.push_unless_empty(code!(&body.codes, core::RpContext::Kotlin));
+
+        out.body.push(self.class(
+            annotations,
+            &body.name,
+            &body.comment,
+            properties,
+            None,
+            class_body,
+        ));
+
+        Ok(())
+    }
+
+    fn process_enum(&self, out: &mut Self::Out, body: &'el RpEnumBody) -> Result<()> {
+        out.imports.insert(SERIALIZATION);
+        out.imports.insert(SERIALIZATION_JSON);
+
+        // String enums are serialized by name, so they don't need a custom serializer.
+        let primitive = match body.enum_type {
+            KotlinType::String => None,
+            ref ty => ty.primitive(),
+        };
+
+        let mut t = Tokens::new();
+
+        t.push_unless_empty(BlockComment(&body.comment));
+        t.push_unless_empty(Deprecated(&body.deprecated));
+
+        if primitive.is_some() {
+            t.push(toks![
+                "@Serializable(with = ",
+                &body.name,
+                ".Serializer::class)"
+            ]);
+        } else {
+            t.push("@Serializable");
+        }
+
+        let mut class_body = Tokens::new();
+
+        class_body.push({
+            let mut variants = Tokens::new();
+            let last = body.variants.len().saturating_sub(1);
+
+            for (i, v) in body.variants.iter().enumerate() {
+                variants.push_unless_empty(BlockComment(v.comment));
+                variants.push_unless_empty(Deprecated(v.deprecated));
+
+                let value = match v.value {
+                    core::RpVariantValue::String(string) => {
+                        variants.push(toks!["@SerialName(", string.quoted(), ")"]);
+                        toks![string.quoted()]
+                    }
+                    core::RpVariantValue::Number(number) => toks![number.to_string()],
+                };
+
+                let sep = if i != last { "," } else { ";" };
+                variants.push(toks![v.ident(), "(", value, ")", sep]);
+            }
+
+            variants
+        });
+
+        if let Some((kind, suffix)) = primitive {
+            out.imports.insert(SERIALIZATION_DESCRIPTORS);
+            out.imports.insert(SERIALIZATION_ENCODING);
+            class_body.push(self.enum_serializer(body, kind, suffix));
+        }
+
+        class_body.push_unless_empty(code!(&body.codes, core::RpContext::Kotlin));
+
+        t.push(block(
+            toks![
+                "enum class ",
+                &body.name,
+                "(val value: ",
+                body.enum_type.ty(),
+                ")"
+            ],
+            class_body.join_line_spacing(),
+        ));
+
+        out.body.push(t);
+        Ok(())
+    }
+
+    fn process_type(&self, out: &mut Self::Out, body: &'el RpTypeBody) -> Result<()> {
+        out.imports.insert(SERIALIZATION);
+        out.imports.insert(SERIALIZATION_JSON);
+
+        let mut annotations = Tokens::new();
+        annotations.push("@Serializable");
+        annotations.push_unless_empty(Deprecated(&body.deprecated));
+
+        let properties = self.properties(body.fields.iter().map(|f| (f, false)));
+
+        let mut class_body = Tokens::new();
+        class_body.push_unless_empty(code!(&body.codes, core::RpContext::Kotlin));
+
+        out.body.push(self.class(
+            annotations,
+            &body.name,
+            &body.comment,
+            properties,
+            None,
+            class_body,
+        ));
+
+        Ok(())
+    }
+
+    fn process_service(&self, _: &mut Self::Out, _: &'el RpServiceBody) -> Result<()> {
+        // Services are not generated yet.
+        Ok(())
+    }
+
+    fn process_interface(&self, out: &mut Self::Out, body: &'el RpInterfaceBody) -> Result<()> {
+        out.imports.insert(SERIALIZATION);
+        out.imports.insert(SERIALIZATION_JSON);
+
+        let mut t = Tokens::new();
+
+        t.push_unless_empty(BlockComment(&body.comment));
+        t.push_unless_empty(Deprecated(&body.deprecated));
+
+        let mut class_body = Tokens::new();
+
+        class_body.push_unless_empty({
+            let mut t = Tokens::new();
+
+            for field in &body.fields {
+                t.push_unless_empty(BlockComment(&field.comment));
+                t.push_unless_empty(Deprecated(&field.deprecated));
+
+                let optional = if field.is_optional() { "?" } else { "" };
+
+                t.push(toks![
+                    "abstract val ",
+                    field.safe_ident(),
+                    ": ",
+                    field.ty.ty(),
+                    optional
+                ]);
+            }
+
+            t
+        });
+
+        match body.sub_type_strategy {
+            core::RpSubTypeStrategy::Tagged { ref tag, .. } => {
+                t.push("@Serializable");
+
+                // NB: the default class discriminator of kotlinx.serialization is the same as the
+                // default tag.
+                if tag.as_str() != core::DEFAULT_TAG {
+                    t.push("@OptIn(ExperimentalSerializationApi::class)");
+                    t.push(toks!["@JsonClassDiscriminator(", tag.as_str().quoted(), ")"]);
+                }
+            }
+            core::RpSubTypeStrategy::Untagged => {
+                t.push(toks![
+                    "@Serializable(with = ",
+                    &body.name,
+                    ".Serializer::class)"
+                ]);
+
+                class_body.push(untagged_serializer(body));
+            }
+        }
+
+        class_body.push_unless_empty(code!(&body.codes, core::RpContext::Kotlin));
+
+        if class_body.is_empty() {
+            t.push(toks!["sealed class ", &body.name]);
+        } else {
+            t.push(block(
+                toks!["sealed class ", &body.name],
+                class_body.join_line_spacing(),
+            ));
+        }
+
+        out.body.push(t);
+
+        body.sub_types
+            .iter()
+            .for_each_loc(|sub_type| self.process_sub_type(out, body, sub_type))?;
+
+        return Ok(());
+
+        /// Build a serializer which picks the sub-type based on which fields are present.
+        fn untagged_serializer<'el>(body: &'el RpInterfaceBody) -> Tokens<'el, Kotlin> {
+            let mut t = Tokens::new();
+
+            t.push("val keys = element.jsonObject.keys");
+
+            for sub_type in body.sub_types.iter() {
+                let mut required = Tokens::new();
+
+                for f in sub_type.discriminating_fields() {
+                    required.append(f.name().quoted());
+                }
+
+                t.push(block(
+                    toks!["if (keys.containsAll(listOf(", required.join(", "), ")))"],
+                    toks!["return ", &sub_type.name, ".serializer()"],
+                ));
+            }
+
+            t.push(toks![
+                "throw SerializationException(",
+                "no legal field combinations found".quoted(),
+                ")"
+            ]);
+
+            let select = block(
+                toks![
+                    "override fun selectDeserializer(element: JsonElement): ",
+                    "DeserializationStrategy<out ",
+                    &body.name,
+                    ">"
+                ],
+                t.join_line_spacing(),
+            );
+
+            block(
+                toks![
+                    "object Serializer : JsonContentPolymorphicSerializer<",
+                    &body.name,
+                    ">(",
+                    &body.name,
+                    "::class)"
+                ],
+                select,
+            )
+        }
+    }
+}
@@ -0,0 +1,260 @@
+//! Kotlin flavor.
+
+#![allow(unused)]
+
+use backend::package_processor;
+use core::errors::Result;
+use core::{self, CoreFlavor, Flavor, FlavorTranslator, Loc, PackageTranslator, Translate,
+           Translator};
+use genco::{Element, Tokens};
+use std::fmt;
+use std::rc::Rc;
+use trans::Packages;
+use {Kotlin, TYPE_SEP};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum KotlinType {
+    Int,
+    Long,
+    Float,
+    Double,
+    Boolean,
+    String,
+    Any,
+    List {
+        argument: Box<KotlinType>,
+    },
+    Map {
+        key: Box<KotlinType>,
+        value: Box<KotlinType>,
+    },
+    Name {
+        name: Rc<String>,
+    },
+}
+
+impl KotlinType {
+    /// Build the type annotation.
+    pub fn ty<'el>(&self) -> Tokens<'el, Kotlin> {
+        use self::KotlinType::*;
+
+        match *self {
+            Int => toks!["Int"],
+            Long => toks!["Long"],
+            Float => toks!["Float"],
+            Double => toks!["Double"],
+            Boolean => toks!["Boolean"],
+            String => toks!["String"],
+            Any => toks!["JsonElement"],
+            List { ref argument } => toks!["List<", argument.ty(), ">"],
+            Map { ref key, ref value } => toks!["Map<", key.ty(), ", ", value.ty(), ">"],
+            Name { ref name } => toks![name.clone()],
+        }
+    }
+
+    /// Serialization primitive used for the type, if it is a primitive.
+    ///
+    /// This is used to build custom serializers for enums, the returned tuple contains the
+    /// `PrimitiveKind`, and the suffix of the `encode` and `decode` functions to use.
+    pub fn primitive(&self) -> Option<(&'static str, &'static str)> {
+        use self::KotlinType::*;
+
+        match *self {
+            Int => Some(("INT", "Int")),
+            Long => Some(("LONG", "Long")),
+            Float => Some(("FLOAT", "Float")),
+            Double => Some(("DOUBLE", "Double")),
+            Boolean => Some(("BOOLEAN", "Boolean")),
+            String => Some(("STRING", "String")),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct KotlinName {
+    pub name: Rc<String>,
+    pub package: RpPackage,
+}
+
+impl fmt::Display for KotlinName {
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        fmt.write_str(self.name.as_str())
+    }
+}
+
+impl<'el> From<&'el KotlinName> for Element<'el, Kotlin> {
+    fn from(value: &'el KotlinName) -> Element<'el, Kotlin> {
+        Element::Literal(value.name.clone().to_string().into())
+    }
+}
+
+impl package_processor::Name<KotlinFlavor> for KotlinName {
+    fn package(&self) -> &RpPackage {
+        &self.package
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct KotlinFlavor;
+
+impl Flavor for KotlinFlavor {
+    type Type = KotlinType;
+    type Name = KotlinName;
+    type Field = RpField;
+    type Endpoint = RpEndpoint;
+    type Package = RpPackage;
+    type EnumType = KotlinType;
+}
+
+/// Responsible for translating RpType -> Kotlin type.
+pub struct KotlinFlavorTranslator {
+    packages: Rc<Packages>,
+}
+
+impl KotlinFlavorTranslator {
+    pub fn new(packages: Rc<Packages>) -> Self {
+        Self { packages }
+    }
+}
+
+impl FlavorTranslator for KotlinFlavorTranslator {
+    type Source = CoreFlavor;
+    type Target = KotlinFlavor;
+
+    translator_defaults!(Self, field, endpoint);
+
+    fn translate_i32(&self) -> Result<KotlinType> {
+        Ok(KotlinType::Int)
+    }
+
+    fn translate_i64(&self) -> Result<KotlinType> {
+        Ok(KotlinType::Long)
+    }
+
+    fn translate_u32(&self) -> Result<KotlinType> {
+        Ok(KotlinType::Int)
+    }
+
+    fn translate_u64(&self) -> Result<KotlinType> {
+        Ok(KotlinType::Long)
+    }
+
+    fn translate_float(&self) -> Result<KotlinType> {
+        Ok(KotlinType::Float)
+    }
+
+    fn translate_double(&self) -> Result<KotlinType> {
+        Ok(KotlinType::Double)
+    }
+
+    fn translate_boolean(&self) -> Result<KotlinType> {
+        Ok(KotlinType::Boolean)
+    }
+
+    fn translate_string(&self) -> Result<KotlinType> {
+        Ok(KotlinType::String)
+    }
+
+    fn translate_datetime(&self) -> Result<KotlinType> {
+        Ok(KotlinType::String)
+    }
+
+    fn translate_date(&self) -> Result<KotlinType> {
+        Ok(KotlinType::String)
+    }
+
+    fn translate_time(&self) -> Result<KotlinType> {
+        Ok(KotlinType::String)
+    }
+
+    fn translate_duration(&self) -> Result<KotlinType> {
+        Ok(KotlinType::String)
+    }
+
+    fn translate_uuid(&self) -> Result<KotlinType> {
+        Ok(KotlinType::String)
+    }
+
+    fn translate_array(&self, argument: KotlinType) -> Result<KotlinType> {
+        Ok(KotlinType::List {
+            argument: Box::new(argument),
+        })
+    }
+
+    fn translate_map(&self, key: KotlinType, value: KotlinType) -> Result<KotlinType> {
+        Ok(KotlinType::Map {
+            key: Box::new(key),
+            value: Box::new(value),
+        })
+    }
+
+    fn translate_any(&self) -> Result<KotlinType> {
+        Ok(KotlinType::Any)
+    }
+
+    fn translate_bytes(&self) -> Result<KotlinType> {
+        Ok(KotlinType::String)
+    }
+
+    fn translate_name(&self, reg: RpReg, name: RpName) -> Result<KotlinType> {
+        let ident = reg.ident(&name, |p| p.join(TYPE_SEP), |c| c.join(TYPE_SEP));
+
+        // Names from other packages are referenced by their fully qualified name.
+        if name.prefix.is_some() {
+            let package = self.translate_package(name.package)?;
+            let name = format!("{}.{}", package.join("."), ident);
+
+            return Ok(KotlinType::Name {
+                name: Rc::new(name),
+            });
+        }
+
+        Ok(KotlinType::Name {
+            name: Rc::new(ident),
+        })
+    }
+
+    fn translate_package(&self, source: RpVersionedPackage) -> Result<RpPackage> {
+        Ok(self.packages.translate_package(source)?)
+    }
+
+    fn translate_local_name<T>(
+        &self,
+        translator: &T,
+        reg: RpReg,
+        name: core::RpName<CoreFlavor>,
+    ) -> Result<KotlinName>
+    where
+        T: Translator<Source = Self::Source, Target = Self::Target>,
+    {
+        let ident = reg.ident(&name, |p| p.join(TYPE_SEP), |v| v.join(TYPE_SEP));
+        let package = self.translate_package(name.package)?;
+
+        Ok(KotlinName {
+            name: Rc::new(ident),
+            package,
+        })
+    }
+
+    fn translate_enum_type<T>(
+        &self,
+        translator: &T,
+        enum_type: core::RpEnumType,
+    ) -> Result<KotlinType>
+    where
+        T: Translator<Source = Self::Source, Target = Self::Target>,
+    {
+        use core::RpEnumType::*;
+
+        match enum_type {
+            String => self.translate_string(),
+            U32 => self.translate_u32(),
+            U64 => self.translate_u64(),
+            I32 => self.translate_i32(),
+            I64 => self.translate_i64(),
+        }
+    }
+}
+
+decl_flavor!(KotlinFlavor, core);
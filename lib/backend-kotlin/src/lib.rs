@@ -0,0 +1,197 @@
+#[macro_use]
+extern crate genco;
+#[macro_use]
+extern crate reproto_backend as backend;
+#[macro_use]
+extern crate reproto_core as core;
+#[macro_use]
+extern crate reproto_manifest as manifest;
+extern crate reproto_naming as naming;
+extern crate reproto_trans as trans;
+extern crate toml;
+
+mod compiler;
+mod flavored;
+mod utils;
+
+use backend::IntoBytes;
+use compiler::Compiler;
+use core::errors::Result;
+use core::{Context, CoreFlavor, RpPackage};
+use genco::{Custom, Formatter, Tokens};
+use manifest::{Lang, Manifest, NoModule, TryFromToml};
+use naming::Naming;
+use std::any::Any;
+use std::collections::BTreeSet;
+use std::fmt::{self, Write};
+use std::path::Path;
+use std::rc::Rc;
+use trans::Environment;
+
+const TYPE_SEP: &str = "_";
+const EXT: &str = "kt";
+
+#[derive(Clone, Copy, Default, Debug)]
+pub struct KotlinLang;
+
+impl Lang for KotlinLang {
+    lang_base!(KotlinModule, compile);
+
+    fn comment(&self, input: &str) -> Option<String> {
+        Some(format!("// {}", input))
+    }
+
+    fn field_ident_naming(&self) -> Option<Box<Naming>> {
+        Some(Box::new(naming::to_lower_camel()))
+    }
+
+    fn endpoint_ident_naming(&self) -> Option<Box<Naming>> {
+        Some(Box::new(naming::to_lower_camel()))
+    }
+
+    fn keywords(&self) -> Vec<(&'static str, &'static str)> {
+        vec![
+            ("as", "_as"),
+            ("break", "_break"),
+            ("class", "_class"),
+            ("continue", "_continue"),
+            ("do", "_do"),
+            ("else", "_else"),
+            ("false", "_false"),
+            ("for", "_for"),
+            ("fun", "_fun"),
+            ("if", "_if"),
+            ("in", "_in"),
+            ("interface", "_interface"),
+            ("is", "_is"),
+            ("null", "_null"),
+            ("object", "_object"),
+            ("package", "_package"),
+            ("return", "_return"),
+            ("super", "_super"),
+            ("this", "_this"),
+            ("throw", "_throw"),
+            ("true", "_true"),
+            ("try", "_try"),
+            ("typealias", "_typealias"),
+            ("typeof", "_typeof"),
+            ("val", "_val"),
+            ("var", "_var"),
+            ("when", "_when"),
+            ("while", "_while"),
+        ]
+    }
+}
+
+#[derive(Debug)]
+pub enum KotlinModule {}
+
+impl TryFromToml for KotlinModule {
+    fn try_from_string(path: &Path, id: &str, value: String) -> Result<Self> {
+        NoModule::illegal(path, id, value)
+    }
+
+    fn try_from_value(path: &Path, id: &str, value: toml::Value) -> Result<Self> {
+        NoModule::illegal(path, id, value)
+    }
+}
+
+/// Kotlin code, which is formatted without any language-specific elements.
+#[derive(Clone)]
+pub enum Kotlin {}
+
+impl Custom for Kotlin {
+    type Extra = ();
+
+    fn quote_string(out: &mut Formatter, input: &str) -> fmt::Result {
+        out.write_char('"')?;
+
+        for c in input.chars() {
+            match c {
+                '\t' => out.write_str("\\t")?,
+                '\u{0007}' => out.write_str("\\b")?,
+                '\n' => out.write_str("\\n")?,
+                '\r' => out.write_str("\\r")?,
+                '\'' => out.write_str("\\'")?,
+                '"' => out.write_str("\\\"")?,
+                '\\' => out.write_str("\\\\")?,
+                '$' => out.write_str("\\$")?,
+                c => out.write_char(c)?,
+            }
+        }
+
+        out.write_char('"')?;
+
+        Ok(())
+    }
+}
+
+pub struct Options {}
+
+impl Options {
+    pub fn new() -> Options {
+        Options {}
+    }
+}
+
+pub struct FileSpec<'el> {
+    /// Packages which are imported using wildcard imports.
+    pub imports: BTreeSet<&'static str>,
+    pub body: Tokens<'el, Kotlin>,
+}
+
+impl<'el> Default for FileSpec<'el> {
+    fn default() -> Self {
+        FileSpec {
+            imports: BTreeSet::new(),
+            body: Tokens::new(),
+        }
+    }
+}
+
+impl<'el> IntoBytes<Compiler<'el>> for FileSpec<'el> {
+    fn into_bytes(self, _: &Compiler<'el>, package: &RpPackage) -> Result<Vec<u8>> {
+        let mut t = Tokens::new();
+
+        t.push(toks!["package ", package.join(".")]);
+
+        if !self.imports.is_empty() {
+            let mut imports = Tokens::new();
+
+            for import in self.imports {
+                imports.push(toks!["import ", import, ".*"]);
+            }
+
+            t.push(imports);
+        }
+
+        t.push(self.body.join_line_spacing());
+
+        let out = t.join_line_spacing().to_file()?;
+        Ok(out.into_bytes())
+    }
+}
+
+pub fn setup_options(modules: Vec<KotlinModule>) -> Result<Options> {
+    let options = Options::new();
+
+    for module in modules {
+        match module {}
+    }
+
+    Ok(options)
+}
+
+fn compile(ctx: Rc<Context>, env: Environment<CoreFlavor>, manifest: Manifest) -> Result<()> {
+    let packages = env.packages()?;
+
+    let translator = env.translator(flavored::KotlinFlavorTranslator::new(packages))?;
+
+    let env = env.translate(translator)?;
+
+    let modules = manifest::checked_modules(manifest.modules)?;
+    let options = setup_options(modules)?;
+    let handle = ctx.filesystem(manifest.output.as_ref().map(AsRef::as_ref))?;
+
+    Compiler::new(&env, options, handle.as_ref()).compile()
+}
@@ -0,0 +1,50 @@
+use core::RpDeprecated;
+use genco::{IntoTokens, Quoted, Tokens};
+use Kotlin;
+
+/// Documentation comments.
+pub struct BlockComment<'el>(pub &'el [String]);
+
+impl<'el> IntoTokens<'el, Kotlin> for BlockComment<'el> {
+    fn into_tokens(self) -> Tokens<'el, Kotlin> {
+        let mut t = Tokens::new();
+
+        if self.0.is_empty() {
+            return t;
+        }
+
+        t.push("/**");
+
+        for c in self.0.iter() {
+            t.push(toks![" * ", c.as_str()]);
+        }
+
+        t.push(" */");
+        t
+    }
+}
+
+/// Deprecated annotation, empty unless the item is deprecated.
+pub struct Deprecated<'el>(pub &'el Option<RpDeprecated>);
+
+impl<'el> IntoTokens<'el, Kotlin> for Deprecated<'el> {
+    fn into_tokens(self) -> Tokens<'el, Kotlin> {
+        match *self.0 {
+            Some(ref deprecated) => toks!["@Deprecated(", deprecated.message().quoted(), ")"],
+            None => toks![],
+        }
+    }
+}
+
+/// Build a block with the given head, like `if (...)` or `object Serializer`.
+pub fn block<'el, H, B>(head: H, body: B) -> Tokens<'el, Kotlin>
+where
+    H: Into<Tokens<'el, Kotlin>>,
+    B: Into<Tokens<'el, Kotlin>>,
+{
+    let mut t = Tokens::new();
+    t.push(toks![head.into(), " {"]);
+    t.nested(body.into());
+    t.push("}");
+    t
+}
@@ -0,0 +1,78 @@
+#[macro_use]
+extern crate log;
+#[allow(unused)]
+#[macro_use]
+extern crate reproto_backend as backend;
+extern crate reproto_core as core;
+#[macro_use]
+extern crate reproto_manifest as manifest;
+extern crate reproto_trans as trans;
+extern crate serde;
+#[macro_use]
+extern crate serde_json;
+extern crate toml;
+
+mod spec;
+
+use core::errors::*;
+use core::{Context, CoreFlavor, RpDecl};
+use manifest::{Lang, Manifest, NoModule, TryFromToml};
+use spec::Spec;
+use std::any::Any;
+use std::path::Path;
+use std::rc::Rc;
+use trans::Environment;
+
+#[derive(Clone, Copy, Default, Debug)]
+pub struct OpenApiLang;
+
+impl Lang for OpenApiLang {
+    lang_base!(OpenApiModule, compile);
+}
+
+#[derive(Debug)]
+pub enum OpenApiModule {
+}
+
+impl TryFromToml for OpenApiModule {
+    fn try_from_string(path: &Path, id: &str, value: String) -> Result<Self> {
+        NoModule::illegal(path, id, value)
+    }
+
+    fn try_from_value(path: &Path, id: &str, value: toml::Value) -> Result<Self> {
+        NoModule::illegal(path, id, value)
+    }
+}
+
+/// Compile to one OpenAPI document for each service.
+fn compile(ctx: Rc<Context>, env: Environment<CoreFlavor>, manifest: Manifest) -> Result<()> {
+    let env = env.translate_default()?;
+    let handle = ctx.filesystem(manifest.output.as_ref().map(AsRef::as_ref))?;
+    let spec = Spec::new(env.decl_iter());
+
+    for decl in env.decl_iter() {
+        let body = match *decl {
+            RpDecl::Service(ref body) => body,
+            _ => continue,
+        };
+
+        let path = spec::path(&body.name);
+        let value = spec.service(body)?;
+
+        if let Some(parent) = path.parent() {
+            if !handle.is_dir(parent) {
+                debug!("+dir: {}", parent.display());
+                handle.create_dir_all(parent)?;
+            }
+        }
+
+        debug!("+file: {}", path.display());
+        writeln!(
+            handle.create(&path)?,
+            "{}",
+            serde_json::to_string_pretty(&value)?,
+        )?;
+    }
+
+    Ok(())
+}
@@ -0,0 +1,466 @@
+//! Conversion of services into OpenAPI documents.
+
+use backend::json_schema::{default, description, enum_body, package_parts, validate};
+use core::errors::*;
+use core::flavored::{RpAccept, RpDecl, RpEndpoint, RpEndpointHttp1, RpField, RpHttpMethod,
+                     RpInterfaceBody, RpName, RpPathPart, RpPathSpec, RpServiceBody, RpSubType,
+                     RpTupleBody, RpType, RpTypeBody, RpVersionedPackage};
+use core::{self, Loc, RelativePathBuf, RpSubTypeStrategy};
+use serde_json::{Map, Value};
+use std::collections::{HashMap, HashSet, VecDeque};
+
+/// The version of OpenAPI that is generated.
+const OPENAPI: &str = "3.0.3";
+
+/// Path of the document for the given service, relative to the output directory.
+pub fn path(name: &RpName) -> RelativePathBuf {
+    let path = package_parts(&name.package)
+        .iter()
+        .fold(RelativePathBuf::new(), |path, part| path.join(part));
+
+    path.join(format!("{}.json", name.parts.join(".")))
+}
+
+/// Keep track of the schemas which are referenced by a document.
+struct Refs<'a> {
+    /// Package of the service being converted.
+    package: &'a RpVersionedPackage,
+    /// Names which have been referenced, but not yet added as components.
+    queue: VecDeque<RpName>,
+}
+
+impl<'a> Refs<'a> {
+    /// Key of the component for the given name.
+    ///
+    /// Names in the same package as the service are referenced by their local name, everything
+    /// else is qualified with its package.
+    fn key(&self, name: &RpName) -> String {
+        if name.package == *self.package {
+            return name.parts.join(".");
+        }
+
+        let mut parts = package_parts(&name.package);
+        parts.extend(name.parts.iter().cloned());
+        parts.join(".")
+    }
+
+    /// Reference the schema of the given name, queueing it up to be added as a component.
+    fn reference(&mut self, name: &RpName) -> Value {
+        let name = name.clone().without_prefix();
+        let reference = format!("#/components/schemas/{}", self.key(&name));
+        self.queue.push_back(name);
+        json!({ "$ref": reference })
+    }
+}
+
+/// Builds OpenAPI documents out of services.
+pub struct Spec<'a> {
+    /// All declarations, which referenced types are looked up in.
+    decls: HashMap<RpName, &'a RpDecl>,
+}
+
+impl<'a> Spec<'a> {
+    pub fn new<I>(decls: I) -> Spec<'a>
+    where
+        I: IntoIterator<Item = &'a RpDecl>,
+    {
+        let mut out = HashMap::new();
+
+        for decl in decls {
+            // NB: sub-types are described as part of their interface.
+            if let core::RpDecl::Interface(ref body) = *decl {
+                for sub_type in &body.sub_types {
+                    out.insert(sub_type.name.clone().without_prefix(), decl);
+                }
+            }
+
+            out.insert(decl.name().clone().without_prefix(), decl);
+        }
+
+        Spec { decls: out }
+    }
+
+    /// Build the document for a single service.
+    pub fn service(&self, body: &RpServiceBody) -> Result<Value> {
+        let mut refs = Refs {
+            package: &body.name.package,
+            queue: VecDeque::new(),
+        };
+
+        let mut paths = Map::new();
+
+        for endpoint in &body.endpoints {
+            let http = match RpEndpointHttp1::from_endpoint(endpoint) {
+                Some(http) => http,
+                None => {
+                    debug!("skipping endpoint without HTTP support: {}", endpoint.ident());
+                    continue;
+                }
+            };
+
+            let method = match http.method {
+                RpHttpMethod::Update => {
+                    warn!(
+                        "{}: UPDATE is not supported by OpenAPI, skipping",
+                        endpoint.ident()
+                    );
+                    continue;
+                }
+                ref method => method.as_str().to_lowercase(),
+            };
+
+            let operation = operation(&mut refs, endpoint, &http)?;
+
+            let item = paths
+                .entry(path_template(&http.path))
+                .or_insert_with(|| json!({}));
+
+            if let Value::Object(ref mut item) = *item {
+                item.insert(method, operation);
+            }
+        }
+
+        let mut schemas = Map::new();
+        let mut seen = HashSet::new();
+
+        while let Some(name) = refs.queue.pop_front() {
+            if !seen.insert(name.clone()) {
+                continue;
+            }
+
+            let decl = match self.decls.get(&name) {
+                Some(decl) => *decl,
+                None => return Err(format!("no such type: {}", name).into()),
+            };
+
+            self::decl(&mut refs, decl, &mut schemas)?;
+        }
+
+        let mut info = Map::new();
+        info.insert("title".to_string(), json!(body.name.parts.join(".")));
+
+        let version = body.name
+            .package
+            .version
+            .as_ref()
+            .map(|v| v.to_string())
+            .unwrap_or_else(|| "0.0.0".to_string());
+
+        info.insert("version".to_string(), json!(version));
+        description(&mut info, &body.comment);
+
+        let mut spec = Map::new();
+        spec.insert("openapi".to_string(), json!(OPENAPI));
+        spec.insert("info".to_string(), Value::Object(info));
+
+        if let Some(ref url) = body.http.url {
+            spec.insert("servers".to_string(), json!([{ "url": url.as_str() }]));
+        }
+
+        spec.insert("paths".to_string(), Value::Object(paths));
+
+        if !schemas.is_empty() {
+            spec.insert("components".to_string(), json!({ "schemas": schemas }));
+        }
+
+        Ok(Value::Object(spec))
+    }
+}
+
+/// Build the path template, like `/users/{id}`.
+fn path_template(path: &RpPathSpec) -> String {
+    let mut out = String::new();
+
+    for step in &path.steps {
+        out.push('/');
+
+        for part in &step.parts {
+            match *part {
+                RpPathPart::Variable(ref arg) => {
+                    out.push('{');
+                    out.push_str(arg.ident());
+                    out.push('}');
+                }
+                RpPathPart::Segment(ref segment) => out.push_str(segment),
+            }
+        }
+    }
+
+    if out.is_empty() {
+        out.push('/');
+    }
+
+    out
+}
+
+/// Build the operation for a single endpoint.
+fn operation(refs: &mut Refs, endpoint: &RpEndpoint, http: &RpEndpointHttp1) -> Result<Value> {
+    let mut operation = Map::new();
+    operation.insert("operationId".to_string(), json!(endpoint.ident()));
+    description(&mut operation, &endpoint.comment);
+
+    let mut parameters = Vec::new();
+
+    for var in http.path.vars() {
+        parameters.push(json!({
+            "name": var.ident(),
+            "in": "path",
+            "required": true,
+            "schema": ty(refs, var.channel.ty())?,
+        }));
+    }
+
+    if !parameters.is_empty() {
+        operation.insert("parameters".to_string(), Value::Array(parameters));
+    }
+
+    if let Some(ref request) = http.request {
+        operation.insert(
+            "requestBody".to_string(),
+            json!({
+                "required": true,
+                "content": {
+                    "application/json": {"schema": ty(refs, request)?},
+                },
+            }),
+        );
+    }
+
+    let mut response = Map::new();
+    response.insert("description".to_string(), json!("successful response"));
+
+    if let Some(ref ty) = http.response {
+        let content = match endpoint.http.accept {
+            RpAccept::Json => json!({"application/json": {"schema": self::ty(refs, ty)?}}),
+            RpAccept::Text => json!({"text/plain": {"schema": {"type": "string"}}}),
+        };
+
+        response.insert("content".to_string(), content);
+    }
+
+    operation.insert("responses".to_string(), json!({ "200": response }));
+    Ok(Value::Object(operation))
+}
+
+/// Build the schema for a type.
+fn ty(refs: &mut Refs, input: &RpType) -> Result<Value> {
+    let value = match *input {
+        core::RpType::Double => json!({"type": "number", "format": "double"}),
+        core::RpType::Float => json!({"type": "number", "format": "float"}),
+        core::RpType::Signed { size } => json!({
+            "type": "integer",
+            "format": integer_format(size),
+        }),
+        core::RpType::Unsigned { size } => json!({
+            "type": "integer",
+            "format": integer_format(size),
+            "minimum": 0,
+        }),
+        core::RpType::Boolean => json!({"type": "boolean"}),
+        core::RpType::String => json!({"type": "string"}),
+        core::RpType::DateTime => json!({"type": "string", "format": "date-time"}),
+        core::RpType::Date => json!({"type": "string", "format": "date"}),
+        core::RpType::Time => json!({"type": "string", "format": "time"}),
+        core::RpType::Duration => json!({"type": "string", "format": "duration"}),
+        core::RpType::Uuid => json!({"type": "string", "format": "uuid"}),
+        core::RpType::Bytes => json!({"type": "string", "format": "byte"}),
+        core::RpType::Any => json!({}),
+        core::RpType::Name { ref name } => refs.reference(name),
+        core::RpType::Array { ref inner } => json!({"type": "array", "items": ty(refs, inner)?}),
+        core::RpType::Map { ref value, .. } => json!({
+            "type": "object",
+            "additionalProperties": ty(refs, value)?,
+        }),
+    };
+
+    Ok(value)
+}
+
+fn integer_format(size: usize) -> &'static str {
+    if size <= 32 {
+        "int32"
+    } else {
+        "int64"
+    }
+}
+
+/// Add the schemas for the given declaration to `schemas`.
+///
+/// Services can't be represented as a schema, and are ignored.
+fn decl(refs: &mut Refs, decl: &RpDecl, schemas: &mut Map<String, Value>) -> Result<()> {
+    use core::RpDecl::*;
+
+    let mut sub_types = Vec::new();
+
+    let mut schema = match *decl {
+        Type(ref body) => type_body(refs, body)?,
+        Tuple(ref body) => tuple_body(refs, body)?,
+        Interface(ref body) => interface_body(refs, body, &mut sub_types)?,
+        Enum(ref body) => enum_body(body)?,
+        Service(_) => return Ok(()),
+    };
+
+    let name = decl.name();
+    schema.insert("title".to_string(), json!(name.parts.join(".")));
+    description(&mut schema, decl.comment());
+    schemas.insert(refs.key(name), Value::Object(schema));
+    schemas.extend(sub_types);
+    Ok(())
+}
+
+/// Build an object schema with the given fields.
+fn object<'a, I>(refs: &mut Refs, fields: I) -> Result<Map<String, Value>>
+where
+    I: IntoIterator<Item = &'a Loc<RpField>>,
+{
+    let mut properties = Map::new();
+    let mut required = Vec::new();
+
+    for field in fields {
+        let mut property = match ty(refs, &field.ty)? {
+            Value::Object(property) => property,
+            _ => return Err("expected object".into()),
+        };
+
+        description(&mut property, &field.comment);
+
+        if let Some(ref v) = field.validate {
+            validate(&mut property, &field.ty, v)?;
+        }
+
+        if let Some(ref d) = field.default {
+            property.insert("default".to_string(), default(d)?);
+        }
+
+        if !field.is_optional() {
+            required.push(json!(field.name()));
+        }
+
+        properties.insert(field.name().to_string(), Value::Object(property));
+    }
+
+    let mut schema = Map::new();
+    schema.insert("type".to_string(), json!("object"));
+    schema.insert("properties".to_string(), Value::Object(properties));
+
+    if !required.is_empty() {
+        schema.insert("required".to_string(), Value::Array(required));
+    }
+
+    Ok(schema)
+}
+
+fn type_body(refs: &mut Refs, body: &RpTypeBody) -> Result<Map<String, Value>> {
+    object(refs, &body.fields)
+}
+
+/// Tuples are encoded as arrays.
+///
+/// OpenAPI 3.0 has no way to describe positional items, so the items are described as one of the
+/// types of the fields.
+fn tuple_body(refs: &mut Refs, body: &RpTupleBody) -> Result<Map<String, Value>> {
+    let mut items: Vec<Value> = Vec::new();
+
+    for field in &body.fields {
+        let mut item = ty(refs, &field.ty)?;
+
+        if let Some(ref v) = field.validate {
+            if let Value::Object(ref mut item) = item {
+                validate(item, &field.ty, v)?;
+            }
+        }
+
+        if !items.contains(&item) {
+            items.push(item);
+        }
+    }
+
+    let items = match items.len() {
+        1 => items.pop().unwrap_or_else(|| json!({})),
+        _ => json!({ "anyOf": items }),
+    };
+
+    let min_items = body.fields.iter().filter(|f| !f.is_optional()).count();
+
+    let mut schema = Map::new();
+    schema.insert("type".to_string(), json!("array"));
+    schema.insert("minItems".to_string(), json!(min_items));
+    schema.insert("maxItems".to_string(), json!(body.fields.len()));
+    schema.insert("items".to_string(), items);
+    Ok(schema)
+}
+
+/// Interfaces are a choice between sub-types, each of which is added as a separate component.
+///
+/// Tagged sub-types are described through a discriminator.
+fn interface_body(
+    refs: &mut Refs,
+    body: &RpInterfaceBody,
+    sub_types: &mut Vec<(String, Value)>,
+) -> Result<Map<String, Value>> {
+    let mut choices = Vec::new();
+    let mut mapping = Map::new();
+
+    for sub_type in &body.sub_types {
+        let schema = sub_type_schema(refs, body, sub_type)?;
+        let key = refs.key(&sub_type.name);
+        let reference = format!("#/components/schemas/{}", key);
+
+        choices.push(json!({ "$ref": reference.as_str() }));
+        mapping.insert(sub_type.name().to_string(), json!(reference));
+        sub_types.push((key, Value::Object(schema)));
+    }
+
+    let mut schema = Map::new();
+
+    if choices.is_empty() {
+        // NB: an interface without sub-types can't be matched by anything.
+        schema.insert("not".to_string(), json!({}));
+        return Ok(schema);
+    }
+
+    match body.sub_type_strategy {
+        RpSubTypeStrategy::Tagged { ref tag, .. } => {
+            schema.insert("oneOf".to_string(), Value::Array(choices));
+            schema.insert(
+                "discriminator".to_string(),
+                json!({"propertyName": tag.as_str(), "mapping": mapping}),
+            );
+        }
+        RpSubTypeStrategy::Untagged => {
+            schema.insert("anyOf".to_string(), Value::Array(choices));
+        }
+    }
+
+    Ok(schema)
+}
+
+fn sub_type_schema(
+    refs: &mut Refs,
+    body: &RpInterfaceBody,
+    sub_type: &RpSubType,
+) -> Result<Map<String, Value>> {
+    let fields = body.fields.iter().chain(sub_type.fields.iter());
+    let mut schema = object(refs, fields)?;
+
+    if let RpSubTypeStrategy::Tagged { ref tag, .. } = body.sub_type_strategy {
+        if let Some(&mut Value::Object(ref mut properties)) = schema.get_mut("properties") {
+            properties.insert(
+                tag.to_string(),
+                json!({"type": "string", "enum": [sub_type.name()]}),
+            );
+        }
+
+        let mut required = vec![json!(tag.as_str())];
+
+        if let Some(Value::Array(rest)) = schema.remove("required") {
+            required.extend(rest);
+        }
+
+        schema.insert("required".to_string(), Value::Array(required));
+    }
+
+    schema.insert("title".to_string(), json!(sub_type.name.parts.join(".")));
+    description(&mut schema, &sub_type.comment);
+    Ok(schema)
+}
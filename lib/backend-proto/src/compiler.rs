@@ -0,0 +1,525 @@
+//! Conversion of declarations into proto3 files.
+
+use core::errors::*;
+use core::flavored::{RpChannel, RpDecl, RpEndpoint, RpEnumBody, RpField, RpInterfaceBody, RpName,
+                     RpServiceBody, RpType, RpVersionedPackage};
+use core::{self, CoreFlavor, Handle, Loc, PackageTranslator, RelativePathBuf, RpPackage,
+           RpVariantValue, WithSpan};
+use genco::{IoFmt, Quoted, Tokens, WriteTokens};
+use naming::{self, Naming};
+use std::collections::{BTreeSet, HashSet};
+use std::rc::Rc;
+use trans::{Packages, Translated};
+use {Comments, Proto};
+
+/// Message used for rpcs without a request or a response.
+const EMPTY: &str = "google.protobuf.Empty";
+const EMPTY_PROTO: &str = "google/protobuf/empty.proto";
+/// Message used for `datetime`.
+const TIMESTAMP: &str = "google.protobuf.Timestamp";
+const TIMESTAMP_PROTO: &str = "google/protobuf/timestamp.proto";
+/// Message used for `duration`.
+const DURATION: &str = "google.protobuf.Duration";
+const DURATION_PROTO: &str = "google/protobuf/duration.proto";
+/// Message used for `any`.
+const VALUE: &str = "google.protobuf.Value";
+const VALUE_PROTO: &str = "google/protobuf/struct.proto";
+
+/// Path of the file for the given package, relative to the output directory.
+fn path(package: &RpPackage) -> RelativePathBuf {
+    let mut path = package
+        .parts()
+        .fold(RelativePathBuf::new(), |path, part| path.join(part));
+
+    path.set_extension("proto");
+    path
+}
+
+/// Identifier of a declaration.
+///
+/// Protobuf doesn't permit services and messages to be nested in each other, so all declarations
+/// are generated at the top level of the file.
+fn ident(name: &RpName) -> String {
+    name.parts.join("_")
+}
+
+/// Check if the given number is in the range reserved by protobuf.
+fn is_reserved(number: u32) -> bool {
+    number >= 19_000 && number <= 19_999
+}
+
+/// Assign numbers to fields or sub-types, in the order they are declared.
+///
+/// Pinned numbers are used as they are, and the remaining items are given the lowest numbers
+/// which are neither pinned nor reserved by protobuf.
+fn numbers<I>(pinned: I) -> Result<Vec<u32>>
+where
+    I: IntoIterator<Item = Option<u32>>,
+{
+    let pinned = pinned.into_iter().collect::<Vec<_>>();
+    let mut used = HashSet::new();
+
+    for number in pinned.iter().filter_map(|n| *n) {
+        if is_reserved(number) {
+            return Err(format!("number `{}` is reserved by protobuf", number).into());
+        }
+
+        used.insert(number);
+    }
+
+    let mut next = 1;
+    let mut numbers = Vec::new();
+
+    for number in pinned {
+        let number = match number {
+            Some(number) => number,
+            None => {
+                while used.contains(&next) || is_reserved(next) {
+                    next += 1;
+                }
+
+                let n = next;
+                next += 1;
+                n
+            }
+        };
+
+        numbers.push(number);
+    }
+
+    Ok(numbers)
+}
+
+/// State of the file being generated.
+struct File<'el> {
+    /// Package of the file.
+    package: &'el RpVersionedPackage,
+    /// Files which need to be imported.
+    imports: BTreeSet<String>,
+}
+
+pub struct Compiler<'el> {
+    env: &'el Translated<CoreFlavor>,
+    packages: Rc<Packages>,
+    handle: &'el Handle,
+    to_lower_camel: naming::ToLowerCamel,
+    to_lower_snake: naming::ToLowerSnake,
+    to_upper_snake: naming::ToUpperSnake,
+}
+
+impl<'el> Compiler<'el> {
+    pub fn new(
+        env: &'el Translated<CoreFlavor>,
+        packages: Rc<Packages>,
+        handle: &'el Handle,
+    ) -> Compiler<'el> {
+        Compiler {
+            env,
+            packages,
+            handle,
+            to_lower_camel: naming::to_lower_camel(),
+            to_lower_snake: naming::to_lower_snake(),
+            to_upper_snake: naming::to_upper_snake(),
+        }
+    }
+
+    pub fn compile(&self) -> Result<()> {
+        for (package, file) in self.env.for_each_file() {
+            let proto_package = self.packages.translate_package(package.clone())?;
+
+            let mut out = File {
+                package: package,
+                imports: BTreeSet::new(),
+            };
+
+            let mut items = Vec::new();
+
+            for decl in &file.decls {
+                self.decl(&mut out, &mut items, decl)?;
+            }
+
+            let mut t = Tokens::new();
+            t.push("syntax = \"proto3\";");
+            t.push(toks!["package ", proto_package.join("."), ";"]);
+
+            if !out.imports.is_empty() {
+                t.push({
+                    let mut t = Tokens::new();
+
+                    for import in out.imports {
+                        t.push(toks!["import ", Rc::new(import).quoted(), ";"]);
+                    }
+
+                    t
+                });
+            }
+
+            for item in items {
+                t.push(item);
+            }
+
+            let path = path(&proto_package);
+
+            if let Some(parent) = path.parent() {
+                if !self.handle.is_dir(parent) {
+                    debug!("+dir: {}", parent.display());
+                    self.handle.create_dir_all(parent)?;
+                }
+            }
+
+            debug!("+file: {}", path.display());
+            IoFmt(&mut self.handle.create(&path)?).write_file(t.join_line_spacing(), &mut ())?;
+        }
+
+        Ok(())
+    }
+
+    /// Generate the given declaration, followed by all of its inner declarations.
+    fn decl(
+        &self,
+        out: &mut File<'el>,
+        items: &mut Vec<Tokens<'el, Proto>>,
+        decl: &'el RpDecl,
+    ) -> Result<()> {
+        use core::RpDecl::*;
+
+        let result = match *decl {
+            Type(ref body) => self.message(out, &body.name, &body.comment, &body.fields)
+                .map(|t| items.push(t)),
+            Tuple(ref body) => self.message(out, &body.name, &body.comment, &body.fields)
+                .map(|t| items.push(t)),
+            Interface(ref body) => self.interface(out, items, body),
+            Enum(ref body) => self.enum_(body).map(|t| items.push(t)),
+            Service(ref body) => self.service(out, body).map(|t| items.push(t)),
+        };
+
+        result.with_span(decl.span())?;
+
+        for d in decl.decls() {
+            self.decl(out, items, d)?;
+        }
+
+        Ok(())
+    }
+
+    /// Build a message with the given fields.
+    fn message<I>(
+        &self,
+        out: &mut File<'el>,
+        name: &'el RpName,
+        comment: &'el [String],
+        fields: I,
+    ) -> Result<Tokens<'el, Proto>>
+    where
+        I: IntoIterator<Item = &'el Loc<RpField>>,
+    {
+        let fields = fields.into_iter().collect::<Vec<_>>();
+        let numbers = numbers(fields.iter().map(|f| f.number))?;
+
+        let mut t = Tokens::new();
+
+        t.push_unless_empty(Comments(comment));
+        t.push(toks!["message ", ident(name), " {"]);
+
+        t.nested({
+            let mut t = Tokens::new();
+
+            for (field, number) in fields.into_iter().zip(numbers) {
+                t.push(self.field(out, field, number).with_span(Loc::span(field))?);
+            }
+
+            t
+        });
+
+        t.push("}");
+
+        Ok(t)
+    }
+
+    fn field(
+        &self,
+        out: &mut File<'el>,
+        field: &'el RpField,
+        number: u32,
+    ) -> Result<Tokens<'el, Proto>> {
+        let ty = match field.ty {
+            core::RpType::Array { ref inner } => format!("repeated {}", self.ty(out, inner)?),
+            core::RpType::Map { ref key, ref value } => {
+                format!("map<{}, {}>", self.key_ty(key)?, self.ty(out, value)?)
+            }
+            ref ty if field.is_optional() => format!("optional {}", self.ty(out, ty)?),
+            ref ty => self.ty(out, ty)?,
+        };
+
+        let mut t = Tokens::new();
+
+        t.push_unless_empty(Comments(&field.comment));
+        t.push(toks![ty, " ", field.ident(), " = ", number.to_string()]);
+
+        // NB: the JSON mapping of proto3 uses the lower camel case name of fields by default.
+        if field.name() != self.to_lower_camel.convert(field.ident()) {
+            t.extend(toks![" [json_name = ", field.name().quoted(), "]"]);
+        }
+
+        t.append(";");
+
+        Ok(t)
+    }
+
+    /// Convert a type which is neither repeated nor a map.
+    fn ty(&self, out: &mut File<'el>, ty: &RpType) -> Result<String> {
+        use core::RpType::*;
+
+        let ty = match *ty {
+            Double => "double",
+            Float => "float",
+            Signed { size: 32 } => "int32",
+            Signed { .. } => "int64",
+            Unsigned { size: 32 } => "uint32",
+            Unsigned { .. } => "uint64",
+            Boolean => "bool",
+            String | Date | Time | Uuid => "string",
+            Bytes => "bytes",
+            DateTime => {
+                out.imports.insert(TIMESTAMP_PROTO.to_string());
+                TIMESTAMP
+            }
+            Duration => {
+                out.imports.insert(DURATION_PROTO.to_string());
+                DURATION
+            }
+            Any => {
+                out.imports.insert(VALUE_PROTO.to_string());
+                VALUE
+            }
+            Name { ref name } => return self.reference(out, name),
+            Array { .. } | Map { .. } => {
+                return Err("arrays and maps can't be nested in protobuf".into())
+            }
+        };
+
+        Ok(ty.to_string())
+    }
+
+    /// Convert the key of a map.
+    fn key_ty(&self, ty: &RpType) -> Result<&'static str> {
+        use core::RpType::*;
+
+        let ty = match *ty {
+            Signed { size: 32 } => "int32",
+            Signed { .. } => "int64",
+            Unsigned { size: 32 } => "uint32",
+            Unsigned { .. } => "uint64",
+            Boolean => "bool",
+            String => "string",
+            _ => return Err("map keys must be strings, integers, or booleans in protobuf".into()),
+        };
+
+        Ok(ty)
+    }
+
+    /// Reference to the given declaration, importing its file if needed.
+    fn reference(&self, out: &mut File<'el>, name: &RpName) -> Result<String> {
+        if name.package == *out.package {
+            return Ok(ident(name));
+        }
+
+        let package = self.packages.translate_package(name.package.clone())?;
+        out.imports.insert(path(&package).as_str().to_string());
+        Ok(format!(".{}.{}", package.join("."), ident(name)))
+    }
+
+    /// Interfaces are messages with a `oneof` of their sub-types.
+    ///
+    /// Each sub-type is a separate message, containing both the fields of the interface and its
+    /// own fields.
+    fn interface(
+        &self,
+        out: &mut File<'el>,
+        items: &mut Vec<Tokens<'el, Proto>>,
+        body: &'el RpInterfaceBody,
+    ) -> Result<()> {
+        let numbers = numbers(body.sub_types.iter().map(|s| s.number))?;
+
+        let mut t = Tokens::new();
+
+        t.push_unless_empty(Comments(&body.comment));
+        t.push(toks!["message ", ident(&body.name), " {"]);
+
+        // NB: oneof can't be empty.
+        if !body.sub_types.is_empty() {
+            t.nested({
+                let mut t = Tokens::new();
+
+                t.push("oneof value {");
+
+                t.nested({
+                    let mut t = Tokens::new();
+
+                    for (sub_type, number) in body.sub_types.iter().zip(numbers) {
+                        t.push(toks![
+                            ident(&sub_type.name),
+                            " ",
+                            self.to_lower_snake.convert(sub_type.ident.as_str()),
+                            " = ",
+                            number.to_string(),
+                            ";",
+                        ]);
+                    }
+
+                    t
+                });
+
+                t.push("}");
+                t
+            });
+        }
+
+        t.push("}");
+        items.push(t);
+
+        for sub_type in &body.sub_types {
+            let fields = body.fields.iter().chain(sub_type.fields.iter());
+
+            let message = self.message(out, &sub_type.name, &sub_type.comment, fields)
+                .with_span(Loc::span(sub_type))?;
+
+            items.push(message);
+        }
+
+        Ok(())
+    }
+
+    /// Enums are numbered in the order they are declared, unless they have numeric values.
+    ///
+    /// The first value of an enum must be zero in proto3, so an unspecified value is added unless
+    /// one is already present.
+    fn enum_(&self, body: &'el RpEnumBody) -> Result<Tokens<'el, Proto>> {
+        let prefix = self.to_upper_snake.convert(ident(&body.name).as_str());
+        let unspecified = format!("{}_UNSPECIFIED", prefix);
+
+        // NB: a leading unspecified variant, like the ones imported from proto3, is numbered zero.
+        let offset = match body.variants.iter().next() {
+            Some(ref first) if self.to_upper_snake.convert(first.ident()) == "UNSPECIFIED" => 0,
+            _ => 1,
+        };
+
+        let mut values = Vec::new();
+
+        for (index, variant) in body.variants.iter().enumerate() {
+            let name = format!(
+                "{}_{}",
+                prefix,
+                self.to_upper_snake.convert(variant.ident())
+            );
+
+            let number = match variant.value {
+                RpVariantValue::String(_) => index as i32 + offset,
+                RpVariantValue::Number(number) => number
+                    .to_i32()
+                    .ok_or_else(|| format!("enum value out of range for protobuf: {}", number))?,
+            };
+
+            values.push((variant.comment, name, number));
+        }
+
+        // NB: stable sort, which moves the zero value first.
+        values.sort_by_key(|v| v.2 != 0);
+
+        let mut t = Tokens::new();
+
+        t.push_unless_empty(Comments(&body.comment));
+        t.push(toks!["enum ", ident(&body.name), " {"]);
+
+        t.nested({
+            let mut t = Tokens::new();
+
+            if values.iter().all(|v| v.2 != 0) {
+                t.push(toks![unspecified, " = 0;"]);
+            }
+
+            for (comment, name, number) in values {
+                t.push_unless_empty(Comments(comment));
+                t.push(toks![name, " = ", number.to_string(), ";"]);
+            }
+
+            t
+        });
+
+        t.push("}");
+
+        Ok(t)
+    }
+
+    fn service(&self, out: &mut File<'el>, body: &'el RpServiceBody) -> Result<Tokens<'el, Proto>> {
+        let mut t = Tokens::new();
+
+        t.push_unless_empty(Comments(&body.comment));
+        t.push(toks!["service ", ident(&body.name), " {"]);
+
+        t.nested({
+            let mut t = Tokens::new();
+
+            for e in &body.endpoints {
+                t.push(self.endpoint(out, e).with_span(Loc::span(e))?);
+            }
+
+            t
+        });
+
+        t.push("}");
+
+        Ok(t)
+    }
+
+    /// Endpoints are rpcs, named after the endpoint like in gRPC.
+    ///
+    /// The request is the first argument of the endpoint.
+    fn endpoint(&self, out: &mut File<'el>, e: &'el RpEndpoint) -> Result<Tokens<'el, Proto>> {
+        let request = match e.request {
+            Some(ref request) => self.channel(out, &request.channel)?,
+            None => self.empty(out),
+        };
+
+        let response = match e.response {
+            Some(ref response) => self.channel(out, response)?,
+            None => self.empty(out),
+        };
+
+        let mut t = Tokens::new();
+
+        t.push_unless_empty(Comments(&e.comment));
+        t.push(toks![
+            "rpc ",
+            e.name(),
+            " (",
+            request,
+            ") returns (",
+            response,
+            ");",
+        ]);
+
+        Ok(t)
+    }
+
+    /// Requests and responses of rpcs must be messages.
+    fn channel(&self, out: &mut File<'el>, channel: &RpChannel) -> Result<String> {
+        let ty = match *channel.ty() {
+            core::RpType::Name { ref name } => self.reference(out, name)?,
+            ref ty => {
+                return Err(format!("expected declared type in rpc, but got `{}`", ty).into());
+            }
+        };
+
+        if channel.is_streaming() {
+            return Ok(format!("stream {}", ty));
+        }
+
+        Ok(ty)
+    }
+
+    fn empty(&self, out: &mut File<'el>) -> String {
+        out.imports.insert(EMPTY_PROTO.to_string());
+        EMPTY.to_string()
+    }
+}
@@ -0,0 +1,111 @@
+#[macro_use]
+extern crate genco;
+#[macro_use]
+extern crate log;
+#[allow(unused)]
+#[macro_use]
+extern crate reproto_backend as backend;
+extern crate reproto_core as core;
+#[macro_use]
+extern crate reproto_manifest as manifest;
+extern crate reproto_naming as naming;
+extern crate reproto_trans as trans;
+extern crate toml;
+
+mod compiler;
+
+use compiler::Compiler;
+use core::errors::*;
+use core::{Context, CoreFlavor};
+use genco::{Custom, Formatter, IntoTokens, Tokens};
+use manifest::{Lang, Manifest, NoModule, TryFromToml};
+use std::any::Any;
+use std::fmt::{self, Write};
+use std::path::Path;
+use std::rc::Rc;
+use trans::Environment;
+
+/// Documentation comments.
+pub struct Comments<'el, S: 'el>(&'el [S]);
+
+impl<'el, S> IntoTokens<'el, Proto> for Comments<'el, S>
+where
+    S: AsRef<str>,
+{
+    fn into_tokens(self) -> Tokens<'el, Proto> {
+        let mut t = Tokens::new();
+
+        for line in self.0 {
+            let line = line.as_ref();
+
+            if line.is_empty() {
+                t.push("//");
+            } else {
+                t.push(toks!["// ", line]);
+            }
+        }
+
+        t
+    }
+}
+
+#[derive(Clone, Copy, Default, Debug)]
+pub struct ProtoLang;
+
+impl Lang for ProtoLang {
+    lang_base!(ProtoModule, compile);
+
+    fn comment(&self, input: &str) -> Option<String> {
+        Some(format!("//{}", input.to_string()))
+    }
+}
+
+#[derive(Debug)]
+pub enum ProtoModule {
+}
+
+impl TryFromToml for ProtoModule {
+    fn try_from_string(path: &Path, id: &str, value: String) -> Result<Self> {
+        NoModule::illegal(path, id, value)
+    }
+
+    fn try_from_value(path: &Path, id: &str, value: toml::Value) -> Result<Self> {
+        NoModule::illegal(path, id, value)
+    }
+}
+
+#[derive(Clone)]
+pub enum Proto {
+}
+
+impl Custom for Proto {
+    type Extra = ();
+
+    fn quote_string(out: &mut Formatter, input: &str) -> fmt::Result {
+        out.write_char('"')?;
+
+        for c in input.chars() {
+            match c {
+                '\t' => out.write_str("\\t")?,
+                '\n' => out.write_str("\\n")?,
+                '\r' => out.write_str("\\r")?,
+                '\'' => out.write_str("\\'")?,
+                '"' => out.write_str("\\\"")?,
+                '\\' => out.write_str("\\\\")?,
+                c => out.write_char(c)?,
+            }
+        }
+
+        out.write_char('"')?;
+
+        Ok(())
+    }
+}
+
+/// Compile to one proto3 file for each package.
+fn compile(ctx: Rc<Context>, env: Environment<CoreFlavor>, manifest: Manifest) -> Result<()> {
+    let packages = env.packages()?;
+    let env = env.translate_default()?;
+    let handle = ctx.filesystem(manifest.output.as_ref().map(AsRef::as_ref))?;
+    Compiler::new(&env, packages, handle.as_ref()).compile()
+}
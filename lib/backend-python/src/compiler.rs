@@ -3,7 +3,7 @@
 use backend::PackageProcessor;
 use codegen::{ServiceAdded, ServiceCodegen};
 use core::errors::*;
-use core::{self, ForEachLoc, Handle, Loc, RelativePathBuf};
+use core::{self, ForEachLoc, Handle, Loc, RelativePathBuf, RpDefault};
 use flavored::{PythonFlavor, PythonName, RpEnumBody, RpField, RpInterfaceBody, RpPackage,
                RpServiceBody, RpTupleBody, RpTypeBody};
 use genco::python::{imported, Python};
@@ -164,6 +164,19 @@ impl<'el> Compiler<'el> {
         repr
     }
 
+    /// Python literal for the default value of a field.
+    fn default_literal(&self, default: &RpDefault) -> Tokens<'el, Python<'el>> {
+        use self::RpDefault::*;
+
+        match *default {
+            Boolean(true) => toks!["True"],
+            Boolean(false) => toks!["False"],
+            Signed { ref value, .. } | Unsigned { ref value, .. } => toks![value.to_string()],
+            Float(ref value) | Double(ref value) => toks![value.to_string()],
+            String(ref value) => toks![value.clone().quoted()],
+        }
+    }
+
     fn decode_method<F, I>(
         &self,
         name: &'el PythonName,
@@ -185,7 +198,10 @@ impl<'el> Compiler<'el> {
                 t.push({
                     let mut t = Tokens::new();
 
-                    push!(t, n, " = None");
+                    match field.default {
+                        Some(ref default) => push!(t, n, " = ", self.default_literal(default)),
+                        None => push!(t, n, " = None"),
+                    }
 
                     t.push_into(|t| {
                         push!(t, "if ", var, " in data:");
@@ -195,10 +211,22 @@ impl<'el> Compiler<'el> {
 
                             push!(t, n.clone(), " = data[", var, "]");
 
-                            if let Some(d) = field.ty.decode(n.clone(), 0) {
+                            let mut d = Tokens::new();
+
+                            if let Some(decode) = field.ty.decode(n.clone(), 0) {
+                                d.push(decode);
+                            }
+
+                            if let Some(ref validate) = field.validate {
+                                if let Some(v) = field.ty.validate(n.clone(), validate) {
+                                    d.push(v);
+                                }
+                            }
+
+                            if !d.is_empty() {
                                 t.push_into(|t| {
                                     push!(t, "if ", n.clone(), " is not None:");
-                                    t.nested(d);
+                                    t.nested(d.join_line_spacing());
                                 });
                             }
 
@@ -214,6 +242,12 @@ impl<'el> Compiler<'el> {
                 if let Some(d) = field.ty.decode(n.clone(), 0) {
                     t.push(d);
                 }
+
+                if let Some(ref validate) = field.validate {
+                    if let Some(v) = field.ty.validate(n.clone(), validate) {
+                        t.push(v);
+                    }
+                }
             }
 
             args.append(toks!(n));
@@ -4,10 +4,10 @@
 
 use backend::package_processor;
 use core::errors::Result;
-use core::{self, CoreFlavor, Flavor, FlavorTranslator, Loc, PackageTranslator, Translate,
-           Translator};
+use core::{self, CoreFlavor, Flavor, FlavorTranslator, Loc, PackageTranslator, RpValidate,
+           Translate, Translator};
 use genco::python::{self, Python};
-use genco::{Cons, Element, IntoTokens, Tokens};
+use genco::{Cons, Element, IntoTokens, Quoted, Tokens};
 use naming::{self, Naming};
 use std::cmp;
 use std::collections::HashMap;
@@ -25,6 +25,12 @@ pub enum PythonKind<'el> {
     Float,
     Boolean,
     String,
+    /// `datetime.date`, encoded as an ISO-8601 string.
+    Date,
+    /// `datetime.time`, encoded as an ISO-8601 string.
+    Time,
+    /// `uuid.UUID`, encoded as a string.
+    Uuid,
     Array {
         argument: Box<PythonType<'el>>,
     },
@@ -107,6 +113,55 @@ impl<'el> PythonType<'el> {
                 nested!(t, "raise ", Exception("not a string"));
                 Some(t)
             }
+            Date => {
+                let test = self.helper.is_string(var.clone());
+                let datetime = python::imported("datetime");
+
+                let mut t = Tokens::new();
+                push!(t, "if not ", test, ":");
+                nested!(t, "raise ", Exception("not a string"));
+                push!(
+                    t,
+                    var.clone(),
+                    " = ",
+                    datetime,
+                    ".datetime.strptime(",
+                    var,
+                    ", \"%Y-%m-%d\").date()"
+                );
+                Some(t)
+            }
+            Time => {
+                let test = self.helper.is_string(var.clone());
+                let datetime = python::imported("datetime");
+
+                let mut t = Tokens::new();
+                push!(t, "if not ", test, ":");
+                nested!(t, "raise ", Exception("not a string"));
+                // NB: fractions of a second are optional.
+                push!(
+                    t,
+                    var.clone(),
+                    " = ",
+                    datetime,
+                    ".datetime.strptime(",
+                    var.clone(),
+                    ", \"%H:%M:%S.%f\" if \".\" in ",
+                    var,
+                    " else \"%H:%M:%S\").time()"
+                );
+                Some(t)
+            }
+            Uuid => {
+                let test = self.helper.is_string(var.clone());
+                let uuid = python::imported("uuid");
+
+                let mut t = Tokens::new();
+                push!(t, "if not ", test, ":");
+                nested!(t, "raise ", Exception("not a string"));
+                push!(t, var.clone(), " = ", uuid, ".UUID(", var, ")");
+                Some(t)
+            }
             Native => None,
             Array { ref argument } => {
                 let mut t = Tokens::new();
@@ -180,12 +235,71 @@ impl<'el> PythonType<'el> {
         }
     }
 
+    /// Build the checks for the constraints of `#[validate(..)]`.
+    ///
+    /// `var` is the decoded variable to check.
+    pub fn validate<V>(&self, var: V, validate: &RpValidate) -> Option<Tokens<'el, Python<'el>>>
+    where
+        V: Into<Cons<'el>>,
+    {
+        use self::PythonKind::*;
+
+        let var = var.into();
+
+        // What to compare to the bounds, and what it's called in errors.
+        let (value, what) = match self.kind {
+            Integer | Float => (toks![var.clone()], "value"),
+            String => (toks!["len(", var.clone(), ")"], "length"),
+            Array { .. } | Map { .. } => (toks!["len(", var.clone(), ")"], "number of items"),
+            _ => return None,
+        };
+
+        let mut t = Tokens::new();
+
+        if let Some(ref min) = validate.min {
+            let error = Rc::new(format!("{} must be at least {}", what, min));
+
+            t.push_into(|t| {
+                push!(t, "if ", value.clone(), " < ", Rc::new(min.to_string()), ":");
+                nested!(t, "raise ", Exception(error));
+            });
+        }
+
+        if let Some(ref max) = validate.max {
+            let error = Rc::new(format!("{} must be at most {}", what, max));
+
+            t.push_into(|t| {
+                push!(t, "if ", value.clone(), " > ", Rc::new(max.to_string()), ":");
+                nested!(t, "raise ", Exception(error));
+            });
+        }
+
+        if let Some(ref pattern) = validate.pattern {
+            let re = python::imported("re");
+            let pattern = Rc::new(pattern.to_string());
+            let error = Rc::new(format!("does not match pattern: {}", pattern));
+
+            t.push_into(|t| {
+                push!(t, "if not ", re, ".search(", pattern.quoted(), ", ", var, "):");
+                nested!(t, "raise ", Exception(error));
+            });
+        }
+
+        if t.is_empty() {
+            return None;
+        }
+
+        Some(t.join_line_spacing())
+    }
+
     /// Build encode method.
     pub fn encode(&self, var: Tokens<'el, Python<'el>>) -> Tokens<'el, Python<'el>> {
         use self::PythonKind::*;
 
         match self.kind {
             Integer | Float | Boolean | Native | String => toks![var],
+            Date | Time => toks![var, ".isoformat()"],
+            Uuid => toks!["str(", var, ")"],
             ref v if v.is_native() => toks![var],
             Array { ref argument } => {
                 let v = argument.encode("v".into());
@@ -298,6 +412,22 @@ impl FlavorTranslator for PythonFlavorTranslator {
         Ok(self.ty(PythonKind::String))
     }
 
+    fn translate_date(&self) -> Result<PythonType<'static>> {
+        Ok(self.ty(PythonKind::Date))
+    }
+
+    fn translate_time(&self) -> Result<PythonType<'static>> {
+        Ok(self.ty(PythonKind::Time))
+    }
+
+    fn translate_duration(&self) -> Result<PythonType<'static>> {
+        Ok(self.ty(PythonKind::String))
+    }
+
+    fn translate_uuid(&self) -> Result<PythonType<'static>> {
+        Ok(self.ty(PythonKind::Uuid))
+    }
+
     fn translate_array(&self, argument: PythonType<'static>) -> Result<PythonType<'static>> {
         Ok(self.ty(PythonKind::Array {
             argument: Box::new(argument),
@@ -11,9 +11,9 @@ extern crate reproto_trans as trans;
 extern crate toml;
 
 use core::errors::Result;
-use core::flavored::{RpDecl, RpEndpoint, RpEnumBody, RpField, RpInterfaceBody, RpServiceBody,
-                     RpTupleBody, RpTypeBody, RpVariantRef};
-use core::{Context, CoreFlavor, RelativePathBuf, DEFAULT_TAG};
+use core::flavored::{RpChannel, RpDecl, RpEndpoint, RpEnumBody, RpField, RpInterfaceBody,
+                     RpServiceBody, RpTupleBody, RpTypeBody, RpVariantRef};
+use core::{Context, CoreFlavor, RelativePathBuf, RpDeprecated, RpValidate, DEFAULT_TAG};
 use genco::{Custom, Formatter, IntoTokens, IoFmt, Quoted, Tokens, WriteTokens};
 use manifest::{Lang, Manifest, NoModule, TryFromToml};
 use std::any::Any;
@@ -45,6 +45,18 @@ where
     }
 }
 
+/// The `#[deprecated]` attribute, empty unless the item is deprecated.
+pub struct Deprecated<'el>(&'el Option<RpDeprecated>);
+
+impl<'el> IntoTokens<'el, Reproto> for Deprecated<'el> {
+    fn into_tokens(self) -> Tokens<'el, Reproto> {
+        match *self.0 {
+            Some(ref deprecated) => toks![Rc::new(deprecated.to_string())],
+            None => toks![],
+        }
+    }
+}
+
 #[derive(Clone, Copy, Default, Debug)]
 pub struct ReprotoLang;
 
@@ -163,6 +175,7 @@ pub fn format<'el>(decl: &'el RpDecl) -> Result<Tokens<'el, Reproto>> {
         let mut t = Tokens::new();
 
         t.push_unless_empty(Comments(&body.comment));
+        t.push_unless_empty(Deprecated(&body.deprecated));
         t.push(toks!["type ", body.ident.as_str(), " {"]);
 
         t.nested({
@@ -205,6 +218,7 @@ pub fn format<'el>(decl: &'el RpDecl) -> Result<Tokens<'el, Reproto>> {
         }
 
         t.push_unless_empty(Comments(&body.comment));
+        t.push_unless_empty(Deprecated(&body.deprecated));
         t.push(toks!["interface ", body.ident.as_str(), " {"]);
 
         t.nested({
@@ -214,6 +228,12 @@ pub fn format<'el>(decl: &'el RpDecl) -> Result<Tokens<'el, Reproto>> {
                 t.push({
                     let mut t = Tokens::new();
 
+                    t.push_unless_empty(Deprecated(&sub_type.deprecated));
+
+                    if let Some(number) = sub_type.number {
+                        t.push(format_number(number));
+                    }
+
                     if let Some(ref alias) = sub_type.sub_type_name {
                         t.push(toks![
                             sub_type.ident.as_str(),
@@ -261,6 +281,7 @@ pub fn format<'el>(decl: &'el RpDecl) -> Result<Tokens<'el, Reproto>> {
         let mut t = Tokens::new();
 
         t.push_unless_empty(Comments(&body.comment));
+        t.push_unless_empty(Deprecated(&body.deprecated));
         t.push(toks!["tuple ", body.ident.as_str(), " {"]);
 
         t.nested({
@@ -286,6 +307,7 @@ pub fn format<'el>(decl: &'el RpDecl) -> Result<Tokens<'el, Reproto>> {
         let mut t = Tokens::new();
 
         t.push_unless_empty(Comments(&body.comment));
+        t.push_unless_empty(Deprecated(&body.deprecated));
 
         t.push(toks![
             "enum ",
@@ -313,6 +335,7 @@ pub fn format<'el>(decl: &'el RpDecl) -> Result<Tokens<'el, Reproto>> {
     fn format_service<'el>(body: &'el RpServiceBody) -> Result<Tokens<'el, Reproto>> {
         let mut t = Tokens::new();
         t.push_unless_empty(Comments(&body.comment));
+        t.push_unless_empty(Deprecated(&body.deprecated));
         t.push(toks!["service ", body.ident.as_str(), " {"]);
 
         t.nested({
@@ -320,6 +343,7 @@ pub fn format<'el>(decl: &'el RpDecl) -> Result<Tokens<'el, Reproto>> {
 
             for e in &body.endpoints {
                 t.push_unless_empty(Comments(&e.comment));
+                t.push_unless_empty(Deprecated(&e.deprecated));
                 t.push(format_endpoint(e)?);
             }
 
@@ -344,7 +368,7 @@ pub fn format<'el>(decl: &'el RpDecl) -> Result<Tokens<'el, Reproto>> {
 
                         t.append(a.ident.as_str());
                         t.append(": ");
-                        t.append(a.channel.to_string());
+                        t.append(format_channel(&a.channel));
 
                         t
                     });
@@ -354,14 +378,43 @@ pub fn format<'el>(decl: &'el RpDecl) -> Result<Tokens<'el, Reproto>> {
             });
             t.append(")");
 
+            if let Some(ref response) = e.response {
+                t.append(" -> ");
+                t.append(format_channel(response));
+            }
+
+            if let Some(ref name) = e.name {
+                t.append(" as ");
+                t.append(name.as_str().quoted());
+            }
+
+            t.append(";");
+
             Ok(t)
         }
+
+        fn format_channel(channel: &RpChannel) -> String {
+            if channel.is_streaming() {
+                format!("stream {}", channel.ty())
+            } else {
+                channel.ty().to_string()
+            }
+        }
     }
 
     fn format_field<'el>(field: &'el RpField) -> Result<Tokens<'el, Reproto>> {
         let mut t = Tokens::new();
 
         t.push_unless_empty(Comments(&field.comment));
+        t.push_unless_empty(Deprecated(&field.deprecated));
+
+        if let Some(number) = field.number {
+            t.push(format_number(number));
+        }
+
+        if let Some(ref validate) = field.validate {
+            t.push(format_validate(validate));
+        }
 
         let field_name = field.safe_ident();
 
@@ -382,15 +435,42 @@ pub fn format<'el>(decl: &'el RpDecl) -> Result<Tokens<'el, Reproto>> {
             t.extend(toks![" as ", field_as.as_str().quoted()]);
         }
 
+        if let Some(ref default) = field.default {
+            t.extend(toks![" = ", default.to_string()]);
+        }
+
         t.append(";");
 
         Ok(t)
     }
 
+    fn format_number<'el>(number: u32) -> Tokens<'el, Reproto> {
+        toks!["#[number(", number.to_string(), ")]"]
+    }
+
+    fn format_validate<'el>(validate: &'el RpValidate) -> Tokens<'el, Reproto> {
+        let mut args = Tokens::new();
+
+        if let Some(ref min) = validate.min {
+            args.append(toks!["min = ", min.to_string()]);
+        }
+
+        if let Some(ref max) = validate.max {
+            args.append(toks!["max = ", max.to_string()]);
+        }
+
+        if let Some(ref pattern) = validate.pattern {
+            args.append(toks!["pattern = ", pattern.as_str().quoted()]);
+        }
+
+        toks!["#[validate(", args.join(", "), ")]"]
+    }
+
     fn format_variant<'el>(variant: RpVariantRef<'el>) -> Result<Tokens<'el, Reproto>> {
         let mut t = Tokens::new();
 
         t.push_unless_empty(Comments(&variant.comment));
+        t.push_unless_empty(Deprecated(variant.deprecated));
 
         t.push_into(|t| {
             t.append(variant.ident());
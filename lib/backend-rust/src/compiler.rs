@@ -2,7 +2,8 @@
 
 use backend::PackageProcessor;
 use core::errors::*;
-use core::{self, ForEachLoc, Handle, Loc, RelativePath, RelativePathBuf};
+use core::{self, ForEachLoc, Handle, Loc, RelativePath, RelativePathBuf, RpDefault,
+           RpDeprecated};
 use flavored::{RpEnumBody, RpField, RpInterfaceBody, RpName, RpPackage, RpServiceBody,
                RpTupleBody, RpTypeBody, RpVariant, RustFlavor};
 use genco::rust;
@@ -50,6 +51,30 @@ impl<'a> IntoTokens<'a, Rust<'a>> for Rename<'a> {
     }
 }
 
+/// Default attribute, referencing the function which provides the default value.
+pub struct SerdeDefault(Rc<String>);
+
+impl<'a> IntoTokens<'a, Rust<'a>> for SerdeDefault {
+    fn into_tokens(self) -> Tokens<'a, Rust<'a>> {
+        toks!["#[serde(default = ", self.0.quoted(), ")]"]
+    }
+}
+
+/// Deprecated attribute, empty unless the item is deprecated.
+pub struct Deprecated<'a>(&'a Option<RpDeprecated>);
+
+impl<'a> IntoTokens<'a, Rust<'a>> for Deprecated<'a> {
+    fn into_tokens(self) -> Tokens<'a, Rust<'a>> {
+        match *self.0 {
+            Some(RpDeprecated {
+                message: Some(ref message),
+            }) => toks!["#[deprecated(note = ", message.as_str().quoted(), ")]"],
+            Some(_) => toks!["#[deprecated]"],
+            None => toks![],
+        }
+    }
+}
+
 /// Tag attribute.
 pub struct Tag<'a>(&'a str);
 
@@ -169,6 +194,43 @@ impl<'el> Compiler<'el> {
         Ok(t.into())
     }
 
+    /// Build the function which provides the default value of a field, if it has one.
+    ///
+    /// The function is added to the impl of `owner`, and is named after the field with an
+    /// optional `prefix` to keep it unique. Returns the attribute to put on the field, and the
+    /// function.
+    fn field_default<'a>(
+        &self,
+        owner: &Rc<String>,
+        prefix: &str,
+        field: &'a RpField,
+    ) -> Option<(Tokens<'a, Rust<'a>>, Tokens<'a, Rust<'a>>)> {
+        use self::RpDefault::*;
+
+        let default = match field.default {
+            Some(ref default) => default,
+            None => return None,
+        };
+
+        let name = Rc::new(format!("default_{}{}", prefix, field.ident()));
+
+        let literal = match *default {
+            Boolean(value) => value.to_string(),
+            Signed { ref value, .. } | Unsigned { ref value, .. } => value.to_string(),
+            Float(ref value) => format!("{}f32", value),
+            Double(ref value) => format!("{}f64", value),
+            String(ref value) => format!("{:?}.to_string()", value),
+        };
+
+        let mut f = Tokens::new();
+        push!(f, "fn ", name.clone(), "() -> Option<", field.ty.clone(), "> {");
+        nested!(f, "Some(", Rc::new(literal), ")");
+        push!(f, "}");
+
+        let path = Rc::new(format!("{}::{}", owner, name));
+        Some((SerdeDefault(path).into_tokens(), f))
+    }
+
     pub fn compile(&self) -> Result<()> {
         let mut files = self.populate_files()?;
 
@@ -280,6 +342,7 @@ impl<'el> PackageProcessor<'el, RustFlavor, RpName> for Compiler<'el> {
         let mut t = Tokens::new();
 
         t.push_unless_empty(Comments(&body.comment));
+        t.push_unless_empty(Deprecated(&body.deprecated));
         t.push_unless_empty(attributes);
         t.push(Derives);
         t.push(toks!["pub struct ", name, "(", fields.join(", "), ");",]);
@@ -307,6 +370,7 @@ impl<'el> PackageProcessor<'el, RustFlavor, RpName> for Compiler<'el> {
 
         for v in body.variants.iter() {
             vars.push_unless_empty(Comments(&v.comment));
+            vars.push_unless_empty(Deprecated(v.deprecated));
 
             match v.value {
                 core::RpVariantValue::String(string) => {
@@ -328,6 +392,7 @@ impl<'el> PackageProcessor<'el, RustFlavor, RpName> for Compiler<'el> {
             let mut t = Tokens::new();
 
             t.push_unless_empty(Comments(&body.comment));
+            t.push_unless_empty(Deprecated(&body.deprecated));
             t.push_unless_empty(attributes);
             t.push(toks!["pub enum ", name.clone(), " {"]);
             t.nested(vars);
@@ -589,10 +654,13 @@ impl<'el> PackageProcessor<'el, RustFlavor, RpName> for Compiler<'el> {
         let mut t = Tokens::new();
 
         t.push_unless_empty(Comments(&body.comment));
+        t.push_unless_empty(Deprecated(&body.deprecated));
         t.push_unless_empty(attributes);
         t.push(Derives);
         t.push(toks!["pub struct ", name.clone(), " {"]);
 
+        let mut defaults = Tokens::new();
+
         // fields
         t.nested({
             let mut t = Tokens::new();
@@ -601,6 +669,13 @@ impl<'el> PackageProcessor<'el, RustFlavor, RpName> for Compiler<'el> {
                 t.push({
                     let mut t = Tokens::new();
                     t.push_unless_empty(Comments(&field.comment));
+                    t.push_unless_empty(Deprecated(&field.deprecated));
+
+                    if let Some((attribute, f)) = self.field_default(&name, "", field) {
+                        t.push(attribute);
+                        defaults.push(f);
+                    }
+
                     t.push(self.field_element(field, true)?);
                     t
                 });
@@ -613,8 +688,9 @@ impl<'el> PackageProcessor<'el, RustFlavor, RpName> for Compiler<'el> {
 
         out.0.push(t);
 
-        // if custom code is present, punt it into an impl.
-        let impl_body = code!(&body.codes, core::RpContext::Rust).into_tokens();
+        // if custom code or defaults are present, punt them into an impl.
+        let mut impl_body = code!(&body.codes, core::RpContext::Rust).into_tokens();
+        impl_body.push_unless_empty(defaults.join_line_spacing());
 
         if !impl_body.is_empty() {
             out.0.push(self.build_impl(name.clone(), impl_body));
@@ -629,6 +705,7 @@ impl<'el> PackageProcessor<'el, RustFlavor, RpName> for Compiler<'el> {
         let mut t = Tokens::new();
 
         t.push_unless_empty(Comments(&body.comment));
+        t.push_unless_empty(Deprecated(&body.deprecated));
         t.push_unless_empty(attributes);
         t.push(Derives);
 
@@ -643,11 +720,14 @@ impl<'el> PackageProcessor<'el, RustFlavor, RpName> for Compiler<'el> {
 
         t.push(toks!["pub enum ", name.clone(), " {"]);
 
+        let mut defaults = Tokens::new();
+
         body.sub_types.iter().for_each_loc(|s| {
             t.nested({
                 let mut t = Tokens::new();
 
                 t.push_unless_empty(Comments(&s.comment));
+                t.push_unless_empty(Deprecated(&s.deprecated));
 
                 // TODO: clone should not be needed
                 if let Some(ref name) = s.sub_type_name {
@@ -661,10 +741,20 @@ impl<'el> PackageProcessor<'el, RustFlavor, RpName> for Compiler<'el> {
                 t.push({
                     let mut t = Tokens::new();
 
+                    let prefix = format!("{}_", s.ident.to_lowercase());
+
                     for field in body.fields.iter().chain(s.fields.iter()) {
                         t.nested({
                             let mut t = Tokens::new();
                             t.push_unless_empty(Comments(&field.comment));
+                            t.push_unless_empty(Deprecated(&field.deprecated));
+
+                            if let Some((attribute, f)) = self.field_default(&name, &prefix, field)
+                            {
+                                t.push(attribute);
+                                defaults.push(f);
+                            }
+
                             t.push(self.field_element(field, false)?);
                             t
                         });
@@ -685,7 +775,8 @@ impl<'el> PackageProcessor<'el, RustFlavor, RpName> for Compiler<'el> {
 
         out.0.push(t);
 
-        let impl_body = code!(&body.codes, core::RpContext::Rust).into_tokens();
+        let mut impl_body = code!(&body.codes, core::RpContext::Rust).into_tokens();
+        impl_body.push_unless_empty(defaults.join_line_spacing());
 
         if !impl_body.is_empty() {
             out.0.push(self.build_impl(name.clone(), impl_body));
@@ -11,7 +11,7 @@ use std::collections::HashMap;
 use std::ops::Deref;
 use std::rc::Rc;
 use trans::Packages;
-use {SCOPE_SEP, TYPE_SEP};
+use {Options, SCOPE_SEP, TYPE_SEP};
 
 #[derive(Debug, Clone)]
 pub struct RustEndpoint {
@@ -45,15 +45,21 @@ pub struct RustFlavorTranslator {
     map: Rust<'static>,
     json_value: Rust<'static>,
     datetime: Option<Rust<'static>>,
+    date: Option<Rust<'static>>,
+    time: Option<Rust<'static>>,
+    uuid: Option<Rust<'static>>,
 }
 
 impl RustFlavorTranslator {
-    pub fn new(packages: Rc<Packages>, datetime: Option<Rust<'static>>) -> Self {
+    pub fn new(packages: Rc<Packages>, options: &Options) -> Self {
         Self {
             packages,
             map: rust::imported("std::collections", "HashMap"),
             json_value: rust::imported("serde_json", "Value").alias("json"),
-            datetime: datetime,
+            datetime: options.datetime.clone(),
+            date: options.date.clone(),
+            time: options.time.clone(),
+            uuid: options.uuid.clone(),
         }
     }
 }
@@ -104,6 +110,34 @@ impl FlavorTranslator for RustFlavorTranslator {
         Err("Missing implementation for `datetime`, try: -m chrono".into())
     }
 
+    fn translate_date(&self) -> Result<Rust<'static>> {
+        if let Some(ref date) = self.date {
+            return Ok(date.clone());
+        }
+
+        Err("Missing implementation for `date`, try: -m chrono".into())
+    }
+
+    fn translate_time(&self) -> Result<Rust<'static>> {
+        if let Some(ref time) = self.time {
+            return Ok(time.clone());
+        }
+
+        Err("Missing implementation for `time`, try: -m chrono".into())
+    }
+
+    fn translate_duration(&self) -> Result<Rust<'static>> {
+        Ok(rust::local("String"))
+    }
+
+    fn translate_uuid(&self) -> Result<Rust<'static>> {
+        if let Some(ref uuid) = self.uuid {
+            return Ok(uuid.clone());
+        }
+
+        Err("Missing implementation for `uuid`, try: -m uuid".into())
+    }
+
     fn translate_array(&self, argument: Rust<'static>) -> Result<Rust<'static>> {
         Ok(rust::local("Vec").with_arguments(vec![argument]))
     }
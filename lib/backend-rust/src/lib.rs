@@ -113,6 +113,7 @@ pub enum RustModule {
     Chrono,
     Grpc,
     Reqwest,
+    Uuid,
 }
 
 impl TryFromToml for RustModule {
@@ -123,6 +124,7 @@ impl TryFromToml for RustModule {
             "chrono" => Chrono,
             "grpc" => Grpc,
             "reqwest" => Reqwest,
+            "uuid" => Uuid,
             _ => return NoModule::illegal(path, id, value),
         };
 
@@ -136,6 +138,7 @@ impl TryFromToml for RustModule {
             "chrono" => Chrono,
             "grpc" => Grpc,
             "reqwest" => Reqwest,
+            "uuid" => Uuid,
             _ => return NoModule::illegal(path, id, value),
         };
 
@@ -145,6 +148,9 @@ impl TryFromToml for RustModule {
 
 pub struct Options {
     pub datetime: Option<Rust<'static>>,
+    pub date: Option<Rust<'static>>,
+    pub time: Option<Rust<'static>>,
+    pub uuid: Option<Rust<'static>>,
     pub root: Vec<Box<RootCodegen>>,
     pub service: Vec<Box<ServiceCodegen>>,
     pub packages: Rc<Packages>,
@@ -176,6 +182,9 @@ fn options(modules: Vec<RustModule>, packages: Rc<Packages>) -> Result<Options>
 
     let mut options = Options {
         datetime: None,
+        date: None,
+        time: None,
+        uuid: None,
         root: Vec::new(),
         service: Vec::new(),
         packages: packages,
@@ -188,6 +197,7 @@ fn options(modules: Vec<RustModule>, packages: Rc<Packages>) -> Result<Options>
             Chrono => Box::new(module::Chrono::new()),
             Grpc => Box::new(module::Grpc::new()),
             Reqwest => Box::new(module::Reqwest::new()),
+            Uuid => Box::new(module::Uuid::new()),
         };
 
         initializer.initialize(&mut options)?;
@@ -203,7 +213,7 @@ fn compile(ctx: Rc<Context>, env: Environment<CoreFlavor>, manifest: Manifest) -
 
     let translator = env.translator(flavored::RustFlavorTranslator::new(
         packages.clone(),
-        options.datetime.clone(),
+        &options,
     ))?;
     let env = env.translate(translator)?;
 
@@ -21,6 +21,9 @@ impl Initializer for Module {
             imported("chrono", "DateTime").with_arguments(vec![imported("chrono::offset", "Utc")]),
         );
 
+        options.date = Some(imported("chrono", "NaiveDate"));
+        options.time = Some(imported("chrono", "NaiveTime"));
+
         Ok(())
     }
 }
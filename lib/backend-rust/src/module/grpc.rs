@@ -1,7 +1,14 @@
 //! gRPC module for Rust.
 
 use backend::Initializer;
-use Options;
+use core::errors::Result;
+use core::Loc;
+use flavored::{RpPackage, RustEndpoint};
+use genco::rust::{imported, local};
+use genco::{Cons, Quoted, Rust, Tokens};
+use std::rc::Rc;
+use utils::Comments;
+use {Options, Root, RootCodegen, RustFileSpec, Service, ServiceCodegen, SCOPE_SEP};
 
 pub struct Module {}
 
@@ -13,4 +20,632 @@ impl Module {
 
 impl Initializer for Module {
     type Options = Options;
+
+    fn initialize(&self, options: &mut Options) -> Result<()> {
+        let utils_package = options.packages.new("reproto")?;
+
+        let imported_utils_package = Rc::new(utils_package.join(SCOPE_SEP));
+        let json_marshaller = imported(imported_utils_package.clone(), "JsonMarshaller");
+        let void_marshaller = imported(imported_utils_package.clone(), "VoidMarshaller");
+
+        options
+            .service
+            .push(Box::new(GrpcService::new(json_marshaller, void_marshaller)));
+
+        options.root.push(Box::new(GrpcUtils::new(utils_package)));
+
+        Ok(())
+    }
+}
+
+/// The kind of gRPC method an endpoint corresponds to.
+#[derive(Clone, Copy)]
+enum MethodType {
+    Unary,
+    ClientStreaming,
+    ServerStreaming,
+    BidiStreaming,
+}
+
+impl MethodType {
+    /// Get the method type for the given endpoint.
+    ///
+    /// Endpoints without a request or a response are treated as unary in that direction.
+    fn from_endpoint(e: &RustEndpoint) -> MethodType {
+        let request = e.request
+            .as_ref()
+            .map(|r| r.channel.is_streaming())
+            .unwrap_or(false);
+
+        let response = e.response
+            .as_ref()
+            .map(|r| r.is_streaming())
+            .unwrap_or(false);
+
+        match (request, response) {
+            (false, false) => MethodType::Unary,
+            (true, false) => MethodType::ClientStreaming,
+            (false, true) => MethodType::ServerStreaming,
+            (true, true) => MethodType::BidiStreaming,
+        }
+    }
+
+    /// Variant of `grpc::rt::GrpcStreaming`.
+    fn streaming(&self) -> &'static str {
+        use self::MethodType::*;
+
+        match *self {
+            Unary => "Unary",
+            ClientStreaming => "ClientStreaming",
+            ServerStreaming => "ServerStreaming",
+            BidiStreaming => "Bidi",
+        }
+    }
+
+    /// Method on `grpc::Client` used to perform the call.
+    fn client_call(&self) -> &'static str {
+        use self::MethodType::*;
+
+        match *self {
+            Unary => "call_unary",
+            ClientStreaming => "call_client_streaming",
+            ServerStreaming => "call_server_streaming",
+            BidiStreaming => "call_bidi",
+        }
+    }
+
+    /// Method handler in `grpc::rt` used to dispatch the call.
+    fn handler(&self) -> &'static str {
+        use self::MethodType::*;
+
+        match *self {
+            Unary => "MethodHandlerUnary",
+            ClientStreaming => "MethodHandlerClientStreaming",
+            ServerStreaming => "MethodHandlerServerStreaming",
+            BidiStreaming => "MethodHandlerBidi",
+        }
+    }
+}
+
+struct GrpcUtils {
+    utils_package: RpPackage,
+}
+
+impl GrpcUtils {
+    pub fn new(utils_package: RpPackage) -> Self {
+        Self { utils_package }
+    }
+
+    /// Append the marshallers used by gRPC services to the given file.
+    fn reproto<'el>(&self, f: &mut RustFileSpec<'el>) -> Result<()> {
+        let marshaller = imported("grpc::marshall", "Marshaller");
+        let error = imported("grpc", "Error");
+        let result = imported("grpc", "Result");
+
+        f.0.push({
+            let mut t = Tokens::new();
+
+            push!(t, "/// Marshaller for JSON-encoded gRPC messages.");
+            push!(t, "pub struct JsonMarshaller;");
+
+            t
+        });
+
+        f.0.push({
+            let mut t = Tokens::new();
+
+            let serialize = imported("serde", "Serialize");
+            let deserialize = imported("serde::de", "DeserializeOwned");
+            let to_vec = imported("serde_json", "to_vec");
+            let from_slice = imported("serde_json", "from_slice");
+
+            push!(t, "impl<T> ", marshaller, "<T> for JsonMarshaller");
+            push!(t, "where");
+            nested!(t, "T: ", serialize, " + ", deserialize, " + Send + Sync + 'static,");
+            push!(t, "{");
+
+            t.nested({
+                let mut t = Tokens::new();
+
+                t.push_into(|t| {
+                    push!(t, "fn write(&self, m: &T) -> ", result, "<Vec<u8>> {");
+                    nested!(
+                        t,
+                        to_vec,
+                        "(m).map_err(|e| ",
+                        error,
+                        "::Panic(e.to_string()))"
+                    );
+                    push!(t, "}");
+                });
+
+                t.push_into(|t| {
+                    push!(t, "fn read(&self, bytes: &[u8]) -> ", result, "<T> {");
+                    nested!(
+                        t,
+                        from_slice,
+                        "(bytes).map_err(|e| ",
+                        error,
+                        "::Panic(e.to_string()))"
+                    );
+                    push!(t, "}");
+                });
+
+                t.join_line_spacing()
+            });
+
+            push!(t, "}");
+
+            t
+        });
+
+        f.0.push({
+            let mut t = Tokens::new();
+
+            push!(t, "/// Marshaller for empty gRPC messages.");
+            push!(t, "pub struct VoidMarshaller;");
+
+            t
+        });
+
+        f.0.push({
+            let mut t = Tokens::new();
+
+            push!(t, "impl ", marshaller, "<()> for VoidMarshaller {");
+
+            t.nested({
+                let mut t = Tokens::new();
+
+                t.push_into(|t| {
+                    push!(t, "fn write(&self, _: &()) -> ", result, "<Vec<u8>> {");
+                    nested!(t, "Ok(Vec::new())");
+                    push!(t, "}");
+                });
+
+                t.push_into(|t| {
+                    push!(t, "fn read(&self, _: &[u8]) -> ", result, "<()> {");
+                    nested!(t, "Ok(())");
+                    push!(t, "}");
+                });
+
+                t.join_line_spacing()
+            });
+
+            push!(t, "}");
+
+            t
+        });
+
+        Ok(())
+    }
+}
+
+impl RootCodegen for GrpcUtils {
+    fn generate(&self, root: Root) -> Result<()> {
+        let Root { files, .. } = root;
+
+        let f = files
+            .entry(self.utils_package.clone())
+            .or_insert_with(RustFileSpec::default);
+
+        self.reproto(f)
+    }
+}
+
+struct GrpcService {
+    json_marshaller: Rust<'static>,
+    void_marshaller: Rust<'static>,
+    arc: Rust<'static>,
+    request_options: Rust<'static>,
+    single_response: Rust<'static>,
+    streaming_request: Rust<'static>,
+    streaming_response: Rust<'static>,
+}
+
+impl GrpcService {
+    pub fn new(json_marshaller: Rust<'static>, void_marshaller: Rust<'static>) -> Self {
+        Self {
+            json_marshaller,
+            void_marshaller,
+            arc: imported("std::sync", "Arc").qualified(),
+            request_options: imported("grpc", "RequestOptions"),
+            single_response: imported("grpc", "SingleResponse"),
+            streaming_request: imported("grpc", "StreamingRequest"),
+            streaming_response: imported("grpc", "StreamingResponse"),
+        }
+    }
+
+    /// Request type of the endpoint, `()` if the endpoint takes no request.
+    fn request_ty<'el>(&self, e: &'el RustEndpoint) -> Rust<'el> {
+        e.request
+            .as_ref()
+            .map(|r| r.channel.ty().clone())
+            .unwrap_or_else(|| local("()"))
+    }
+
+    /// Response type of the endpoint, `()` if the endpoint has no response.
+    fn response_ty<'el>(&self, e: &'el RustEndpoint) -> Rust<'el> {
+        e.response
+            .as_ref()
+            .map(|r| r.ty().clone())
+            .unwrap_or_else(|| local("()"))
+    }
+
+    /// Marshaller to use for the given type.
+    fn marshaller<'el>(&self, ty: &Rust<'el>) -> Rust<'el> {
+        if *ty == local("()") {
+            self.void_marshaller.clone()
+        } else {
+            self.json_marshaller.clone()
+        }
+    }
+
+    /// The argument and return value of the method implementing the given endpoint.
+    fn signature<'el>(&self, e: &'el RustEndpoint) -> (Tokens<'el, Rust<'el>>, Rust<'el>) {
+        use self::MethodType::*;
+
+        let request_ty = self.request_ty(e);
+        let response_ty = self.response_ty(e);
+
+        let (request_ty, response_ty) = match MethodType::from_endpoint(e) {
+            Unary => (
+                request_ty,
+                self.single_response.clone().with_arguments(vec![response_ty]),
+            ),
+            ClientStreaming => (
+                self.streaming_request.clone().with_arguments(vec![request_ty]),
+                self.single_response.clone().with_arguments(vec![response_ty]),
+            ),
+            ServerStreaming => (
+                request_ty,
+                self.streaming_response.clone().with_arguments(vec![response_ty]),
+            ),
+            BidiStreaming => (
+                self.streaming_request.clone().with_arguments(vec![request_ty]),
+                self.streaming_response.clone().with_arguments(vec![response_ty]),
+            ),
+        };
+
+        let mut args = Tokens::new();
+        args.append("&self");
+        args.append(toks!["o: ", self.request_options.clone()]);
+        args.append(toks![request_ident(e), ": ", request_ty]);
+
+        (args.join(", "), response_ty)
+    }
+
+    /// Build the method descriptor for the given endpoint.
+    ///
+    /// The descriptor is surrounded by `prefix` and `suffix`, so that it can be used both as an
+    /// expression and as a field initializer.
+    fn method_descriptor<'el>(
+        &self,
+        service_name: &Rc<String>,
+        e: &'el RustEndpoint,
+        prefix: Tokens<'el, Rust<'el>>,
+        suffix: &'el str,
+    ) -> Tokens<'el, Rust<'el>> {
+        let mut t = Tokens::new();
+
+        let descriptor = imported("grpc::rt", "MethodDescriptor");
+        let streaming = imported("grpc::rt", "GrpcStreaming");
+
+        let name = format!("/{}/{}", service_name, e.name());
+        let method_type = MethodType::from_endpoint(e);
+
+        let req_marshaller = self.marshaller(&self.request_ty(e));
+        let resp_marshaller = self.marshaller(&self.response_ty(e));
+
+        push!(t, prefix, self.arc, "::new(", descriptor, " {");
+        nested!(t, "name: ", name.quoted(), ".to_string(),");
+        nested!(t, "streaming: ", streaming, "::", method_type.streaming(), ",");
+        nested!(t, "req_marshaller: Box::new(", req_marshaller, "),");
+        nested!(t, "resp_marshaller: Box::new(", resp_marshaller, "),");
+        push!(t, "})", suffix);
+
+        t
+    }
+
+    /// Build the trait which is implemented by both clients and servers.
+    fn service_trait<'el>(
+        &self,
+        name: &Cons<'el>,
+        endpoints: &'el [Loc<RustEndpoint>],
+        attributes: &Tokens<'el, Rust<'el>>,
+    ) -> Tokens<'el, Rust<'el>> {
+        let mut t = Tokens::new();
+
+        t.push_unless_empty(attributes.clone());
+        push!(t, "pub trait ", *name, " {");
+
+        t.nested({
+            let mut t = Tokens::new();
+
+            for e in endpoints {
+                t.push({
+                    let mut t = Tokens::new();
+
+                    let (args, ret) = self.signature(e);
+
+                    t.push_unless_empty(Comments(&e.comment));
+                    push!(t, "fn ", e.safe_ident(), "(", args, ") -> ", ret, ";");
+
+                    t
+                });
+            }
+
+            t.join_line_spacing()
+        });
+
+        push!(t, "}");
+
+        t
+    }
+
+    /// Build the client struct, and its implementation of the service trait.
+    fn client<'el>(
+        &self,
+        service_name: &Rc<String>,
+        name: &Cons<'el>,
+        trait_name: &Cons<'el>,
+        endpoints: &'el [Loc<RustEndpoint>],
+        attributes: &Tokens<'el, Rust<'el>>,
+    ) -> Tokens<'el, Rust<'el>> {
+        let client = imported("grpc", "Client");
+        let client_stub = imported("grpc", "ClientStub");
+        let descriptor = imported("grpc::rt", "MethodDescriptor");
+
+        let mut out = Tokens::new();
+
+        out.push({
+            let mut t = Tokens::new();
+
+            t.push_unless_empty(attributes.clone());
+            push!(t, "pub struct ", *name, " {");
+            nested!(
+                t,
+                "grpc_client: ",
+                self.arc.clone().with_arguments(vec![client.clone()]),
+                ","
+            );
+
+            for e in endpoints {
+                let descriptor = descriptor.clone().with_arguments(vec![
+                    self.request_ty(e),
+                    self.response_ty(e),
+                ]);
+
+                nested!(
+                    t,
+                    method_field(e),
+                    ": ",
+                    self.arc.clone().with_arguments(vec![descriptor]),
+                    ","
+                );
+            }
+
+            push!(t, "}");
+
+            t
+        });
+
+        out.push({
+            let mut t = Tokens::new();
+
+            push!(t, "impl ", client_stub, " for ", *name, " {");
+
+            t.nested_into(|t| {
+                push!(
+                    t,
+                    "fn with_client(grpc_client: ",
+                    self.arc.clone().with_arguments(vec![client.clone()]),
+                    ") -> Self {"
+                );
+
+                t.nested_into(|t| {
+                    push!(t, "Self {");
+
+                    t.nested_into(|t| {
+                        push!(t, "grpc_client,");
+
+                        for e in endpoints {
+                            let prefix = toks![method_field(e), ": "];
+                            t.push(self.method_descriptor(service_name, e, prefix, ","));
+                        }
+                    });
+
+                    push!(t, "}");
+                });
+
+                push!(t, "}");
+            });
+
+            push!(t, "}");
+
+            t
+        });
+
+        out.push({
+            let mut t = Tokens::new();
+
+            push!(t, "impl ", *trait_name, " for ", *name, " {");
+
+            t.nested({
+                let mut t = Tokens::new();
+
+                for e in endpoints {
+                    t.push({
+                        let mut t = Tokens::new();
+
+                        let method_type = MethodType::from_endpoint(e);
+                        let (args, ret) = self.signature(e);
+
+                        push!(t, "fn ", e.safe_ident(), "(", args, ") -> ", ret, " {");
+                        nested!(
+                            t,
+                            "self.grpc_client.",
+                            method_type.client_call(),
+                            "(o, ",
+                            request_ident(e),
+                            ", self.",
+                            method_field(e),
+                            ".clone())"
+                        );
+                        push!(t, "}");
+
+                        t
+                    });
+                }
+
+                t.join_line_spacing()
+            });
+
+            push!(t, "}");
+
+            t
+        });
+
+        out.join_line_spacing()
+    }
+
+    /// Build the server struct, which binds an implementation of the service trait.
+    fn server<'el>(
+        &self,
+        service_name: &Rc<String>,
+        name: &Cons<'el>,
+        trait_name: &Cons<'el>,
+        endpoints: &'el [Loc<RustEndpoint>],
+        attributes: &Tokens<'el, Rust<'el>>,
+    ) -> Tokens<'el, Rust<'el>> {
+        let definition = imported("grpc::rt", "ServerServiceDefinition");
+        let server_method = imported("grpc::rt", "ServerMethod");
+
+        let mut out = Tokens::new();
+
+        out.push({
+            let mut t = Tokens::new();
+
+            t.push_unless_empty(attributes.clone());
+            push!(t, "pub struct ", *name, ";");
+
+            t
+        });
+
+        out.push({
+            let mut t = Tokens::new();
+
+            push!(t, "impl ", *name, " {");
+
+            t.nested_into(|t| {
+                push!(
+                    t,
+                    "pub fn new_service_def<H: ",
+                    *trait_name,
+                    " + Send + Sync + 'static>(handler: H) -> ",
+                    definition,
+                    " {"
+                );
+
+                t.nested({
+                    let mut t = Tokens::new();
+
+                    push!(t, "let handler = ", self.arc, "::new(handler);");
+
+                    t.push_into(|t| {
+                        let path = format!("/{}", service_name);
+
+                        push!(t, definition, "::new(", path.quoted(), ", vec![");
+
+                        for e in endpoints {
+                            t.nested_into(|t| {
+                                let method_type = MethodType::from_endpoint(e);
+                                let handler = imported("grpc::rt", method_type.handler());
+
+                                push!(t, server_method, "::new(");
+                                t.nested(self.method_descriptor(service_name, e, Tokens::new(), ","));
+
+                                t.nested_into(|t| {
+                                    push!(t, "{");
+                                    nested!(t, "let handler = handler.clone();");
+                                    nested!(
+                                        t,
+                                        handler,
+                                        "::new(move |o, p| handler.",
+                                        e.safe_ident(),
+                                        "(o, p))"
+                                    );
+                                    push!(t, "},");
+                                });
+
+                                push!(t, "),");
+                            });
+                        }
+
+                        push!(t, "])");
+                    });
+
+                    t.join_line_spacing()
+                });
+
+                push!(t, "}");
+            });
+
+            push!(t, "}");
+
+            t
+        });
+
+        out.join_line_spacing()
+    }
+}
+
+impl ServiceCodegen for GrpcService {
+    fn generate(&self, service: Service) -> Result<()> {
+        let Service {
+            body,
+            container,
+            name,
+            attributes,
+            ..
+        } = service;
+
+        let service_name = Rc::new(format!("{}.{}", body.name.package.join("."), body.name));
+
+        let trait_name = Cons::from(format!("{}_Grpc", name));
+        let client_name = Cons::from(format!("{}_GrpcClient", name));
+        let server_name = Cons::from(format!("{}_GrpcServer", name));
+
+        container.push(self.service_trait(&trait_name, &body.endpoints, attributes));
+
+        container.push(self.client(
+            &service_name,
+            &client_name,
+            &trait_name,
+            &body.endpoints,
+            attributes,
+        ));
+
+        container.push(self.server(
+            &service_name,
+            &server_name,
+            &trait_name,
+            &body.endpoints,
+            attributes,
+        ));
+
+        Ok(())
+    }
+}
+
+/// Name of the field holding the method descriptor of an endpoint.
+fn method_field(e: &RustEndpoint) -> Cons<'static> {
+    Cons::from(format!("method_{}", e.ident()))
+}
+
+/// Identifier of the request argument of an endpoint.
+fn request_ident<'el>(e: &'el RustEndpoint) -> &'el str {
+    e.request
+        .as_ref()
+        .map(|r| r.safe_ident())
+        .unwrap_or("request")
 }
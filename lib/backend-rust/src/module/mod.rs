@@ -1,7 +1,9 @@
 mod chrono;
 mod grpc;
 mod reqwest;
+mod uuid;
 
 pub use self::chrono::Module as Chrono;
 pub use self::grpc::Module as Grpc;
 pub use self::reqwest::Module as Reqwest;
+pub use self::uuid::Module as Uuid;
@@ -49,9 +49,8 @@ impl ReqwestUtils {
         Self { utils_package }
     }
 
-    fn reproto<'el>(&self) -> Result<RustFileSpec<'el>> {
-        let mut f = RustFileSpec::default();
-
+    /// Append the error types and utilities used by reqwest services to the given file.
+    fn reproto<'el>(&self, f: &mut RustFileSpec<'el>) -> Result<()> {
         let mut errors = Vec::new();
         errors.push((imported("reqwest", "Error"), "ReqwestError"));
         errors.push((imported("reqwest", "UrlError"), "UrlError"));
@@ -177,15 +176,19 @@ impl ReqwestUtils {
             t.join_line_spacing()
         });
 
-        Ok(f)
+        Ok(())
     }
 }
 
 impl RootCodegen for ReqwestUtils {
     fn generate(&self, root: Root) -> Result<()> {
         let Root { files, .. } = root;
-        files.insert(self.utils_package.clone(), self.reproto()?);
-        Ok(())
+
+        let f = files
+            .entry(self.utils_package.clone())
+            .or_insert_with(RustFileSpec::default);
+
+        self.reproto(f)
     }
 }
 
@@ -2,14 +2,15 @@
 
 use backend::PackageProcessor;
 use core::errors::*;
-use core::{Handle, Loc};
-use flavored::{RpEnumBody, RpField, RpInterfaceBody, RpTupleBody, RpTypeBody, SwiftFlavor,
-               SwiftName};
+use core::{Handle, Loc, RpDeprecated};
+use flavored::{RpEnumBody, RpField, RpInterfaceBody, RpServiceBody, RpTupleBody, RpTypeBody,
+               SwiftFlavor, SwiftName};
 use genco::swift::Swift;
-use genco::{IntoTokens, Tokens};
+use genco::{IntoTokens, Quoted, Tokens};
+use std::rc::Rc;
 use trans::{self, Packages, Translated};
-use {EnumAdded, FileSpec, InterfaceAdded, InterfaceModelAdded, Options, PackageAdded,
-     StructModelAdded, TupleAdded, TypeAdded, EXT};
+use {DataCodec, EnumAdded, FileSpec, InterfaceAdded, InterfaceModelAdded, Options, PackageAdded,
+     ServiceAdded, StructModelAdded, TupleAdded, TypeAdded, EXT};
 
 /// Documentation comments.
 pub struct Comments<'el, S: 'el>(pub &'el [S]);
@@ -26,6 +27,25 @@ impl<'el, S: 'el + AsRef<str>> IntoTokens<'el, Swift<'el>> for Comments<'el, S>
     }
 }
 
+/// Deprecation attribute, empty unless the item is deprecated.
+pub struct Deprecated<'el>(pub &'el Option<RpDeprecated>);
+
+impl<'el> IntoTokens<'el, Swift<'el>> for Deprecated<'el> {
+    fn into_tokens(self) -> Tokens<'el, Swift<'el>> {
+        match *self.0 {
+            Some(RpDeprecated {
+                message: Some(ref message),
+            }) => toks![
+                "@available(*, deprecated, message: ",
+                message.as_str().quoted(),
+                ")",
+            ],
+            Some(_) => toks!["@available(*, deprecated)"],
+            None => toks![],
+        }
+    }
+}
+
 pub struct Compiler<'el> {
     pub env: &'el Translated<SwiftFlavor>,
     options: Options,
@@ -47,6 +67,26 @@ impl<'el> Compiler<'el> {
         Ok(c)
     }
 
+    /// Access the codec used to convert models to and from `Data`.
+    ///
+    /// Returns `None` if no module provides one.
+    pub fn data_codec(&self) -> Result<Option<Rc<DataCodec>>> {
+        let mut codecs = self.options.data_codec.iter();
+
+        if let Some(&(first_mod, ref codec)) = codecs.next() {
+            if let Some(&(second_mod, _)) = codecs.next() {
+                return Err(format!(
+                    "Data codec provided by more than one module: {}, {}",
+                    first_mod, second_mod
+                ).into());
+            }
+
+            return Ok(Some(codec.clone()));
+        }
+
+        Ok(None)
+    }
+
     pub fn into_field<'a>(&self, field: &'a RpField) -> Result<Tokens<'a, Swift<'a>>> {
         if field.is_optional() {
             return Ok(toks![field.ty().ty(), "?"]);
@@ -60,6 +100,7 @@ impl<'el> Compiler<'el> {
         &self,
         name: &SwiftName,
         comment: &'a [String],
+        deprecated: &'a Option<RpDeprecated>,
         fields: F,
         extends: bool,
     ) -> Result<Tokens<'a, Swift<'a>>>
@@ -71,6 +112,7 @@ impl<'el> Compiler<'el> {
         let mut t = Tokens::new();
 
         t.push_unless_empty(Comments(comment));
+        t.push_unless_empty(Deprecated(deprecated));
 
         if self.options.struct_model_extends.is_empty() || !extends {
             t.push(toks!["public struct ", name.name.clone(), " {"]);
@@ -94,6 +136,7 @@ impl<'el> Compiler<'el> {
 
                 for field in fields.iter() {
                     t.push_unless_empty(Comments(&field.comment));
+                    t.push_unless_empty(Deprecated(&field.deprecated));
                     let ty = self.into_field(field)?;
                     t.push(toks!["let ", field.safe_ident(), ": ", ty]);
                 }
@@ -120,6 +163,7 @@ impl<'el> Compiler<'el> {
         &self,
         name: &'a SwiftName,
         comment: &'a [String],
+        deprecated: &'a Option<RpDeprecated>,
         fields: F,
     ) -> Result<Tokens<'a, Swift<'a>>>
     where
@@ -129,7 +173,7 @@ impl<'el> Compiler<'el> {
 
         let mut tokens = Tokens::new();
 
-        tokens.push(self.model_struct(name, comment, fields.iter().cloned(), true)?);
+        tokens.push(self.model_struct(name, comment, deprecated, fields.iter().cloned(), true)?);
 
         for g in &self.options.type_gens {
             g.generate(TypeAdded {
@@ -183,6 +227,7 @@ impl<'el> PackageProcessor<'el, SwiftFlavor, SwiftName> for Compiler<'el> {
         out.0.extend(self.model_type(
             &body.name,
             &body.comment,
+            &body.deprecated,
             body.fields.iter().map(Loc::value),
         )?);
 
@@ -198,6 +243,7 @@ impl<'el> PackageProcessor<'el, SwiftFlavor, SwiftName> for Compiler<'el> {
             tokens.push(self.model_struct(
                 &body.name,
                 &body.comment,
+                &body.deprecated,
                 fields.iter().cloned(),
                 false,
             )?);
@@ -221,10 +267,14 @@ impl<'el> PackageProcessor<'el, SwiftFlavor, SwiftName> for Compiler<'el> {
         out.0.push({
             let mut t = Tokens::new();
 
+            t.push_unless_empty(Deprecated(&body.deprecated));
             t.push(toks!["public enum ", body.name.name.clone(), " {"]);
 
             for v in &body.variants {
-                nested!(t, "case ", v.ident());
+                t.nested_into(|t| {
+                    t.push_unless_empty(Deprecated(v.deprecated));
+                    push!(t, "case ", v.ident());
+                });
             }
 
             t.push("}");
@@ -248,6 +298,7 @@ impl<'el> PackageProcessor<'el, SwiftFlavor, SwiftName> for Compiler<'el> {
             let mut t = Tokens::new();
 
             t.push_unless_empty(Comments(&body.comment));
+            t.push_unless_empty(Deprecated(&body.deprecated));
             t.push(toks!["public enum ", body.name.name.clone(), " {"]);
 
             t.push({
@@ -295,10 +346,27 @@ impl<'el> PackageProcessor<'el, SwiftFlavor, SwiftName> for Compiler<'el> {
                 .chain(sub_type.fields.iter())
                 .map(Loc::value);
 
-            out.0
-                .push(self.model_type(&sub_type.name, &sub_type.comment, fields)?);
+            out.0.push(self.model_type(
+                &sub_type.name,
+                &sub_type.comment,
+                &sub_type.deprecated,
+                fields,
+            )?);
         }
 
         return Ok(());
     }
+
+    fn process_service(&self, out: &mut Self::Out, body: &'el RpServiceBody) -> Result<()> {
+        for g in &self.options.service_gens {
+            g.generate(ServiceAdded {
+                container: &mut out.0,
+                compiler: self,
+                name: &body.name,
+                body: body,
+            })?;
+        }
+
+        Ok(())
+    }
 }
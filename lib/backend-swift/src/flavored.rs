@@ -47,6 +47,8 @@ impl<'el> SwiftType<'el> {
 pub struct SwiftName {
     pub name: Rc<String>,
     pub package: RpPackage,
+    /// The package as it was declared in the specification, before any renaming.
+    pub spec_package: RpPackage,
 }
 
 impl fmt::Display for SwiftName {
@@ -84,6 +86,7 @@ pub struct SwiftFlavorTranslator {
     packages: Rc<Packages>,
     data: Swift<'static>,
     date: Swift<'static>,
+    uuid: Swift<'static>,
     any: Swift<'static>,
     to_upper_camel: naming::ToUpperCamel,
 }
@@ -111,6 +114,7 @@ impl SwiftFlavorTranslator {
             packages,
             data: swift::imported("Foundation", "Data"),
             date: swift::imported("Foundation", "Date"),
+            uuid: swift::imported("Foundation", "UUID"),
             any,
             to_upper_camel: naming::to_upper_camel(),
         })
@@ -162,6 +166,28 @@ impl FlavorTranslator for SwiftFlavorTranslator {
         })
     }
 
+    fn translate_date(&self) -> Result<SwiftType<'static>> {
+        Ok(SwiftType {
+            simple: Simple::Date,
+            ty: self.date.clone(),
+        })
+    }
+
+    fn translate_time(&self) -> Result<SwiftType<'static>> {
+        Ok(SwiftType::from_type(swift::local("String")))
+    }
+
+    fn translate_duration(&self) -> Result<SwiftType<'static>> {
+        Ok(SwiftType::from_type(swift::local("String")))
+    }
+
+    fn translate_uuid(&self) -> Result<SwiftType<'static>> {
+        Ok(SwiftType {
+            simple: Simple::Uuid,
+            ty: self.uuid.clone(),
+        })
+    }
+
     fn translate_array(&self, argument: SwiftType<'static>) -> Result<SwiftType<'static>> {
         Ok(SwiftType {
             simple: Simple::Array {
@@ -225,6 +251,7 @@ impl FlavorTranslator for SwiftFlavorTranslator {
     where
         T: Translator<Source = Self::Source, Target = Self::Target>,
     {
+        let spec_package = name.package.package.clone();
         let name = name.translate(translator)?;
 
         let package_name = name.package.join("_");
@@ -234,6 +261,7 @@ impl FlavorTranslator for SwiftFlavorTranslator {
         Ok(SwiftName {
             name: Rc::new(ident),
             package: name.package,
+            spec_package: spec_package,
         })
     }
 
@@ -23,7 +23,8 @@ use backend::{Initializer, IntoBytes};
 use compiler::Compiler;
 use core::errors::Result;
 use core::{Context, CoreFlavor};
-use flavored::{RpEnumBody, RpField, RpInterfaceBody, RpPackage, SwiftName};
+use flavored::{RpEnumBody, RpField, RpInterfaceBody, RpPackage, RpServiceBody, SwiftName,
+               SwiftType};
 use genco::swift::Swift;
 use genco::Tokens;
 use manifest::{Lang, Manifest, NoModule, TryFromToml};
@@ -177,8 +178,11 @@ pub struct Options {
     pub interface_gens: Vec<Box<InterfaceCodegen>>,
     pub interface_model_gens: Vec<Box<InterfaceModelCodegen>>,
     pub package_gens: Vec<Box<PackageCodegen>>,
+    pub service_gens: Vec<Box<ServiceCodegen>>,
     /// The provided Any type that should be used in structs.
     pub any_type: Vec<(&'static str, Swift<'static>)>,
+    /// The provided codec that should be used to convert models to and from `Data`.
+    pub data_codec: Vec<(&'static str, Rc<DataCodec>)>,
 }
 
 impl Options {
@@ -192,7 +196,9 @@ impl Options {
             interface_model_gens: Vec::new(),
             enum_gens: Vec::new(),
             package_gens: Vec::new(),
+            service_gens: Vec::new(),
             any_type: Vec::new(),
+            data_codec: Vec::new(),
         }
     }
 }
@@ -312,6 +318,35 @@ pub struct PackageAdded<'a, 'el: 'a> {
 
 codegen!(PackageCodegen, PackageAdded);
 
+/// Event emitted when a service has been added.
+pub struct ServiceAdded<'a, 'c: 'a, 'el: 'a> {
+    pub container: &'a mut Tokens<'el, Swift<'el>>,
+    pub compiler: &'a Compiler<'c>,
+    pub name: &'el SwiftName,
+    pub body: &'el RpServiceBody,
+}
+
+codegen!(ServiceCodegen, ServiceAdded);
+
+/// Converts models to and from JSON-encoded `Data`.
+///
+/// Provided by the module which is responsible for serialization.
+pub trait DataCodec {
+    /// Build an expression which encodes `var` of the given type into `Data`.
+    fn encode_data<'el>(
+        &self,
+        ty: &'el SwiftType<'static>,
+        var: Tokens<'el, Swift<'el>>,
+    ) -> Result<Tokens<'el, Swift<'el>>>;
+
+    /// Build an expression which decodes a value of the given type from the `Data` in `var`.
+    fn decode_data<'el>(
+        &self,
+        ty: &'el SwiftType<'static>,
+        var: Tokens<'el, Swift<'el>>,
+    ) -> Result<Tokens<'el, Swift<'el>>>;
+}
+
 fn compile(ctx: Rc<Context>, env: Environment<CoreFlavor>, manifest: Manifest) -> Result<()> {
     let modules = manifest::checked_modules(manifest.modules)?;
     let options = options(modules)?;
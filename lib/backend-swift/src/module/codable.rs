@@ -3,14 +3,15 @@
 use backend::Initializer;
 use core;
 use core::errors::{Error, Result};
-use flavored::{RpEnumBody, RpField, RpInterfaceBody, RpPackage, SwiftName};
-use genco::swift::{local, Swift};
+use flavored::{RpEnumBody, RpField, RpInterfaceBody, RpPackage, SwiftName, SwiftType};
+use genco::swift::{imported, local, Swift};
 use genco::{Quoted, Tokens};
+use module::simple::Simple;
 use std::collections::BTreeSet;
 use std::rc::Rc;
-use {EnumAdded, EnumCodegen, FileSpec, InterfaceAdded, InterfaceCodegen, InterfaceModelAdded,
-     InterfaceModelCodegen, Options, PackageAdded, PackageCodegen, StructModelAdded,
-     StructModelCodegen, TupleAdded, TupleCodegen};
+use {DataCodec, EnumAdded, EnumCodegen, FileSpec, InterfaceAdded, InterfaceCodegen,
+     InterfaceModelAdded, InterfaceModelCodegen, Options, PackageAdded, PackageCodegen,
+     StructModelAdded, StructModelCodegen, TupleAdded, TupleCodegen, TypeAdded, TypeCodegen};
 
 pub struct Module {}
 
@@ -26,6 +27,7 @@ impl Initializer for Module {
     fn initialize(&self, options: &mut Self::Options) -> Result<()> {
         let codegen = Rc::new(Codegen);
         options.struct_model_extends.append("Codable");
+        options.type_gens.push(Box::new(codegen.clone()));
         options.tuple_gens.push(Box::new(codegen.clone()));
         options.struct_model_gens.push(Box::new(codegen.clone()));
         options.enum_gens.push(Box::new(codegen.clone()));
@@ -33,6 +35,9 @@ impl Initializer for Module {
         options.interface_model_gens.push(Box::new(codegen.clone()));
         options.any_type.push(("codable", local("AnyCodable")));
         options.package_gens.push(Box::new(codegen.clone()));
+        options
+            .data_codec
+            .push(("codable", codegen.clone() as Rc<DataCodec>));
         Ok(())
     }
 }
@@ -652,6 +657,104 @@ impl Codegen {
     }
 }
 
+impl TypeCodegen for Codegen {
+    fn generate(&self, e: TypeAdded) -> Result<()> {
+        let TypeAdded {
+            container,
+            name,
+            fields,
+            ..
+        } = e;
+
+        let mut dates = false;
+
+        for field in fields {
+            dates = is_date(field)? || dates;
+        }
+
+        let defaults = fields.iter().any(|f| f.default.is_some());
+
+        // NB: the synthesized implementations are used unless a field needs custom handling.
+        if dates || defaults {
+            container.push(decodable(name, fields)?);
+        }
+
+        if dates {
+            container.push(encodable(name, fields)?);
+        }
+
+        return Ok(());
+
+        fn decodable<'a>(
+            name: &'a SwiftName,
+            fields: &[&'a RpField],
+        ) -> Result<Tokens<'a, Swift<'a>>> {
+            let mut t = Tokens::new();
+
+            t.push(toks!["public extension ", name, " {"]);
+
+            t.nested({
+                let mut t = Tokens::new();
+
+                t.push("init(from decoder: Decoder) throws {");
+
+                t.nested({
+                    let mut t = Tokens::new();
+
+                    t.push("let values = try decoder.container(keyedBy: CodingKeys.self)");
+
+                    for field in fields.iter().cloned() {
+                        t.push(decode_field(field, Some(toks![".", field.safe_ident()]))?);
+                    }
+
+                    t.join_line_spacing()
+                });
+
+                t.push("}");
+                t
+            });
+
+            t.push("}");
+
+            Ok(t)
+        }
+
+        fn encodable<'a>(
+            name: &'a SwiftName,
+            fields: &[&'a RpField],
+        ) -> Result<Tokens<'a, Swift<'a>>> {
+            let mut t = Tokens::new();
+
+            t.push(toks!["public extension ", name, " {"]);
+
+            t.nested({
+                let mut t = Tokens::new();
+
+                t.push("func encode(to encoder: Encoder) throws {");
+
+                t.nested({
+                    let mut t = Tokens::new();
+
+                    t.push("var values = encoder.container(keyedBy: CodingKeys.self)");
+
+                    for field in fields.iter().cloned() {
+                        t.push(encode_field(field, Some(toks![".", field.safe_ident()]))?);
+                    }
+
+                    t.join_line_spacing()
+                });
+
+                t.push("}");
+                t
+            });
+
+            t.push("}");
+
+            Ok(t)
+        }
+    }
+}
+
 impl TupleCodegen for Codegen {
     fn generate(&self, e: TupleAdded) -> Result<()> {
         let TupleAdded {
@@ -691,6 +794,11 @@ impl TupleCodegen for Codegen {
                         let mut t = Tokens::new();
 
                         for field in fields {
+                            if is_date(field)? || field.default.is_some() {
+                                t.push(decode_field(*field, None)?);
+                                continue;
+                            }
+
                             let s = toks!["self.", field.safe_ident()];
                             let ty = field.ty.ty();
 
@@ -744,6 +852,11 @@ impl TupleCodegen for Codegen {
                         let mut t = Tokens::new();
 
                         for field in fields {
+                            if is_date(field)? {
+                                t.push(encode_field(*field, None)?);
+                                continue;
+                            }
+
                             let s = toks!["self.", field.safe_ident()];
 
                             if field.is_optional() {
@@ -1294,9 +1407,229 @@ impl InterfaceModelCodegen for Codegen {
     }
 }
 
+impl DataCodec for Codegen {
+    fn encode_data<'el>(
+        &self,
+        _: &'el SwiftType<'static>,
+        var: Tokens<'el, Swift<'el>>,
+    ) -> Result<Tokens<'el, Swift<'el>>> {
+        let encoder = imported("Foundation", "JSONEncoder");
+        Ok(toks!["try ", encoder, "().encode(", var, ")"])
+    }
+
+    fn decode_data<'el>(
+        &self,
+        ty: &'el SwiftType<'static>,
+        var: Tokens<'el, Swift<'el>>,
+    ) -> Result<Tokens<'el, Swift<'el>>> {
+        let decoder = imported("Foundation", "JSONDecoder");
+        Ok(toks![
+            "try ",
+            decoder,
+            "().decode(",
+            ty.ty().clone(),
+            ".self, from: ",
+            var,
+            ")"
+        ])
+    }
+}
+
 impl PackageCodegen for Codegen {
     fn generate(&self, e: PackageAdded) -> Result<()> {
         e.files.push((self.utils_package(), self.utils()?));
         Ok(())
     }
 }
+
+/// Check if the field is of type `date`.
+///
+/// `date` and `datetime` are both represented as `Date`, and the strategy of a `JSONDecoder` or a
+/// `JSONEncoder` applies to both. So `date` fields are encoded using a separate formatter.
+fn is_date(field: &RpField) -> Result<bool> {
+    return match *field.ty.simple() {
+        Simple::Date => Ok(true),
+        ref simple if contains_date(simple) => Err(format!(
+            "{}: `date` in arrays and maps is not supported by the codable module",
+            field.name()
+        ).into()),
+        _ => Ok(false),
+    };
+
+    fn contains_date(simple: &Simple) -> bool {
+        match *simple {
+            Simple::Date => true,
+            Simple::Array { ref argument } => contains_date(argument),
+            Simple::Map { ref key, ref value } => contains_date(key) || contains_date(value),
+            _ => false,
+        }
+    }
+}
+
+/// Decode a field from the `values` container, falling back to its default value if absent.
+///
+/// `key` is the coding key of the field, or `None` if the container is unkeyed.
+fn decode_field<'a>(
+    field: &'a RpField,
+    key: Option<Tokens<'a, Swift<'a>>>,
+) -> Result<Tokens<'a, Swift<'a>>> {
+    let s = toks!["self.", field.safe_ident()];
+
+    let for_key = match key {
+        Some(ref key) => toks![", forKey: ", key.clone()],
+        None => Tokens::new(),
+    };
+
+    if !is_date(field)? {
+        let ty = field.ty.ty();
+
+        if let Some(ref default) = field.default {
+            return Ok(toks![
+                s,
+                " = try values.decodeIfPresent(",
+                ty,
+                ".self",
+                for_key,
+                ") ?? ",
+                default.to_string()
+            ]);
+        }
+
+        if field.is_optional() {
+            return Ok(toks![
+                s,
+                " = try values.decodeIfPresent(",
+                ty,
+                ".self",
+                for_key,
+                ")"
+            ]);
+        }
+
+        return Ok(toks![s, " = try values.decode(", ty, ".self", for_key, ")"]);
+    }
+
+    let string = Rc::new(format!("f_{}", field.ident()));
+    let value = field.safe_ident();
+
+    let error = match key {
+        Some(key) => toks![
+            "DecodingError.dataCorruptedError(forKey: ",
+            key,
+            ", in: values"
+        ],
+        None => toks!["DecodingError.dataCorruptedError(in: values"],
+    };
+
+    let mut parse = Tokens::new();
+
+    parse.push(toks![
+        "guard let ",
+        value,
+        " = ",
+        date_formatter(),
+        ".date(from: ",
+        string.clone(),
+        ") else {"
+    ]);
+    parse.nested(toks![
+        "throw ",
+        error,
+        ", debugDescription: ",
+        "not a date".quoted(),
+        ")"
+    ]);
+    parse.push("}");
+    parse.push(toks![s.clone(), " = ", value]);
+
+    let mut t = Tokens::new();
+
+    if field.is_optional() {
+        t.push(toks![
+            "if let ",
+            string.clone(),
+            " = try values.decodeIfPresent(String.self",
+            for_key,
+            ") {"
+        ]);
+        t.nested(parse);
+        t.push("} else {");
+        t.nested(toks![s, " = nil"]);
+        t.push("}");
+    } else {
+        t.push(toks![
+            "let ",
+            string,
+            " = try values.decode(String.self",
+            for_key,
+            ")"
+        ]);
+        t.push(parse);
+    }
+
+    Ok(t)
+}
+
+/// Encode a field into the `values` container.
+///
+/// `key` is the coding key of the field, or `None` if the container is unkeyed.
+fn encode_field<'a>(
+    field: &'a RpField,
+    key: Option<Tokens<'a, Swift<'a>>>,
+) -> Result<Tokens<'a, Swift<'a>>> {
+    let s = toks!["self.", field.safe_ident()];
+
+    let for_key = match key {
+        Some(key) => toks![", forKey: ", key],
+        None => Tokens::new(),
+    };
+
+    if !is_date(field)? {
+        if field.is_optional() {
+            return Ok(toks!["try values.encodeIfPresent(", s, for_key, ")"]);
+        }
+
+        return Ok(toks!["try values.encode(", s, for_key, ")"]);
+    }
+
+    if field.is_optional() {
+        let value = field.safe_ident();
+
+        let mut t = Tokens::new();
+        t.push(toks!["if let ", value, " = ", s, " {"]);
+        t.nested(toks![
+            "try values.encode(",
+            date_formatter(),
+            ".string(from: ",
+            value,
+            ")",
+            for_key,
+            ")"
+        ]);
+        t.push("}");
+        return Ok(t);
+    }
+
+    Ok(toks![
+        "try values.encode(",
+        date_formatter(),
+        ".string(from: ",
+        s,
+        ")",
+        for_key,
+        ")"
+    ])
+}
+
+/// Build a formatter for ISO-8601 dates without a time, like `2017-10-14`.
+fn date_formatter<'el>() -> Tokens<'el, Swift<'el>> {
+    let formatter = imported("Foundation", "ISO8601DateFormatter");
+
+    toks![
+        "{ () -> ",
+        formatter.clone(),
+        " in let f = ",
+        formatter,
+        "(); f.formatOptions = [.withFullDate, .withDashSeparatorInDate]; return f }()"
+    ]
+}
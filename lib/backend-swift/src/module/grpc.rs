@@ -1,7 +1,13 @@
-//! gRPC module for Rust.
+//! gRPC module for Swift.
 
 use backend::Initializer;
-use Options;
+use compiler::Comments;
+use core::errors::Result;
+use flavored::{RpEndpoint, RpPackage};
+use genco::swift::{imported, local, Swift};
+use genco::{Cons, Quoted, Tokens};
+use std::rc::Rc;
+use {DataCodec, FileSpec, Options, PackageAdded, PackageCodegen, ServiceAdded, ServiceCodegen};
 
 pub struct Module {}
 
@@ -13,4 +19,471 @@ impl Module {
 
 impl Initializer for Module {
     type Options = Options;
+
+    fn initialize(&self, options: &mut Options) -> Result<()> {
+        let codegen = Rc::new(Codegen::new());
+        options.service_gens.push(Box::new(codegen.clone()));
+        options.package_gens.push(Box::new(codegen.clone()));
+        Ok(())
+    }
+}
+
+/// The kind of gRPC call an endpoint corresponds to.
+#[derive(Clone, Copy)]
+enum CallStyle {
+    Unary,
+    ClientStreaming,
+    ServerStreaming,
+    BidiStreaming,
+}
+
+impl CallStyle {
+    /// Get the call style for the given endpoint.
+    ///
+    /// Endpoints without a request or a response are treated as unary in that direction.
+    fn from_endpoint(e: &RpEndpoint) -> CallStyle {
+        let request = e.request
+            .as_ref()
+            .map(|r| r.channel.is_streaming())
+            .unwrap_or(false);
+
+        let response = e.response
+            .as_ref()
+            .map(|r| r.is_streaming())
+            .unwrap_or(false);
+
+        match (request, response) {
+            (false, false) => CallStyle::Unary,
+            (true, false) => CallStyle::ClientStreaming,
+            (false, true) => CallStyle::ServerStreaming,
+            (true, true) => CallStyle::BidiStreaming,
+        }
+    }
+
+    /// Variant of `SwiftGRPC.CallStyle`.
+    fn variant(&self) -> &'static str {
+        use self::CallStyle::*;
+
+        match *self {
+            Unary => ".unary",
+            ClientStreaming => ".clientStreaming",
+            ServerStreaming => ".serverStreaming",
+            BidiStreaming => ".bidiStreaming",
+        }
+    }
+}
+
+struct Codegen {
+    data: Swift<'static>,
+    channel: Swift<'static>,
+    metadata: Swift<'static>,
+    call: Swift<'static>,
+    call_result: Swift<'static>,
+    grpc_call: Swift<'static>,
+}
+
+impl Codegen {
+    pub fn new() -> Codegen {
+        Self {
+            data: imported("Foundation", "Data"),
+            channel: imported("SwiftGRPC", "Channel"),
+            metadata: imported("SwiftGRPC", "Metadata"),
+            call: imported("SwiftGRPC", "Call"),
+            call_result: imported("SwiftGRPC", "CallResult"),
+            grpc_call: local("GrpcCall"),
+        }
+    }
+
+    fn utils_package(&self) -> RpPackage {
+        RpPackage::parse("reproto_grpc")
+    }
+
+    fn utils<'el>(&self) -> Result<FileSpec<'el>> {
+        let mut out = FileSpec::default();
+
+        out.0.push(decode_func(self));
+        out.0.push(grpc_call(self));
+
+        return Ok(out);
+
+        /// Decode the data of a call result, if present.
+        ///
+        /// Errors raised while decoding are propagated to the caller.
+        fn decode_func<'el>(codegen: &Codegen) -> Tokens<'el, Swift<'el>> {
+            let mut t = Tokens::new();
+
+            t.push(toks![
+                "func grpc_decode<T>(_ data: ",
+                codegen.data.clone(),
+                "?, decode: (",
+                codegen.data.clone(),
+                ") throws -> T) throws -> T? {"
+            ]);
+
+            t.nested({
+                let mut t = Tokens::new();
+
+                t.push("guard let data = data else {");
+                t.nested("return nil");
+                t.push("}");
+
+                t.push("return try decode(data)");
+
+                t.join_line_spacing()
+            });
+
+            t.push("}");
+
+            t
+        }
+
+        /// Typed wrapper around a streaming call.
+        fn grpc_call<'el>(codegen: &Codegen) -> Tokens<'el, Swift<'el>> {
+            let data = codegen.data.clone();
+            let mut t = Tokens::new();
+
+            t.push("public class GrpcCall<Request, Response> {");
+
+            t.nested({
+                let mut t = Tokens::new();
+
+                t.push({
+                    let mut t = Tokens::new();
+                    push!(t, "let call: ", codegen.call.clone());
+                    push!(t, "let encode: (Request) throws -> ", data.clone());
+                    push!(t, "let decode: (", data.clone(), ") throws -> Response");
+                    t
+                });
+
+                t.push({
+                    let mut t = Tokens::new();
+
+                    push!(
+                        t,
+                        "init(call: ",
+                        codegen.call.clone(),
+                        ", encode: @escaping (Request) throws -> ",
+                        data.clone(),
+                        ", decode: @escaping (",
+                        data.clone(),
+                        ") throws -> Response) {"
+                    );
+                    nested!(t, "self.call = call");
+                    nested!(t, "self.encode = encode");
+                    nested!(t, "self.decode = decode");
+                    push!(t, "}");
+
+                    t
+                });
+
+                t.push({
+                    let mut t = Tokens::new();
+
+                    push!(
+                        t,
+                        "public func send(_ message: Request, completion: ((Error?) -> Void)? = nil) throws {"
+                    );
+                    nested!(
+                        t,
+                        "try self.call.sendMessage(data: try self.encode(message), completion: completion)"
+                    );
+                    push!(t, "}");
+
+                    t
+                });
+
+                t.push({
+                    let mut t = Tokens::new();
+
+                    push!(
+                        t,
+                        "public func receive(completion: @escaping (Response?, Error?, ",
+                        codegen.call_result.clone(),
+                        ") -> Void) throws {"
+                    );
+                    nested!(t, "try self.call.receiveMessage { result in");
+                    t.nested({
+                        let mut t = Tokens::new();
+                        t.nested(decode_completion(toks!["self.decode"]));
+                        t
+                    });
+                    nested!(t, "}");
+                    push!(t, "}");
+
+                    t
+                });
+
+                t.push({
+                    let mut t = Tokens::new();
+
+                    push!(t, "public func close(completion: (() -> Void)? = nil) throws {");
+                    nested!(t, "try self.call.close(completion: completion)");
+                    push!(t, "}");
+
+                    t
+                });
+
+                t.push({
+                    let mut t = Tokens::new();
+
+                    push!(t, "public func cancel() {");
+                    nested!(t, "self.call.cancel()");
+                    push!(t, "}");
+
+                    t
+                });
+
+                t.join_line_spacing()
+            });
+
+            t.push("}");
+
+            t
+        }
+    }
+
+    /// Build the client method for a single endpoint.
+    fn endpoint<'el>(
+        &self,
+        codec: &DataCodec,
+        service_name: &str,
+        e: &'el RpEndpoint,
+    ) -> Result<Tokens<'el, Swift<'el>>> {
+        let style = CallStyle::from_endpoint(e);
+        let path = Cons::from(format!("/{}/{}", service_name, e.name()));
+
+        let request_ty = match e.request {
+            Some(ref r) => Some((r.safe_ident(), r.channel.ty())),
+            None => None,
+        };
+
+        let response_ty = e.response.as_ref().map(|r| r.ty());
+
+        let mut args = Tokens::new();
+        let mut body = Tokens::new();
+
+        body.push(toks!["let call = self.channel.makeCall(", path.quoted(), ")"]);
+
+        // the message sent when the call is started, if any.
+        let message = match style {
+            CallStyle::Unary | CallStyle::ServerStreaming => match request_ty {
+                Some((ident, ty)) => {
+                    args.append(toks!["_ ", ident, ": ", ty.ty().clone()]);
+                    body.push(toks![
+                        "let message = ",
+                        codec.encode_data(ty, toks![ident])?
+                    ]);
+                    toks!["message"]
+                }
+                None => toks![self.data.clone(), "()"],
+            },
+            CallStyle::ClientStreaming | CallStyle::BidiStreaming => toks!["nil"],
+        };
+
+        let response = match response_ty {
+            Some(ty) => Some((ty.ty().clone(), codec.decode_data(ty, toks!["data"])?)),
+            None => None,
+        };
+
+        let ret = match style {
+            CallStyle::Unary => {
+                let completion = match response {
+                    Some((ref ty, _)) => toks![
+                        "@escaping (",
+                        ty.clone(),
+                        "?, Error?, ",
+                        self.call_result.clone(),
+                        ") -> Void"
+                    ],
+                    None => toks!["@escaping (", self.call_result.clone(), ") -> Void"],
+                };
+
+                args.append(toks!["completion: ", completion]);
+
+                body.push({
+                    let mut t = Tokens::new();
+
+                    push!(
+                        t,
+                        "try call.start(",
+                        style.variant(),
+                        ", metadata: self.metadata, message: ",
+                        message,
+                        ") { result in"
+                    );
+
+                    match response {
+                        Some((_, decode)) => {
+                            t.nested(decode_completion(toks!["{ data in ", decode, " }"]));
+                        }
+                        None => {
+                            nested!(t, "completion(result)");
+                        }
+                    }
+
+                    push!(t, "}");
+
+                    t
+                });
+
+                body.push("return call");
+                toks![self.call.clone()]
+            }
+            _ => {
+                body.push(toks![
+                    "try call.start(",
+                    style.variant(),
+                    ", metadata: self.metadata, message: ",
+                    message,
+                    ", completion: nil)"
+                ]);
+
+                let (request, encode) = match (style, request_ty) {
+                    (CallStyle::ClientStreaming, Some((_, ty)))
+                    | (CallStyle::BidiStreaming, Some((_, ty))) => (
+                        ty.ty().clone(),
+                        toks!["{ value in ", codec.encode_data(ty, toks!["value"])?, " }"],
+                    ),
+                    _ => (local("Void"), toks!["{ _ in ", self.data.clone(), "() }"]),
+                };
+
+                let (response, decode) = match response {
+                    Some((ty, decode)) => (ty, toks!["{ data in ", decode, " }"]),
+                    None => (local("Void"), toks!["{ _ in () }"]),
+                };
+
+                body.push(toks![
+                    "return ",
+                    self.grpc_call.clone(),
+                    "(call: call, encode: ",
+                    encode,
+                    ", decode: ",
+                    decode,
+                    ")"
+                ]);
+
+                toks![
+                    self.grpc_call.clone(),
+                    "<",
+                    request,
+                    ", ",
+                    response,
+                    ">"
+                ]
+            }
+        };
+
+        let mut t = Tokens::new();
+
+        t.push_unless_empty(Comments(&e.comment));
+        t.push(toks![
+            "public func ",
+            e.safe_ident(),
+            "(",
+            args.join(", "),
+            ") throws -> ",
+            ret,
+            " {"
+        ]);
+        t.nested(body);
+        t.push("}");
+
+        Ok(t)
+    }
+}
+
+/// Decode the data of a call result with `decode`, and pass it to `completion`.
+///
+/// If decoding fails, the error is passed to `completion` instead.
+fn decode_completion<'el>(decode: Tokens<'el, Swift<'el>>) -> Tokens<'el, Swift<'el>> {
+    let mut t = Tokens::new();
+
+    push!(t, "do {");
+    nested!(
+        t,
+        "completion(try grpc_decode(result.resultData, decode: ",
+        decode,
+        "), nil, result)"
+    );
+    push!(t, "} catch {");
+    nested!(t, "completion(nil, error, result)");
+    push!(t, "}");
+
+    t
+}
+
+impl ServiceCodegen for Codegen {
+    fn generate(&self, e: ServiceAdded) -> Result<()> {
+        let ServiceAdded {
+            container,
+            compiler,
+            name,
+            body,
+        } = e;
+
+        let codec = match compiler.data_codec()? {
+            Some(codec) => codec,
+            None => {
+                return Err(
+                    "Missing serialization module for gRPC, try: -m simple, or -m codable".into(),
+                );
+            }
+        };
+
+        let service_name = format!("{}.{}", name.spec_package.join("."), body.ident);
+
+        let mut t = Tokens::new();
+
+        t.push_unless_empty(Comments(&body.comment));
+        t.push(toks!["public class ", name.name.clone(), "_GrpcClient {"]);
+
+        t.nested({
+            let mut t = Tokens::new();
+
+            t.push({
+                let mut t = Tokens::new();
+                push!(t, "let channel: ", self.channel.clone());
+                push!(t, "public var metadata: ", self.metadata.clone());
+                t
+            });
+
+            t.push({
+                let mut t = Tokens::new();
+
+                push!(
+                    t,
+                    "public init(channel: ",
+                    self.channel.clone(),
+                    ", metadata: ",
+                    self.metadata.clone(),
+                    " = ",
+                    self.metadata.clone(),
+                    "()) {"
+                );
+                nested!(t, "self.channel = channel");
+                nested!(t, "self.metadata = metadata");
+                push!(t, "}");
+
+                t
+            });
+
+            for e in &body.endpoints {
+                t.push(self.endpoint(codec.as_ref(), &service_name, e)?);
+            }
+
+            t.join_line_spacing()
+        });
+
+        t.push("}");
+
+        container.push(t);
+        Ok(())
+    }
+}
+
+impl PackageCodegen for Codegen {
+    fn generate(&self, e: PackageAdded) -> Result<()> {
+        e.files.push((self.utils_package(), self.utils()?));
+        Ok(())
+    }
 }
@@ -4,16 +4,19 @@ use backend::Initializer;
 use compiler::Comments;
 use core::errors::Result;
 use core::{self, Loc};
-use flavored::{RpEnumBody, RpField, RpInterfaceBody, RpPackage, RpSubType, SwiftName};
+use flavored::{RpEnumBody, RpField, RpInterfaceBody, RpPackage, RpSubType, SwiftName,
+               SwiftType};
 use genco::swift::{imported, Swift};
 use genco::{Cons, IntoTokens, Quoted, Tokens};
 use std::rc::Rc;
-use {Compiler, EnumAdded, EnumCodegen, FileSpec, InterfaceAdded, InterfaceCodegen, Options,
-     PackageAdded, PackageCodegen, TupleAdded, TupleCodegen, TypeAdded, TypeCodegen};
+use {Compiler, DataCodec, EnumAdded, EnumCodegen, FileSpec, InterfaceAdded, InterfaceCodegen,
+     Options, PackageAdded, PackageCodegen, TupleAdded, TupleCodegen, TypeAdded, TypeCodegen};
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum Simple<'el> {
     DateTime,
+    Date,
+    Uuid,
     Bytes,
     Array {
         argument: Box<Simple<'el>>,
@@ -49,6 +52,16 @@ impl<'el> Simple<'el> {
                 let date = toks![codegen.formatter.clone(), "().date(from: ", string, ")"];
                 toks!["try decode_value(", date, ")"]
             }
+            Date => {
+                let string = toks!["try decode_value(", var, " as? String)"];
+                let date = toks![codegen.date_formatter(), ".date(from: ", string, ")"];
+                toks!["try decode_value(", date, ")"]
+            }
+            Uuid => {
+                let string = toks!["try decode_value(", var, " as? String)"];
+                let uuid = toks![codegen.uuid.clone(), "(uuidString: ", string, ")"];
+                toks!["try decode_value(", uuid, ")"]
+            }
             Bytes => toks![
                 codegen.data.clone(),
                 "(base64Encoded: try decode_value(",
@@ -112,6 +125,8 @@ impl<'el> Simple<'el> {
 
         let encode = match *self {
             DateTime => toks![codegen.formatter.clone(), "().string(from: ", var, ")"],
+            Date => toks![codegen.date_formatter(), ".string(from: ", var, ")"],
+            Uuid => toks![var, ".uuidString"],
             Bytes => toks![var, ".base64EncodedString()"],
             Array { ref argument } => {
                 let argument = argument.encode_value(codegen, name, "inner".into())?;
@@ -187,6 +202,9 @@ impl Initializer for Module {
         options.enum_gens.push(Box::new(codegen.clone()));
         options.interface_gens.push(Box::new(codegen.clone()));
         options.package_gens.push(Box::new(codegen.clone()));
+        options
+            .data_codec
+            .push(("simple", codegen.clone() as Rc<DataCodec>));
         Ok(())
     }
 }
@@ -194,6 +212,8 @@ impl Initializer for Module {
 struct Codegen {
     data: Swift<'static>,
     formatter: Swift<'static>,
+    json_serialization: Swift<'static>,
+    uuid: Swift<'static>,
 }
 
 impl Codegen {
@@ -201,9 +221,22 @@ impl Codegen {
         Self {
             data: imported("Foundation", "Data"),
             formatter: imported("Foundation", "ISO8601DateFormatter"),
+            json_serialization: imported("Foundation", "JSONSerialization"),
+            uuid: imported("Foundation", "UUID"),
         }
     }
 
+    /// Build a formatter for ISO-8601 dates without a time, like `2018-01-31`.
+    fn date_formatter<'el>(&self) -> Tokens<'el, Swift<'el>> {
+        toks![
+            "{ () -> ",
+            self.formatter.clone(),
+            " in let f = ",
+            self.formatter.clone(),
+            "(); f.formatOptions = [.withFullDate, .withDashSeparatorInDate]; return f }()"
+        ]
+    }
+
     // Setup a field initializer.
     pub fn encode_field<'a, A>(
         &self,
@@ -263,7 +296,12 @@ impl Codegen {
 
                 let mut t = Tokens::new();
 
-                t.push(toks!["var ", ident, ": ", ty, " = Optional.none"]);
+                let initial = match field.default {
+                    Some(ref default) => format!("Optional.some({})", default),
+                    None => "Optional.none".to_string(),
+                };
+
+                t.push(toks!["var ", ident, ": ", ty, " = ", initial]);
 
                 t.push({
                     let mut t = Tokens::new();
@@ -1147,6 +1185,40 @@ impl InterfaceCodegen for Codegen {
     }
 }
 
+impl DataCodec for Codegen {
+    fn encode_data<'el>(
+        &self,
+        ty: &'el SwiftType<'static>,
+        var: Tokens<'el, Swift<'el>>,
+    ) -> Result<Tokens<'el, Swift<'el>>> {
+        let value = ty.simple().encode_value(self, "value", var)?;
+
+        Ok(toks![
+            "try ",
+            self.json_serialization.clone(),
+            ".data(withJSONObject: ",
+            value,
+            ")"
+        ])
+    }
+
+    fn decode_data<'el>(
+        &self,
+        ty: &'el SwiftType<'static>,
+        var: Tokens<'el, Swift<'el>>,
+    ) -> Result<Tokens<'el, Swift<'el>>> {
+        let json = toks![
+            "try ",
+            self.json_serialization.clone(),
+            ".jsonObject(with: ",
+            var,
+            ")"
+        ];
+
+        ty.simple().decode_value(self, "value".into(), json)
+    }
+}
+
 impl PackageCodegen for Codegen {
     fn generate(&self, e: PackageAdded) -> Result<()> {
         e.files.push((self.utils_package(), self.utils()?));
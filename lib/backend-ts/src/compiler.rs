@@ -0,0 +1,613 @@
+use backend::PackageProcessor;
+use core::errors::*;
+use core::{self, ForEachLoc, Handle, Loc, RpDeprecated};
+use flavored::{RpEnumBody, RpField, RpInterfaceBody, RpServiceBody, RpSubType, RpTupleBody,
+               RpTypeBody, TypeScriptFlavor, TypeScriptName};
+use genco::{Element, JavaScript, Quoted, Tokens};
+use std::rc::Rc;
+use trans::{self, Translated};
+use utils::{block, is_defined, is_not_defined, Documentation};
+use {FileSpec, Options, EXT};
+
+pub struct Compiler<'el> {
+    pub env: &'el Translated<TypeScriptFlavor>,
+    handle: &'el Handle,
+}
+
+impl<'el> Compiler<'el> {
+    pub fn new(
+        env: &'el Translated<TypeScriptFlavor>,
+        _: Options,
+        handle: &'el Handle,
+    ) -> Compiler<'el> {
+        Compiler { env, handle }
+    }
+
+    pub fn compile(&self) -> Result<()> {
+        let files = self.populate_files()?;
+        self.write_files(files)
+    }
+
+    /// Build an interface declaration for the given fields.
+    ///
+    /// `tag` is the optional type tag and its value, which is used to discriminate sub-types.
+    fn interface<I>(
+        &self,
+        name: &'el TypeScriptName,
+        comment: &'el [String],
+        deprecated: &'el Option<RpDeprecated>,
+        tag: Option<(&'el str, &'el str)>,
+        fields: I,
+    ) -> Tokens<'el, JavaScript<'el>>
+    where
+        I: IntoIterator<Item = &'el Loc<RpField>>,
+    {
+        let mut body = Tokens::new();
+
+        if let Some((tag, value)) = tag {
+            body.push(toks![tag.quoted(), ": ", value.quoted(), ";"]);
+        }
+
+        for field in fields {
+            body.push_unless_empty(Documentation(&field.comment, &field.deprecated));
+
+            let optional = if field.is_optional() { "?" } else { "" };
+
+            body.push(toks![
+                field.safe_ident(),
+                optional,
+                ": ",
+                field.ty.ty(),
+                ";"
+            ]);
+        }
+
+        let mut t = Tokens::new();
+        t.push_unless_empty(Documentation(comment, deprecated));
+        t.push(block(toks!["export interface ", name], body));
+        t
+    }
+
+    /// Build a namespace, which is merged with the type of the same name.
+    fn namespace(
+        &self,
+        name: &'el TypeScriptName,
+        body: Tokens<'el, JavaScript<'el>>,
+    ) -> Tokens<'el, JavaScript<'el>> {
+        block(toks!["export namespace ", name], body.join_line_spacing())
+    }
+
+    /// Build statements which decode the given fields from `data` into local variables.
+    ///
+    /// Returns the statements, and the entries of the object literal to construct.
+    fn decode_fields<F, I>(
+        &self,
+        fields: I,
+        variable_fn: F,
+    ) -> (Tokens<'el, JavaScript<'el>>, Tokens<'el, JavaScript<'el>>)
+    where
+        F: Fn(usize, &'el Loc<RpField>) -> Element<'el, JavaScript<'el>>,
+        I: IntoIterator<Item = &'el Loc<RpField>>,
+    {
+        let mut assign = Tokens::new();
+        let mut entries = Tokens::new();
+
+        for (i, field) in fields.into_iter().enumerate() {
+            let var_name = toks![Rc::new(format!("v_{}", field.ident()))];
+            let data = toks!["data[", variable_fn(i, field), "]"];
+            let value = field.ty.decode(data.clone());
+
+            if field.is_optional() {
+                assign.push(toks![
+                    "let ",
+                    var_name.clone(),
+                    ": ",
+                    field.ty.ty(),
+                    " | undefined = undefined;"
+                ]);
+
+                assign.push(block(
+                    toks!["if (", is_defined(data), ")"],
+                    toks![var_name.clone(), " = ", value, ";"],
+                ));
+            } else {
+                let required = toks![
+                    "throw new Error(",
+                    field.name().quoted(),
+                    " + ",
+                    ": required field".quoted(),
+                    ");"
+                ];
+
+                assign.push(block(toks!["if (", is_not_defined(data), ")"], required));
+
+                assign.push(toks![
+                    "const ",
+                    var_name.clone(),
+                    ": ",
+                    field.ty.ty(),
+                    " = ",
+                    value,
+                    ";"
+                ]);
+            }
+
+            entries.push(toks![field.safe_ident(), ": ", var_name, ","]);
+        }
+
+        (assign, entries)
+    }
+
+    /// Build a decode function which constructs an object literal.
+    fn decode_function<F, I>(
+        &self,
+        name: &'el TypeScriptName,
+        tag: Option<(&'el str, &'el str)>,
+        fields: I,
+        variable_fn: F,
+    ) -> Tokens<'el, JavaScript<'el>>
+    where
+        F: Fn(usize, &'el Loc<RpField>) -> Element<'el, JavaScript<'el>>,
+        I: IntoIterator<Item = &'el Loc<RpField>>,
+    {
+        let (assign, entries) = self.decode_fields(fields, variable_fn);
+
+        let mut body = Tokens::new();
+
+        if !assign.is_empty() {
+            body.push(assign.join_line_spacing());
+        }
+
+        let mut literal = Tokens::new();
+
+        if let Some((tag, value)) = tag {
+            literal.push(toks![tag.quoted(), ": ", value.quoted(), ","]);
+        }
+
+        literal.push_unless_empty(entries);
+
+        if literal.is_empty() {
+            body.push("return {};");
+        } else {
+            let mut t = Tokens::new();
+            t.push("return {");
+            t.nested(literal);
+            t.push("};");
+            body.push(t);
+        }
+
+        block(
+            toks!["export function decode(data: any): ", name],
+            body.join_line_spacing(),
+        )
+    }
+
+    /// Build an encode function which encodes fields into a JSON object.
+    fn encode_function<I>(
+        &self,
+        name: &'el TypeScriptName,
+        tag: Option<(&'el str, &'el str)>,
+        fields: I,
+    ) -> Tokens<'el, JavaScript<'el>>
+    where
+        I: IntoIterator<Item = &'el Loc<RpField>>,
+    {
+        let mut body = Tokens::new();
+
+        body.push("const data: any = {};");
+
+        if let Some((tag, value)) = tag {
+            body.push(toks!["data[", tag.quoted(), "] = ", value.quoted(), ";"]);
+        }
+
+        let mut assign = Tokens::new();
+
+        for field in fields {
+            let key = field.name().quoted();
+            let var = toks!["value.", field.safe_ident()];
+            let value = field.ty.encode(var.clone());
+
+            if field.is_optional() {
+                assign.push(block(
+                    toks!["if (", is_defined(var), ")"],
+                    toks!["data[", key, "] = ", value, ";"],
+                ));
+            } else {
+                assign.push(toks!["data[", key, "] = ", value, ";"]);
+            }
+        }
+
+        if !assign.is_empty() {
+            body.push(assign.join_line_spacing());
+        }
+
+        body.push("return data;");
+
+        block(
+            toks!["export function encode(value: ", name, "): any"],
+            body.join_line_spacing(),
+        )
+    }
+
+    fn field_by_name(_i: usize, field: &'el Loc<RpField>) -> Element<'el, JavaScript<'el>> {
+        field.name().quoted()
+    }
+
+    fn field_by_index(i: usize, _field: &'el Loc<RpField>) -> Element<'el, JavaScript<'el>> {
+        i.to_string().into()
+    }
+
+    /// Process a single sub-type of an interface.
+    fn process_sub_type(
+        &self,
+        out: &mut FileSpec<'el>,
+        body: &'el RpInterfaceBody,
+        sub_type: &'el RpSubType,
+    ) -> Result<()> {
+        let tag = match body.sub_type_strategy {
+            core::RpSubTypeStrategy::Tagged { ref tag, .. } => {
+                Some((tag.as_str(), sub_type.name()))
+            }
+            core::RpSubTypeStrategy::Untagged => None,
+        };
+
+        let fields: Vec<&'el Loc<RpField>> =
+            body.fields.iter().chain(sub_type.fields.iter()).collect();
+
+        out.0.push(self.interface(
+            &sub_type.name,
+            &sub_type.comment,
+            &sub_type.deprecated,
+            tag,
+            fields.iter().cloned(),
+        ));
+
+        let mut namespace = Tokens::new();
+
+        namespace.push(self.decode_function(
+            &sub_type.name,
+            tag,
+            fields.iter().cloned(),
+            Self::field_by_name,
+        ));
+
+        namespace.push(self.encode_function(&sub_type.name, tag, fields.iter().cloned()));
+        namespace.push_unless_empty(code!(&sub_type.codes, core::RpContext::TypeScript));
+
+        out.0.push(self.namespace(&sub_type.name, namespace));
+        Ok(())
+    }
+}
+
+impl<'el> PackageProcessor<'el, TypeScriptFlavor, TypeScriptName> for Compiler<'el> {
+    type Out = FileSpec<'el>;
+    type DeclIter = trans::translated::DeclIter<'el, TypeScriptFlavor>;
+
+    fn ext(&self) -> &str {
+        EXT
+    }
+
+    fn decl_iter(&self) -> Self::DeclIter {
+        self.env.decl_iter()
+    }
+
+    fn handle(&self) -> &'el Handle {
+        self.handle
+    }
+
+    fn process_tuple(&self, out: &mut Self::Out, body: &'el RpTupleBody) -> Result<()> {
+        out.0.push(self.interface(
+            &body.name,
+            &body.comment,
+            &body.deprecated,
+            None,
+            &body.fields,
+        ));
+
+        let mut namespace = Tokens::new();
+
+        namespace.push(self.decode_function(&body.name, None, &body.fields, Self::field_by_index));
+
+        namespace.push({
+            let mut values = Tokens::new();
+
+            for field in &body.fields {
+                values.append(field.ty.encode(toks!["value.", field.safe_ident()]));
+            }
+
+            block(
+                toks!["export function encode(value: ", &body.name, "): any"],
+                toks!["return [", values.join(", "), "];"],
+            )
+        });
+
+        namespace.push_unless_empty(code!(&body.codes, core::RpContext::TypeScript));
+
+        out.0.push(self.namespace(&body.name, namespace));
+        Ok(())
+    }
+
+    fn process_enum(&self, out: &mut Self::Out, body: &'el RpEnumBody) -> Result<()> {
+        let mut values: Vec<Element<'el, JavaScript<'el>>> = Vec::new();
+
+        for v in body.variants.iter() {
+            match v.value {
+                core::RpVariantValue::String(string) => {
+                    values.push(string.quoted());
+                }
+                core::RpVariantValue::Number(number) => {
+                    values.push(number.to_string().into());
+                }
+            }
+        }
+
+        out.0.push({
+            let mut union = Tokens::new();
+
+            for value in &values {
+                union.append(value.clone());
+            }
+
+            let mut t = Tokens::new();
+            t.push_unless_empty(Documentation(&body.comment, &body.deprecated));
+            t.push(toks!["export type ", &body.name, " = ", union.join(" | "), ";"]);
+            t
+        });
+
+        let mut namespace = Tokens::new();
+
+        namespace.push({
+            let mut t = Tokens::new();
+
+            for (v, value) in body.variants.iter().zip(values.into_iter()) {
+                t.push_unless_empty(Documentation(v.comment, v.deprecated));
+                t.push(toks![
+                    "export const ",
+                    v.ident(),
+                    ": ",
+                    &body.name,
+                    " = ",
+                    value,
+                    ";"
+                ]);
+            }
+
+            t
+        });
+
+        namespace.push({
+            let mut members = Tokens::new();
+
+            for v in body.variants.iter() {
+                members.append(v.ident());
+            }
+
+            toks![
+                "export const values: Array<",
+                &body.name,
+                "> = [",
+                members.join(", "),
+                "];"
+            ]
+        });
+
+        namespace.push({
+            let mut t = Tokens::new();
+
+            t.push(block(
+                "for (const member of values)",
+                block("if (member === data)", "return member;"),
+            ));
+
+            t.push(toks![
+                "throw new Error(",
+                "no matching value: ".quoted(),
+                " + data);"
+            ]);
+
+            block(
+                toks!["export function decode(data: any): ", &body.name],
+                t.join_line_spacing(),
+            )
+        });
+
+        namespace.push(block(
+            toks!["export function encode(value: ", &body.name, "): any"],
+            "return value;",
+        ));
+
+        namespace.push_unless_empty(code!(&body.codes, core::RpContext::TypeScript));
+
+        out.0.push(self.namespace(&body.name, namespace));
+        Ok(())
+    }
+
+    fn process_type(&self, out: &mut Self::Out, body: &'el RpTypeBody) -> Result<()> {
+        out.0.push(self.interface(
+            &body.name,
+            &body.comment,
+            &body.deprecated,
+            None,
+            &body.fields,
+        ));
+
+        let mut namespace = Tokens::new();
+
+        namespace.push(self.decode_function(&body.name, None, &body.fields, Self::field_by_name));
+        namespace.push(self.encode_function(&body.name, None, &body.fields));
+        namespace.push_unless_empty(code!(&body.codes, core::RpContext::TypeScript));
+
+        out.0.push(self.namespace(&body.name, namespace));
+        Ok(())
+    }
+
+    fn process_service(&self, _: &mut Self::Out, _: &'el RpServiceBody) -> Result<()> {
+        // Services are not generated yet.
+        Ok(())
+    }
+
+    fn process_interface(&self, out: &mut Self::Out, body: &'el RpInterfaceBody) -> Result<()> {
+        out.0.push({
+            let mut sub_types = Tokens::new();
+
+            for sub_type in body.sub_types.iter() {
+                sub_types.append(&sub_type.name);
+            }
+
+            let sub_types = if sub_types.is_empty() {
+                toks!["never"]
+            } else {
+                sub_types.join(" | ")
+            };
+
+            let mut t = Tokens::new();
+            t.push_unless_empty(Documentation(&body.comment, &body.deprecated));
+            t.push(toks!["export type ", &body.name, " = ", sub_types, ";"]);
+            t
+        });
+
+        let mut namespace = Tokens::new();
+
+        match body.sub_type_strategy {
+            core::RpSubTypeStrategy::Tagged { ref tag, .. } => {
+                namespace.push(decode_tagged(body, tag.as_str()));
+                namespace.push(encode_tagged(body, tag.as_str()));
+            }
+            core::RpSubTypeStrategy::Untagged => {
+                namespace.push(decode_untagged(body));
+                namespace.push(encode_untagged(body));
+            }
+        }
+
+        namespace.push_unless_empty(code!(&body.codes, core::RpContext::TypeScript));
+
+        out.0.push(self.namespace(&body.name, namespace));
+
+        body.sub_types
+            .iter()
+            .for_each_loc(|sub_type| self.process_sub_type(out, body, sub_type))?;
+
+        return Ok(());
+
+        fn decode_tagged<'el>(
+            body: &'el RpInterfaceBody,
+            tag: &'el str,
+        ) -> Tokens<'el, JavaScript<'el>> {
+            let mut t = Tokens::new();
+
+            t.push(toks!["const f_tag = data[", tag.quoted(), "];"]);
+
+            for sub_type in body.sub_types.iter() {
+                let cond = toks!["if (f_tag === ", sub_type.name().quoted(), ")"];
+                let ret = toks!["return ", &sub_type.name, ".decode(data);"];
+                t.push(block(cond, ret));
+            }
+
+            t.push(toks!["throw new Error(", "bad type: ".quoted(), " + f_tag);"]);
+
+            block(
+                toks!["export function decode(data: any): ", &body.name],
+                t.join_line_spacing(),
+            )
+        }
+
+        fn encode_tagged<'el>(
+            body: &'el RpInterfaceBody,
+            tag: &'el str,
+        ) -> Tokens<'el, JavaScript<'el>> {
+            let mut t = Tokens::new();
+
+            t.push(toks!["const f_tag = value[", tag.quoted(), "];"]);
+
+            for sub_type in body.sub_types.iter() {
+                let cond = toks!["if (f_tag === ", sub_type.name().quoted(), ")"];
+                let ret = toks![
+                    "return ",
+                    &sub_type.name,
+                    ".encode(value as ",
+                    &sub_type.name,
+                    ");"
+                ];
+                t.push(block(cond, ret));
+            }
+
+            t.push(toks!["throw new Error(", "bad type: ".quoted(), " + f_tag);"]);
+
+            block(
+                toks!["export function encode(value: ", &body.name, "): any"],
+                t.join_line_spacing(),
+            )
+        }
+
+        fn decode_untagged<'el>(body: &'el RpInterfaceBody) -> Tokens<'el, JavaScript<'el>> {
+            let mut t = Tokens::new();
+
+            for sub_type in body.sub_types.iter() {
+                let mut required = Tokens::new();
+
+                for f in sub_type.discriminating_fields() {
+                    required.append(toks!["(", f.name().quoted(), " in data)"]);
+                }
+
+                let cond = toks!["if (", condition(required), ")"];
+                let ret = toks!["return ", &sub_type.name, ".decode(data);"];
+                t.push(block(cond, ret));
+            }
+
+            t.push(toks![
+                "throw new Error(",
+                "no legal field combinations found".quoted(),
+                ");"
+            ]);
+
+            block(
+                toks!["export function decode(data: any): ", &body.name],
+                t.join_line_spacing(),
+            )
+        }
+
+        fn encode_untagged<'el>(body: &'el RpInterfaceBody) -> Tokens<'el, JavaScript<'el>> {
+            let mut t = Tokens::new();
+
+            for sub_type in body.sub_types.iter() {
+                let mut required = Tokens::new();
+
+                for f in sub_type.discriminating_fields() {
+                    required.append(toks!["(", f.safe_ident().quoted(), " in value)"]);
+                }
+
+                let cond = toks!["if (", condition(required), ")"];
+                let ret = toks![
+                    "return ",
+                    &sub_type.name,
+                    ".encode(value as ",
+                    &sub_type.name,
+                    ");"
+                ];
+                t.push(block(cond, ret));
+            }
+
+            t.push(toks![
+                "throw new Error(",
+                "no legal field combinations found".quoted(),
+                ");"
+            ]);
+
+            block(
+                toks!["export function encode(value: ", &body.name, "): any"],
+                t.join_line_spacing(),
+            )
+        }
+
+        /// Join the required conditions, a sub-type without discriminating fields always matches.
+        fn condition<'el>(
+            required: Tokens<'el, JavaScript<'el>>,
+        ) -> Tokens<'el, JavaScript<'el>> {
+            if required.is_empty() {
+                return toks!["true"];
+            }
+
+            required.join(" && ")
+        }
+    }
+}
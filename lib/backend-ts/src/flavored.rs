@@ -0,0 +1,299 @@
+//! TypeScript flavor.
+
+#![allow(unused)]
+
+use backend::package_processor;
+use core::errors::Result;
+use core::{self, CoreFlavor, Flavor, FlavorTranslator, Loc, PackageTranslator, Translate,
+           Translator};
+use genco::js::{self, JavaScript};
+use genco::{Cons, Element, IntoTokens, Tokens};
+use naming::{self, Naming};
+use std::collections::HashMap;
+use std::fmt;
+use std::ops::Deref;
+use std::rc::Rc;
+use trans::Packages;
+use TYPE_SEP;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TypeScriptType<'el> {
+    Number,
+    Boolean,
+    String,
+    Any,
+    Array {
+        argument: Box<TypeScriptType<'el>>,
+    },
+    Map {
+        key: Box<TypeScriptType<'el>>,
+        value: Box<TypeScriptType<'el>>,
+    },
+    Name {
+        js: JavaScript<'el>,
+    },
+}
+
+impl<'el> TypeScriptType<'el> {
+    /// Build the type annotation.
+    ///
+    /// Maps are always annotated with string keys, since that is how they are represented in JSON.
+    pub fn ty(&self) -> Tokens<'el, JavaScript<'el>> {
+        use self::TypeScriptType::*;
+
+        match *self {
+            Number => toks!["number"],
+            Boolean => toks!["boolean"],
+            String => toks!["string"],
+            Any => toks!["any"],
+            Array { ref argument } => toks!["Array<", argument.ty(), ">"],
+            Map { ref value, .. } => toks!["{[key: string]: ", value.ty(), "}"],
+            Name { ref js } => toks![js.clone()],
+        }
+    }
+
+    /// Build decode method.
+    pub fn decode(&self, var: Tokens<'el, JavaScript<'el>>) -> Tokens<'el, JavaScript<'el>> {
+        use self::TypeScriptType::*;
+
+        match *self {
+            ref v if v.is_native() => toks![var],
+            Array { ref argument } => {
+                let a = argument.decode("v".into());
+                toks![var, ".map((v: any) => ", a, ")"]
+            }
+            Map { ref value, .. } => {
+                let v = value.decode("data[k]".into());
+
+                let mut t = Tokens::new();
+
+                t.append("((data: any) => {");
+                t.append(toks![" const o: ", self.ty(), " = {};"]);
+                t.append(" for (const k in data) {");
+                t.append(toks![" o[k] = ", v, ";"]);
+                t.append(" }");
+                t.append(" return o;");
+                t.append(toks![" })(", var, ")"]);
+
+                t
+            }
+            Name { ref js } => toks![js.clone(), ".decode(", var, ")"],
+            _ => toks![var],
+        }
+    }
+
+    /// Build encode method.
+    pub fn encode(&self, var: Tokens<'el, JavaScript<'el>>) -> Tokens<'el, JavaScript<'el>> {
+        use self::TypeScriptType::*;
+
+        match *self {
+            ref v if v.is_native() => toks![var],
+            Array { ref argument } => {
+                let v = argument.encode("v".into());
+                toks![var, ".map((v: ", argument.ty(), ") => ", v, ")"]
+            }
+            Map { ref value, .. } => {
+                let v = value.encode("data[k]".into());
+
+                let mut t = Tokens::new();
+
+                t.append(toks!["((data: ", self.ty(), ") => {"]);
+                t.append(" const o: any = {};");
+                t.append(" for (const k in data) {");
+                t.append(toks![" o[k] = ", v, ";"]);
+                t.append(" }");
+                t.append(" return o;");
+                t.append(toks![" })(", var, ")"]);
+
+                t
+            }
+            Name { ref js } => toks![js.clone(), ".encode(", var, ")"],
+            _ => toks![var],
+        }
+    }
+
+    /// Check if the current type is completely native.
+    pub fn is_native(&self) -> bool {
+        use self::TypeScriptType::*;
+
+        match *self {
+            Number | Boolean | String | Any => true,
+            Array { ref argument } => argument.is_native(),
+            Map { ref key, ref value } => key.is_native() && value.is_native(),
+            Name { .. } => false,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TypeScriptName {
+    pub name: JavaScript<'static>,
+    pub package: RpPackage,
+}
+
+impl fmt::Display for TypeScriptName {
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        self.name.fmt(fmt)
+    }
+}
+
+impl<'el> From<&'el TypeScriptName> for Element<'el, JavaScript<'el>> {
+    fn from(value: &'el TypeScriptName) -> Element<'el, JavaScript<'el>> {
+        Element::Literal(value.name.clone().to_string().into())
+    }
+}
+
+impl package_processor::Name<TypeScriptFlavor> for TypeScriptName {
+    fn package(&self) -> &RpPackage {
+        &self.package
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct TypeScriptFlavor;
+
+impl Flavor for TypeScriptFlavor {
+    type Type = TypeScriptType<'static>;
+    type Name = TypeScriptName;
+    type Field = RpField;
+    type Endpoint = RpEndpoint;
+    type Package = RpPackage;
+    type EnumType = RpEnumType;
+}
+
+/// Responsible for translating RpType -> TypeScript type.
+pub struct TypeScriptFlavorTranslator {
+    packages: Rc<Packages>,
+}
+
+impl TypeScriptFlavorTranslator {
+    pub fn new(packages: Rc<Packages>) -> Self {
+        Self { packages }
+    }
+}
+
+impl FlavorTranslator for TypeScriptFlavorTranslator {
+    type Source = CoreFlavor;
+    type Target = TypeScriptFlavor;
+
+    translator_defaults!(Self, field, endpoint, enum_type);
+
+    fn translate_i32(&self) -> Result<TypeScriptType<'static>> {
+        Ok(TypeScriptType::Number)
+    }
+
+    fn translate_i64(&self) -> Result<TypeScriptType<'static>> {
+        Ok(TypeScriptType::Number)
+    }
+
+    fn translate_u32(&self) -> Result<TypeScriptType<'static>> {
+        Ok(TypeScriptType::Number)
+    }
+
+    fn translate_u64(&self) -> Result<TypeScriptType<'static>> {
+        Ok(TypeScriptType::Number)
+    }
+
+    fn translate_float(&self) -> Result<TypeScriptType<'static>> {
+        Ok(TypeScriptType::Number)
+    }
+
+    fn translate_double(&self) -> Result<TypeScriptType<'static>> {
+        Ok(TypeScriptType::Number)
+    }
+
+    fn translate_boolean(&self) -> Result<TypeScriptType<'static>> {
+        Ok(TypeScriptType::Boolean)
+    }
+
+    fn translate_string(&self) -> Result<TypeScriptType<'static>> {
+        Ok(TypeScriptType::String)
+    }
+
+    fn translate_datetime(&self) -> Result<TypeScriptType<'static>> {
+        Ok(TypeScriptType::String)
+    }
+
+    fn translate_date(&self) -> Result<TypeScriptType<'static>> {
+        Ok(TypeScriptType::String)
+    }
+
+    fn translate_time(&self) -> Result<TypeScriptType<'static>> {
+        Ok(TypeScriptType::String)
+    }
+
+    fn translate_duration(&self) -> Result<TypeScriptType<'static>> {
+        Ok(TypeScriptType::String)
+    }
+
+    fn translate_uuid(&self) -> Result<TypeScriptType<'static>> {
+        Ok(TypeScriptType::String)
+    }
+
+    fn translate_array(
+        &self,
+        argument: TypeScriptType<'static>,
+    ) -> Result<TypeScriptType<'static>> {
+        Ok(TypeScriptType::Array {
+            argument: Box::new(argument),
+        })
+    }
+
+    fn translate_map(
+        &self,
+        key: TypeScriptType<'static>,
+        value: TypeScriptType<'static>,
+    ) -> Result<TypeScriptType<'static>> {
+        Ok(TypeScriptType::Map {
+            key: Box::new(key),
+            value: Box::new(value),
+        })
+    }
+
+    fn translate_any(&self) -> Result<TypeScriptType<'static>> {
+        Ok(TypeScriptType::Any)
+    }
+
+    fn translate_bytes(&self) -> Result<TypeScriptType<'static>> {
+        Ok(TypeScriptType::String)
+    }
+
+    fn translate_name(&self, reg: RpReg, name: RpName) -> Result<TypeScriptType<'static>> {
+        let ident = reg.ident(&name, |p| p.join(TYPE_SEP), |c| c.join(TYPE_SEP));
+
+        if let Some(ref used) = name.prefix {
+            let package = name.package.join(".");
+            return Ok(TypeScriptType::Name {
+                js: js::imported(package, ident).alias(used.to_string()),
+            });
+        }
+
+        Ok(TypeScriptType::Name {
+            js: js::local(ident),
+        })
+    }
+
+    fn translate_package(&self, source: RpVersionedPackage) -> Result<RpPackage> {
+        Ok(self.packages.translate_package(source)?)
+    }
+
+    fn translate_local_name<T>(
+        &self,
+        translator: &T,
+        reg: RpReg,
+        name: core::RpName<CoreFlavor>,
+    ) -> Result<TypeScriptName>
+    where
+        T: Translator<Source = Self::Source, Target = Self::Target>,
+    {
+        let ident = reg.ident(&name, |p| p.join(TYPE_SEP), |v| v.join(TYPE_SEP));
+        let package = self.translate_package(name.package)?;
+
+        Ok(TypeScriptName {
+            name: js::local(ident),
+            package,
+        })
+    }
+}
+
+decl_flavor!(TypeScriptFlavor, core);
@@ -0,0 +1,57 @@
+use core::RpDeprecated;
+use genco::{IntoTokens, JavaScript, Tokens};
+
+/// Documentation comments, with a `@deprecated` tag if the item is deprecated.
+pub struct Documentation<'el>(pub &'el [String], pub &'el Option<RpDeprecated>);
+
+impl<'el> IntoTokens<'el, JavaScript<'el>> for Documentation<'el> {
+    fn into_tokens(self) -> Tokens<'el, JavaScript<'el>> {
+        let mut t = Tokens::new();
+
+        if self.0.is_empty() && self.1.is_none() {
+            return t;
+        }
+
+        t.push("/**");
+
+        for c in self.0.iter() {
+            t.push(toks![" * ", c.as_str()]);
+        }
+
+        if let Some(ref deprecated) = *self.1 {
+            t.push(toks![" * @deprecated ", deprecated.message()]);
+        }
+
+        t.push(" */");
+        t
+    }
+}
+
+pub fn is_defined<'el, S>(toks: S) -> Tokens<'el, JavaScript<'el>>
+where
+    S: Into<Tokens<'el, JavaScript<'el>>>,
+{
+    let s = toks.into();
+    toks![s.clone(), " !== null && ", s, " !== undefined"]
+}
+
+pub fn is_not_defined<'el, S>(toks: S) -> Tokens<'el, JavaScript<'el>>
+where
+    S: Into<Tokens<'el, JavaScript<'el>>>,
+{
+    let s = toks.into();
+    toks![s.clone(), " === null || ", s, " === undefined"]
+}
+
+/// Build a block with the given head, like `if (...)` or `export function foo()`.
+pub fn block<'el, H, B>(head: H, body: B) -> Tokens<'el, JavaScript<'el>>
+where
+    H: Into<Tokens<'el, JavaScript<'el>>>,
+    B: Into<Tokens<'el, JavaScript<'el>>>,
+{
+    let mut t = Tokens::new();
+    t.push(toks![head.into(), " {"]);
+    t.nested(body.into());
+    t.push("}");
+    t
+}
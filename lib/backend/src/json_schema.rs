@@ -0,0 +1,109 @@
+//! Helpers shared by backends which describe values using JSON Schema, like `jsonschema` and
+//! `openapi`.
+
+use core::errors::*;
+use core::flavored::{RpEnumBody, RpType, RpVersionedPackage};
+use core::{self, RpDefault, RpNumber, RpValidate, RpVariantValue};
+use serde_json::{Map, Value};
+
+/// Parts of the package, where the last part is suffixed with the version if present.
+pub fn package_parts(package: &RpVersionedPackage) -> Vec<String> {
+    let mut parts = package.package.parts().cloned().collect::<Vec<_>>();
+
+    if let Some(version) = package.version.as_ref() {
+        if let Some(last) = parts.last_mut() {
+            *last = format!("{}-{}", last, version);
+        }
+    }
+
+    parts
+}
+
+/// Add a description to the schema, if there is a comment.
+pub fn description(schema: &mut Map<String, Value>, comment: &[String]) {
+    if !comment.is_empty() {
+        schema.insert("description".to_string(), json!(comment.join("\n")));
+    }
+}
+
+/// Add the constraints of `#[validate(..)]` to the schema of a field.
+pub fn validate(schema: &mut Map<String, Value>, ty: &RpType, validate: &RpValidate) -> Result<()> {
+    let (min, max) = match *ty {
+        core::RpType::String => ("minLength", "maxLength"),
+        core::RpType::Array { .. } => ("minItems", "maxItems"),
+        core::RpType::Map { .. } => ("minProperties", "maxProperties"),
+        _ => ("minimum", "maximum"),
+    };
+
+    if let Some(ref n) = validate.min {
+        schema.insert(min.to_string(), bound(n)?);
+    }
+
+    if let Some(ref n) = validate.max {
+        schema.insert(max.to_string(), bound(n)?);
+    }
+
+    if let Some(ref pattern) = validate.pattern {
+        schema.insert("pattern".to_string(), json!(pattern));
+    }
+
+    Ok(())
+}
+
+/// Convert the default value of a field.
+pub fn default(default: &RpDefault) -> Result<Value> {
+    use self::RpDefault::*;
+
+    match *default {
+        Boolean(value) => Ok(json!(value)),
+        Signed { ref value, .. } | Unsigned { ref value, .. } => number(value),
+        Float(ref value) | Double(ref value) => bound(value),
+        String(ref value) => Ok(json!(value)),
+    }
+}
+
+/// Enums are described by the values of their variants.
+pub fn enum_body(body: &RpEnumBody) -> Result<Map<String, Value>> {
+    let mut values = Vec::new();
+    let mut kind = "string";
+
+    for variant in body.variants.iter() {
+        match variant.value {
+            RpVariantValue::String(string) => values.push(json!(string)),
+            RpVariantValue::Number(number) => {
+                kind = "integer";
+                values.push(self::number(number)?);
+            }
+        }
+    }
+
+    let mut schema = Map::new();
+    schema.insert("type".to_string(), json!(kind));
+    schema.insert("enum".to_string(), Value::Array(values));
+    Ok(schema)
+}
+
+/// Convert a number into an integer.
+pub fn number(number: &RpNumber) -> Result<Value> {
+    if let Some(n) = number.to_i64() {
+        return Ok(json!(n));
+    }
+
+    if let Some(n) = number.to_u64() {
+        return Ok(json!(n));
+    }
+
+    Err(format!("not a legal integer: {}", number).into())
+}
+
+/// Convert a bound into a number, which might have decimals.
+pub fn bound(n: &RpNumber) -> Result<Value> {
+    if n.decimal == 0 {
+        return number(n);
+    }
+
+    match n.to_f64() {
+        Some(f) => Ok(json!(f)),
+        None => Err(format!("not a legal number: {}", n).into()),
+    }
+}
@@ -7,11 +7,14 @@ extern crate reproto_path_parser as path_parser;
 #[cfg(feature = "repository")]
 extern crate reproto_repository as repository;
 extern crate reproto_trans as trans;
+#[macro_use]
+extern crate serde_json;
 
 #[macro_use]
 mod macros;
 mod initializer;
 mod into_bytes;
+pub mod json_schema;
 pub mod package_processor;
 
 pub use self::initializer::Initializer;
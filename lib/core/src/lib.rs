@@ -32,6 +32,8 @@ mod resolver;
 mod rp_channel;
 mod rp_code;
 mod rp_decl;
+mod rp_default;
+mod rp_deprecated;
 mod rp_endpoint;
 mod rp_enum;
 mod rp_field;
@@ -48,6 +50,7 @@ mod rp_service;
 mod rp_tuple;
 mod rp_type;
 mod rp_type_model;
+mod rp_validate;
 mod rp_value;
 mod rp_versioned_package;
 mod source;
@@ -70,6 +73,8 @@ pub use self::resolver::{EmptyResolver, Resolved, ResolvedByPrefix, Resolver};
 pub use self::rp_channel::RpChannel;
 pub use self::rp_code::{RpCode, RpContext};
 pub use self::rp_decl::{RpDecl, RpNamed};
+pub use self::rp_default::RpDefault;
+pub use self::rp_deprecated::RpDeprecated;
 pub use self::rp_endpoint::{RpAccept, RpEndpoint, RpEndpointArgument, RpEndpointHttp,
                             RpEndpointHttp1, RpHttpMethod};
 pub use self::rp_enum::{RpEnumBody, RpEnumType, RpVariant, RpVariantRef, RpVariantValue,
@@ -88,6 +93,7 @@ pub use self::rp_service::{RpServiceBody, RpServiceBodyHttp};
 pub use self::rp_tuple::RpTupleBody;
 pub use self::rp_type::RpType;
 pub use self::rp_type_model::RpTypeBody;
+pub use self::rp_validate::RpValidate;
 pub use self::rp_value::RpValue;
 pub use self::rp_versioned_package::RpVersionedPackage;
 pub use self::source::Source;
@@ -19,6 +19,8 @@ macro_rules! decl_body {
             pub ident: String,
             pub comment: Vec<String>,
             pub decls: Vec<$crate::rp_decl::RpDecl<$f>>,
+            #[serde(skip_serializing_if = "Option::is_none")]
+            pub deprecated: Option<$crate::RpDeprecated>,
             $($rest)*
         }
     };
@@ -154,6 +156,22 @@ macro_rules! translator_defaults {
             Ok(RpType::DateTime)
         }
 
+        fn translate_date(&self) -> Result<RpType<$slf::Target>> {
+            Ok(RpType::Date)
+        }
+
+        fn translate_time(&self) -> Result<RpType<$slf::Target>> {
+            Ok(RpType::Time)
+        }
+
+        fn translate_duration(&self) -> Result<RpType<$slf::Target>> {
+            Ok(RpType::Duration)
+        }
+
+        fn translate_uuid(&self) -> Result<RpType<$slf::Target>> {
+            Ok(RpType::Uuid)
+        }
+
         fn translate_array(&self, inner: RpType<$slf::Target>) -> Result<RpType<$slf::Target>> {
             Ok(RpType::Array {
                 inner: Box::new(inner),
@@ -14,10 +14,12 @@ pub enum RpContext {
     },
     Js {},
     Json {},
+    Kotlin {},
     Python {},
     Reproto {},
     Rust {},
     Swift {},
+    TypeScript {},
 }
 
 #[derive(Debug, Clone, Serialize)]
@@ -4,8 +4,8 @@ use errors::Result;
 use serde::Serialize;
 use std::fmt;
 use std::vec;
-use {Flavor, Loc, RpEnumBody, RpInterfaceBody, RpReg, RpServiceBody, RpSubType, RpTupleBody,
-     RpTypeBody, RpVariantRef, Span, Translate, Translator};
+use {Flavor, Loc, RpDeprecated, RpEnumBody, RpInterfaceBody, RpReg, RpServiceBody, RpSubType,
+     RpTupleBody, RpTypeBody, RpVariantRef, Span, Translate, Translator};
 
 /// Iterator over declarations.
 pub struct Decls<'a, F: 'static>
@@ -73,6 +73,21 @@ where
             Service(ref service) => Loc::span(service),
         }
     }
+
+    /// Get the deprecation of the named element, if it is deprecated.
+    pub fn deprecated(&self) -> Option<&'a RpDeprecated> {
+        use self::RpNamed::*;
+
+        match *self {
+            Type(body) => body.deprecated.as_ref(),
+            Tuple(tuple) => tuple.deprecated.as_ref(),
+            Interface(interface) => interface.deprecated.as_ref(),
+            SubType(sub_type) => sub_type.deprecated.as_ref(),
+            Enum(en) => en.deprecated.as_ref(),
+            EnumVariant(ref variant) => variant.deprecated.as_ref(),
+            Service(service) => service.deprecated.as_ref(),
+        }
+    }
 }
 
 #[derive(Debug, Clone, Serialize)]
@@ -150,6 +165,19 @@ where
         }
     }
 
+    /// Get the deprecation of the declaration, if it is deprecated.
+    pub fn deprecated(&self) -> Option<&RpDeprecated> {
+        use self::RpDecl::*;
+
+        match *self {
+            Type(ref body) => body.deprecated.as_ref(),
+            Interface(ref body) => body.deprecated.as_ref(),
+            Enum(ref body) => body.deprecated.as_ref(),
+            Tuple(ref body) => body.deprecated.as_ref(),
+            Service(ref body) => body.deprecated.as_ref(),
+        }
+    }
+
     /// Convert a declaration into its registered types.
     pub fn to_reg(&self) -> Vec<(&F::Name, &Span, RpReg)> {
         use self::RpDecl::*;
@@ -0,0 +1,32 @@
+//! Model for default values of fields.
+
+use std::fmt;
+use RpNumber;
+
+/// The default value of a field, as specified with `field?: u32 = 50;`.
+///
+/// The value has been checked against the type of the field, so each variant corresponds to the
+/// type it is the default for.
+#[derive(Debug, Clone, Serialize, PartialEq, Eq)]
+#[serde(tag = "type", content = "value", rename_all = "snake_case")]
+pub enum RpDefault {
+    Boolean(bool),
+    Signed { size: usize, value: RpNumber },
+    Unsigned { size: usize, value: RpNumber },
+    Float(RpNumber),
+    Double(RpNumber),
+    String(String),
+}
+
+impl fmt::Display for RpDefault {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        use self::RpDefault::*;
+
+        match *self {
+            Boolean(value) => write!(f, "{}", value),
+            Signed { ref value, .. } | Unsigned { ref value, .. } => value.fmt(f),
+            Float(ref value) | Double(ref value) => value.fmt(f),
+            String(ref value) => write!(f, "{:?}", value),
+        }
+    }
+}
@@ -0,0 +1,27 @@
+//! Model for deprecations.
+
+use std::fmt;
+
+/// Deprecation of an item, as specified with `#[deprecated(..)]`.
+#[derive(Debug, Clone, Default, Serialize, PartialEq, Eq)]
+pub struct RpDeprecated {
+    /// Message describing what to use instead.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message: Option<String>,
+}
+
+impl RpDeprecated {
+    /// Get the message of the deprecation, or a generic one if none is specified.
+    pub fn message(&self) -> &str {
+        self.message.as_ref().map(|m| m.as_str()).unwrap_or("deprecated")
+    }
+}
+
+impl fmt::Display for RpDeprecated {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self.message {
+            Some(ref message) => write!(f, "#[deprecated({:?})]", message),
+            None => write!(f, "#[deprecated]"),
+        }
+    }
+}
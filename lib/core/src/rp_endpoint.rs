@@ -3,9 +3,9 @@
 use errors::Result;
 use std::default;
 use std::rc::Rc;
-use {Attributes, Flavor, Loc, RpChannel, RpPathSpec, Translate, Translator};
+use {Attributes, Flavor, Loc, RpChannel, RpDeprecated, RpPathSpec, Translate, Translator};
 
-#[derive(Debug, Clone, Serialize)]
+#[derive(Debug, Clone, Serialize, PartialEq, Eq)]
 pub enum RpHttpMethod {
     Get,
     Post,
@@ -151,6 +151,9 @@ where
     pub name: Option<String>,
     /// Comments for documentation.
     pub comment: Vec<String>,
+    /// Deprecation of the endpoint.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub deprecated: Option<RpDeprecated>,
     /// Attributes associated with the endpoint.
     pub attributes: Attributes,
     /// Request type that this endpoint expects.
@@ -222,6 +225,7 @@ where
             safe_ident: self.safe_ident,
             name: self.name,
             comment: self.comment,
+            deprecated: self.deprecated,
             attributes: self.attributes,
             arguments: self.arguments.translate(translator)?,
             request: self.request.translate(translator)?,
@@ -4,7 +4,8 @@ use errors::Result;
 use serde::Serialize;
 use std::fmt;
 use std::vec;
-use {BigInt, Flavor, Loc, RpCode, RpNumber, RpReg, RpValue, Span, Translate, Translator};
+use {BigInt, Flavor, Loc, RpCode, RpDeprecated, RpNumber, RpReg, RpValue, Span, Translate,
+     Translator};
 
 decl_body!(pub struct RpEnumBody<F> {
     /// The type of the variant.
@@ -35,6 +36,7 @@ where
             ident: self.ident,
             comment: self.comment,
             decls: self.decls.translate(translator)?,
+            deprecated: self.deprecated,
             enum_type,
             variants: self.variants.translate(translator)?,
             codes: self.codes,
@@ -83,6 +85,7 @@ where
     pub name: &'a F::Name,
     pub ident: &'a Loc<String>,
     pub comment: &'a Vec<String>,
+    pub deprecated: &'a Option<RpDeprecated>,
     pub value: RpVariantValue<'a>,
 }
 
@@ -115,6 +118,8 @@ where
     pub name: F::Name,
     pub ident: Loc<String>,
     pub comment: Vec<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub deprecated: Option<RpDeprecated>,
     pub value: V,
 }
 
@@ -157,6 +162,7 @@ where
             name: name,
             ident: self.ident,
             comment: self.comment,
+            deprecated: self.deprecated,
             value: self.value,
         })
     }
@@ -283,6 +289,7 @@ where
                             name: &value.name,
                             ident: &value.ident,
                             comment: &value.comment,
+                            deprecated: &value.deprecated,
                             value: RpVariantValue::from(&value.value),
                         })
                     }
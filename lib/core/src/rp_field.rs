@@ -1,7 +1,7 @@
 //! Data Models for fields
 
 use errors::Result;
-use {Flavor, FlavorField, Translate, Translator};
+use {Flavor, FlavorField, RpDefault, RpDeprecated, RpValidate, Translate, Translator};
 
 #[derive(Debug, Clone, Serialize, PartialEq, Eq)]
 #[serde(bound = "F::Type: ::serde::Serialize")]
@@ -23,6 +23,18 @@ where
     /// Alias of field in JSON.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub field_as: Option<String>,
+    /// Explicit number of the field, for formats which identify fields by number.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub number: Option<u32>,
+    /// Constraints on the value of the field.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub validate: Option<RpValidate>,
+    /// Value used when the field is absent.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub default: Option<RpDefault>,
+    /// Deprecation of the field.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub deprecated: Option<RpDeprecated>,
 }
 
 impl<F: 'static> FlavorField for RpField<F>
@@ -46,6 +58,10 @@ where
             comment: Vec::new(),
             ty: ty,
             field_as: None,
+            number: None,
+            validate: None,
+            default: None,
+            deprecated: None,
         }
     }
 
@@ -112,6 +128,10 @@ where
             comment: self.comment,
             ty: translator.translate_type(self.ty)?,
             field_as: self.field_as,
+            number: self.number,
+            validate: self.validate,
+            default: self.default,
+            deprecated: self.deprecated,
         })
     }
 }
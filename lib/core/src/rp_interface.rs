@@ -2,10 +2,11 @@
 
 use errors::Result;
 use serde::Serialize;
+use std::fmt;
 use std::slice;
 use std::vec;
 use translator;
-use {Flavor, FlavorField, Loc, RpCode, RpDecl, RpReg, Translate, Translator};
+use {Flavor, FlavorField, Loc, RpCode, RpDecl, RpDeprecated, RpReg, Translate, Translator};
 
 /// Default key to use for tagged sub type strategy.
 pub const DEFAULT_TAG: &str = "type";
@@ -21,6 +22,17 @@ pub enum RpSubTypeStrategy {
     Untagged,
 }
 
+impl fmt::Display for RpSubTypeStrategy {
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        use self::RpSubTypeStrategy::*;
+
+        match *self {
+            Tagged { ref tag } => write!(fmt, "tagged (tag = {:?})", tag),
+            Untagged => fmt.write_str("untagged"),
+        }
+    }
+}
+
 impl Default for RpSubTypeStrategy {
     fn default() -> Self {
         RpSubTypeStrategy::Tagged {
@@ -107,6 +119,7 @@ where
             ident: self.ident,
             comment: self.comment,
             decls: self.decls.translate(translator)?,
+            deprecated: self.deprecated,
             fields: translator::Fields(self.fields).translate(translator)?,
             codes: self.codes,
             sub_types: self.sub_types.translate(translator)?,
@@ -133,6 +146,11 @@ where
     pub codes: Vec<Loc<RpCode>>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub sub_type_name: Option<Loc<String>>,
+    /// Explicit number of the sub-type, for formats which identify sub-types by number.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub number: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub deprecated: Option<RpDeprecated>,
 }
 
 impl<F: 'static> RpSubType<F>
@@ -178,9 +196,11 @@ where
             ident: self.ident,
             comment: self.comment,
             decls: self.decls.translate(translator)?,
+            deprecated: self.deprecated,
             fields: translator::Fields(self.fields).translate(translator)?,
             codes: self.codes,
             sub_type_name: self.sub_type_name,
+            number: self.number,
         })
     }
 }
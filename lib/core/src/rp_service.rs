@@ -39,6 +39,7 @@ where
             ident: self.ident,
             comment: self.comment,
             decls: self.decls.translate(translator)?,
+            deprecated: self.deprecated,
             http: self.http,
             endpoints: endpoints,
         })
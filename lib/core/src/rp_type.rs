@@ -23,6 +23,14 @@ where
     String,
     /// ISO-8601 datetime
     DateTime,
+    /// ISO-8601 date
+    Date,
+    /// ISO-8601 time of day
+    Time,
+    /// ISO-8601 duration
+    Duration,
+    /// Universally unique identifier
+    Uuid,
     Bytes,
     Any,
     Name {
@@ -105,6 +113,10 @@ where
             Boolean => write!(f, "boolean"),
             String => write!(f, "string"),
             DateTime => write!(f, "datetime"),
+            Date => write!(f, "date"),
+            Time => write!(f, "time"),
+            Duration => write!(f, "duration"),
+            Uuid => write!(f, "uuid"),
             Name { ref name } => write!(f, "{}", name),
             Array { ref inner } => write!(f, "[{}]", inner),
             Map { ref key, ref value } => write!(f, "{{{}: {}}}", key, value),
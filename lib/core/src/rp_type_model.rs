@@ -59,6 +59,7 @@ where
             ident: self.ident,
             comment: self.comment,
             decls: self.decls.translate(translator)?,
+            deprecated: self.deprecated,
             fields: translator::Fields(self.fields).translate(translator)?,
             codes: self.codes,
         })
@@ -0,0 +1,48 @@
+//! Model for field validation.
+
+use std::fmt;
+use RpNumber;
+
+/// Constraints on the value of a field, as specified with `#[validate(..)]`.
+///
+/// For numeric types, `min` and `max` bound the value itself. For strings they bound the number
+/// of characters, and for arrays and maps the number of items.
+#[derive(Debug, Clone, Default, Serialize, PartialEq, Eq)]
+pub struct RpValidate {
+    /// Lower bound (inclusive).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub min: Option<RpNumber>,
+    /// Upper bound (inclusive).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max: Option<RpNumber>,
+    /// Regular expression that string values must match.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub pattern: Option<String>,
+}
+
+impl RpValidate {
+    /// Check if there are no constraints.
+    pub fn is_empty(&self) -> bool {
+        self.min.is_none() && self.max.is_none() && self.pattern.is_none()
+    }
+}
+
+impl fmt::Display for RpValidate {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let mut parts = Vec::new();
+
+        if let Some(ref min) = self.min {
+            parts.push(format!("min = {}", min));
+        }
+
+        if let Some(ref max) = self.max {
+            parts.push(format!("max = {}", max));
+        }
+
+        if let Some(ref pattern) = self.pattern {
+            parts.push(format!("pattern = {:?}", pattern));
+        }
+
+        write!(f, "{}", parts.join(", "))
+    }
+}
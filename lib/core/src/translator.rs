@@ -35,6 +35,14 @@ pub trait FlavorTranslator {
 
     fn translate_datetime(&self) -> Result<<Self::Target as Flavor>::Type>;
 
+    fn translate_date(&self) -> Result<<Self::Target as Flavor>::Type>;
+
+    fn translate_time(&self) -> Result<<Self::Target as Flavor>::Type>;
+
+    fn translate_duration(&self) -> Result<<Self::Target as Flavor>::Type>;
+
+    fn translate_uuid(&self) -> Result<<Self::Target as Flavor>::Type>;
+
     fn translate_array(
         &self,
         _: <Self::Target as Flavor>::Type,
@@ -345,6 +353,10 @@ where
         let out = match source {
             String => self.flavor.translate_string()?,
             DateTime => self.flavor.translate_datetime()?,
+            Date => self.flavor.translate_date()?,
+            Time => self.flavor.translate_time()?,
+            Duration => self.flavor.translate_duration()?,
+            Uuid => self.flavor.translate_uuid()?,
             Bytes => self.flavor.translate_bytes()?,
             Signed { size: 32 } => self.flavor.translate_i32()?,
             Signed { size: 64 } => self.flavor.translate_i64()?,
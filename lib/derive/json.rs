@@ -4,7 +4,6 @@ use format;
 use linked_hash_map::LinkedHashMap;
 use serde_json as json;
 use sir::{FieldSir, Sir};
-use utils::is_datetime;
 use Opaque;
 
 #[derive(Debug)]
@@ -82,13 +81,7 @@ fn from_json(value: &json::Value) -> Result<Sir> {
             }
         }
         json::Value::Bool(_) => Sir::Boolean,
-        json::Value::String(ref string) => {
-            if is_datetime(string) {
-                Sir::DateTime(Opaque::new(vec![string.to_string()]))
-            } else {
-                Sir::String(Opaque::new(vec![string.to_string()]))
-            }
-        }
+        json::Value::String(ref string) => Sir::from_str(string),
         json::Value::Null => Sir::Any,
         json::Value::Array(ref array) => Sir::process_array(&array, from_json)?,
         json::Value::Object(ref map) => {
@@ -0,0 +1,909 @@
+//! Import declarations from JSON Schema.
+//!
+//! Every entry in `definitions` (or `$defs`) is imported as a separate declaration, and the root
+//! schema is imported using the root name if it describes a declaration.
+
+use ast::{Attribute, AttributeItem, Decl, EnumBody, EnumVariant, Field, InterfaceBody, Item,
+          Name, SubType, TupleBody, Type, TypeBody, TypeMember, Value};
+use core::errors::{Error, Result};
+use core::{Loc, RpNumber, Source, Span, DEFAULT_TAG};
+use inflector::cases::pascalcase::to_pascal_case;
+use inflector::cases::snakecase::to_snake_case;
+use serde_json as json;
+use std::borrow::Cow;
+use std::cell::RefCell;
+use std::collections::{HashMap, HashSet};
+use std::sync::Arc;
+
+type Object = json::Map<String, json::Value>;
+
+/// Import declarations from the given JSON Schema.
+pub fn import_json_schema<'input>(
+    root_name: String,
+    object: &'input Source,
+) -> Result<Vec<Decl<'input>>> {
+    let value: json::Value =
+        json::from_reader(object.read()?).map_err(|e| format!("Bad JSON: {}", e))?;
+
+    let root = value
+        .as_object()
+        .ok_or_else(|| "Expected JSON Schema to be an object")?;
+
+    let span: Span = (Arc::new(object.clone()), 0, 0).into();
+
+    let empty = Object::new();
+
+    let definitions = match root.get("definitions").or_else(|| root.get("$defs")) {
+        Some(definitions) => definitions
+            .as_object()
+            .ok_or_else(|| "Expected `definitions` to be an object")?,
+        None => &empty,
+    };
+
+    let mut importer = Importer {
+        span: &span,
+        root_name: root_name.clone(),
+        root: root,
+        definitions: definitions,
+        sub_types: HashMap::new(),
+        visiting: RefCell::new(Vec::new()),
+    };
+
+    // Definitions which are used as sub-types are imported as part of their interface.
+    if is_interface(root) {
+        importer.register_sub_types(&root_name, root)?;
+    }
+
+    for (key, schema) in definitions {
+        if let Some(schema) = schema.as_object() {
+            if is_interface(schema) {
+                importer.register_sub_types(&to_pascal_case(key), schema)?;
+            }
+        }
+    }
+
+    let mut decls = Vec::new();
+
+    if is_decl(root) {
+        decls.push(importer.decl(vec![root_name], root)?);
+    }
+
+    for (key, schema) in definitions {
+        if importer.sub_types.contains_key(key.as_str()) {
+            continue;
+        }
+
+        let schema = schema
+            .as_object()
+            .ok_or_else(|| format!("Expected definition `{}` to be an object", key))?;
+
+        if is_decl(schema) {
+            decls.push(importer.decl(vec![to_pascal_case(key)], schema)?);
+        }
+    }
+
+    if decls.is_empty() {
+        return Err("JSON Schema does not contain any declarations".into());
+    }
+
+    Ok(decls)
+}
+
+/// Check if the schema describes a declaration, as opposed to a plain type.
+fn is_decl(schema: &Object) -> bool {
+    if schema.contains_key("enum") || is_interface(schema) || schema.contains_key("properties") {
+        return true;
+    }
+
+    match schema.get("type").and_then(|t| t.as_str()) {
+        Some("array") => schema.get("items").map(|i| i.is_array()).unwrap_or(false),
+        _ => false,
+    }
+}
+
+/// Check if the schema describes a choice between multiple objects.
+fn is_interface(schema: &Object) -> bool {
+    schema.contains_key("oneOf") || schema.contains_key("anyOf")
+}
+
+/// Get the choices of an interface.
+fn choices(schema: &Object) -> &[json::Value] {
+    schema
+        .get("oneOf")
+        .or_else(|| schema.get("anyOf"))
+        .and_then(|c| c.as_array())
+        .map(|c| c.as_slice())
+        .unwrap_or(&[])
+}
+
+/// Get the local name of a reference, like `Foo` in `#/definitions/Foo`.
+fn local_ref(reference: &str) -> Option<&str> {
+    if reference.starts_with("#/definitions/") {
+        return Some(&reference["#/definitions/".len()..]);
+    }
+
+    if reference.starts_with("#/$defs/") {
+        return Some(&reference["#/$defs/".len()..]);
+    }
+
+    None
+}
+
+/// Get the string value of a `const`, or of an `enum` with a single value.
+fn constant(schema: &Object) -> Option<&str> {
+    if let Some(value) = schema.get("const") {
+        return value.as_str();
+    }
+
+    match schema.get("enum").and_then(|e| e.as_array()) {
+        Some(values) if values.len() == 1 => values[0].as_str(),
+        _ => None,
+    }
+}
+
+/// Build comments from the description of a schema.
+fn comment<'input>(schema: &Object) -> Vec<Cow<'input, str>> {
+    schema
+        .get("description")
+        .and_then(|d| d.as_str())
+        .map(|d| d.lines().map(|l| Cow::from(l.to_string())).collect())
+        .unwrap_or_default()
+}
+
+/// Build an absolute name out of the given parts.
+fn name(parts: Vec<String>) -> Name {
+    Name::Absolute {
+        prefix: None,
+        parts: Loc::new(parts.into(), Span::empty()),
+    }
+}
+
+/// Build the name of an enum variant from its value.
+///
+/// Names which would not be valid identifiers are prefixed with `Value`.
+fn variant_name(value: &json::Value) -> String {
+    let name = match *value {
+        json::Value::String(ref string) => to_pascal_case(string),
+        ref value => format!("Value{}", value).replace('-', "Minus"),
+    };
+
+    let name = name.chars()
+        .filter(|c| c.is_ascii_alphanumeric() || *c == '_')
+        .collect::<String>();
+
+    match name.chars().next() {
+        Some(c) if c.is_ascii_alphabetic() => name,
+        _ => format!("Value{}", name),
+    }
+}
+
+/// How sub-types of an interface are told apart.
+enum Strategy {
+    Tagged(String),
+    Untagged,
+}
+
+struct Importer<'a> {
+    span: &'a Span,
+    root_name: String,
+    root: &'a Object,
+    definitions: &'a Object,
+    /// Definitions which are used as sub-types, and the name of the sub-type.
+    sub_types: HashMap<&'a str, Vec<String>>,
+    /// Plain definitions which are currently being inlined, used to detect cycles.
+    visiting: RefCell<Vec<&'a str>>,
+}
+
+impl<'a> Importer<'a> {
+    /// Resolve a reference into its name, and the schema it refers to.
+    fn resolve(&self, reference: &'a str) -> Result<(Option<&'a str>, &'a Object)> {
+        if reference == "#" {
+            return Ok((None, self.root));
+        }
+
+        let key = local_ref(reference).ok_or_else(|| {
+            format!(
+                "Unsupported reference `{}`, only local references are supported",
+                reference
+            )
+        })?;
+
+        let schema = self.definitions
+            .get(key)
+            .and_then(|s| s.as_object())
+            .ok_or_else(|| format!("No such definition: {}", reference))?;
+
+        Ok((Some(key), schema))
+    }
+
+    /// Register all definitions which are referenced as sub-types by the given interface.
+    fn register_sub_types(&mut self, interface: &str, schema: &'a Object) -> Result<()> {
+        for choice in choices(schema) {
+            let reference = match choice.get("$ref").and_then(|r| r.as_str()) {
+                Some(reference) => reference,
+                None => continue,
+            };
+
+            if let (Some(key), _) = self.resolve(reference)? {
+                let parts = vec![interface.to_string(), to_pascal_case(key)];
+
+                if let Some(existing) = self.sub_types.get(key) {
+                    if *existing != parts {
+                        return Err(Error::new(format!(
+                            "definition `{}` is used as a sub-type by both `{}` and `{}`",
+                            key, existing[0], interface
+                        )).with_span(self.span.clone()));
+                    }
+                }
+
+                self.sub_types.insert(key, parts);
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Import a single declaration.
+    fn decl<'input>(&self, path: Vec<String>, schema: &'a Object) -> Result<Decl<'input>> {
+        if schema.contains_key("enum") {
+            return Ok(Decl::Enum(self.enum_body(path, schema)?));
+        }
+
+        if is_interface(schema) {
+            return Ok(Decl::Interface(self.interface_body(path, schema)?));
+        }
+
+        if let Some(items) = schema.get("items").and_then(|i| i.as_array()) {
+            return Ok(Decl::Tuple(self.tuple_body(path, schema, items)?));
+        }
+
+        Ok(Decl::Type(self.type_body(path, schema)?))
+    }
+
+    fn type_body<'input>(
+        &self,
+        path: Vec<String>,
+        schema: &'a Object,
+    ) -> Result<Item<'input, TypeBody<'input>>> {
+        let mut members = Vec::new();
+        self.fields(&path, schema, None, &mut members)?;
+
+        let body = TypeBody {
+            name: ident(&path)?.into(),
+            generics: Vec::new(),
+            members: members,
+        };
+
+        Ok(self.item(comment(schema), Vec::new(), body))
+    }
+
+    fn tuple_body<'input>(
+        &self,
+        path: Vec<String>,
+        schema: &'a Object,
+        items: &'a [json::Value],
+    ) -> Result<Item<'input, TupleBody<'input>>> {
+        let min_items = schema
+            .get("minItems")
+            .and_then(|m| m.as_u64())
+            .unwrap_or(items.len() as u64);
+
+        let mut members = Vec::new();
+
+        for (index, item) in items.iter().enumerate() {
+            let item = item.as_object()
+                .ok_or_else(|| format!("Expected item #{} to be an object", index))?;
+
+            let field = self.field(
+                &path,
+                format!("field_{}", index),
+                (index as u64) < min_items,
+                item,
+                &mut members,
+            )?;
+
+            members.push(TypeMember::Field(field));
+        }
+
+        let body = TupleBody {
+            name: ident(&path)?.into(),
+            members: members,
+        };
+
+        Ok(self.item(comment(schema), Vec::new(), body))
+    }
+
+    fn enum_body<'input>(
+        &self,
+        path: Vec<String>,
+        schema: &'a Object,
+    ) -> Result<Item<'input, EnumBody<'input>>> {
+        let values = schema
+            .get("enum")
+            .and_then(|e| e.as_array())
+            .ok_or_else(|| "Expected `enum` to be an array")?;
+
+        let mut ty = Type::String;
+        let mut variants = Vec::new();
+        let mut names = HashSet::new();
+
+        for value in values {
+            let argument = match *value {
+                json::Value::String(ref string) => Value::String(string.to_string()),
+                json::Value::Number(ref number) => {
+                    let number: RpNumber = if let Some(n) = number.as_u64() {
+                        n.into()
+                    } else if let Some(n) = number.as_i64() {
+                        n.into()
+                    } else {
+                        return Err(format!("Unsupported enum value: {}", number).into());
+                    };
+
+                    ty = match (ty, number.to_u64()) {
+                        (Type::Signed { .. }, _) | (_, None) => Type::Signed { size: 64 },
+                        _ => Type::Unsigned { size: 64 },
+                    };
+
+                    Value::Number(number)
+                }
+                ref value => return Err(format!("Unsupported enum value: {}", value).into()),
+            };
+
+            let base = variant_name(value);
+            let mut unique = base.clone();
+            let mut suffix = 2;
+
+            // NB: distinct values might map to the same name, like `foo-bar` and `foo_bar`.
+            while !names.insert(unique.clone()) {
+                unique = format!("{}{}", base, suffix);
+                suffix += 1;
+            }
+
+            let variant = EnumVariant {
+                name: Loc::new(unique.into(), self.span.clone()),
+                argument: Some(Loc::new(argument, self.span.clone())),
+            };
+
+            variants.push(self.item(Vec::new(), Vec::new(), variant));
+        }
+
+        let body = EnumBody {
+            name: ident(&path)?.into(),
+            ty: Loc::new(ty, self.span.clone()),
+            variants: variants,
+            members: Vec::new(),
+        };
+
+        Ok(self.item(comment(schema), Vec::new(), body))
+    }
+
+    /// Import an interface, where each choice is a sub-type.
+    ///
+    /// Sub-types are tagged if the schema has a `discriminator`, or if every choice has a common
+    /// property with a constant value.
+    fn interface_body<'input>(
+        &self,
+        path: Vec<String>,
+        schema: &'a Object,
+    ) -> Result<Item<'input, InterfaceBody<'input>>> {
+        let mut sub_types = Vec::new();
+
+        for (index, choice) in choices(schema).iter().enumerate() {
+            let choice = choice
+                .as_object()
+                .ok_or_else(|| format!("Expected choice #{} to be an object", index))?;
+
+            let (key, choice) = match choice.get("$ref").and_then(|r| r.as_str()) {
+                Some(reference) => self.resolve(reference)?,
+                None => (None, choice),
+            };
+
+            let sub_name = match key {
+                Some(key) => to_pascal_case(key),
+                None => choice
+                    .get("title")
+                    .and_then(|t| t.as_str())
+                    .map(to_pascal_case)
+                    .unwrap_or_else(|| format!("Choice{}", index)),
+            };
+
+            sub_types.push((key, sub_name, choice));
+        }
+
+        let strategy = self.strategy(schema, &sub_types);
+        let mut attributes = Vec::new();
+
+        match strategy {
+            Strategy::Tagged(ref tag) if tag != DEFAULT_TAG => {
+                attributes.push(self.type_info(vec![("strategy", "tagged"), ("tag", tag)]));
+            }
+            Strategy::Untagged => {
+                attributes.push(self.type_info(vec![("strategy", "untagged")]));
+            }
+            _ => {}
+        }
+
+        let tag = match strategy {
+            Strategy::Tagged(ref tag) => Some(tag.as_str()),
+            Strategy::Untagged => None,
+        };
+
+        let mapping = schema
+            .get("discriminator")
+            .and_then(|d| d.get("mapping"))
+            .and_then(|m| m.as_object());
+
+        let mut body = InterfaceBody {
+            name: ident(&path)?.into(),
+            generics: Vec::new(),
+            members: Vec::new(),
+            sub_types: Vec::new(),
+        };
+
+        for (key, sub_name, choice) in sub_types {
+            let mut members = Vec::new();
+            let mut sub_path = path.clone();
+            sub_path.push(sub_name.clone());
+
+            self.fields(&sub_path, choice, tag, &mut members)?;
+
+            let mut alias = tag.and_then(|tag| {
+                choice
+                    .get("properties")
+                    .and_then(|p| p.get(tag))
+                    .and_then(|p| p.as_object())
+                    .and_then(constant)
+                    .map(|c| c.to_string())
+            });
+
+            // NB: the mapping of an OpenAPI discriminator maps tag values to references.
+            if alias.is_none() {
+                if let (Some(mapping), Some(key)) = (mapping, key) {
+                    alias = mapping
+                        .iter()
+                        .find(|&(_, r)| r.as_str().and_then(local_ref) == Some(key))
+                        .map(|(value, _)| value.to_string());
+                }
+            }
+
+            let alias = match alias {
+                Some(ref alias) if *alias == sub_name => None,
+                alias => alias.map(|alias| Loc::new(Value::String(alias), self.span.clone())),
+            };
+
+            let sub_type = SubType {
+                name: Loc::new(sub_name.into(), self.span.clone()),
+                members: members,
+                alias: alias,
+            };
+
+            body.sub_types.push(self.item(comment(choice), Vec::new(), sub_type));
+        }
+
+        Ok(self.item(comment(schema), attributes, body))
+    }
+
+    /// Figure out the strategy used to tell sub-types apart.
+    fn strategy(&self, schema: &Object, sub_types: &[(Option<&str>, String, &Object)]) -> Strategy {
+        if let Some(tag) = schema
+            .get("discriminator")
+            .and_then(|d| d.get("propertyName"))
+            .and_then(|p| p.as_str())
+        {
+            return Strategy::Tagged(tag.to_string());
+        }
+
+        let first = match sub_types.first() {
+            Some(&(_, _, first)) => first,
+            None => return Strategy::Tagged(DEFAULT_TAG.to_string()),
+        };
+
+        let properties = match first.get("properties").and_then(|p| p.as_object()) {
+            Some(properties) => properties,
+            None => return Strategy::Untagged,
+        };
+
+        for (tag, property) in properties {
+            if property.as_object().and_then(constant).is_none() {
+                continue;
+            }
+
+            let shared = sub_types.iter().all(|&(_, _, sub_type)| {
+                sub_type
+                    .get("properties")
+                    .and_then(|p| p.get(tag.as_str()))
+                    .and_then(|p| p.as_object())
+                    .and_then(constant)
+                    .is_some()
+            });
+
+            if shared {
+                return Strategy::Tagged(tag.to_string());
+            }
+        }
+
+        Strategy::Untagged
+    }
+
+    /// Build a `#[type_info(...)]` attribute.
+    fn type_info<'input>(&self, values: Vec<(&str, &str)>) -> Loc<Attribute<'input>> {
+        let values = values
+            .into_iter()
+            .map(|(name, value)| AttributeItem::NameValue {
+                name: Loc::new(name.to_string().into(), self.span.clone()),
+                value: Loc::new(Value::String(value.to_string()), self.span.clone()),
+            })
+            .collect();
+
+        let name = Loc::new("type_info".into(), self.span.clone());
+        Loc::new(Attribute::List(name, values), self.span.clone())
+    }
+
+    /// Add a field for every property in the given schema, except `skip`.
+    fn fields<'input>(
+        &self,
+        path: &[String],
+        schema: &'a Object,
+        skip: Option<&str>,
+        members: &mut Vec<TypeMember<'input>>,
+    ) -> Result<()> {
+        let properties = match schema.get("properties").and_then(|p| p.as_object()) {
+            Some(properties) => properties,
+            None => return Ok(()),
+        };
+
+        let required = schema
+            .get("required")
+            .and_then(|r| r.as_array())
+            .map(|r| r.iter().filter_map(|r| r.as_str()).collect::<Vec<_>>())
+            .unwrap_or_default();
+
+        for (name, property) in properties {
+            if Some(name.as_str()) == skip {
+                continue;
+            }
+
+            let property = property
+                .as_object()
+                .ok_or_else(|| format!("Expected property `{}` to be an object", name))?;
+
+            let is_required = required.contains(&name.as_str());
+            let field = self.field(path, name.to_string(), is_required, property, members)?;
+            members.push(TypeMember::Field(field));
+        }
+
+        Ok(())
+    }
+
+    fn field<'input>(
+        &self,
+        path: &[String],
+        original_name: String,
+        required: bool,
+        schema: &'a Object,
+        members: &mut Vec<TypeMember<'input>>,
+    ) -> Result<Item<'input, Field<'input>>> {
+        let name = to_snake_case(&original_name);
+        let ty = self.ty(path, &name, schema, members)?;
+
+        let field_as = if name != original_name {
+            Some(original_name)
+        } else {
+            None
+        };
+
+        let field = Field {
+            required: required,
+            name: name.into(),
+            ty: Loc::new(ty.into(), self.span.clone()),
+            field_as: field_as,
+            default: None,
+        };
+
+        Ok(self.item(comment(schema), Vec::new(), field))
+    }
+
+    /// Import the type of a field.
+    ///
+    /// Declarations which are defined inline are added as inner declarations to `members`.
+    fn ty<'input>(
+        &self,
+        path: &[String],
+        field: &str,
+        schema: &'a Object,
+        members: &mut Vec<TypeMember<'input>>,
+    ) -> Result<Type> {
+        if let Some(reference) = schema.get("$ref").and_then(|r| r.as_str()) {
+            let (key, target) = self.resolve(reference)?;
+
+            let key = match key {
+                Some(key) => key,
+                None => {
+                    return Ok(Type::Name {
+                        name: name(vec![self.root_name.clone()]),
+                        arguments: Vec::new(),
+                    })
+                }
+            };
+
+            if let Some(parts) = self.sub_types.get(key) {
+                return Ok(Type::Name {
+                    name: name(parts.clone()),
+                    arguments: Vec::new(),
+                });
+            }
+
+            // NB: definitions which are plain types are inlined.
+            if !is_decl(target) {
+                if self.visiting.borrow().contains(&key) {
+                    return Err(Error::new(format!(
+                        "definition `{}` refers to itself, but is not a declaration",
+                        key
+                    )).with_span(self.span.clone()));
+                }
+
+                self.visiting.borrow_mut().push(key);
+                let ty = self.ty(path, field, target, members);
+                self.visiting.borrow_mut().pop();
+                return ty;
+            }
+
+            return Ok(Type::Name {
+                name: name(vec![to_pascal_case(key)]),
+                arguments: Vec::new(),
+            });
+        }
+
+        if is_decl(schema) {
+            let mut path = path.to_vec();
+            path.push(to_pascal_case(field));
+            members.push(TypeMember::InnerDecl(self.decl(path.clone(), schema)?));
+            return Ok(Type::Name {
+                name: name(path),
+                arguments: Vec::new(),
+            });
+        }
+
+        let ty = match schema.get("type") {
+            Some(&json::Value::String(ref ty)) => Some(ty.as_str()),
+            // NB: nullable types, like `["string", "null"]`.
+            Some(&json::Value::Array(ref types)) => types
+                .iter()
+                .filter_map(|t| t.as_str())
+                .find(|t| *t != "null"),
+            _ => None,
+        };
+
+        let format = schema.get("format").and_then(|f| f.as_str());
+
+        let ty = match ty {
+            Some("string") => {
+                let encoding = schema.get("contentEncoding").and_then(|e| e.as_str());
+
+                match (format, encoding) {
+                    (Some("date-time"), _) => Type::DateTime,
+                    (Some("date"), _) => Type::Date,
+                    (Some("time"), _) => Type::Time,
+                    (Some("duration"), _) => Type::Duration,
+                    (Some("uuid"), _) => Type::Uuid,
+                    (Some("byte"), _) | (_, Some("base64")) => Type::Bytes,
+                    _ => Type::String,
+                }
+            }
+            Some("integer") => {
+                let size = match format {
+                    Some("int32") => 32,
+                    _ => 64,
+                };
+
+                let unsigned = schema
+                    .get("minimum")
+                    .and_then(|m| m.as_f64())
+                    .map(|m| m >= 0f64)
+                    .unwrap_or(false);
+
+                if unsigned {
+                    Type::Unsigned { size: size }
+                } else {
+                    Type::Signed { size: size }
+                }
+            }
+            Some("number") => match format {
+                Some("float") => Type::Float,
+                _ => Type::Double,
+            },
+            Some("boolean") => Type::Boolean,
+            Some("array") => {
+                let inner = match schema.get("items").and_then(|i| i.as_object()) {
+                    Some(items) => self.ty(path, field, items, members)?,
+                    None => Type::Any,
+                };
+
+                Type::Array {
+                    inner: Box::new(inner),
+                }
+            }
+            Some("object") => {
+                let value = match schema.get("additionalProperties").and_then(|a| a.as_object()) {
+                    Some(value) => self.ty(path, field, value, members)?,
+                    None => Type::Any,
+                };
+
+                Type::Map {
+                    key: Box::new(Type::String),
+                    value: Box::new(value),
+                }
+            }
+            _ => Type::Any,
+        };
+
+        Ok(ty)
+    }
+
+    /// Wrap the given value in an item.
+    fn item<'input, T>(
+        &self,
+        comment: Vec<Cow<'input, str>>,
+        attributes: Vec<Loc<Attribute<'input>>>,
+        item: T,
+    ) -> Item<'input, T> {
+        Item {
+            comment: comment,
+            attributes: attributes,
+            item: Loc::new(item, self.span.clone()),
+        }
+    }
+}
+
+/// Extract the local name (last component) of a path.
+fn ident(path: &[String]) -> Result<String> {
+    path.last()
+        .cloned()
+        .ok_or_else(|| "No last component in name".into())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::import_json_schema;
+    use ast::{Decl, TypeMember};
+    use core::Source;
+
+    fn input<T>(input: &str, test: T)
+    where
+        T: Fn(Vec<Decl>) -> (),
+    {
+        let source = Source::bytes("test", input.as_bytes().iter().cloned().collect());
+        test(import_json_schema("Generator".to_string(), &source).expect("bad import"))
+    }
+
+    fn error(input: &str) -> String {
+        let source = Source::bytes("test", input.as_bytes().iter().cloned().collect());
+
+        match import_json_schema("Generator".to_string(), &source) {
+            Ok(decls) => panic!("expected error, got: {:?}", decls),
+            Err(e) => e.message().to_string(),
+        }
+    }
+
+    #[test]
+    fn test_definitions() {
+        input(
+            r##"{
+    "type": "object",
+    "properties": {
+        "point": {"$ref": "#/definitions/Point"},
+        "kind": {"enum": ["foo", "bar"]}
+    },
+    "required": ["point"],
+    "definitions": {
+        "Point": {"type": "array", "items": [{"type": "number"}, {"type": "number"}]}
+    }
+}"##,
+            |decls| {
+                assert_eq!(2, decls.len());
+
+                let ty = match decls[0] {
+                    Decl::Type(ref ty) => ty,
+                    ref other => panic!("expected type, got: {:?}", other),
+                };
+
+                // NB: two fields, and the inline enum.
+                assert_eq!(3, ty.members.len());
+
+                match ty.members[1] {
+                    TypeMember::InnerDecl(Decl::Enum(ref en)) => assert_eq!(2, en.variants.len()),
+                    ref other => panic!("expected enum, got: {:?}", other),
+                }
+
+                match decls[1] {
+                    Decl::Tuple(ref tuple) => assert_eq!(2, tuple.members.len()),
+                    ref other => panic!("expected tuple, got: {:?}", other),
+                }
+            },
+        );
+    }
+
+    #[test]
+    fn test_discriminator() {
+        input(
+            r##"{
+    "oneOf": [{"$ref": "#/definitions/Dragon"}, {"$ref": "#/definitions/Knight"}],
+    "discriminator": {"propertyName": "kind"},
+    "definitions": {
+        "Dragon": {
+            "type": "object",
+            "properties": {"kind": {"const": "dragon"}, "fire": {"type": "string"}}
+        },
+        "Knight": {
+            "type": "object",
+            "properties": {"kind": {"const": "knight"}, "armor": {"type": "string"}}
+        }
+    }
+}"##,
+            |decls| {
+                // NB: sub-types are not imported as separate declarations.
+                assert_eq!(1, decls.len());
+
+                let intf = match decls[0] {
+                    Decl::Interface(ref intf) => intf,
+                    ref other => panic!("expected interface, got: {:?}", other),
+                };
+
+                assert_eq!(1, intf.attributes.len());
+                assert_eq!(2, intf.sub_types.len());
+                assert_eq!(1, intf.sub_types[0].members.len());
+                assert!(intf.sub_types[0].alias.is_some());
+            },
+        );
+    }
+
+    #[test]
+    fn test_variant_names() {
+        input(
+            r##"{"enum": ["1st", "foo-bar", "foo_bar", "+"]}"##,
+            |decls| {
+                let en = match decls[0] {
+                    Decl::Enum(ref en) => en,
+                    ref other => panic!("expected enum, got: {:?}", other),
+                };
+
+                let names = en.variants
+                    .iter()
+                    .map(|v| v.name.to_string())
+                    .collect::<Vec<_>>();
+
+                assert_eq!(vec!["Value1st", "FooBar", "FooBar2", "Value"], names);
+            },
+        );
+    }
+
+    #[test]
+    fn test_reference_cycle() {
+        let message = error(
+            r##"{
+    "type": "object",
+    "properties": {"a": {"$ref": "#/definitions/A"}},
+    "definitions": {
+        "A": {"type": "array", "items": {"$ref": "#/definitions/A"}}
+    }
+}"##,
+        );
+
+        assert!(message.contains("`A` refers to itself"), "{}", message);
+    }
+
+    #[test]
+    fn test_sub_type_conflict() {
+        let message = error(
+            r##"{
+    "definitions": {
+        "Foo": {"oneOf": [{"$ref": "#/definitions/Shared"}]},
+        "Bar": {"oneOf": [{"$ref": "#/definitions/Shared"}]},
+        "Shared": {"type": "object", "properties": {"a": {"type": "string"}}}
+    }
+}"##,
+        );
+
+        assert!(message.contains("both `Foo` and `Bar`"), "{}", message);
+    }
+}
@@ -8,12 +8,16 @@ extern crate serde_yaml;
 
 mod format;
 mod json;
+mod json_schema;
+mod proto;
 mod sir;
 mod utils;
 mod yaml;
 
 pub use self::format::Format;
 pub use self::json::Json;
+pub use self::json_schema::import_json_schema;
+pub use self::proto::{import_proto, Proto};
 pub use self::yaml::Yaml;
 use ast::{Attribute, AttributeItem, Decl, Field, InterfaceBody, Item, Name, SubType, TupleBody,
           Type, TypeBody, TypeMember, Value};
@@ -169,6 +173,22 @@ impl<'a> FieldInit<'a> {
                 format_comment(&mut comment, examples)?;
                 Type::DateTime
             }
+            Sir::Date(ref examples) => {
+                format_comment(&mut comment, examples)?;
+                Type::Date
+            }
+            Sir::Time(ref examples) => {
+                format_comment(&mut comment, examples)?;
+                Type::Time
+            }
+            Sir::Duration(ref examples) => {
+                format_comment(&mut comment, examples)?;
+                Type::Duration
+            }
+            Sir::Uuid(ref examples) => {
+                format_comment(&mut comment, examples)?;
+                Type::Uuid
+            }
             Sir::Any => Type::Any,
             Sir::Array(ref inner) => {
                 let field = FieldSir {
@@ -207,7 +227,10 @@ impl<'a> FieldInit<'a> {
                     name
                 };
 
-                Type::Name { name: name }
+                Type::Name {
+                    name: name,
+                    arguments: Vec::new(),
+                }
             }
         };
 
@@ -222,6 +245,7 @@ impl<'a> FieldInit<'a> {
             name: name.clone().into(),
             ty: Loc::new(ty.into(), self.span.clone()),
             field_as: field_as,
+            default: None,
         };
 
         // field referencing inner declaration
@@ -320,6 +344,7 @@ impl<'a> TypeRefiner<'a> {
     ) -> Result<Item<'input, TypeBody<'input>>> {
         let mut body = TypeBody {
             name: self.ctx.ident()?.to_string().into(),
+            generics: Vec::new(),
             members: Vec::new(),
         };
 
@@ -428,6 +453,7 @@ impl<'a> InterfaceRefiner<'a> {
 
         let mut body = InterfaceBody {
             name: self.ctx.ident()?.to_string().into(),
+            generics: Vec::new(),
             members: Vec::new(),
             sub_types: Vec::new(),
         };
@@ -0,0 +1,1194 @@
+//! Import declarations from Protocol Buffers (proto3) files.
+//!
+//! The file is first parsed into a small model of messages, enums and services, which is then
+//! converted into declarations once every name in the file is known.
+
+use ast::{Attribute, AttributeItem, Channel, Decl, Endpoint, EndpointArgument, EnumBody,
+          EnumVariant, Field, Item, Name, ServiceBody, ServiceMember, Type, TypeBody, TypeMember,
+          Value};
+use core::errors::Result;
+use core::{Loc, RpNumber, RpPackage, Source, Span};
+use inflector::cases::pascalcase::to_pascal_case;
+use inflector::cases::screamingsnakecase::to_screaming_snake_case;
+use inflector::cases::snakecase::to_snake_case;
+use std::borrow::Cow;
+use std::collections::HashSet;
+use std::io::Read;
+use std::iter::Peekable;
+use std::str::Chars;
+use std::sync::Arc;
+
+/// Declarations imported from a .proto file.
+#[derive(Debug)]
+pub struct Proto<'input> {
+    /// The package declared in the file, if any.
+    pub package: Option<RpPackage>,
+    pub decls: Vec<Decl<'input>>,
+}
+
+/// Import declarations from the given .proto file.
+pub fn import_proto<'input>(object: &'input Source) -> Result<Proto<'input>> {
+    let mut content = String::new();
+    object.read()?.read_to_string(&mut content)?;
+
+    let tokens = lex(&content)?;
+    let file = Parser { tokens: tokens, pos: 0 }.file()?;
+
+    let mut names = HashSet::new();
+
+    for def in &file.defs {
+        def.register(&mut Vec::new(), &mut names);
+    }
+
+    let converter = Converter {
+        span: (Arc::new(object.clone()), 0, 0).into(),
+        package: file.package.as_ref().map(|p| p.as_str()),
+        names: names,
+    };
+
+    let mut decls = Vec::new();
+
+    for def in &file.defs {
+        decls.push(converter.def(&[], def)?);
+    }
+
+    Ok(Proto {
+        package: file.package.as_ref().map(|p| RpPackage::parse(p)),
+        decls: decls,
+    })
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Token {
+    Ident(String),
+    Number(String),
+    Str(String),
+    Symbol(char),
+}
+
+#[derive(Debug)]
+struct Spanned {
+    token: Token,
+    line: usize,
+    /// Comments directly preceding the token.
+    comment: Vec<String>,
+}
+
+/// Split the given content into tokens.
+fn lex(content: &str) -> Result<Vec<Spanned>> {
+    let mut it = content.chars().peekable();
+    let mut out = Vec::new();
+    let mut line = 1;
+    let mut comment = Vec::new();
+    // Newlines seen since the last comment, a blank line detaches the comment.
+    let mut newlines = 0;
+
+    while let Some(c) = it.next() {
+        match c {
+            '\n' => {
+                line += 1;
+                newlines += 1;
+
+                if newlines > 1 {
+                    comment.clear();
+                }
+            }
+            c if c.is_whitespace() => {}
+            '/' if it.peek() == Some(&'/') => {
+                it.next();
+                let trailing = is_trailing(&out, line);
+                let text = take_while(&mut it, |c| c != '\n');
+
+                if !trailing {
+                    comment.push(strip_comment(&text));
+                }
+
+                newlines = 0;
+            }
+            '/' if it.peek() == Some(&'*') => {
+                it.next();
+                let trailing = is_trailing(&out, line);
+                let mut text = String::new();
+
+                loop {
+                    match it.next() {
+                        Some('*') if it.peek() == Some(&'/') => {
+                            it.next();
+                            break;
+                        }
+                        Some(c) => {
+                            if c == '\n' {
+                                line += 1;
+                            }
+
+                            text.push(c);
+                        }
+                        None => return Err(format!("line {}: unterminated comment", line).into()),
+                    }
+                }
+
+                if !trailing {
+                    for l in text.lines() {
+                        let l = l.trim().trim_left_matches('*');
+                        comment.push(strip_comment(l));
+                    }
+                }
+
+                newlines = 0;
+            }
+            '"' | '\'' => {
+                let mut string = String::new();
+
+                loop {
+                    match it.next() {
+                        Some('\\') => {
+                            if let Some(c) = it.next() {
+                                string.push(c);
+                            }
+                        }
+                        Some(q) if q == c => break,
+                        Some('\n') | None => {
+                            return Err(format!("line {}: unterminated string", line).into())
+                        }
+                        Some(c) => string.push(c),
+                    }
+                }
+
+                out.push(Spanned {
+                    token: Token::Str(string),
+                    line: line,
+                    comment: comment.drain(..).collect(),
+                });
+            }
+            // NB: fully qualified names start with a `.`, like `.foo.Bar`.
+            c if c.is_alphabetic() || c == '_'
+                || (c == '.' && it.peek().map_or(false, |c| c.is_alphabetic())) =>
+            {
+                let mut ident = c.to_string();
+                ident.push_str(&take_while(&mut it, |c| {
+                    c.is_alphanumeric() || c == '_' || c == '.'
+                }));
+
+                out.push(Spanned {
+                    token: Token::Ident(ident),
+                    line: line,
+                    comment: comment.drain(..).collect(),
+                });
+            }
+            c if c.is_digit(10) || c == '-' || c == '+' => {
+                let mut number = c.to_string();
+                number.push_str(&take_while(&mut it, |c| c.is_alphanumeric() || c == '.'));
+
+                out.push(Spanned {
+                    token: Token::Number(number),
+                    line: line,
+                    comment: comment.drain(..).collect(),
+                });
+            }
+            c => {
+                out.push(Spanned {
+                    token: Token::Symbol(c),
+                    line: line,
+                    comment: comment.drain(..).collect(),
+                });
+            }
+        }
+
+        if c != '\n' && !c.is_whitespace() {
+            newlines = 0;
+        }
+    }
+
+    return Ok(out);
+
+    /// Comments following a token on the same line document that token, and not the next one.
+    fn is_trailing(out: &[Spanned], line: usize) -> bool {
+        out.last().map_or(false, |last| last.line == line)
+    }
+
+    fn take_while<F>(it: &mut Peekable<Chars>, f: F) -> String
+    where
+        F: Fn(char) -> bool,
+    {
+        let mut out = String::new();
+
+        while let Some(&c) = it.peek() {
+            if !f(c) {
+                break;
+            }
+
+            out.push(c);
+            it.next();
+        }
+
+        out
+    }
+
+    fn strip_comment(text: &str) -> String {
+        let text = if text.starts_with(' ') {
+            &text[1..]
+        } else {
+            text
+        };
+
+        text.trim_right().to_string()
+    }
+}
+
+#[derive(Debug)]
+struct ProtoFile {
+    package: Option<String>,
+    defs: Vec<Def>,
+}
+
+#[derive(Debug)]
+enum Def {
+    Message(Message),
+    Enum(Enum),
+    Service(Service),
+}
+
+impl Def {
+    fn name(&self) -> &str {
+        match *self {
+            Def::Message(ref message) => &message.name,
+            Def::Enum(ref en) => &en.name,
+            Def::Service(ref service) => &service.name,
+        }
+    }
+
+    /// Register the full name of this definition, and all nested definitions.
+    fn register(&self, path: &mut Vec<String>, names: &mut HashSet<Vec<String>>) {
+        path.push(self.name().to_string());
+        names.insert(path.clone());
+
+        if let Def::Message(ref message) = *self {
+            for def in &message.defs {
+                def.register(path, names);
+            }
+        }
+
+        path.pop();
+    }
+}
+
+#[derive(Debug)]
+struct Message {
+    name: String,
+    comment: Vec<String>,
+    fields: Vec<ProtoField>,
+    defs: Vec<Def>,
+}
+
+#[derive(Debug, PartialEq)]
+enum Label {
+    None,
+    Optional,
+    Required,
+    Repeated,
+}
+
+#[derive(Debug)]
+enum FieldType {
+    Named(String),
+    Map(String, String),
+}
+
+#[derive(Debug)]
+struct ProtoField {
+    name: String,
+    comment: Vec<String>,
+    line: usize,
+    label: Label,
+    ty: FieldType,
+    number: u32,
+    json_name: Option<String>,
+    /// The oneof that this field is part of.
+    oneof: Option<String>,
+}
+
+#[derive(Debug)]
+struct Enum {
+    name: String,
+    comment: Vec<String>,
+    values: Vec<(String, Vec<String>)>,
+}
+
+#[derive(Debug)]
+struct Service {
+    name: String,
+    comment: Vec<String>,
+    rpcs: Vec<Rpc>,
+}
+
+#[derive(Debug)]
+struct Rpc {
+    name: String,
+    comment: Vec<String>,
+    line: usize,
+    request: (bool, String),
+    response: (bool, String),
+}
+
+struct Parser {
+    tokens: Vec<Spanned>,
+    pos: usize,
+}
+
+impl Parser {
+    fn peek(&self) -> Option<&Token> {
+        self.tokens.get(self.pos).map(|t| &t.token)
+    }
+
+    /// The current line, used for errors.
+    fn line(&self) -> usize {
+        self.tokens
+            .get(self.pos)
+            .or_else(|| self.tokens.last())
+            .map(|t| t.line)
+            .unwrap_or(1)
+    }
+
+    fn next(&mut self) -> Result<&Spanned> {
+        match self.tokens.get(self.pos) {
+            Some(token) => {
+                self.pos += 1;
+                Ok(token)
+            }
+            None => Err("unexpected end of file".into()),
+        }
+    }
+
+    fn is_symbol(&self, symbol: char) -> bool {
+        self.peek() == Some(&Token::Symbol(symbol))
+    }
+
+    fn symbol(&mut self, symbol: char) -> Result<()> {
+        let line = self.line();
+
+        match self.next()?.token {
+            Token::Symbol(c) if c == symbol => Ok(()),
+            ref token => {
+                Err(format!("line {}: expected `{}`, got {:?}", line, symbol, token).into())
+            }
+        }
+    }
+
+    /// Take an identifier, together with the comment preceding it.
+    fn ident(&mut self) -> Result<(String, Vec<String>)> {
+        let line = self.line();
+        let next = self.next()?;
+
+        match next.token {
+            Token::Ident(ref ident) => Ok((ident.to_string(), next.comment.clone())),
+            ref token => Err(format!("line {}: expected identifier, got {:?}", line, token).into()),
+        }
+    }
+
+    fn keyword(&mut self, keyword: &str) -> Result<()> {
+        let line = self.line();
+        let (ident, _) = self.ident()?;
+
+        if ident != keyword {
+            return Err(format!("line {}: expected `{}`, got `{}`", line, keyword, ident).into());
+        }
+
+        Ok(())
+    }
+
+    fn string(&mut self) -> Result<String> {
+        let line = self.line();
+
+        match self.next()?.token {
+            Token::Str(ref string) => Ok(string.to_string()),
+            ref token => Err(format!("line {}: expected string, got {:?}", line, token).into()),
+        }
+    }
+
+    /// Skip a statement, like an option, up to and including the terminating `;`.
+    fn skip_statement(&mut self) -> Result<()> {
+        let mut depth = 0;
+
+        loop {
+            match self.next()?.token {
+                Token::Symbol('{') => depth += 1,
+                Token::Symbol('}') => depth -= 1,
+                Token::Symbol(';') if depth == 0 => return Ok(()),
+                _ => {}
+            }
+        }
+    }
+
+    /// Parse field options, like `[json_name = "foo", deprecated = true]`.
+    ///
+    /// Returns the `json_name` option, if present.
+    fn field_options(&mut self) -> Result<Option<String>> {
+        let mut json_name = None;
+
+        if !self.is_symbol('[') {
+            return Ok(json_name);
+        }
+
+        self.symbol('[')?;
+
+        loop {
+            let (name, _) = self.ident()?;
+            self.symbol('=')?;
+
+            let line = self.line();
+
+            match self.next()?.token {
+                Token::Str(ref value) if name == "json_name" => {
+                    json_name = Some(value.to_string());
+                }
+                Token::Symbol(c) => {
+                    return Err(format!("line {}: unexpected `{}` in options", line, c).into())
+                }
+                _ => {}
+            }
+
+            if self.is_symbol(',') {
+                self.symbol(',')?;
+                continue;
+            }
+
+            self.symbol(']')?;
+            return Ok(json_name);
+        }
+    }
+
+    fn file(mut self) -> Result<ProtoFile> {
+        let mut package = None;
+        let mut defs = Vec::new();
+
+        while self.peek().is_some() {
+            let line = self.line();
+
+            if self.is_symbol(';') {
+                self.symbol(';')?;
+                continue;
+            }
+
+            let (keyword, comment) = self.ident()?;
+
+            match keyword.as_str() {
+                "syntax" => {
+                    self.symbol('=')?;
+                    let syntax = self.string()?;
+
+                    if syntax != "proto3" {
+                        return Err(format!(
+                            "line {}: unsupported syntax `{}`, only proto3 is supported",
+                            line, syntax
+                        ).into());
+                    }
+
+                    self.symbol(';')?;
+                }
+                "package" => {
+                    package = Some(self.ident()?.0);
+                    self.symbol(';')?;
+                }
+                // NB: types from imported files can't be resolved, which is reported when they
+                // are used.
+                "import" | "option" => self.skip_statement()?,
+                "message" => defs.push(Def::Message(self.message(comment)?)),
+                "enum" => defs.push(Def::Enum(self.enum_(comment)?)),
+                "service" => defs.push(Def::Service(self.service(comment)?)),
+                other => {
+                    return Err(format!("line {}: unexpected `{}`", line, other).into());
+                }
+            }
+        }
+
+        Ok(ProtoFile {
+            package: package,
+            defs: defs,
+        })
+    }
+
+    fn message(&mut self, comment: Vec<String>) -> Result<Message> {
+        let (name, _) = self.ident()?;
+        self.symbol('{')?;
+
+        let mut message = Message {
+            name: name,
+            comment: comment,
+            fields: Vec::new(),
+            defs: Vec::new(),
+        };
+
+        self.message_body(&mut message, None)?;
+        Ok(message)
+    }
+
+    /// Parse the body of a message or a oneof, up to and including the closing `}`.
+    fn message_body(&mut self, message: &mut Message, oneof: Option<&str>) -> Result<()> {
+        loop {
+            if self.is_symbol('}') {
+                self.symbol('}')?;
+                return Ok(());
+            }
+
+            if self.is_symbol(';') {
+                self.symbol(';')?;
+                continue;
+            }
+
+            let line = self.line();
+            let (keyword, comment) = self.ident()?;
+
+            match keyword.as_str() {
+                "option" | "reserved" | "extensions" => self.skip_statement()?,
+                "message" if oneof.is_none() => {
+                    message.defs.push(Def::Message(self.message(comment)?));
+                }
+                "enum" if oneof.is_none() => {
+                    message.defs.push(Def::Enum(self.enum_(comment)?));
+                }
+                "oneof" if oneof.is_none() => {
+                    let (name, _) = self.ident()?;
+                    self.symbol('{')?;
+                    self.message_body(message, Some(name.as_str()))?;
+                }
+                "map" => {
+                    self.symbol('<')?;
+                    let (key, _) = self.ident()?;
+                    self.symbol(',')?;
+                    let (value, _) = self.ident()?;
+                    self.symbol('>')?;
+
+                    let ty = FieldType::Map(key, value);
+                    let field = self.field(line, comment, Label::None, ty, oneof)?;
+                    message.fields.push(field);
+                }
+                label => {
+                    let (label, ty) = match label {
+                        "optional" => (Label::Optional, self.ident()?.0),
+                        "required" => (Label::Required, self.ident()?.0),
+                        "repeated" => (Label::Repeated, self.ident()?.0),
+                        ty => (Label::None, ty.to_string()),
+                    };
+
+                    let ty = FieldType::Named(ty);
+                    let field = self.field(line, comment, label, ty, oneof)?;
+                    message.fields.push(field);
+                }
+            }
+        }
+    }
+
+    /// Parse the rest of a field, after its type.
+    fn field(
+        &mut self,
+        line: usize,
+        comment: Vec<String>,
+        label: Label,
+        ty: FieldType,
+        oneof: Option<&str>,
+    ) -> Result<ProtoField> {
+        let (name, _) = self.ident()?;
+        self.symbol('=')?;
+
+        let number_line = self.line();
+
+        let number = match self.next()?.token {
+            Token::Number(ref number) => number
+                .parse::<u32>()
+                .map_err(|_| format!("line {}: illegal field number: {}", number_line, number))?,
+            ref token => {
+                return Err(format!("line {}: expected field number, got {:?}", number_line, token)
+                    .into())
+            }
+        };
+
+        let json_name = self.field_options()?;
+        self.symbol(';')?;
+
+        Ok(ProtoField {
+            name: name,
+            comment: comment,
+            line: line,
+            label: label,
+            ty: ty,
+            number: number,
+            json_name: json_name,
+            oneof: oneof.map(|o| o.to_string()),
+        })
+    }
+
+    fn enum_(&mut self, comment: Vec<String>) -> Result<Enum> {
+        let (name, _) = self.ident()?;
+        self.symbol('{')?;
+
+        let mut values = Vec::new();
+
+        loop {
+            if self.is_symbol('}') {
+                self.symbol('}')?;
+                break;
+            }
+
+            if self.is_symbol(';') {
+                self.symbol(';')?;
+                continue;
+            }
+
+            let (value, comment) = self.ident()?;
+
+            match value.as_str() {
+                "option" | "reserved" => self.skip_statement()?,
+                _ => {
+                    self.skip_statement()?;
+                    values.push((value, comment));
+                }
+            }
+        }
+
+        Ok(Enum {
+            name: name,
+            comment: comment,
+            values: values,
+        })
+    }
+
+    fn service(&mut self, comment: Vec<String>) -> Result<Service> {
+        let (name, _) = self.ident()?;
+        self.symbol('{')?;
+
+        let mut rpcs = Vec::new();
+
+        loop {
+            if self.is_symbol('}') {
+                self.symbol('}')?;
+                break;
+            }
+
+            if self.is_symbol(';') {
+                self.symbol(';')?;
+                continue;
+            }
+
+            let line = self.line();
+            let (keyword, comment) = self.ident()?;
+
+            match keyword.as_str() {
+                "option" => self.skip_statement()?,
+                "rpc" => {
+                    let (name, _) = self.ident()?;
+                    let request = self.rpc_type()?;
+                    self.keyword("returns")?;
+                    let response = self.rpc_type()?;
+
+                    // NB: options of the rpc are ignored.
+                    if self.is_symbol('{') {
+                        self.symbol('{')?;
+
+                        while !self.is_symbol('}') {
+                            self.skip_statement()?;
+                        }
+
+                        self.symbol('}')?;
+                    } else {
+                        self.symbol(';')?;
+                    }
+
+                    rpcs.push(Rpc {
+                        name: name,
+                        comment: comment,
+                        line: line,
+                        request: request,
+                        response: response,
+                    });
+                }
+                other => {
+                    return Err(format!("line {}: unexpected `{}` in service", line, other).into());
+                }
+            }
+        }
+
+        Ok(Service {
+            name: name,
+            comment: comment,
+            rpcs: rpcs,
+        })
+    }
+
+    /// Parse the type of an rpc, like `(stream Foo)`.
+    fn rpc_type(&mut self) -> Result<(bool, String)> {
+        self.symbol('(')?;
+
+        let (mut ty, _) = self.ident()?;
+        let mut stream = false;
+
+        if ty == "stream" && !self.is_symbol(')') {
+            stream = true;
+            ty = self.ident()?.0;
+        }
+
+        self.symbol(')')?;
+        Ok((stream, ty))
+    }
+}
+
+/// Well-known types, and the type used for them.
+///
+/// The wrapper types are represented in JSON using their wrapped value.
+fn well_known(name: &str) -> Option<Type> {
+    let ty = match name {
+        "google.protobuf.Timestamp" => Type::DateTime,
+        "google.protobuf.Duration" => Type::Duration,
+        "google.protobuf.FieldMask" => Type::String,
+        "google.protobuf.Any" | "google.protobuf.Struct" | "google.protobuf.Value" => Type::Any,
+        "google.protobuf.ListValue" => Type::Array {
+            inner: Box::new(Type::Any),
+        },
+        "google.protobuf.DoubleValue" => Type::Double,
+        "google.protobuf.FloatValue" => Type::Float,
+        "google.protobuf.Int64Value" => Type::Signed { size: 64 },
+        "google.protobuf.UInt64Value" => Type::Unsigned { size: 64 },
+        "google.protobuf.Int32Value" => Type::Signed { size: 32 },
+        "google.protobuf.UInt32Value" => Type::Unsigned { size: 32 },
+        "google.protobuf.BoolValue" => Type::Boolean,
+        "google.protobuf.StringValue" => Type::String,
+        "google.protobuf.BytesValue" => Type::Bytes,
+        _ => return None,
+    };
+
+    Some(ty)
+}
+
+/// Scalar types.
+fn scalar(name: &str) -> Option<Type> {
+    let ty = match name {
+        "double" => Type::Double,
+        "float" => Type::Float,
+        "int32" | "sint32" | "sfixed32" => Type::Signed { size: 32 },
+        "int64" | "sint64" | "sfixed64" => Type::Signed { size: 64 },
+        "uint32" | "fixed32" => Type::Unsigned { size: 32 },
+        "uint64" | "fixed64" => Type::Unsigned { size: 64 },
+        "bool" => Type::Boolean,
+        "string" => Type::String,
+        "bytes" => Type::Bytes,
+        _ => return None,
+    };
+
+    Some(ty)
+}
+
+/// The empty message, which is used for rpcs without a request or a response.
+const EMPTY: &str = "google.protobuf.Empty";
+
+struct Converter<'a> {
+    span: Span,
+    package: Option<&'a str>,
+    /// Full names of all definitions in the file.
+    names: HashSet<Vec<String>>,
+}
+
+impl<'a> Converter<'a> {
+    fn item<'input, T>(&self, comment: &[String], item: T) -> Item<'input, T> {
+        Item {
+            comment: comment.iter().map(|c| Cow::from(c.to_string())).collect(),
+            attributes: Vec::new(),
+            item: Loc::new(item, self.span.clone()),
+        }
+    }
+
+    /// Pin the number of a field with `#[number(..)]`, to keep it wire-compatible.
+    fn number<'input>(&self, number: u32) -> Loc<Attribute<'input>> {
+        let value = Value::Number(RpNumber::from(number));
+        let item = AttributeItem::Word(Loc::new(value, self.span.clone()));
+        let name = Loc::new(Cow::from("number"), self.span.clone());
+        Loc::new(Attribute::List(name, vec![item]), self.span.clone())
+    }
+
+    fn def<'input>(&self, scope: &[String], def: &Def) -> Result<Decl<'input>> {
+        let mut path = scope.to_vec();
+        path.push(def.name().to_string());
+
+        let decl = match *def {
+            Def::Message(ref message) => Decl::Type(self.message(path, message)?),
+            Def::Enum(ref en) => Decl::Enum(self.enum_(en)?),
+            Def::Service(ref service) => Decl::Service(self.service(service)?),
+        };
+
+        Ok(decl)
+    }
+
+    fn message<'input>(
+        &self,
+        path: Vec<String>,
+        message: &Message,
+    ) -> Result<Item<'input, TypeBody<'input>>> {
+        let mut members = Vec::new();
+
+        for field in &message.fields {
+            let mut ty = match field.ty {
+                FieldType::Named(ref name) => self.ty(&path, name, field.line)?,
+                FieldType::Map(ref key, ref value) => Type::Map {
+                    key: Box::new(self.ty(&path, key, field.line)?),
+                    value: Box::new(self.ty(&path, value, field.line)?),
+                },
+            };
+
+            if field.label == Label::Repeated {
+                ty = Type::Array {
+                    inner: Box::new(ty),
+                };
+            }
+
+            let name = to_snake_case(&field.name);
+
+            let json_name = match field.json_name {
+                Some(ref json_name) => json_name.to_string(),
+                None => default_json_name(&field.name),
+            };
+
+            let field_as = if json_name != name {
+                Some(json_name)
+            } else {
+                None
+            };
+
+            let mut comment = field.comment.clone();
+
+            if let Some(ref oneof) = field.oneof {
+                if !comment.is_empty() {
+                    comment.push("".to_string());
+                }
+
+                comment.push(format!("At most one field of `{}` is set.", oneof));
+            }
+
+            let number = field.number;
+
+            // NB: fields in proto3 are all optional, since default values are not sent.
+            let field = Field {
+                required: field.label == Label::Required,
+                name: name.into(),
+                ty: Loc::new(ty.into(), self.span.clone()),
+                field_as: field_as,
+                default: None,
+            };
+
+            let mut item = self.item(&comment, field);
+            item.attributes.push(self.number(number));
+            members.push(TypeMember::Field(item));
+        }
+
+        for def in &message.defs {
+            members.push(TypeMember::InnerDecl(self.def(&path, def)?));
+        }
+
+        let body = TypeBody {
+            name: message.name.to_string().into(),
+            generics: Vec::new(),
+            members: members,
+        };
+
+        Ok(self.item(&message.comment, body))
+    }
+
+    /// Enums are represented using the names of their values, like in JSON.
+    fn enum_<'input>(&self, en: &Enum) -> Result<Item<'input, EnumBody<'input>>> {
+        // NB: values are commonly prefixed with the name of the enum, like `KIND_FOO`.
+        let prefix = format!("{}_", to_screaming_snake_case(&en.name));
+
+        let mut variants = Vec::new();
+
+        for &(ref value, ref comment) in &en.values {
+            let name = if value.starts_with(&prefix) && value.len() > prefix.len() {
+                &value[prefix.len()..]
+            } else {
+                value.as_str()
+            };
+
+            let variant = EnumVariant {
+                name: Loc::new(to_pascal_case(name).into(), self.span.clone()),
+                argument: Some(Loc::new(Value::String(value.to_string()), self.span.clone())),
+            };
+
+            variants.push(self.item(comment, variant));
+        }
+
+        let body = EnumBody {
+            name: en.name.to_string().into(),
+            ty: Loc::new(Type::String, self.span.clone()),
+            variants: variants,
+            members: Vec::new(),
+        };
+
+        Ok(self.item(&en.comment, body))
+    }
+
+    fn service<'input>(&self, service: &Service) -> Result<Item<'input, ServiceBody<'input>>> {
+        let mut members = Vec::new();
+
+        for rpc in &service.rpcs {
+            let mut arguments = Vec::new();
+
+            let (stream, ref request) = rpc.request;
+
+            if stream || !self.is_empty(request) {
+                let ty = self.ty(&[], request, rpc.line)?;
+
+                arguments.push(EndpointArgument {
+                    ident: Loc::new("request".into(), self.span.clone()),
+                    channel: Loc::new(channel(stream, ty), self.span.clone()),
+                });
+            }
+
+            let (stream, ref response) = rpc.response;
+
+            let response = if stream || !self.is_empty(response) {
+                let ty = self.ty(&[], response, rpc.line)?;
+                Some(Loc::new(channel(stream, ty), self.span.clone()))
+            } else {
+                None
+            };
+
+            let id = to_snake_case(&rpc.name);
+
+            // NB: the name of the rpc is what is used over the wire.
+            let alias = if id != rpc.name {
+                Some(rpc.name.to_string())
+            } else {
+                None
+            };
+
+            let endpoint = Endpoint {
+                id: Loc::new(id.into(), self.span.clone()),
+                alias: alias,
+                arguments: arguments,
+                response: response,
+            };
+
+            members.push(ServiceMember::Endpoint(self.item(&rpc.comment, endpoint)));
+        }
+
+        let body = ServiceBody {
+            name: service.name.to_string().into(),
+            members: members,
+        };
+
+        return Ok(self.item(&service.comment, body));
+
+        fn channel(stream: bool, ty: Type) -> Channel {
+            if stream {
+                Channel::Streaming { ty: ty }
+            } else {
+                Channel::Unary { ty: ty }
+            }
+        }
+    }
+
+    fn is_empty(&self, name: &str) -> bool {
+        name.trim_left_matches('.') == EMPTY
+    }
+
+    /// Resolve the type with the given name, as used in the given scope.
+    ///
+    /// Like in protobuf, names are first looked up in the innermost scope, and then in each
+    /// enclosing scope.
+    fn ty(&self, scope: &[String], name: &str, line: usize) -> Result<Type> {
+        if let Some(ty) = scalar(name) {
+            return Ok(ty);
+        }
+
+        let absolute = name.starts_with('.');
+        let name = name.trim_left_matches('.');
+
+        if let Some(ty) = well_known(name) {
+            return Ok(ty);
+        }
+
+        let local = match self.package {
+            Some(package) if name.starts_with(&format!("{}.", package)) => {
+                &name[package.len() + 1..]
+            }
+            _ => name,
+        };
+
+        let parts = local.split('.').map(|p| p.to_string()).collect::<Vec<_>>();
+
+        let candidates = if absolute {
+            vec![parts]
+        } else {
+            (0..scope.len() + 1)
+                .rev()
+                .map(|n| {
+                    let mut candidate = scope[..n].to_vec();
+                    candidate.extend(parts.iter().cloned());
+                    candidate
+                })
+                .collect()
+        };
+
+        for candidate in candidates {
+            if self.names.contains(&candidate) {
+                return Ok(Type::Name {
+                    name: Name::Absolute {
+                        prefix: None,
+                        parts: Loc::new(candidate.into(), Span::empty()),
+                    },
+                    arguments: Vec::new(),
+                });
+            }
+        }
+
+        Err(format!(
+            "line {}: cannot resolve type `{}`, types from imported files are not supported",
+            line, name
+        ).into())
+    }
+}
+
+/// The JSON name proto3 uses for a field without a `json_name` option.
+///
+/// Underscores are removed, and the character following each underscore is upper-cased.
+fn default_json_name(name: &str) -> String {
+    let mut out = String::new();
+    let mut upper = false;
+
+    for c in name.chars() {
+        if c == '_' {
+            upper = true;
+            continue;
+        }
+
+        if upper {
+            out.extend(c.to_uppercase());
+            upper = false;
+        } else {
+            out.push(c);
+        }
+    }
+
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::import_proto;
+    use ast::{Decl, ServiceMember, TypeMember};
+    use core::Source;
+
+    #[test]
+    fn test_import() {
+        let input = r#"
+syntax = "proto3";
+
+package test;
+
+// A single entry.
+message Entry {
+  string name = 1;
+  repeated Tag tags = 2 [json_name = "allTags"];
+  map<string, int32> counts = 3;
+
+  oneof value {
+    string text = 4;
+    Inner inner = 5;
+  }
+
+  message Inner {
+    Kind kind = 1;
+  }
+}
+
+message Tag {
+  string key = 1;
+}
+
+enum Kind {
+  KIND_UNSPECIFIED = 0;
+  KIND_FOO = 1;
+}
+
+service Entries {
+  rpc GetEntry (Tag) returns (Entry);
+  rpc WatchEntries (google.protobuf.Empty) returns (stream Entry) {}
+}
+"#;
+
+        let source = Source::bytes("test", input.as_bytes().iter().cloned().collect());
+        let proto = import_proto(&source).expect("bad import");
+
+        assert!(proto.package.is_some());
+        assert_eq!(4, proto.decls.len());
+
+        let entry = match proto.decls[0] {
+            Decl::Type(ref ty) => ty,
+            ref other => panic!("expected type, got: {:?}", other),
+        };
+
+        assert_eq!(vec!["A single entry."], entry.comment);
+        // NB: five fields and the inner message.
+        assert_eq!(6, entry.members.len());
+
+        match entry.members[1] {
+            TypeMember::Field(ref field) => {
+                assert_eq!(Some("allTags".to_string()), field.field_as);
+                assert_eq!(1, field.attributes.len());
+            }
+            ref other => panic!("expected field, got: {:?}", other),
+        }
+
+        match proto.decls[2] {
+            Decl::Enum(ref en) => assert_eq!("Foo", &**en.variants[1].name),
+            ref other => panic!("expected enum, got: {:?}", other),
+        }
+
+        let service = match proto.decls[3] {
+            Decl::Service(ref service) => service,
+            ref other => panic!("expected service, got: {:?}", other),
+        };
+
+        match service.members[1] {
+            ServiceMember::Endpoint(ref endpoint) => {
+                assert_eq!("watch_entries", &**endpoint.id);
+                assert!(endpoint.arguments.is_empty());
+            }
+            ref other => panic!("expected endpoint, got: {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_fields() {
+        let input = r#"
+syntax = "proto3";
+
+message Entry {
+  int32 a = 1; // about a
+  int32 display_name = 2; /* about display_name */
+  // about b
+  int32 b = 3;
+}
+"#;
+
+        let source = Source::bytes("test", input.as_bytes().iter().cloned().collect());
+        let proto = import_proto(&source).expect("bad import");
+
+        let entry = match proto.decls[0] {
+            Decl::Type(ref ty) => ty,
+            ref other => panic!("expected type, got: {:?}", other),
+        };
+
+        let fields = entry
+            .members
+            .iter()
+            .map(|m| match *m {
+                TypeMember::Field(ref field) => field,
+                ref other => panic!("expected field, got: {:?}", other),
+            })
+            .collect::<Vec<_>>();
+
+        assert!(fields[0].comment.is_empty());
+        assert!(fields[1].comment.is_empty());
+        assert_eq!(vec!["about b"], fields[2].comment);
+
+        assert_eq!(None, fields[0].field_as);
+        assert_eq!(Some("displayName".to_string()), fields[1].field_as);
+    }
+}
@@ -3,6 +3,7 @@ use format;
 use linked_hash_map::LinkedHashMap;
 use std::collections::HashSet;
 use std::mem;
+use utils::{is_date, is_datetime, is_duration, is_time, is_uuid};
 use Opaque;
 
 /// Results from calling `Sir::test_interface`.
@@ -23,6 +24,10 @@ pub enum Sir {
     Boolean,
     String(Opaque<Vec<String>>),
     DateTime(Opaque<Vec<String>>),
+    Date(Opaque<Vec<String>>),
+    Time(Opaque<Vec<String>>),
+    Duration(Opaque<Vec<String>>),
+    Uuid(Opaque<Vec<String>>),
     Any,
     Object(LinkedHashMap<String, FieldSir>),
     Interface(String, Vec<SubTypeSir>),
@@ -31,6 +36,25 @@ pub enum Sir {
 }
 
 impl Sir {
+    /// Build the SIR for a string sample, detecting the well-known formats it might be in.
+    pub fn from_str(string: &str) -> Sir {
+        let examples = Opaque::new(vec![string.to_string()]);
+
+        if is_datetime(string) {
+            Sir::DateTime(examples)
+        } else if is_date(string) {
+            Sir::Date(examples)
+        } else if is_time(string) {
+            Sir::Time(examples)
+        } else if is_duration(string) {
+            Sir::Duration(examples)
+        } else if is_uuid(string) {
+            Sir::Uuid(examples)
+        } else {
+            Sir::String(examples)
+        }
+    }
+
     /// Recursively simplify this SIR into one that is only used for hashing.
     fn hash(&self) -> HashSir {
         match *self {
@@ -41,6 +65,10 @@ impl Sir {
             | Sir::Boolean
             | Sir::String(_)
             | Sir::DateTime(_)
+            | Sir::Date(_)
+            | Sir::Time(_)
+            | Sir::Duration(_)
+            | Sir::Uuid(_)
             | Sir::Any => HashSir::Scalar,
             Sir::Object(ref fields) => {
                 HashSir::Object(fields.iter().map(|(k, v)| (k.clone(), v.hash())).collect())
@@ -74,6 +102,21 @@ impl Sir {
         }
     }
 
+    /// Get the examples of a SIR which is represented as a string.
+    fn string_examples(&self) -> Option<&[String]> {
+        use self::Sir::*;
+
+        match *self {
+            String(ref examples)
+            | DateTime(ref examples)
+            | Date(ref examples)
+            | Time(ref examples)
+            | Duration(ref examples)
+            | Uuid(ref examples) => Some(examples),
+            _ => None,
+        }
+    }
+
     /// Check if this is an object.
     fn as_object(&self) -> Option<&LinkedHashMap<String, FieldSir>> {
         use self::Sir::*;
@@ -98,6 +141,16 @@ impl Sir {
             (&Sir::U64(_), &Sir::Double) | (&Sir::I64(_), &Sir::Double) => Some(Sir::Double),
             // current value unknown, replace with other.
             (&Sir::Any, other) if *other != Sir::Any => Some(other.clone()),
+            // strings in different formats, fall back to a plain string.
+            (current, other) if mem::discriminant(current) != mem::discriminant(other) => {
+                match (current.string_examples(), other.string_examples()) {
+                    (Some(current), Some(other)) => {
+                        let examples = current.iter().chain(other.iter()).cloned().collect();
+                        Some(Sir::String(Opaque::new(examples)))
+                    }
+                    _ => None,
+                }
+            }
             _ => None,
         };
 
@@ -120,7 +173,11 @@ impl Sir {
             (&mut Sir::String(ref mut examples), &Sir::String(ref other)) => {
                 examples.extend(other.iter().cloned());
             }
-            (&mut Sir::DateTime(ref mut examples), &Sir::DateTime(ref other)) => {
+            (&mut Sir::DateTime(ref mut examples), &Sir::DateTime(ref other))
+            | (&mut Sir::Date(ref mut examples), &Sir::Date(ref other))
+            | (&mut Sir::Time(ref mut examples), &Sir::Time(ref other))
+            | (&mut Sir::Duration(ref mut examples), &Sir::Duration(ref other))
+            | (&mut Sir::Uuid(ref mut examples), &Sir::Uuid(ref other)) => {
                 examples.extend(other.iter().cloned());
             }
             (&mut Sir::Object(ref mut entries), &Sir::Object(ref other)) => {
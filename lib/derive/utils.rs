@@ -58,6 +58,93 @@ pub fn is_datetime(input: &str) -> bool {
     }
 }
 
+/// Check if the given input looks like a date type, like `2018-02-01`.
+pub fn is_date(input: &str) -> bool {
+    parts(input, '-', &[4, 2, 2], is_digit)
+}
+
+/// Check if the given input looks like a time type, like `12:30:00`.
+pub fn is_time(input: &str) -> bool {
+    parts(input, ':', &[2, 2, 2], is_digit)
+}
+
+/// Check if the given input looks like an ISO-8601 duration, like `P1DT12H` or `PT0.5S`.
+pub fn is_duration(input: &str) -> bool {
+    let mut chars = input.chars().peekable();
+
+    if chars.next() != Some('P') {
+        return false;
+    }
+
+    let mut designators = "YMWD";
+    let mut time = false;
+    let mut any = false;
+
+    loop {
+        let mut number = String::new();
+
+        while let Some(c) = chars.peek().cloned() {
+            if !c.is_digit(10) && c != '.' {
+                break;
+            }
+
+            number.push(c);
+            chars.next();
+        }
+
+        let designator = match chars.next() {
+            Some('T') if number.is_empty() && !time => {
+                designators = "HMS";
+                time = true;
+                any = false;
+                continue;
+            }
+            Some(c) => c,
+            None if number.is_empty() => return any,
+            None => return false,
+        };
+
+        if number.is_empty() || number.parse::<f64>().is_err() {
+            return false;
+        }
+
+        // designators have to be in order, and only seconds can be fractional.
+        match designators.find(designator) {
+            Some(index) => designators = &designators[index + 1..],
+            None => return false,
+        }
+
+        if number.contains('.') && designator != 'S' {
+            return false;
+        }
+
+        any = true;
+    }
+}
+
+/// Check if the given input looks like a UUID, like `a1a2a3a4-b1b2-c1c2-d1d2-d3d4d5d6d7d8`.
+pub fn is_uuid(input: &str) -> bool {
+    parts(input, '-', &[8, 4, 4, 4, 12], |c| c.is_digit(16))
+}
+
+/// Check that the input consists of parts with the given sizes, separated by `sep`.
+fn parts(input: &str, sep: char, sizes: &[usize], valid: fn(char) -> bool) -> bool {
+    let mut it = input.split(sep);
+
+    for size in sizes {
+        match it.next() {
+            Some(part) if part.len() == *size && part.chars().all(valid) => {}
+            _ => return false,
+        }
+    }
+
+    it.next().is_none()
+}
+
+fn is_digit(c: char) -> bool {
+    c.is_digit(10)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -69,4 +156,35 @@ mod tests {
         assert_eq!(false, is_datetime("2018-02-01T00:02:02+00:00Z"));
         assert_eq!(false, is_datetime("-02-01T00:02:02+00:00Z"));
     }
+
+    #[test]
+    fn test_date_and_time() {
+        assert_eq!(true, is_date("2018-02-01"));
+        assert_eq!(false, is_date("2018-02-01T00:02:02Z"));
+        assert_eq!(false, is_date("18-02-01"));
+        assert_eq!(true, is_time("00:02:02"));
+        assert_eq!(false, is_time("00:02"));
+        assert_eq!(false, is_time("0a:02:02"));
+    }
+
+    #[test]
+    fn test_duration() {
+        assert_eq!(true, is_duration("P1Y2M3DT4H5M6S"));
+        assert_eq!(true, is_duration("P2W"));
+        assert_eq!(true, is_duration("PT0.5S"));
+        assert_eq!(false, is_duration("P"));
+        assert_eq!(false, is_duration("PT"));
+        assert_eq!(false, is_duration("P1DT"));
+        assert_eq!(false, is_duration("P1H"));
+        assert_eq!(false, is_duration("P1D2Y"));
+        assert_eq!(false, is_duration("PT0.5H"));
+        assert_eq!(false, is_duration("Paris"));
+    }
+
+    #[test]
+    fn test_uuid() {
+        assert_eq!(true, is_uuid("0fdd2a9e-2f6c-4a4f-9d3c-7b1f0e5b8a11"));
+        assert_eq!(false, is_uuid("0fdd2a9e-2f6c-4a4f-9d3c"));
+        assert_eq!(false, is_uuid("0fdd2a9g-2f6c-4a4f-9d3c-7b1f0e5b8a11"));
+    }
 }
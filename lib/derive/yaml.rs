@@ -4,7 +4,6 @@ use format;
 use linked_hash_map::LinkedHashMap;
 use serde_yaml as yaml;
 use sir::{FieldSir, Sir};
-use utils::is_datetime;
 use Opaque;
 
 #[derive(Debug)]
@@ -74,13 +73,7 @@ fn from_yaml(value: &yaml::Value) -> Result<Sir> {
             }
         }
         yaml::Value::Bool(_) => Sir::Boolean,
-        yaml::Value::String(ref string) => {
-            if is_datetime(string) {
-                Sir::DateTime(Opaque::new(vec![string.to_string()]))
-            } else {
-                Sir::String(Opaque::new(vec![string.to_string()]))
-            }
-        }
+        yaml::Value::String(ref string) => Sir::from_str(string),
         yaml::Value::Null => Sir::Any,
         yaml::Value::Sequence(ref sequence) => Sir::process_array(&sequence, from_yaml)?,
         yaml::Value::Mapping(ref mapping) => {
@@ -39,6 +39,10 @@ pub fn match_keyword(content: &str) -> Option<Token> {
         "boolean" => Token::BooleanKeyword,
         "string" => Token::StringKeyword,
         "datetime" => Token::DateTimeKeyword,
+        "date" => Token::DateKeyword,
+        "time" => Token::TimeKeyword,
+        "duration" => Token::DurationKeyword,
+        "uuid" => Token::UuidKeyword,
         "bytes" => Token::BytesKeyword,
         "stream" => Token::StreamKeyword,
         _ => return None,
@@ -442,6 +446,8 @@ impl<'input> Lexer<'input> {
                     '}' => Token::RightCurly,
                     '[' => Token::LeftBracket,
                     ']' => Token::RightBracket,
+                    '<' => Token::LeftAngle,
+                    '>' => Token::RightAngle,
                     '(' => Token::LeftParen,
                     ')' => Token::RightParen,
                     ';' => Token::SemiColon,
@@ -597,6 +603,19 @@ pub mod tests {
         assert_eq!(expected, tokenize("foo::Bar.Baz(hello: 12)").unwrap());
     }
 
+    #[test]
+    pub fn test_type_arguments() {
+        let expected = vec![
+            (0, TypeIdentifier("Page".into()), 4),
+            (4, LeftAngle, 5),
+            (5, TypeIdentifier("User".into()), 9),
+            (9, RightAngle, 10),
+            (11, RightArrow, 13),
+        ];
+
+        assert_eq!(expected, tokenize("Page<User> ->").unwrap());
+    }
+
     #[test]
     pub fn test_comments() {
         let tokens = tokenize("// hello \n world");
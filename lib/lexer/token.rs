@@ -12,6 +12,8 @@ pub enum Token<'input> {
     RightCurly,
     LeftBracket,
     RightBracket,
+    LeftAngle,
+    RightAngle,
     LeftParen,
     RightParen,
     SemiColon,
@@ -46,6 +48,10 @@ pub enum Token<'input> {
     BooleanKeyword,
     StringKeyword,
     DateTimeKeyword,
+    DateKeyword,
+    TimeKeyword,
+    DurationKeyword,
+    UuidKeyword,
     BytesKeyword,
     StreamKeyword,
 }
@@ -71,6 +77,10 @@ impl<'input> Token<'input> {
             Token::BooleanKeyword => "_boolean",
             Token::StringKeyword => "_string",
             Token::DateTimeKeyword => "_datetime",
+            Token::DateKeyword => "_date",
+            Token::TimeKeyword => "_time",
+            Token::DurationKeyword => "_duration",
+            Token::UuidKeyword => "_uuid",
             Token::BytesKeyword => "_bytes",
             Token::StreamKeyword => "_stream",
             _ => return None,
@@ -282,10 +282,15 @@ pub enum Language {
     Java,
     Js,
     Json,
+    JsonSchema,
+    Kotlin,
+    OpenApi,
+    Proto,
     Python,
     Reproto,
     Rust,
     Swift,
+    TypeScript,
 }
 
 impl Language {
@@ -298,10 +303,15 @@ impl Language {
             "java" => Java,
             "js" => Js,
             "json" => Json,
+            "jsonschema" => JsonSchema,
+            "kotlin" => Kotlin,
+            "openapi" => OpenApi,
+            "proto" => Proto,
             "python" => Python,
             "reproto" => Reproto,
             "rust" => Rust,
             "swift" => Swift,
+            "typescript" => TypeScript,
             _ => return None,
         };
 
@@ -498,6 +508,10 @@ pub struct Manifest {
     ///
     /// This is not part of the manifest.
     pub stdin: bool,
+    /// Require that packages are resolved exactly as recorded in the lockfile.
+    ///
+    /// This is not part of the manifest.
+    pub locked: bool,
     /// Packages to publish.
     pub publish: Vec<Publish>,
     /// Modules to enable.
@@ -524,6 +538,7 @@ impl Manifest {
             packages: Vec::default(),
             files: Vec::default(),
             stdin: false,
+            locked: false,
             publish: Vec::default(),
             modules: Vec::default(),
             paths: Vec::default(),
@@ -255,6 +255,74 @@ mod tests {
         };
 
         assert_type_spec_eq!(Type::String, "string");
-        assert_type_spec_eq!(Type::Name { name: c }, "Hello::World");
+        assert_type_spec_eq!(Type::Date, "date");
+        assert_type_spec_eq!(Type::Time, "time");
+        assert_type_spec_eq!(Type::Duration, "duration");
+        assert_type_spec_eq!(Type::Uuid, "uuid");
+        assert_type_spec_eq!(
+            Type::Name {
+                name: c,
+                arguments: vec![],
+            },
+            "Hello::World"
+        );
+    }
+
+    #[test]
+    fn test_field_default() {
+        match parse_member("page_size?: u32 = 50;") {
+            TypeMember::Field(field) => {
+                let default = field.default.as_ref().map(Loc::value).cloned();
+                assert_eq!(Some(Value::Number(50.into())), default);
+            }
+            _ => panic!("expected field"),
+        }
+    }
+
+    #[test]
+    fn test_type_arguments() {
+        let page = Name::Absolute {
+            prefix: None,
+            parts: Loc::new(vec!["Page".to_string()].into(), Span::empty()),
+        };
+
+        let user = Name::Absolute {
+            prefix: None,
+            parts: Loc::new(vec!["User".to_string()].into(), Span::empty()),
+        };
+
+        let expected = Type::Name {
+            name: page,
+            arguments: vec![
+                Loc::new(
+                    Type::Name {
+                        name: user,
+                        arguments: vec![],
+                    },
+                    Span::empty(),
+                ),
+                Loc::new(
+                    Type::Array {
+                        inner: Box::new(Type::String),
+                    },
+                    Span::empty(),
+                ),
+            ],
+        };
+
+        assert_type_spec_eq!(expected, "Page<User, [string]>");
+    }
+
+    #[test]
+    fn test_generic_type() {
+        let file = parse_file("type Page<T> { items: [T]; }");
+
+        match file.decls.first() {
+            Some(&Decl::Type(ref body)) => {
+                let generics = body.generics.iter().map(|g| g.to_string()).collect::<Vec<_>>();
+                assert_eq!(vec!["T".to_string()], generics);
+            }
+            _ => panic!("expected type declaration"),
+        }
     }
 }
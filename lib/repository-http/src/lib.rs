@@ -1,34 +1,87 @@
-//! ## Load objects from a remote repository over HTTP
+//! ## Load objects and indexes from a remote repository over HTTP
 
 extern crate futures;
 extern crate hyper;
 extern crate hyper_rustls;
 extern crate reproto_core as core;
 extern crate reproto_repository as repository;
+extern crate serde;
+#[macro_use]
+extern crate serde_derive;
+extern crate serde_json;
 extern crate tokio_core;
 extern crate url;
 
 use core::errors::{Error, Result};
-use core::Source;
+use core::{Range, RelativePath, RpPackage, Source, Version};
 use futures::future::{err, ok};
 use futures::{Future, Stream};
-use hyper::header::ContentLength;
+use hyper::header::{Authorization, Bearer, ContentLength, ContentType};
 use hyper::{Client, Method, Request, StatusCode};
-use repository::{CachedObjects, Checksum, HexSlice, Objects, ObjectsConfig};
+use repository::{CachedObjects, Checksum, Deployment, HexSlice, Index, Objects, ObjectsConfig};
+use std::cell::RefCell;
 use std::io::Read;
 use std::time::Duration;
-use tokio_core::reactor::Core;
+use tokio_core::reactor::{Core, Handle};
 use url::Url;
 
+/// Perform the given request, resulting in the body and the status of the response.
+fn handle_request(
+    handle: &Handle,
+    request: Request,
+) -> Box<Future<Item = (Vec<u8>, StatusCode), Error = Error>> {
+    let client = Client::configure()
+        .connector(hyper_rustls::HttpsConnector::new(4, handle))
+        .build(handle);
+
+    let body_and_status = client
+        .request(request)
+        .map_err::<_, Error>(|e| format!("Request to repository failed: {}", e).into())
+        .and_then(|res| {
+            let status = res.status().clone();
+
+            res.body()
+                .map_err::<Error, _>(|e| format!("Failed to perform request: {}", e).into())
+                .fold(Vec::new(), |mut out: Vec<u8>, chunk| {
+                    out.extend(chunk.as_ref());
+                    ok::<_, Error>(out)
+                })
+                .map(move |body| (body, status))
+        });
+
+    Box::new(body_and_status)
+}
+
+/// Authorize the given request using the token, if present.
+fn authorize(request: &mut Request, token: Option<&String>) {
+    if let Some(token) = token {
+        request.headers_mut().set(Authorization(Bearer {
+            token: token.to_string(),
+        }));
+    }
+}
+
+/// Build an error from a response which was not successful.
+fn bad_response(status: StatusCode, body: Vec<u8>) -> Error {
+    if let Ok(body) = String::from_utf8(body) {
+        return format!("bad response: {}: {}", status, body).into();
+    }
+
+    format!("bad response: {}", status).into()
+}
+
 pub struct HttpObjects {
     url: Url,
+    /// Token used to authorize requests.
+    token: Option<String>,
     core: Core,
 }
 
 impl HttpObjects {
-    pub fn new(url: Url, core: Core) -> HttpObjects {
+    pub fn new(url: Url, token: Option<String>, core: Core) -> HttpObjects {
         HttpObjects {
             url: url,
+            token: token,
             core: core,
         }
     }
@@ -47,28 +100,7 @@ impl HttpObjects {
         &mut self,
         request: Request,
     ) -> Box<Future<Item = (Vec<u8>, StatusCode), Error = Error>> {
-        let handle = self.core.handle();
-
-        let client = Client::configure()
-            .connector(hyper_rustls::HttpsConnector::new(4, &handle))
-            .build(&handle);
-
-        let body_and_status = client
-            .request(request)
-            .map_err::<_, Error>(|e| format!("Request to repository failed: {}", e).into())
-            .and_then(|res| {
-                let status = res.status().clone();
-
-                res.body()
-                    .map_err::<Error, _>(|e| format!("Failed to perform request: {}", e).into())
-                    .fold(Vec::new(), |mut out: Vec<u8>, chunk| {
-                        out.extend(chunk.as_ref());
-                        ok::<_, Error>(out)
-                    })
-                    .map(move |body| (body, status))
-            });
-
-        Box::new(body_and_status)
+        handle_request(&self.core.handle(), request)
     }
 }
 
@@ -83,15 +115,12 @@ impl Objects for HttpObjects {
         request
             .headers_mut()
             .set(ContentLength(buffer.len() as u64));
+        authorize(&mut request, self.token.as_ref());
         request.set_body(buffer);
 
         let work = self.handle_request(request).and_then(|(body, status)| {
             if !status.is_success() {
-                if let Ok(body) = String::from_utf8(body) {
-                    return err(format!("bad response: {}: {}", status, body).into());
-                }
-
-                return err(format!("bad response: {}", status).into());
+                return err(bad_response(status, body));
             }
 
             ok(())
@@ -118,11 +147,7 @@ impl Objects for HttpObjects {
                 return ok(None);
             }
 
-            if let Ok(body) = String::from_utf8(body) {
-                return err(format!("bad response: {}: {}", status, body).into());
-            }
-
-            return err(format!("bad response: {}", status).into());
+            err(bad_response(status, body))
         });
 
         let out = self.core.run(work)?;
@@ -130,11 +155,208 @@ impl Objects for HttpObjects {
     }
 }
 
+/// Request to publish a version, as sent to the index.
+#[derive(Debug, Serialize)]
+struct PutVersion<'a> {
+    object: &'a Checksum,
+    force: bool,
+}
+
+/// An index accessed over HTTP, as served by `reproto-server`.
+pub struct HttpIndex {
+    /// URL to the root of the server.
+    url: Url,
+    /// URL to objects stored on the same server.
+    objects_url: String,
+    /// Token used to authorize requests.
+    token: Option<String>,
+    core: RefCell<Core>,
+}
+
+impl HttpIndex {
+    pub fn new(url: Url, token: Option<String>, core: Core) -> Result<HttpIndex> {
+        let objects_url = Self::join(&url, &["objects", ""])?.to_string();
+
+        Ok(HttpIndex {
+            url: url,
+            objects_url: objects_url,
+            token: token,
+            core: RefCell::new(core),
+        })
+    }
+
+    /// Join the given path segments to the URL, escaping them as necessary.
+    fn join(url: &Url, segments: &[&str]) -> Result<Url> {
+        let mut url = url.clone();
+
+        url.path_segments_mut()
+            .map_err(|_| "URL cannot be a base")?
+            .pop_if_empty()
+            .extend(segments);
+
+        Ok(url)
+    }
+
+    /// Build the URL to the given path of the index.
+    fn index_url(&self, segments: &[&str]) -> Result<hyper::Uri> {
+        let mut path = vec!["index"];
+        path.extend(segments);
+
+        let url = Self::join(&self.url, &path)?;
+
+        let url = url.to_string()
+            .parse::<hyper::Uri>()
+            .map_err(|e| format!("Failed to parse URL: {}: {}", e, url))?;
+
+        Ok(url)
+    }
+
+    /// Get a list of deployments from the given URL.
+    fn get_deployments_from(&self, url: hyper::Uri) -> Result<Vec<Deployment>> {
+        let request = Request::new(Method::Get, url);
+
+        let mut core = self.core.borrow_mut();
+
+        let work = handle_request(&core.handle(), request).and_then(|(body, status)| {
+            if !status.is_success() {
+                return err(bad_response(status, body));
+            }
+
+            match serde_json::from_slice(&body) {
+                Ok(deployments) => ok(deployments),
+                Err(e) => err(format!("bad deployments in response: {}", e).into()),
+            }
+        });
+
+        core.run(work)
+    }
+}
+
+impl Index for HttpIndex {
+    fn resolve(&self, package: &RpPackage, range: &Range) -> Result<Vec<Deployment>> {
+        let package = package.to_string();
+        let range = range.to_string();
+        let url = self.index_url(&[package.as_str(), "resolve", range.as_str()])?;
+        self.get_deployments_from(url)
+    }
+
+    fn all(&self, package: &RpPackage) -> Result<Vec<Deployment>> {
+        let package = package.to_string();
+        let url = self.index_url(&[package.as_str()])?;
+        self.get_deployments_from(url)
+    }
+
+    fn packages(&self) -> Result<Vec<RpPackage>> {
+        let url = self.index_url(&[])?;
+        let request = Request::new(Method::Get, url);
+
+        let mut core = self.core.borrow_mut();
+
+        let work = handle_request(&core.handle(), request).and_then(|(body, status)| {
+            if !status.is_success() {
+                return err(bad_response(status, body));
+            }
+
+            match serde_json::from_slice(&body) {
+                Ok(packages) => ok(packages),
+                Err(e) => err(format!("bad packages in response: {}", e).into()),
+            }
+        });
+
+        core.run(work)
+    }
+
+    fn put_version(
+        &self,
+        checksum: &Checksum,
+        package: &RpPackage,
+        version: &Version,
+        force: bool,
+    ) -> Result<()> {
+        let package = package.to_string();
+        let version = version.to_string();
+        let url = self.index_url(&[package.as_str(), version.as_str()])?;
+
+        let body = serde_json::to_vec(&PutVersion {
+            object: checksum,
+            force: force,
+        })?;
+
+        let mut request = Request::new(Method::Put, url);
+        request.headers_mut().set(ContentLength(body.len() as u64));
+        request.headers_mut().set(ContentType::json());
+        authorize(&mut request, self.token.as_ref());
+        request.set_body(body);
+
+        let mut core = self.core.borrow_mut();
+
+        let work = handle_request(&core.handle(), request).and_then(|(body, status)| {
+            if !status.is_success() {
+                return err(bad_response(status, body));
+            }
+
+            ok(())
+        });
+
+        core.run(work)
+    }
+
+    fn get_deployments(&self, package: &RpPackage, version: &Version) -> Result<Vec<Deployment>> {
+        let package = package.to_string();
+        let version = version.to_string();
+        let url = self.index_url(&[package.as_str(), version.as_str()])?;
+        self.get_deployments_from(url)
+    }
+
+    fn yank_version(&self, package: &RpPackage, version: &Version) -> Result<()> {
+        Err(format!(
+            "{}@{}: yanking is not supported over HTTP: {}",
+            package, version, self.url
+        ).into())
+    }
+
+    /// Objects are stored on the same server, under `/objects/`.
+    fn objects_url(&self) -> Result<&str> {
+        Ok(self.objects_url.as_str())
+    }
+
+    fn objects_from_index(&self, relative_path: &RelativePath) -> Result<Box<Objects>> {
+        let mut segments = relative_path
+            .components()
+            .map(|c| c.as_str())
+            .collect::<Vec<_>>();
+
+        // objects are joined with a trailing slash.
+        segments.push("");
+
+        let url = Self::join(&self.url, &segments)?;
+        Ok(Box::new(HttpObjects::new(
+            url,
+            self.token.clone(),
+            Core::new()?,
+        )))
+    }
+}
+
+/// Load an index from an HTTP url.
+///
+/// The token, if present, is used to authorize requests.
+pub fn index_from_url(url: &Url, token: Option<String>) -> Result<Box<Index>> {
+    let core = Core::new()?;
+    Ok(Box::new(HttpIndex::new(url.clone(), token, core)?))
+}
+
 /// Load objects from an HTTP url.
-pub fn objects_from_url(config: ObjectsConfig, url: &Url) -> Result<Box<Objects>> {
+///
+/// The token, if present, is used to authorize requests.
+pub fn objects_from_url(
+    config: ObjectsConfig,
+    url: &Url,
+    token: Option<String>,
+) -> Result<Box<Objects>> {
     let core = Core::new()?;
 
-    let http_objects = HttpObjects::new(url.clone(), core);
+    let http_objects = HttpObjects::new(url.clone(), token, core);
 
     if let Some(cache_home) = config.cache_home {
         let missing_cache_time = config
@@ -121,6 +121,45 @@ impl FileIndex {
         Ok(())
     }
 
+    /// Find all packages with metadata under the given directory.
+    ///
+    /// The `skip` directory is not walked.
+    fn find_packages(
+        &self,
+        dir: &Path,
+        parts: &[String],
+        skip: &Path,
+        out: &mut Vec<RpPackage>,
+    ) -> Result<()> {
+        if !parts.is_empty() && dir.join(METADATA_JSON).is_file() {
+            out.push(RpPackage::new(parts.to_vec()));
+        }
+
+        for entry in fs::read_dir(dir)? {
+            let path = entry?.path();
+
+            if !path.is_dir() || path == skip {
+                continue;
+            }
+
+            let name = match path.file_name().and_then(|n| n.to_str()) {
+                Some(name) => name.to_string(),
+                None => continue,
+            };
+
+            // NB: skip hidden directories, like `.git`.
+            if name.starts_with(".") {
+                continue;
+            }
+
+            let mut parts = parts.to_vec();
+            parts.push(name);
+            self.find_packages(&path, &parts, skip, out)?;
+        }
+
+        Ok(())
+    }
+
     fn path_for(&self, package: &RpPackage) -> PathBuf {
         package
             .parts()
@@ -143,6 +182,16 @@ impl Index for FileIndex {
             })
     }
 
+    fn packages(&self) -> Result<Vec<RpPackage>> {
+        // NB: objects are stored in the index by default, and can be plentiful.
+        let objects = RelativePath::new(&self.config.objects).to_path(&self.path);
+
+        let mut out = Vec::new();
+        self.find_packages(&self.path, &[], &objects, &mut out)?;
+        out.sort();
+        Ok(out)
+    }
+
     fn put_version(
         &self,
         checksum: &Checksum,
@@ -169,6 +218,26 @@ impl Index for FileIndex {
             .map(|r| r.0)
     }
 
+    fn yank_version(&self, package: &RpPackage, version: &Version) -> Result<()> {
+        let (mut deployments, _) = self.read_package(package, |_| true)?;
+
+        {
+            let deployment = deployments
+                .iter_mut()
+                .find(|d| d.version == *version)
+                .ok_or_else(|| format!("{}@{}: not published", package, version))?;
+
+            if deployment.yanked {
+                return Err(format!("{}@{}: already yanked", package, version).into());
+            }
+
+            deployment.yanked = true;
+        }
+
+        self.write_package(package, deployments)?;
+        Ok(())
+    }
+
     fn objects_from_index(&self, relative_path: &RelativePath) -> Result<Box<Objects>> {
         let path = relative_path.to_path(&self.path);
         Ok(Box::new(FileObjects::new(&path)))
@@ -225,3 +294,70 @@ fn read_config<P: AsRef<Path> + ?Sized>(path: &P) -> Result<Config> {
 
     Ok(config)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::{init_file_index, FileIndex, METADATA_JSON};
+    use checksum::Checksum;
+    use core::{RpPackage, Version};
+    use index::Index;
+    use std::env;
+    use std::fs::{self, File};
+    use std::path::PathBuf;
+    use std::process;
+
+    /// A file index in a temporary directory.
+    struct Fixture {
+        path: PathBuf,
+    }
+
+    impl Fixture {
+        fn new(name: &str) -> Fixture {
+            let path = env::temp_dir().join(format!("reproto-index-{}-{}", name, process::id()));
+
+            if path.is_dir() {
+                fs::remove_dir_all(&path).unwrap();
+            }
+
+            init_file_index(&path).unwrap();
+            Fixture { path: path }
+        }
+    }
+
+    impl Drop for Fixture {
+        fn drop(&mut self) {
+            let _ = fs::remove_dir_all(&self.path);
+        }
+    }
+
+    #[test]
+    fn test_packages() {
+        let fixture = Fixture::new("packages");
+        let index = FileIndex::new(&fixture.path).unwrap();
+
+        let checksum = Checksum::new(vec![0u8; 32]);
+        let version = Version::parse("1.0.0").unwrap();
+
+        for package in &["foo", "foo.bar", "baz"] {
+            index
+                .put_version(&checksum, &RpPackage::parse(package), &version, false)
+                .unwrap();
+        }
+
+        // anything in the objects directory is not a package.
+        let objects = fixture.path.join("objects").join("qux");
+        fs::create_dir_all(&objects).unwrap();
+        File::create(objects.join(METADATA_JSON)).unwrap();
+
+        let packages = index.packages().unwrap();
+
+        assert_eq!(
+            vec![
+                RpPackage::parse("baz"),
+                RpPackage::parse("foo"),
+                RpPackage::parse("foo.bar"),
+            ],
+            packages
+        );
+    }
+}
@@ -40,6 +40,10 @@ impl Index for GitIndex {
         self.file_index.all(package)
     }
 
+    fn packages(&self) -> Result<Vec<RpPackage>> {
+        self.file_index.packages()
+    }
+
     fn put_version(
         &self,
         checksum: &Checksum,
@@ -69,6 +73,24 @@ impl Index for GitIndex {
         self.file_index.get_deployments(package, version)
     }
 
+    fn yank_version(&self, package: &RpPackage, version: &Version) -> Result<()> {
+        if !self.publishing {
+            return Err(format!(
+                "index does not support yanking: {}",
+                self.url.to_string()
+            ).into());
+        }
+
+        self.file_index.yank_version(package, version)?;
+
+        let path = self.file_index.metadata_path(package);
+        self.git_repo.add(path)?;
+        self.git_repo
+            .commit(&format!("yank: {} {}", package, version))?;
+
+        Ok(())
+    }
+
     fn objects_url(&self) -> Result<&str> {
         self.file_index.objects_url()
     }
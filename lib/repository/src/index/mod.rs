@@ -1,7 +1,7 @@
 mod file_index;
 mod git_index;
 
-pub use self::file_index::init_file_index;
+pub use self::file_index::{init_file_index, FileIndex};
 use self::git_index::GitIndex;
 use checksum::Checksum;
 use core::errors::*;
@@ -19,10 +19,17 @@ pub struct IndexConfig {
     pub repo_dir: PathBuf,
 }
 
+fn is_false(value: &bool) -> bool {
+    !*value
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Deployment {
     pub version: Version,
     pub object: Checksum,
+    /// Yanked deployments are not used for new resolutions, but can still be resolved exactly.
+    #[serde(default, skip_serializing_if = "is_false")]
+    pub yanked: bool,
 }
 
 impl Deployment {
@@ -30,6 +37,7 @@ impl Deployment {
         Deployment {
             version: version,
             object: object,
+            yanked: false,
         }
     }
 }
@@ -43,6 +51,11 @@ pub trait Index {
     /// The returned versions are sorted.
     fn all(&self, package: &RpPackage) -> Result<Vec<Deployment>>;
 
+    /// Get all packages available in the index.
+    ///
+    /// The returned packages are sorted.
+    fn packages(&self) -> Result<Vec<RpPackage>>;
+
     fn put_version(
         &self,
         checksum: &Checksum,
@@ -53,6 +66,9 @@ pub trait Index {
 
     fn get_deployments(&self, package: &RpPackage, version: &Version) -> Result<Vec<Deployment>>;
 
+    /// Mark the given version of a package as yanked.
+    fn yank_version(&self, package: &RpPackage, version: &Version) -> Result<()>;
+
     /// Get an objects URL as configured in the index.
     ///
     /// If relative, will cause objects to be loaded from the same repository as the index.
@@ -78,6 +94,10 @@ impl Index for NoIndex {
         Ok(vec![])
     }
 
+    fn packages(&self) -> Result<Vec<RpPackage>> {
+        Ok(vec![])
+    }
+
     fn put_version(&self, _: &Checksum, _: &RpPackage, _: &Version, _: bool) -> Result<()> {
         Err("Empty Index".into())
     }
@@ -86,6 +106,10 @@ impl Index for NoIndex {
         Ok(vec![])
     }
 
+    fn yank_version(&self, _: &RpPackage, _: &Version) -> Result<()> {
+        Err("Empty Index".into())
+    }
+
     /// Get an objects URL as configured in the index.
     ///
     /// If relative, will cause objects to be loaded from the same repository as the index.
@@ -156,7 +180,16 @@ where
     open_git_index(url, git_repo, publishing)
 }
 
-pub fn index_from_url(config: IndexConfig, url: &Url, publishing: bool) -> Result<Box<Index>> {
+/// Load an index from an URL.
+pub fn index_from_url<F>(
+    config: IndexConfig,
+    url: &Url,
+    fallback: F,
+    publishing: bool,
+) -> Result<Box<Index>>
+where
+    F: Fn(IndexConfig, &str, &Url) -> Result<Option<Box<Index>>>,
+{
     let mut scheme = url.scheme().split("+");
 
     let first = scheme.next().ok_or_else(|| format!("bad scheme: {}", url))?;
@@ -166,6 +199,9 @@ pub fn index_from_url(config: IndexConfig, url: &Url, publishing: bool) -> Resul
             .map_err(|_| format!("url is not a file path: {}", url).into())
             .and_then(|path| index_from_path(&path)),
         "git" => index_from_git(config, scheme, url, publishing),
-        scheme => Err(format!("bad scheme: {}", scheme).into()),
+        scheme => match fallback(config, scheme, url)? {
+            Some(index) => Ok(index),
+            None => Err(format!("bad scheme: {}", scheme).into()),
+        },
     }.chain_err(|| format!("loading index from URL: {}", url))
 }
@@ -17,6 +17,7 @@ mod checksum;
 mod git;
 mod hex_slice;
 mod index;
+mod lockfile;
 mod metadata;
 mod objects;
 mod repository;
@@ -27,11 +28,12 @@ mod update;
 pub use self::checksum::Checksum;
 pub use self::git::GitRepo;
 pub use self::hex_slice::HexSlice;
-pub use self::index::{index_from_path, index_from_url, init_file_index, Index, IndexConfig,
-                      NoIndex};
+pub use self::index::{index_from_path, index_from_url, init_file_index, Deployment, FileIndex,
+                      Index, IndexConfig, NoIndex};
+pub use self::lockfile::{read_lockfile, write_lockfile, LockedPackage, Lockfile};
 pub use self::objects::{objects_from_path, objects_from_url, CachedObjects, FileObjects,
                         NoObjects, Objects, ObjectsConfig};
 pub use self::repository::Repository;
-pub use self::resolver::{Paths, Resolvers};
+pub use self::resolver::{Locked, Overrides, Paths, Resolvers};
 pub use self::sha256::{to_sha256 as to_checksum, Sha256 as Digest};
 pub use self::update::Update;
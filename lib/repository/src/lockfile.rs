@@ -0,0 +1,151 @@
+//! # Lockfile
+//!
+//! Records the exact version and checksum of every package resolved from a repository, so that
+//! subsequent builds resolve the same packages.
+
+use checksum::Checksum;
+use core::errors::*;
+use core::{Range, RpPackage, Version};
+use std::fs::{self, File};
+use std::io::{Read, Write};
+use std::path::Path;
+use toml;
+
+/// A single locked package.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct LockedPackage {
+    /// Name of the package.
+    pub name: RpPackage,
+    /// Exact version that was resolved.
+    pub version: Version,
+    /// Checksum of the object that was resolved.
+    pub checksum: Checksum,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Lockfile {
+    #[serde(default, rename = "package")]
+    packages: Vec<LockedPackage>,
+}
+
+impl Lockfile {
+    /// Find the most recent locked version of the given package that matches the range.
+    pub fn find(&self, package: &RpPackage, range: &Range) -> Option<&LockedPackage> {
+        self.packages
+            .iter()
+            .filter(|p| p.name == *package && range.matches(&p.version))
+            .max_by(|a, b| a.version.cmp(&b.version))
+    }
+
+    /// Insert the given package, replacing any existing entry with the same name and version.
+    pub fn insert(&mut self, locked: LockedPackage) {
+        self.packages
+            .retain(|p| p.name != locked.name || p.version != locked.version);
+        self.packages.push(locked);
+        self.packages
+            .sort_by(|a, b| (&a.name, &a.version).cmp(&(&b.name, &b.version)));
+    }
+
+    /// Iterate over all locked packages.
+    pub fn packages(&self) -> &[LockedPackage] {
+        &self.packages
+    }
+}
+
+/// Read the lockfile at the given path, if it exists.
+pub fn read_lockfile(path: &Path) -> Result<Option<Lockfile>> {
+    if !path.is_file() {
+        return Ok(None);
+    }
+
+    let mut f = File::open(path)?;
+    let mut content = String::new();
+    f.read_to_string(&mut content)?;
+
+    let lockfile: Lockfile = toml::from_str(content.as_str())
+        .map_err(|e| format!("{}: bad lockfile: {}", path.display(), e))?;
+
+    Ok(Some(lockfile))
+}
+
+/// Write the lockfile to the given path.
+pub fn write_lockfile(path: &Path, lockfile: &Lockfile) -> Result<()> {
+    let content = toml::to_string(lockfile)
+        .map_err(|e| format!("{}: failed to serialize lockfile: {}", path.display(), e))?;
+
+    let mut tmp_path = path.to_owned();
+    tmp_path.set_extension("lock.tmp");
+
+    debug!("writing: {}", path.display());
+
+    {
+        let mut f = File::create(&tmp_path)?;
+        f.write_all(b"# This file is automatically generated by `reproto update`.\n")?;
+        f.write_all(content.as_bytes())?;
+    }
+
+    fs::rename(&tmp_path, path)?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{LockedPackage, Lockfile};
+    use checksum::Checksum;
+    use core::{Range, RpPackage, Version};
+
+    fn locked(name: &str, version: &str, checksum: u8) -> LockedPackage {
+        LockedPackage {
+            name: RpPackage::parse(name),
+            version: Version::parse(version).unwrap(),
+            checksum: Checksum::new(vec![checksum]),
+        }
+    }
+
+    fn range(range: &str) -> Range {
+        Range::parse(range).unwrap()
+    }
+
+    #[test]
+    fn test_find() {
+        let mut lockfile = Lockfile::default();
+        lockfile.insert(locked("foo", "1.0.0", 0));
+        lockfile.insert(locked("foo", "1.1.0", 1));
+        lockfile.insert(locked("foo", "2.0.0", 2));
+        lockfile.insert(locked("bar", "1.2.0", 3));
+
+        let foo = RpPackage::parse("foo");
+
+        assert_eq!(
+            Some(&locked("foo", "1.1.0", 1)),
+            lockfile.find(&foo, &range("^1.0.0"))
+        );
+
+        assert_eq!(
+            Some(&locked("foo", "2.0.0", 2)),
+            lockfile.find(&foo, &Range::any())
+        );
+
+        assert_eq!(None, lockfile.find(&foo, &range("^3.0.0")));
+        assert_eq!(None, lockfile.find(&RpPackage::parse("baz"), &Range::any()));
+    }
+
+    #[test]
+    fn test_insert() {
+        let mut lockfile = Lockfile::default();
+        lockfile.insert(locked("foo", "1.1.0", 0));
+        lockfile.insert(locked("bar", "1.0.0", 1));
+        lockfile.insert(locked("foo", "1.0.0", 2));
+        // replaces the existing entry for the same version.
+        lockfile.insert(locked("foo", "1.1.0", 3));
+
+        assert_eq!(
+            &[
+                locked("bar", "1.0.0", 1),
+                locked("foo", "1.0.0", 2),
+                locked("foo", "1.1.0", 3),
+            ],
+            lockfile.packages()
+        );
+    }
+}
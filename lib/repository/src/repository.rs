@@ -56,19 +56,39 @@ impl Repository {
         self.index.all(package)
     }
 
+    /// Mark the given package and version as yanked.
+    pub fn yank(&mut self, package: &RpPackage, version: &Version) -> Result<()> {
+        self.index.yank_version(package, version)
+    }
+
+    /// Get all packages in this repository.
+    pub fn packages(&self) -> Result<Vec<RpPackage>> {
+        self.index.packages()
+    }
+
     /// Get the object for the specific deployment.
     pub fn get_object(&mut self, deployment: &Deployment) -> Result<Option<Source>> {
         self.objects.get_object(&deployment.object)
     }
-}
 
-impl Resolver for Repository {
-    fn resolve(&mut self, package: &RpRequiredPackage) -> core::errors::Result<Vec<Resolved>> {
+    /// Resolve all versions of the given package.
+    ///
+    /// Yanked versions are only resolved if `allow_yanked` is set, like when resolving a version
+    /// that is recorded in a lockfile.
+    pub fn resolve_versions(
+        &mut self,
+        package: &RpRequiredPackage,
+        allow_yanked: bool,
+    ) -> Result<Vec<Resolved>> {
         let mut out = Vec::new();
 
         let deployments = self.index.resolve(&package.package, &package.range)?;
 
         for deployment in deployments {
+            if deployment.yanked && !allow_yanked {
+                continue;
+            }
+
             if let Some(source) = self.get_object(&deployment)? {
                 out.push(Resolved {
                     version: Some(deployment.version),
@@ -81,6 +101,12 @@ impl Resolver for Repository {
 
         Ok(out)
     }
+}
+
+impl Resolver for Repository {
+    fn resolve(&mut self, package: &RpRequiredPackage) -> core::errors::Result<Vec<Resolved>> {
+        self.resolve_versions(package, false)
+    }
 
     fn resolve_by_prefix(&mut self, _: &RpPackage) -> core::errors::Result<Vec<ResolvedByPrefix>> {
         Err("repository does not support resolve by prefix".into())
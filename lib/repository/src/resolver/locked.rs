@@ -0,0 +1,307 @@
+//! # Lockfile-aware resolver
+//!
+//! Resolves packages to the exact versions recorded in a lockfile, and records every package that
+//! was resolved.
+
+use core::errors::Result;
+use core::{Range, Resolved, ResolvedByPrefix, Resolver, RpPackage, RpRequiredPackage};
+use lockfile::{LockedPackage, Lockfile};
+use repository::Repository;
+use sha256::to_sha256;
+use std::cell::RefCell;
+use std::rc::Rc;
+
+pub struct Locked {
+    /// Repository to resolve packages from.
+    repository: Repository,
+    /// Lockfile to honor.
+    lockfile: Lockfile,
+    /// Fail if a package would be resolved which is not present in the lockfile.
+    locked: bool,
+    /// Packages that have been resolved.
+    resolved: Rc<RefCell<Lockfile>>,
+}
+
+impl Locked {
+    pub fn new(
+        repository: Repository,
+        lockfile: Lockfile,
+        locked: bool,
+        resolved: Rc<RefCell<Lockfile>>,
+    ) -> Locked {
+        Locked {
+            repository: repository,
+            lockfile: lockfile,
+            locked: locked,
+            resolved: resolved,
+        }
+    }
+
+    /// Record the given resolved package.
+    fn record(&self, package: &RpPackage, resolved: &Resolved) -> Result<()> {
+        let version = match resolved.version {
+            Some(ref version) => version.clone(),
+            None => return Ok(()),
+        };
+
+        let checksum = to_sha256(resolved.source.read()?)?;
+
+        self.resolved.borrow_mut().insert(LockedPackage {
+            name: package.clone(),
+            version: version,
+            checksum: checksum,
+        });
+
+        Ok(())
+    }
+}
+
+impl Resolver for Locked {
+    fn resolve(&mut self, package: &RpRequiredPackage) -> Result<Vec<Resolved>> {
+        let locked = self.lockfile
+            .find(&package.package, &package.range)
+            .cloned();
+
+        let locked = match locked {
+            Some(locked) => locked,
+            None => {
+                let resolved = self.repository.resolve_versions(package, false)?;
+
+                if let Some(last) = resolved.last() {
+                    if self.locked {
+                        return Err(format!(
+                            "{}: not present in lockfile, and resolution is locked",
+                            package
+                        ).into());
+                    }
+
+                    self.record(&package.package, last)?;
+                }
+
+                return Ok(resolved);
+            }
+        };
+
+        let exact =
+            RpRequiredPackage::new(package.package.clone(), Range::exact(&locked.version));
+
+        // NB: yanked versions are still resolved if they are recorded in the lockfile.
+        let resolved = self.repository
+            .resolve_versions(&exact, true)?
+            .into_iter()
+            .last()
+            .ok_or_else(|| {
+                format!(
+                    "{}@{}: locked version is no longer available",
+                    locked.name, locked.version
+                )
+            })?;
+
+        let checksum = to_sha256(resolved.source.read()?)?;
+
+        if checksum != locked.checksum {
+            return Err(format!(
+                "{}@{}: checksum mismatch, expected {} but got {}",
+                locked.name, locked.version, locked.checksum, checksum
+            ).into());
+        }
+
+        self.resolved.borrow_mut().insert(locked);
+        Ok(vec![resolved])
+    }
+
+    fn resolve_by_prefix(&mut self, package: &RpPackage) -> Result<Vec<ResolvedByPrefix>> {
+        self.repository.resolve_by_prefix(package)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Locked;
+    use checksum::Checksum;
+    use core::errors::Result;
+    use core::{Range, Resolver, RpPackage, RpRequiredPackage, Source, Version};
+    use index::{init_file_index, FileIndex};
+    use lockfile::{LockedPackage, Lockfile};
+    use objects::FileObjects;
+    use repository::Repository;
+    use sha256::to_sha256;
+    use std::cell::RefCell;
+    use std::env;
+    use std::fs;
+    use std::path::PathBuf;
+    use std::process;
+    use std::rc::Rc;
+
+    /// A repository backed by a file index in a temporary directory.
+    struct Fixture {
+        path: PathBuf,
+    }
+
+    impl Fixture {
+        fn new(name: &str) -> Fixture {
+            let path = env::temp_dir().join(format!("reproto-locked-{}-{}", name, process::id()));
+
+            if path.is_dir() {
+                fs::remove_dir_all(&path).unwrap();
+            }
+
+            init_file_index(&path).unwrap();
+            Fixture { path: path }
+        }
+
+        fn repository(&self) -> Repository {
+            let index = FileIndex::new(&self.path).unwrap();
+            let objects = FileObjects::new(&self.path.join("objects"));
+            Repository::new(Box::new(index), Box::new(objects))
+        }
+
+        /// Publish a version of the `foo` package with the given content.
+        fn publish(&self, version: &str, content: &str) -> Checksum {
+            let source = Source::bytes("test", content.as_bytes().to_vec());
+            let version = Version::parse(version).unwrap();
+
+            self.repository()
+                .publish(&source, &foo(), &version, false)
+                .unwrap();
+
+            to_sha256(content.as_bytes()).unwrap()
+        }
+
+        fn locked(&self, lockfile: Lockfile, locked: bool) -> (Locked, Rc<RefCell<Lockfile>>) {
+            let resolved = Rc::new(RefCell::new(Lockfile::default()));
+            let locked = Locked::new(self.repository(), lockfile, locked, resolved.clone());
+            (locked, resolved)
+        }
+    }
+
+    impl Drop for Fixture {
+        fn drop(&mut self) {
+            let _ = fs::remove_dir_all(&self.path);
+        }
+    }
+
+    fn foo() -> RpPackage {
+        RpPackage::parse("foo")
+    }
+
+    fn required() -> RpRequiredPackage {
+        RpRequiredPackage::new(foo(), Range::parse("^1.0.0").unwrap())
+    }
+
+    fn locked_package(version: &str, checksum: Checksum) -> LockedPackage {
+        LockedPackage {
+            name: foo(),
+            version: Version::parse(version).unwrap(),
+            checksum: checksum,
+        }
+    }
+
+    fn error<T>(result: Result<T>) -> String {
+        match result {
+            Ok(_) => panic!("expected error"),
+            Err(e) => e.message().to_string(),
+        }
+    }
+
+    #[test]
+    fn test_record() {
+        let fixture = Fixture::new("record");
+        fixture.publish("1.0.0", "type Foo {}");
+        let checksum = fixture.publish("1.1.0", "type Bar {}");
+
+        let (mut locked, resolved) = fixture.locked(Lockfile::default(), false);
+
+        let out = locked.resolve(&required()).unwrap();
+        assert_eq!(2, out.len());
+
+        assert_eq!(
+            &[locked_package("1.1.0", checksum)],
+            resolved.borrow().packages()
+        );
+    }
+
+    #[test]
+    fn test_honor_lockfile() {
+        let fixture = Fixture::new("honor");
+        let checksum = fixture.publish("1.0.0", "type Foo {}");
+        fixture.publish("1.1.0", "type Bar {}");
+
+        let mut lockfile = Lockfile::default();
+        lockfile.insert(locked_package("1.0.0", checksum.clone()));
+
+        let (mut locked, resolved) = fixture.locked(lockfile, true);
+
+        let out = locked.resolve(&required()).unwrap();
+        assert_eq!(1, out.len());
+        assert_eq!(Some(Version::parse("1.0.0").unwrap()), out[0].version);
+
+        assert_eq!(
+            &[locked_package("1.0.0", checksum)],
+            resolved.borrow().packages()
+        );
+    }
+
+    #[test]
+    fn test_yanked() {
+        let fixture = Fixture::new("yanked");
+        let checksum = fixture.publish("1.0.0", "type Foo {}");
+        fixture.publish("1.1.0", "type Bar {}");
+
+        let first = Version::parse("1.0.0").unwrap();
+        let second = Version::parse("1.1.0").unwrap();
+
+        fixture.repository().yank(&foo(), &second).unwrap();
+
+        // new resolutions skip the yanked version.
+        let (mut locked, _) = fixture.locked(Lockfile::default(), false);
+        let out = locked.resolve(&required()).unwrap();
+        assert_eq!(1, out.len());
+        assert_eq!(Some(first.clone()), out[0].version);
+
+        let mut lockfile = Lockfile::default();
+        lockfile.insert(locked_package("1.0.0", checksum));
+        fixture.repository().yank(&foo(), &first).unwrap();
+
+        // yanked versions in the lockfile are still resolved.
+        let (mut locked, _) = fixture.locked(lockfile, true);
+        let out = locked.resolve(&required()).unwrap();
+        assert_eq!(1, out.len());
+        assert_eq!(Some(first), out[0].version);
+    }
+
+    #[test]
+    fn test_locked_missing() {
+        let fixture = Fixture::new("missing");
+        fixture.publish("1.0.0", "type Foo {}");
+
+        let (mut locked, resolved) = fixture.locked(Lockfile::default(), true);
+
+        assert_eq!(
+            "foo@^1.0.0: not present in lockfile, and resolution is locked",
+            error(locked.resolve(&required()))
+        );
+
+        assert!(resolved.borrow().packages().is_empty());
+    }
+
+    #[test]
+    fn test_checksum_mismatch() {
+        let fixture = Fixture::new("mismatch");
+        let checksum = fixture.publish("1.0.0", "type Foo {}");
+        let other = to_sha256("type Bar {}".as_bytes()).unwrap();
+
+        let mut lockfile = Lockfile::default();
+        lockfile.insert(locked_package("1.0.0", other.clone()));
+
+        let (mut locked, _) = fixture.locked(lockfile, false);
+
+        assert_eq!(
+            format!(
+                "foo@1.0.0: checksum mismatch, expected {} but got {}",
+                other, checksum
+            ),
+            error(locked.resolve(&required()))
+        );
+    }
+}
@@ -1,5 +1,9 @@
+mod locked;
+mod overrides;
 mod paths;
 mod resolvers;
 
+pub use self::locked::Locked;
+pub use self::overrides::Overrides;
 pub use self::paths::Paths;
 pub use self::resolvers::Resolvers;
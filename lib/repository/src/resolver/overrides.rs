@@ -0,0 +1,100 @@
+//! # Resolver with overrides
+//!
+//! Packages which are available from the overriding resolver are never resolved from the
+//! fallback.
+
+use core::errors::Result;
+use core::{Resolved, ResolvedByPrefix, Resolver, RpPackage, RpRequiredPackage};
+
+pub struct Overrides {
+    /// Resolver whose packages take precedence.
+    overrides: Box<Resolver>,
+    /// Resolver used for packages which are not overridden.
+    fallback: Box<Resolver>,
+}
+
+impl Overrides {
+    pub fn new(overrides: Box<Resolver>, fallback: Box<Resolver>) -> Overrides {
+        Overrides {
+            overrides: overrides,
+            fallback: fallback,
+        }
+    }
+}
+
+impl Resolver for Overrides {
+    fn resolve(&mut self, package: &RpRequiredPackage) -> Result<Vec<Resolved>> {
+        let resolved = self.overrides.resolve(package)?;
+
+        if !resolved.is_empty() {
+            return Ok(resolved);
+        }
+
+        self.fallback.resolve(package)
+    }
+
+    fn resolve_by_prefix(&mut self, package: &RpPackage) -> Result<Vec<ResolvedByPrefix>> {
+        let mut out = self.fallback.resolve_by_prefix(package)?;
+        out.extend(self.overrides.resolve_by_prefix(package)?);
+        Ok(out)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Overrides;
+    use core::errors::Result;
+    use core::{EmptyResolver, Range, Resolved, ResolvedByPrefix, Resolver, RpPackage,
+               RpRequiredPackage, Source, Version};
+
+    /// Resolver which resolves every package to the given version.
+    struct Fixed(&'static str);
+
+    impl Resolver for Fixed {
+        fn resolve(&mut self, _package: &RpRequiredPackage) -> Result<Vec<Resolved>> {
+            Ok(vec![Resolved {
+                version: Some(Version::parse(self.0).unwrap()),
+                source: Source::empty("fixed"),
+            }])
+        }
+
+        fn resolve_by_prefix(&mut self, _package: &RpPackage) -> Result<Vec<ResolvedByPrefix>> {
+            Ok(vec![])
+        }
+    }
+
+    /// Resolver which fails if it is used.
+    struct Unused;
+
+    impl Resolver for Unused {
+        fn resolve(&mut self, package: &RpRequiredPackage) -> Result<Vec<Resolved>> {
+            Err(format!("{}: should not be resolved", package).into())
+        }
+
+        fn resolve_by_prefix(&mut self, _package: &RpPackage) -> Result<Vec<ResolvedByPrefix>> {
+            Ok(vec![])
+        }
+    }
+
+    fn required() -> RpRequiredPackage {
+        RpRequiredPackage::new(RpPackage::parse("foo"), Range::any())
+    }
+
+    #[test]
+    fn test_overridden() {
+        let mut overrides = Overrides::new(Box::new(Fixed("1.0.0")), Box::new(Unused));
+
+        let out = overrides.resolve(&required()).unwrap();
+        assert_eq!(1, out.len());
+        assert_eq!(Some(Version::parse("1.0.0").unwrap()), out[0].version);
+    }
+
+    #[test]
+    fn test_fallback() {
+        let mut overrides = Overrides::new(Box::new(EmptyResolver), Box::new(Fixed("2.0.0")));
+
+        let out = overrides.resolve(&required()).unwrap();
+        assert_eq!(1, out.len());
+        assert_eq!(Some(Version::parse("2.0.0").unwrap()), out[0].version);
+    }
+}
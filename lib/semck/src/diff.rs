@@ -0,0 +1,663 @@
+//! Lists every difference between two versions of a specification.
+//!
+//! Unlike the checks performed by `check`, this doesn't care if a change is permitted or not.
+
+use super::{decls_to_map, endpoints_to_map, enum_type, enum_variants, field_index, fields,
+            http_method, is_positional, pair_fields, path_segments, path_template,
+            sub_type_strategy, variants_to_map};
+use core::errors::*;
+use core::flavored::{RpChannel, RpEndpoint, RpField, RpFile, RpNamed, RpVariantRef};
+use core::{Loc, RpDeprecated};
+use std::collections::HashMap;
+
+/// How an element changed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ChangeKind {
+    Added,
+    Removed,
+    Changed,
+}
+
+impl ChangeKind {
+    /// Symbol used when listing the change.
+    pub fn symbol(&self) -> &str {
+        use self::ChangeKind::*;
+
+        match *self {
+            Added => "+",
+            Removed => "-",
+            Changed => "~",
+        }
+    }
+}
+
+/// The kind of element that changed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Element {
+    Decl,
+    Field,
+    Variant,
+    Endpoint,
+}
+
+impl Element {
+    /// Describe the element.
+    pub fn describe(&self) -> &str {
+        use self::Element::*;
+
+        match *self {
+            Decl => "declaration",
+            Field => "field",
+            Variant => "variant",
+            Endpoint => "endpoint",
+        }
+    }
+}
+
+/// A single difference between two versions of a specification.
+#[derive(Debug, Clone, Serialize)]
+pub struct Change {
+    pub kind: ChangeKind,
+    pub element: Element,
+    /// Path to the element, like `Foo::Bar.field`.
+    pub path: String,
+    /// What changed about the element, only present for changed elements.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub details: Vec<String>,
+}
+
+impl Change {
+    fn new(kind: ChangeKind, element: Element, path: String) -> Change {
+        Change {
+            kind: kind,
+            element: element,
+            path: path,
+            details: vec![],
+        }
+    }
+
+    fn changed(element: Element, path: String, details: Vec<String>) -> Change {
+        Change {
+            kind: ChangeKind::Changed,
+            element: element,
+            path: path,
+            details: details,
+        }
+    }
+}
+
+/// Describe the kind of a named element.
+fn named_kind(named: &RpNamed) -> &'static str {
+    use core::RpNamed::*;
+
+    match *named {
+        Type(_) => "type",
+        Tuple(_) => "tuple",
+        Interface(_) => "interface",
+        SubType(_) => "subtype",
+        Enum(_) => "enum",
+        EnumVariant(_) => "variant",
+        Service(_) => "service",
+    }
+}
+
+/// Describe a change in deprecation.
+fn diff_deprecated(
+    details: &mut Vec<String>,
+    from: Option<&RpDeprecated>,
+    to: Option<&RpDeprecated>,
+) {
+    match (from, to) {
+        (None, Some(deprecated)) => {
+            details.push(format!("deprecated: {}", deprecated.message()));
+        }
+        (Some(_), None) => {
+            details.push("no longer deprecated".to_string());
+        }
+        _ => {}
+    }
+}
+
+/// Format an optional channel.
+fn fmt_channel(channel: Option<&RpChannel>) -> String {
+    match channel {
+        None => "*empty*".to_string(),
+        Some(channel) if channel.is_streaming() => format!("stream {}", channel.ty()),
+        Some(channel) => channel.ty().to_string(),
+    }
+}
+
+/// Test if two optional channels are different, ignoring versions of the referenced types.
+fn channel_changed(from: Option<&RpChannel>, to: Option<&RpChannel>) -> bool {
+    let from = from.map(|c| (c.is_streaming(), c.ty().clone().localize()));
+    let to = to.map(|c| (c.is_streaming(), c.ty().clone().localize()));
+    from != to
+}
+
+/// Diff fields, where `positional` indicates that fields are identified by their index.
+fn diff_fields<'a>(
+    changes: &mut Vec<Change>,
+    path: &str,
+    from: Vec<&'a Loc<RpField>>,
+    to: Vec<&'a Loc<RpField>>,
+    positional: bool,
+) {
+    let field_pairs = pair_fields(from.clone(), to.clone());
+
+    for (from_field, to_field) in field_pairs.pairs {
+        let path = format!("{}.{}", path, from_field.ident());
+
+        let mut details = Vec::new();
+
+        if from_field.ident() != to_field.ident() {
+            details.push(format!(
+                "identifier changed from `{}` to `{}`",
+                from_field.ident(),
+                to_field.ident()
+            ));
+        }
+
+        if positional {
+            let from_index = field_index(&from, from_field);
+            let to_index = field_index(&to, to_field);
+
+            if let (Some(from_index), Some(to_index)) = (from_index, to_index) {
+                if from_index != to_index {
+                    details.push(format!(
+                        "index changed from `{}` to `{}`",
+                        from_index, to_index
+                    ));
+                }
+            }
+        }
+
+        if from_field.ty.clone().localize() != to_field.ty.clone().localize() {
+            details.push(format!(
+                "type changed from `{}` to `{}`",
+                from_field.ty, to_field.ty
+            ));
+        }
+
+        if from_field.name() != to_field.name() {
+            details.push(format!(
+                "name changed from `{}` to `{}`",
+                from_field.name(),
+                to_field.name()
+            ));
+        }
+
+        if from_field.is_optional() != to_field.is_optional() {
+            if to_field.is_optional() {
+                details.push("changed to be optional".to_string());
+            } else {
+                details.push("changed to be required".to_string());
+            }
+        }
+
+        if from_field.default != to_field.default {
+            details.push(match to_field.default {
+                Some(ref default) => format!("default value changed to `{}`", default),
+                None => "default value removed".to_string(),
+            });
+        }
+
+        diff_deprecated(
+            &mut details,
+            from_field.deprecated.as_ref(),
+            to_field.deprecated.as_ref(),
+        );
+
+        if !details.is_empty() {
+            changes.push(Change::changed(Element::Field, path, details));
+        }
+    }
+
+    for from_field in field_pairs.removed {
+        let path = format!("{}.{}", path, from_field.ident());
+        changes.push(Change::new(ChangeKind::Removed, Element::Field, path));
+    }
+
+    for to_field in field_pairs.added {
+        let path = format!("{}.{}", path, to_field.ident());
+        changes.push(Change::new(ChangeKind::Added, Element::Field, path));
+    }
+}
+
+fn diff_variants<'a>(
+    changes: &mut Vec<Change>,
+    path: &str,
+    from: Vec<RpVariantRef<'a>>,
+    to: Vec<RpVariantRef<'a>>,
+) {
+    let from_variants = variants_to_map(from);
+    let mut to_variants = variants_to_map(to);
+
+    for (name, from_variant) in from_variants {
+        let path = format!("{}::{}", path, from_variant.ident());
+
+        let to_variant = match to_variants.remove(&name) {
+            Some(to_variant) => to_variant,
+            None => {
+                changes.push(Change::new(ChangeKind::Removed, Element::Variant, path));
+                continue;
+            }
+        };
+
+        let mut details = Vec::new();
+
+        if from_variant.value != to_variant.value {
+            details.push(format!(
+                "value changed from `{}` to `{}`",
+                from_variant.value, to_variant.value
+            ));
+        }
+
+        diff_deprecated(
+            &mut details,
+            from_variant.deprecated.as_ref(),
+            to_variant.deprecated.as_ref(),
+        );
+
+        if !details.is_empty() {
+            changes.push(Change::changed(Element::Variant, path, details));
+        }
+    }
+
+    for (_, to_variant) in to_variants {
+        let path = format!("{}::{}", path, to_variant.ident());
+        changes.push(Change::new(ChangeKind::Added, Element::Variant, path));
+    }
+}
+
+fn diff_endpoints<'a>(
+    changes: &mut Vec<Change>,
+    path: &str,
+    from: HashMap<&'a str, &'a Loc<RpEndpoint>>,
+    mut to: HashMap<&'a str, &'a Loc<RpEndpoint>>,
+) {
+    for (ident, from_endpoint) in from {
+        let path = format!("{}.{}", path, ident);
+
+        let to_endpoint = match to.remove(ident) {
+            Some(to_endpoint) => to_endpoint,
+            None => {
+                changes.push(Change::new(ChangeKind::Removed, Element::Endpoint, path));
+                continue;
+            }
+        };
+
+        let mut details = Vec::new();
+
+        if from_endpoint.name() != to_endpoint.name() {
+            details.push(format!(
+                "name changed from `{}` to `{}`",
+                from_endpoint.name(),
+                to_endpoint.name()
+            ));
+        }
+
+        for from_argument in &from_endpoint.arguments {
+            let to_argument = to_endpoint
+                .arguments
+                .iter()
+                .find(|a| a.ident() == from_argument.ident());
+
+            let to_argument = match to_argument {
+                Some(to_argument) => to_argument,
+                None => {
+                    details.push(format!("argument `{}` removed", from_argument.ident()));
+                    continue;
+                }
+            };
+
+            let from_channel = Some(Loc::value(&from_argument.channel));
+            let to_channel = Some(Loc::value(&to_argument.channel));
+
+            if channel_changed(from_channel, to_channel) {
+                details.push(format!(
+                    "argument `{}` changed from `{}` to `{}`",
+                    from_argument.ident(),
+                    fmt_channel(from_channel),
+                    fmt_channel(to_channel)
+                ));
+            }
+        }
+
+        for to_argument in &to_endpoint.arguments {
+            let exists = from_endpoint
+                .arguments
+                .iter()
+                .any(|a| a.ident() == to_argument.ident());
+
+            if !exists {
+                details.push(format!("argument `{}` added", to_argument.ident()));
+            }
+        }
+
+        let from_path = from_endpoint.http.path.as_ref();
+        let to_path = to_endpoint.http.path.as_ref();
+
+        if from_path.map(path_segments) != to_path.map(path_segments) {
+            details.push(format!(
+                "HTTP path changed from `{}` to `{}`",
+                from_path
+                    .map(path_template)
+                    .unwrap_or_else(|| "*empty*".to_string()),
+                to_path
+                    .map(path_template)
+                    .unwrap_or_else(|| "*empty*".to_string())
+            ));
+        }
+
+        if http_method(from_endpoint) != http_method(to_endpoint) {
+            details.push(format!(
+                "HTTP method changed from `{}` to `{}`",
+                http_method(from_endpoint).as_str(),
+                http_method(to_endpoint).as_str()
+            ));
+        }
+
+        let from_response = from_endpoint.response.as_ref().map(Loc::value);
+        let to_response = to_endpoint.response.as_ref().map(Loc::value);
+
+        if channel_changed(from_response, to_response) {
+            details.push(format!(
+                "response changed from `{}` to `{}`",
+                fmt_channel(from_response),
+                fmt_channel(to_response)
+            ));
+        }
+
+        diff_deprecated(
+            &mut details,
+            from_endpoint.deprecated.as_ref(),
+            to_endpoint.deprecated.as_ref(),
+        );
+
+        if !details.is_empty() {
+            changes.push(Change::changed(Element::Endpoint, path, details));
+        }
+    }
+
+    for (ident, _) in to {
+        let path = format!("{}.{}", path, ident);
+        changes.push(Change::new(ChangeKind::Added, Element::Endpoint, path));
+    }
+}
+
+/// List every added, removed, and changed declaration, field, variant, and endpoint.
+///
+/// Changes are sorted by the path of the element they apply to.
+pub fn diff(from: &RpFile, to: &RpFile) -> Result<Vec<Change>> {
+    let mut changes = Vec::new();
+
+    let from_storage = decls_to_map(&from.decls);
+    let mut to_storage = decls_to_map(&to.decls);
+
+    for (name, from_named) in &from_storage {
+        let path = name.parts.join("::");
+
+        let to_named = match to_storage.remove(name) {
+            Some(to_named) => to_named,
+            None => {
+                changes.push(Change::new(ChangeKind::Removed, Element::Decl, path));
+                continue;
+            }
+        };
+
+        let mut details = Vec::new();
+
+        if named_kind(from_named) != named_kind(&to_named) {
+            details.push(format!(
+                "changed from {} to {}",
+                named_kind(from_named),
+                named_kind(&to_named)
+            ));
+        }
+
+        if let (Some(from_type), Some(to_type)) = (enum_type(from_named), enum_type(&to_named)) {
+            if from_type != to_type {
+                details.push(format!(
+                    "enum type changed from `{}` to `{}`",
+                    from_type, to_type
+                ));
+            }
+        }
+
+        let from_strategy = sub_type_strategy(from_named);
+        let to_strategy = sub_type_strategy(&to_named);
+
+        if let (Some(from_strategy), Some(to_strategy)) = (from_strategy, to_strategy) {
+            if from_strategy != to_strategy {
+                details.push(format!(
+                    "sub-type strategy changed from {} to {}",
+                    from_strategy, to_strategy
+                ));
+            }
+        }
+
+        diff_deprecated(&mut details, from_named.deprecated(), to_named.deprecated());
+
+        if !details.is_empty() {
+            changes.push(Change::changed(Element::Decl, path.clone(), details));
+        }
+
+        let positional = is_positional(from_named) && is_positional(&to_named);
+
+        diff_fields(
+            &mut changes,
+            &path,
+            fields(from_named),
+            fields(&to_named),
+            positional,
+        );
+
+        diff_variants(
+            &mut changes,
+            &path,
+            enum_variants(from_named),
+            enum_variants(&to_named),
+        );
+
+        diff_endpoints(
+            &mut changes,
+            &path,
+            endpoints_to_map(from_named),
+            endpoints_to_map(&to_named),
+        );
+    }
+
+    for (name, _) in to_storage {
+        let path = name.parts.join("::");
+        changes.push(Change::new(ChangeKind::Added, Element::Decl, path));
+    }
+
+    changes.sort_by(|a, b| a.path.cmp(&b.path));
+    Ok(changes)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{diff, Change, ChangeKind, Element};
+    use core::flavored::{RpDecl, RpEnumType, RpFile, RpHttpMethod, RpType};
+    use tests::{endpoint, enumeration, field, segment, service, tuple, type_decl, unsigned};
+
+    fn changes(from: RpDecl, to: RpDecl) -> Vec<Change> {
+        let from = RpFile {
+            comment: vec![],
+            decls: vec![from],
+        };
+
+        let to = RpFile {
+            comment: vec![],
+            decls: vec![to],
+        };
+
+        diff(&from, &to).unwrap()
+    }
+
+    fn assert_change(
+        change: &Change,
+        kind: ChangeKind,
+        element: Element,
+        path: &str,
+        details: &[&str],
+    ) {
+        assert_eq!(kind, change.kind);
+        assert_eq!(element, change.element);
+        assert_eq!(path, change.path);
+        assert_eq!(details, change.details.as_slice());
+    }
+
+    #[test]
+    fn test_fields() {
+        let mut optional = field("c", None, RpType::String);
+        optional.required = false;
+
+        let from = type_decl(vec![
+            field("a", None, unsigned(32)),
+            field("b", None, RpType::String),
+            field("c", None, RpType::String),
+        ]);
+
+        let to = type_decl(vec![
+            field("a", None, unsigned(64)),
+            optional,
+            field("d", None, RpType::String),
+        ]);
+
+        let changes = changes(from, to);
+
+        assert_eq!(4, changes.len());
+        assert_change(
+            &changes[0],
+            ChangeKind::Changed,
+            Element::Field,
+            "Foo.a",
+            &["type changed from `u32` to `u64`"],
+        );
+        assert_change(
+            &changes[1],
+            ChangeKind::Removed,
+            Element::Field,
+            "Foo.b",
+            &[],
+        );
+        assert_change(
+            &changes[2],
+            ChangeKind::Changed,
+            Element::Field,
+            "Foo.c",
+            &["changed to be optional"],
+        );
+        assert_change(&changes[3], ChangeKind::Added, Element::Field, "Foo.d", &[]);
+    }
+
+    #[test]
+    fn test_tuple_fields() {
+        let from = tuple(vec![
+            field("a", None, RpType::String),
+            field("b", None, RpType::String),
+        ]);
+
+        let to = tuple(vec![
+            field("b", None, RpType::String),
+            field("a", None, RpType::String),
+        ]);
+
+        let changes = changes(from, to);
+
+        assert_eq!(2, changes.len());
+        assert_change(
+            &changes[0],
+            ChangeKind::Changed,
+            Element::Field,
+            "Foo.a",
+            &["index changed from `0` to `1`"],
+        );
+        assert_change(
+            &changes[1],
+            ChangeKind::Changed,
+            Element::Field,
+            "Foo.b",
+            &["index changed from `1` to `0`"],
+        );
+    }
+
+    #[test]
+    fn test_variants() {
+        let from = enumeration(RpEnumType::String, &[("A", "a"), ("B", "b")]);
+        let to = enumeration(RpEnumType::String, &[("A", "x"), ("C", "c")]);
+
+        let changes = changes(from, to);
+
+        assert_eq!(3, changes.len());
+        assert_change(
+            &changes[0],
+            ChangeKind::Changed,
+            Element::Variant,
+            "Foo::A",
+            &["value changed from `\"a\"` to `\"x\"`"],
+        );
+        assert_change(
+            &changes[1],
+            ChangeKind::Removed,
+            Element::Variant,
+            "Foo::B",
+            &[],
+        );
+        assert_change(
+            &changes[2],
+            ChangeKind::Added,
+            Element::Variant,
+            "Foo::C",
+            &[],
+        );
+    }
+
+    #[test]
+    fn test_endpoints() {
+        let from = service(vec![
+            endpoint("delete", vec![segment("bar")], Some(RpHttpMethod::Delete)),
+            endpoint("get", vec![segment("foo")], None),
+        ]);
+
+        let to = service(vec![
+            endpoint("get", vec![segment("foo")], Some(RpHttpMethod::Post)),
+            endpoint("put", vec![segment("baz")], Some(RpHttpMethod::Put)),
+        ]);
+
+        let changes = changes(from, to);
+
+        assert_eq!(3, changes.len());
+        assert_change(
+            &changes[0],
+            ChangeKind::Removed,
+            Element::Endpoint,
+            "Foo.delete",
+            &[],
+        );
+        assert_change(
+            &changes[1],
+            ChangeKind::Changed,
+            Element::Endpoint,
+            "Foo.get",
+            &["HTTP method changed from `GET` to `POST`"],
+        );
+        assert_change(
+            &changes[2],
+            ChangeKind::Added,
+            Element::Endpoint,
+            "Foo.put",
+            &[],
+        );
+    }
+
+    #[test]
+    fn test_unchanged() {
+        let decl = type_decl(vec![field("a", None, RpType::String)]);
+        assert!(changes(decl.clone(), decl).is_empty());
+    }
+}
@@ -1,15 +1,23 @@
 extern crate reproto_core as core;
+extern crate serde;
+#[macro_use]
+extern crate serde_derive;
 
+mod diff;
+
+pub use self::diff::{diff, Change, ChangeKind, Element};
 use self::Component::*;
 use self::Violation::*;
 use core::errors::*;
-use core::flavored::{RpChannel, RpDecl, RpEndpoint, RpField, RpFile, RpName, RpNamed, RpType,
+use core::flavored::{RpChannel, RpDecl, RpEndpoint, RpEnumType, RpField, RpFile, RpHttpMethod,
+                     RpName, RpNamed, RpPathPart, RpPathSpec, RpSubType, RpSubTypeStrategy, RpType,
                      RpVariantRef};
 use core::{Loc, Span, Version};
 use std::collections::HashMap;
 
 #[derive(Debug, Clone)]
 pub enum Component {
+    Major,
     Minor,
     Patch,
 }
@@ -18,12 +26,32 @@ impl Component {
     /// Describe the component that was violated.
     pub fn describe(&self) -> &str {
         match *self {
+            Major => "major change violation",
             Minor => "minor change violation",
             Patch => "patch change violation",
         }
     }
 }
 
+/// What a violation breaks.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Breaks {
+    /// Generated code changes, but values are serialized the same way.
+    Source,
+    /// The serialized representation of values changes.
+    Wire,
+}
+
+impl Breaks {
+    /// Describe what is broken.
+    pub fn describe(&self) -> &str {
+        match *self {
+            Breaks::Source => "source-breaking",
+            Breaks::Wire => "wire-breaking",
+        }
+    }
+}
+
 #[derive(Debug)]
 pub enum Violation {
     /// An entire declaration has been removed.
@@ -39,9 +67,18 @@ pub enum Violation {
     /// Variant added.
     AddVariant(Component, Span),
     /// Field type was changed from one to another.
-    FieldTypeChange(Component, RpType, Span, RpType, Span),
-    /// Field name was changed from one to another.
+    ///
+    /// This only breaks source compatibility if the new type is serialized the same way, like
+    /// when widening `u32` to `u64`.
+    FieldTypeChange(Component, Breaks, RpType, Span, RpType, Span),
+    /// Serialized name of a field was changed from one to another.
     FieldNameChange(Component, String, Span, String, Span),
+    /// Field identifier was changed from one to another, while keeping its serialized name.
+    FieldIdentChange(Component, String, Span, String, Span),
+    /// Index of a tuple field was changed from one to another.
+    ///
+    /// Tuples are serialized as arrays, so this changes where the value of the field is found.
+    FieldIndexChange(Component, usize, Span, usize, Span),
     /// Variant identifier was changed from one to another.
     VariantOrdinalChange(Component, String, Span, String, Span),
     /// Field made required.
@@ -58,6 +95,29 @@ pub enum Violation {
     EndpointRequestChange(Component, Option<RpChannel>, Span, Option<RpChannel>, Span),
     /// Endpoint response type changed.
     EndpointResponseChange(Component, Option<RpChannel>, Span, Option<RpChannel>, Span),
+    /// HTTP path of an endpoint changed.
+    EndpointHttpPathChange(Component, Option<String>, Span, Option<String>, Span),
+    /// HTTP method of an endpoint changed.
+    EndpointHttpMethodChange(Component, RpHttpMethod, Span, RpHttpMethod, Span),
+    /// Type of an enum changed.
+    EnumTypeChange(Component, RpEnumType, Span, RpEnumType, Span),
+    /// Sub-type added to an interface.
+    AddSubType(Component, Span),
+    /// Sub-type removed from an interface.
+    RemoveSubType(Component, Span),
+    /// Strategy used to distinguish between the sub-types of an interface changed.
+    SubTypeStrategyChange(Component, RpSubTypeStrategy, Span, RpSubTypeStrategy, Span),
+}
+
+impl Violation {
+    /// What the violation breaks.
+    pub fn breaks(&self) -> Breaks {
+        match *self {
+            DeclAdded(..) | FieldIdentChange(..) => Breaks::Source,
+            FieldTypeChange(_, breaks, ..) => breaks,
+            _ => Breaks::Wire,
+        }
+    }
 }
 
 fn fields<'a>(named: &RpNamed<'a>) -> Vec<&'a Loc<RpField>> {
@@ -90,6 +150,110 @@ fn endpoints_to_map<'a>(named: &RpNamed<'a>) -> HashMap<&'a str, &'a Loc<RpEndpo
     }
 }
 
+fn sub_types_to_map<'a>(named: &RpNamed<'a>) -> HashMap<&'a str, &'a Loc<RpSubType>> {
+    use core::RpNamed::*;
+
+    match *named {
+        Interface(target) => target
+            .sub_types
+            .iter()
+            .map(|s| (s.ident.as_str(), s))
+            .collect(),
+        _ => HashMap::new(),
+    }
+}
+
+/// Test if the named element is a sub-type.
+///
+/// Sub-types are checked together with the interface they belong to.
+fn is_sub_type(named: &RpNamed) -> bool {
+    match *named {
+        core::RpNamed::SubType(_) => true,
+        _ => false,
+    }
+}
+
+/// Test if the fields of the named element are identified by their position, like in tuples.
+fn is_positional(named: &RpNamed) -> bool {
+    match *named {
+        core::RpNamed::Tuple(_) => true,
+        _ => false,
+    }
+}
+
+/// Find the index of the field with the same identifier as `field`.
+fn field_index(fields: &[&Loc<RpField>], field: &RpField) -> Option<usize> {
+    fields.iter().position(|f| f.ident() == field.ident())
+}
+
+fn enum_type<'a>(named: &RpNamed<'a>) -> Option<&'a RpEnumType> {
+    match *named {
+        core::RpNamed::Enum(target) => Some(&target.enum_type),
+        _ => None,
+    }
+}
+
+fn sub_type_strategy<'a>(named: &RpNamed<'a>) -> Option<&'a RpSubTypeStrategy> {
+    match *named {
+        core::RpNamed::Interface(target) => Some(&target.sub_type_strategy),
+        _ => None,
+    }
+}
+
+/// Build the path template, like `/users/{id}`.
+fn path_template(path: &RpPathSpec) -> String {
+    let mut out = String::new();
+
+    for step in &path.steps {
+        out.push('/');
+
+        for part in &step.parts {
+            match *part {
+                RpPathPart::Variable(ref arg) => {
+                    out.push('{');
+                    out.push_str(arg.ident());
+                    out.push('}');
+                }
+                RpPathPart::Segment(ref segment) => out.push_str(segment),
+            }
+        }
+    }
+
+    if out.is_empty() {
+        out.push('/');
+    }
+
+    out
+}
+
+/// The segments of a path, where variables are `None`.
+///
+/// Used to compare paths, since renaming a variable doesn't change which requests match the path.
+fn path_segments(path: &RpPathSpec) -> Vec<Vec<Option<&str>>> {
+    path.steps
+        .iter()
+        .map(|step| {
+            step.parts
+                .iter()
+                .map(|part| match *part {
+                    RpPathPart::Variable(_) => None,
+                    RpPathPart::Segment(ref segment) => Some(segment.as_str()),
+                })
+                .collect()
+        })
+        .collect()
+}
+
+/// The HTTP method used by an endpoint, which defaults to `GET`.
+fn http_method(endpoint: &RpEndpoint) -> RpHttpMethod {
+    endpoint
+        .http
+        .method
+        .as_ref()
+        .cloned()
+        .unwrap_or(RpHttpMethod::Get)
+}
+
 fn decls_to_map<'a, I: 'a>(decls: I) -> HashMap<RpName, RpNamed<'a>>
 where
     I: IntoIterator<Item = &'a RpDecl>,
@@ -123,6 +287,83 @@ where
     storage
 }
 
+/// Fields paired up between two versions of a declaration.
+struct FieldPairs<'a> {
+    /// Fields present in both versions.
+    pairs: Vec<(&'a Loc<RpField>, &'a Loc<RpField>)>,
+    /// Fields only present in the old version.
+    removed: Vec<&'a Loc<RpField>>,
+    /// Fields only present in the new version.
+    added: Vec<&'a Loc<RpField>>,
+}
+
+/// Pair up fields by identifier, falling back to their serialized name.
+///
+/// This permits detecting fields whose identifier changed while their serialized name stayed the
+/// same.
+fn pair_fields<'a>(from: Vec<&'a Loc<RpField>>, to: Vec<&'a Loc<RpField>>) -> FieldPairs<'a> {
+    let mut to_fields = fields_to_map(to);
+
+    let mut pairs = Vec::new();
+    let mut unmatched = Vec::new();
+
+    for from_field in from {
+        match to_fields.remove(from_field.ident()) {
+            Some(to_field) => pairs.push((from_field, to_field)),
+            None => unmatched.push(from_field),
+        }
+    }
+
+    let mut removed = Vec::new();
+
+    for from_field in unmatched {
+        let ident = to_fields
+            .values()
+            .find(|to_field| to_field.name() == from_field.name())
+            .map(|to_field| to_field.ident().to_string());
+
+        match ident.and_then(|ident| to_fields.remove(&ident)) {
+            Some(to_field) => pairs.push((from_field, to_field)),
+            None => removed.push(from_field),
+        }
+    }
+
+    let added = to_fields.into_iter().map(|(_, field)| field).collect();
+
+    FieldPairs {
+        pairs: pairs,
+        removed: removed,
+        added: added,
+    }
+}
+
+/// Test if values of type `from` are serialized the same way when they are of type `to`.
+///
+/// Widening numeric types is permitted, since every value of the old type is a valid value of the
+/// new one.
+fn is_wire_compatible(from: &RpType, to: &RpType) -> bool {
+    use core::RpType::*;
+
+    match (from, to) {
+        (&Signed { size: from }, &Signed { size: to }) => from <= to,
+        (&Unsigned { size: from }, &Unsigned { size: to }) => from <= to,
+        (&Unsigned { size: from }, &Signed { size: to }) => from < to,
+        (&Float, &Double) => true,
+        (&Array { inner: ref from }, &Array { inner: ref to }) => is_wire_compatible(from, to),
+        (
+            &Map {
+                key: ref from_key,
+                value: ref from_value,
+            },
+            &Map {
+                key: ref to_key,
+                value: ref to_value,
+            },
+        ) => is_wire_compatible(from_key, to_key) && is_wire_compatible(from_value, to_value),
+        (from, to) => from.clone().localize() == to.clone().localize(),
+    }
+}
+
 fn fields_to_map<'a, I: 'a>(fields: I) -> HashMap<String, &'a Loc<RpField>>
 where
     I: IntoIterator<Item = &'a Loc<RpField>>,
@@ -214,6 +455,102 @@ fn check_endpoint_type(
     Ok(())
 }
 
+/// Check that the HTTP path and method of an endpoint is unchanged.
+fn check_endpoint_http(
+    component: Component,
+    violations: &mut Vec<Violation>,
+    from_endpoint: &Loc<RpEndpoint>,
+    to_endpoint: &Loc<RpEndpoint>,
+) -> Result<()> {
+    let from_path = from_endpoint.http.path.as_ref();
+    let to_path = to_endpoint.http.path.as_ref();
+
+    if from_path.map(path_segments) != to_path.map(path_segments) {
+        violations.push(EndpointHttpPathChange(
+            component.clone(),
+            from_path.map(path_template),
+            Loc::span(from_endpoint).into(),
+            to_path.map(path_template),
+            Loc::span(to_endpoint).into(),
+        ));
+    }
+
+    let from_method = http_method(from_endpoint);
+    let to_method = http_method(to_endpoint);
+
+    if from_method != to_method {
+        violations.push(EndpointHttpMethodChange(
+            component.clone(),
+            from_method,
+            Loc::span(from_endpoint).into(),
+            to_method,
+            Loc::span(to_endpoint).into(),
+        ));
+    }
+
+    Ok(())
+}
+
+/// Check that the enum type and sub-type strategy of a declaration is unchanged.
+fn common_check_named(
+    component: Component,
+    violations: &mut Vec<Violation>,
+    from_named: &RpNamed,
+    to_named: &RpNamed,
+) -> Result<()> {
+    if let (Some(from_type), Some(to_type)) = (enum_type(from_named), enum_type(to_named)) {
+        if from_type != to_type {
+            violations.push(EnumTypeChange(
+                component.clone(),
+                from_type.clone(),
+                from_named.span().into(),
+                to_type.clone(),
+                to_named.span().into(),
+            ));
+        }
+    }
+
+    let from_strategy = sub_type_strategy(from_named);
+    let to_strategy = sub_type_strategy(to_named);
+
+    if let (Some(from_strategy), Some(to_strategy)) = (from_strategy, to_strategy) {
+        if from_strategy != to_strategy {
+            violations.push(SubTypeStrategyChange(
+                component.clone(),
+                from_strategy.clone(),
+                from_named.span().into(),
+                to_strategy.clone(),
+                to_named.span().into(),
+            ));
+        }
+    }
+
+    // fields in tuples are not permitted to move, since they are serialized by position.
+    if is_positional(from_named) && is_positional(to_named) {
+        let from_fields = fields(from_named);
+        let to_fields = fields(to_named);
+
+        for (from_field, to_field) in pair_fields(from_fields.clone(), to_fields.clone()).pairs {
+            let from_index = field_index(&from_fields, from_field);
+            let to_index = field_index(&to_fields, to_field);
+
+            if let (Some(from_index), Some(to_index)) = (from_index, to_index) {
+                if from_index != to_index {
+                    violations.push(FieldIndexChange(
+                        component.clone(),
+                        from_index,
+                        Loc::span(from_field).into(),
+                        to_index,
+                        Loc::span(to_field).into(),
+                    ));
+                }
+            }
+        }
+    }
+
+    Ok(())
+}
+
 fn common_check_variant(
     component: Component,
     violations: &mut Vec<Violation>,
@@ -233,15 +570,25 @@ fn common_check_variant(
     Ok(())
 }
 
+/// Check a pair of fields, where `positional` indicates that fields are identified by their
+/// index.
 fn common_check_field(
     component: Component,
     violations: &mut Vec<Violation>,
+    positional: bool,
     from_field: &Loc<RpField>,
     to_field: &Loc<RpField>,
 ) -> Result<()> {
     if to_field.ty.clone().localize() != from_field.ty.clone().localize() {
+        let breaks = if is_wire_compatible(&from_field.ty, &to_field.ty) {
+            Breaks::Source
+        } else {
+            Breaks::Wire
+        };
+
         violations.push(FieldTypeChange(
             component.clone(),
+            breaks,
             from_field.ty.clone(),
             Loc::span(from_field).into(),
             to_field.ty.clone(),
@@ -249,6 +596,22 @@ fn common_check_field(
         ));
     }
 
+    // names are not serialized for positional fields, moving them is checked separately.
+    if positional {
+        return Ok(());
+    }
+
+    // renaming the field in source is permitted on the wire, as long as its name is unchanged.
+    if to_field.ident() != from_field.ident() {
+        violations.push(FieldIdentChange(
+            component.clone(),
+            from_field.ident().to_string(),
+            Loc::span(from_field).into(),
+            to_field.ident().to_string(),
+            Loc::span(to_field).into(),
+        ));
+    }
+
     // not permitted to rename fields.
     if to_field.name() != from_field.name() {
         violations.push(FieldNameChange(
@@ -263,6 +626,95 @@ fn common_check_field(
     Ok(())
 }
 
+/// Test if the named element, or any declaration enclosing it, is deprecated.
+fn is_deprecated(storage: &HashMap<RpName, RpNamed>, name: &RpName) -> bool {
+    let mut parts = name.parts.clone();
+
+    while !parts.is_empty() {
+        let parent = name.clone().with_parts(parts.clone());
+
+        if let Some(named) = storage.get(&parent) {
+            if named.deprecated().is_some() {
+                return true;
+            }
+        }
+
+        parts.pop();
+    }
+
+    false
+}
+
+/// Performs checks for major version violations.
+///
+/// Anything may change in a major version, but only deprecated elements may be removed.
+/// This gives clients at least one version to migrate away from them.
+fn check_major(from: &RpFile, to: &RpFile) -> Result<Vec<Violation>> {
+    let mut violations = Vec::new();
+
+    let from_storage = decls_to_map(&from.decls);
+    let mut to_storage = decls_to_map(&to.decls);
+
+    for (name, from_named) in &from_storage {
+        let to_named = match to_storage.remove(name) {
+            Some(to_named) => to_named,
+            None => {
+                if !is_sub_type(from_named) && !is_deprecated(&from_storage, name) {
+                    violations.push(DeclRemoved(Major, from_named.span().into()));
+                }
+
+                continue;
+            }
+        };
+
+        let deprecated = is_deprecated(&from_storage, name);
+
+        let field_pairs = pair_fields(fields(from_named), fields(&to_named));
+
+        for from_field in field_pairs.removed {
+            if !deprecated && from_field.deprecated.is_none() {
+                violations.push(RemoveField(Major, Loc::span(from_field).into()));
+            }
+        }
+
+        let from_variants = variants_to_map(enum_variants(from_named));
+        let to_variants = variants_to_map(enum_variants(&to_named));
+
+        for (name, from_variant) in from_variants.into_iter() {
+            if !to_variants.contains_key(&name) && !deprecated && from_variant.deprecated.is_none()
+            {
+                violations.push(RemoveVariant(Major, from_variant.span.into()));
+            }
+        }
+
+        let from_endpoints = endpoints_to_map(from_named);
+        let to_endpoints = endpoints_to_map(&to_named);
+
+        for (name, from_endpoint) in from_endpoints.into_iter() {
+            if !to_endpoints.contains_key(&name)
+                && !deprecated
+                && from_endpoint.deprecated.is_none()
+            {
+                violations.push(RemoveEndpoint(Major, Loc::span(from_endpoint).into()));
+            }
+        }
+
+        let from_sub_types = sub_types_to_map(from_named);
+        let to_sub_types = sub_types_to_map(&to_named);
+
+        for (name, from_sub_type) in from_sub_types.into_iter() {
+            if !to_sub_types.contains_key(&name)
+                && !deprecated
+                && from_sub_type.deprecated.is_none()
+            {
+                violations.push(RemoveSubType(Major, Loc::span(from_sub_type).into()));
+            }
+        }
+    }
+
+    Ok(violations)
+}
+
 /// Performs checks for minor version violations.
 fn check_minor(from: &RpFile, to: &RpFile) -> Result<Vec<Violation>> {
     let mut violations = Vec::new();
@@ -272,19 +724,21 @@ fn check_minor(from: &RpFile, to: &RpFile) -> Result<Vec<Violation>> {
 
     for (name, from_named) in from_storage {
         if let Some(to_named) = to_storage.remove(&name) {
-            let from_fields = fields_to_map(fields(&from_named));
-            let mut to_fields = fields_to_map(fields(&to_named));
+            common_check_named(Minor, &mut violations, &from_named, &to_named)?;
 
-            for (name, from_field) in from_fields.into_iter() {
-                if let Some(to_field) = to_fields.remove(&name) {
-                    check_field(&mut violations, from_field, to_field)?;
-                } else {
-                    violations.push(RemoveField(Minor, Loc::span(from_field).into()));
-                }
+            let positional = is_positional(&from_named) && is_positional(&to_named);
+            let field_pairs = pair_fields(fields(&from_named), fields(&to_named));
+
+            for (from_field, to_field) in field_pairs.pairs {
+                check_field(&mut violations, positional, from_field, to_field)?;
+            }
+
+            for from_field in field_pairs.removed {
+                violations.push(RemoveField(Minor, Loc::span(from_field).into()));
             }
 
             // check that added fields are not required.
-            for (_, to_field) in to_fields.into_iter() {
+            for to_field in field_pairs.added {
                 if to_field.is_required() {
                     violations.push(AddRequiredField(Minor, Loc::span(to_field).into()));
                 }
@@ -311,7 +765,21 @@ fn check_minor(from: &RpFile, to: &RpFile) -> Result<Vec<Violation>> {
                     violations.push(RemoveEndpoint(Minor, Loc::span(from_endpoint).into()));
                 }
             }
-        } else {
+
+            let from_sub_types = sub_types_to_map(&from_named);
+            let mut to_sub_types = sub_types_to_map(&to_named);
+
+            for (name, from_sub_type) in from_sub_types.into_iter() {
+                if to_sub_types.remove(&name).is_none() {
+                    violations.push(RemoveSubType(Minor, Loc::span(from_sub_type).into()));
+                }
+            }
+
+            // added sub-types are not permitted, since clients can't handle them
+            for (_, to_sub_type) in to_sub_types.into_iter() {
+                violations.push(AddSubType(Minor, Loc::span(to_sub_type).into()));
+            }
+        } else if !is_sub_type(&from_named) {
             violations.push(DeclRemoved(Minor, from_named.span().into()));
         }
     }
@@ -320,10 +788,11 @@ fn check_minor(from: &RpFile, to: &RpFile) -> Result<Vec<Violation>> {
 
     fn check_field(
         violations: &mut Vec<Violation>,
+        positional: bool,
         from_field: &Loc<RpField>,
         to_field: &Loc<RpField>,
     ) -> Result<()> {
-        common_check_field(Minor, violations, from_field, to_field)?;
+        common_check_field(Minor, violations, positional, from_field, to_field)?;
 
         // Minor patch may make fields optional, but not required.
         if from_field.is_optional() && to_field.is_required() {
@@ -352,6 +821,7 @@ fn check_minor(from: &RpFile, to: &RpFile) -> Result<Vec<Violation>> {
         to_endpoint: &Loc<RpEndpoint>,
     ) -> Result<()> {
         check_endpoint_type(Minor, violations, from_endpoint, to_endpoint)?;
+        check_endpoint_http(Minor, violations, from_endpoint, to_endpoint)?;
         Ok(())
     }
 }
@@ -364,19 +834,21 @@ fn check_patch(from: &RpFile, to: &RpFile) -> Result<Vec<Violation>> {
 
     for (name, from_named) in from_storage {
         if let Some(to_named) = to_storage.remove(&name) {
-            let from_fields = fields_to_map(fields(&from_named));
-            let mut to_fields = fields_to_map(fields(&to_named));
+            common_check_named(Patch, &mut violations, &from_named, &to_named)?;
 
-            for (name, from_field) in from_fields.into_iter() {
-                if let Some(to_field) = to_fields.remove(&name) {
-                    check_field(&mut violations, from_field, to_field)?;
-                } else {
-                    violations.push(RemoveField(Patch, Loc::span(from_field).into()));
-                }
+            let positional = is_positional(&from_named) && is_positional(&to_named);
+            let field_pairs = pair_fields(fields(&from_named), fields(&to_named));
+
+            for (from_field, to_field) in field_pairs.pairs {
+                check_field(&mut violations, positional, from_field, to_field)?;
+            }
+
+            for from_field in field_pairs.removed {
+                violations.push(RemoveField(Patch, Loc::span(from_field).into()));
             }
 
             // added fields are not permitted
-            for (_, to_field) in to_fields.into_iter() {
+            for to_field in field_pairs.added {
                 violations.push(AddField(Patch, Loc::span(to_field).into()));
             }
 
@@ -411,23 +883,40 @@ fn check_patch(from: &RpFile, to: &RpFile) -> Result<Vec<Violation>> {
             for (_, to_endpoint) in to_endpoints.into_iter() {
                 violations.push(AddEndpoint(Patch, Loc::span(to_endpoint).into()));
             }
-        } else {
+
+            let from_sub_types = sub_types_to_map(&from_named);
+            let mut to_sub_types = sub_types_to_map(&to_named);
+
+            for (name, from_sub_type) in from_sub_types.into_iter() {
+                if to_sub_types.remove(&name).is_none() {
+                    violations.push(RemoveSubType(Patch, Loc::span(from_sub_type).into()));
+                }
+            }
+
+            // added sub-types are not permitted, since clients can't handle them
+            for (_, to_sub_type) in to_sub_types.into_iter() {
+                violations.push(AddSubType(Patch, Loc::span(to_sub_type).into()));
+            }
+        } else if !is_sub_type(&from_named) {
             violations.push(DeclRemoved(Patch, from_named.span().into()));
         }
     }
 
     for (_, to_named) in to_storage.into_iter() {
-        violations.push(DeclAdded(Patch, to_named.span().into()));
+        if !is_sub_type(&to_named) {
+            violations.push(DeclAdded(Patch, to_named.span().into()));
+        }
     }
 
     return Ok(violations);
 
     fn check_field(
         violations: &mut Vec<Violation>,
+        positional: bool,
         from_field: &Loc<RpField>,
         to_field: &Loc<RpField>,
     ) -> Result<()> {
-        common_check_field(Patch, violations, from_field, to_field)?;
+        common_check_field(Patch, violations, positional, from_field, to_field)?;
 
         if to_field.required != from_field.required {
             violations.push(FieldModifierChange(
@@ -455,6 +944,7 @@ fn check_patch(from: &RpFile, to: &RpFile) -> Result<Vec<Violation>> {
         to_endpoint: &Loc<RpEndpoint>,
     ) -> Result<()> {
         check_endpoint_type(Patch, violations, from_endpoint, to_endpoint)?;
+        check_endpoint_http(Patch, violations, from_endpoint, to_endpoint)?;
         Ok(())
     }
 }
@@ -463,6 +953,10 @@ pub fn check(from: (&Version, &RpFile), to: (&Version, &RpFile)) -> Result<Vec<V
     let (from_version, from_file) = from;
     let (to_version, to_file) = to;
 
+    if from_version.major < to_version.major {
+        return check_major(from_file, to_file);
+    }
+
     if from_version.major == to_version.major {
         if from_version.minor < to_version.minor {
             return check_minor(from_file, to_file);
@@ -475,3 +969,484 @@ pub fn check(from: (&Version, &RpFile), to: (&Version, &RpFile)) -> Result<Vec<V
 
     Ok(vec![])
 }
+
+#[cfg(test)]
+mod tests {
+    // helpers are public, since they are also used by the tests for `diff`.
+
+    use super::Component::*;
+    use super::Violation::*;
+    use super::{check, check_endpoint_http, common_check_named, is_wire_compatible, pair_fields,
+                Violation};
+    use core::flavored::{RpChannel, RpDecl, RpEndpoint, RpEndpointArgument, RpEndpointHttp,
+                         RpEnumBody, RpEnumType, RpField, RpFile, RpHttpMethod, RpInterfaceBody,
+                         RpName, RpPathPart, RpPathSpec, RpPathStep, RpServiceBody,
+                         RpServiceBodyHttp, RpSubType, RpSubTypeStrategy, RpTupleBody, RpType,
+                         RpTypeBody, RpVariant, RpVariants};
+    use core::{Attributes, Loc, RpPackage, RpVersionedPackage, Span, Version};
+    use std::collections::HashMap;
+    use std::rc::Rc;
+
+    pub fn field(ident: &str, field_as: Option<&str>, ty: RpType) -> Loc<RpField> {
+        let mut field = RpField::new(ident, ty);
+        field.field_as = field_as.map(|s| s.to_string());
+        Loc::new(field, Span::empty())
+    }
+
+    pub fn name(parts: &[&str]) -> RpName {
+        let package = RpVersionedPackage::new(RpPackage::empty(), None);
+        RpName::new(None, package, parts.iter().map(|p| p.to_string()).collect())
+    }
+
+    pub fn type_decl(fields: Vec<Loc<RpField>>) -> RpDecl {
+        let body = RpTypeBody {
+            name: name(&["Foo"]),
+            ident: "Foo".to_string(),
+            comment: vec![],
+            decls: vec![],
+            deprecated: None,
+            fields: fields,
+            codes: vec![],
+        };
+
+        RpDecl::Type(Loc::new(body, Span::empty()))
+    }
+
+    pub fn tuple(fields: Vec<Loc<RpField>>) -> RpDecl {
+        let body = RpTupleBody {
+            name: name(&["Foo"]),
+            ident: "Foo".to_string(),
+            comment: vec![],
+            decls: vec![],
+            deprecated: None,
+            fields: fields,
+            codes: vec![],
+        };
+
+        RpDecl::Tuple(Loc::new(body, Span::empty()))
+    }
+
+    /// Build an enum, where each variant is an identifier and a value.
+    pub fn enumeration(enum_type: RpEnumType, variants: &[(&str, &str)]) -> RpDecl {
+        let variants = variants
+            .iter()
+            .map(|&(ident, value)| {
+                let variant = RpVariant {
+                    name: name(&["Foo", ident]),
+                    ident: Loc::new(ident.to_string(), Span::empty()),
+                    comment: vec![],
+                    deprecated: None,
+                    value: value.to_string(),
+                };
+
+                Loc::new(variant, Span::empty())
+            })
+            .collect();
+
+        let body = RpEnumBody {
+            name: name(&["Foo"]),
+            ident: "Foo".to_string(),
+            comment: vec![],
+            decls: vec![],
+            deprecated: None,
+            enum_type: enum_type,
+            variants: RpVariants::String { variants: variants },
+            codes: vec![],
+        };
+
+        RpDecl::Enum(Loc::new(body, Span::empty()))
+    }
+
+    pub fn interface(sub_type_strategy: RpSubTypeStrategy, sub_types: &[&str]) -> RpDecl {
+        let sub_types = sub_types
+            .iter()
+            .map(|s| {
+                let sub_type = RpSubType {
+                    name: name(&["Foo", *s]),
+                    ident: s.to_string(),
+                    comment: vec![],
+                    decls: vec![],
+                    fields: vec![],
+                    codes: vec![],
+                    sub_type_name: None,
+                    number: None,
+                    deprecated: None,
+                };
+
+                Loc::new(sub_type, Span::empty())
+            })
+            .collect();
+
+        let body = RpInterfaceBody {
+            name: name(&["Foo"]),
+            ident: "Foo".to_string(),
+            comment: vec![],
+            decls: vec![],
+            deprecated: None,
+            fields: vec![],
+            codes: vec![],
+            sub_types: sub_types,
+            sub_type_strategy: sub_type_strategy,
+        };
+
+        RpDecl::Interface(Loc::new(body, Span::empty()))
+    }
+
+    pub fn segment(segment: &str) -> RpPathPart {
+        RpPathPart::Segment(segment.to_string())
+    }
+
+    pub fn variable(ident: &str) -> RpPathPart {
+        RpPathPart::Variable(RpEndpointArgument {
+            ident: Rc::new(Loc::new(ident.to_string(), Span::empty())),
+            safe_ident: Rc::new(None),
+            channel: Loc::new(RpChannel::Unary { ty: RpType::String }, Span::empty()),
+        })
+    }
+
+    /// Build an endpoint, where every part of the path is a separate step.
+    pub fn endpoint(
+        ident: &str,
+        parts: Vec<RpPathPart>,
+        method: Option<RpHttpMethod>,
+    ) -> Loc<RpEndpoint> {
+        let steps = parts
+            .into_iter()
+            .map(|part| RpPathStep { parts: vec![part] })
+            .collect();
+
+        let endpoint = RpEndpoint {
+            ident: ident.to_string(),
+            safe_ident: None,
+            name: None,
+            comment: vec![],
+            deprecated: None,
+            attributes: Attributes::new(HashMap::new(), HashMap::new()),
+            arguments: vec![],
+            request: None,
+            response: None,
+            http: RpEndpointHttp {
+                path: Some(RpPathSpec { steps: steps }),
+                method: method,
+                ..RpEndpointHttp::default()
+            },
+        };
+
+        Loc::new(endpoint, Span::empty())
+    }
+
+    pub fn service(endpoints: Vec<Loc<RpEndpoint>>) -> RpDecl {
+        let body = RpServiceBody {
+            name: name(&["Foo"]),
+            ident: "Foo".to_string(),
+            comment: vec![],
+            decls: vec![],
+            deprecated: None,
+            http: RpServiceBodyHttp::default(),
+            endpoints: endpoints,
+        };
+
+        RpDecl::Service(Loc::new(body, Span::empty()))
+    }
+
+    /// Check the given declaration, going from version `from` to `to`.
+    fn check_decl(from: (&str, RpDecl), to: (&str, RpDecl)) -> Vec<Violation> {
+        let from_version = Version::parse(from.0).unwrap();
+        let to_version = Version::parse(to.0).unwrap();
+
+        let from_file = RpFile {
+            comment: vec![],
+            decls: vec![from.1],
+        };
+
+        let to_file = RpFile {
+            comment: vec![],
+            decls: vec![to.1],
+        };
+
+        check((&from_version, &from_file), (&to_version, &to_file)).unwrap()
+    }
+
+    fn check_named(from: &RpDecl, to: &RpDecl) -> Vec<Violation> {
+        let mut violations = Vec::new();
+        // the declaration itself comes after any of its variants or sub-types.
+        let from_named = from.to_named().pop().unwrap();
+        let to_named = to.to_named().pop().unwrap();
+        common_check_named(Minor, &mut violations, &from_named, &to_named).unwrap();
+        violations
+    }
+
+    fn check_http(from: &Loc<RpEndpoint>, to: &Loc<RpEndpoint>) -> Vec<Violation> {
+        let mut violations = Vec::new();
+        check_endpoint_http(Minor, &mut violations, from, to).unwrap();
+        violations
+    }
+
+    pub fn signed(size: usize) -> RpType {
+        RpType::Signed { size: size }
+    }
+
+    pub fn unsigned(size: usize) -> RpType {
+        RpType::Unsigned { size: size }
+    }
+
+    pub fn array(inner: RpType) -> RpType {
+        RpType::Array {
+            inner: Box::new(inner),
+        }
+    }
+
+    #[test]
+    fn test_widening() {
+        assert!(is_wire_compatible(&unsigned(32), &unsigned(64)));
+        assert!(is_wire_compatible(&signed(32), &signed(64)));
+        assert!(is_wire_compatible(&RpType::Float, &RpType::Double));
+        assert!(is_wire_compatible(&array(signed(32)), &array(signed(64))));
+    }
+
+    #[test]
+    fn test_narrowing() {
+        assert!(!is_wire_compatible(&unsigned(64), &unsigned(32)));
+        assert!(!is_wire_compatible(&signed(64), &signed(32)));
+        assert!(!is_wire_compatible(&RpType::Double, &RpType::Float));
+        assert!(!is_wire_compatible(&array(signed(64)), &array(signed(32))));
+        assert!(!is_wire_compatible(&signed(32), &unsigned(64)));
+    }
+
+    #[test]
+    fn test_unsigned_to_signed() {
+        assert!(is_wire_compatible(&unsigned(32), &signed(64)));
+        assert!(!is_wire_compatible(&unsigned(32), &signed(32)));
+        assert!(!is_wire_compatible(&unsigned(64), &signed(64)));
+    }
+
+    #[test]
+    fn test_rename_with_same_name() {
+        let from = vec![field("foo", None, RpType::String)];
+        let to = vec![field("bar", Some("foo"), RpType::String)];
+
+        let pairs = pair_fields(from.iter().collect(), to.iter().collect());
+
+        assert_eq!(1, pairs.pairs.len());
+        assert_eq!("foo", pairs.pairs[0].0.ident());
+        assert_eq!("bar", pairs.pairs[0].1.ident());
+        assert!(pairs.removed.is_empty());
+        assert!(pairs.added.is_empty());
+    }
+
+    #[test]
+    fn test_rename_with_new_name() {
+        let from = vec![field("foo", None, RpType::String)];
+        let to = vec![field("bar", None, RpType::String)];
+
+        let pairs = pair_fields(from.iter().collect(), to.iter().collect());
+
+        assert!(pairs.pairs.is_empty());
+        assert_eq!(1, pairs.removed.len());
+        assert_eq!(1, pairs.added.len());
+    }
+
+    #[test]
+    fn test_rename_and_retype() {
+        let from = vec![field("foo", None, unsigned(32))];
+        let to = vec![field("bar", Some("foo"), unsigned(64))];
+
+        let pairs = pair_fields(from.iter().collect(), to.iter().collect());
+
+        assert_eq!(1, pairs.pairs.len());
+
+        let (from_field, to_field) = pairs.pairs[0];
+        assert!(is_wire_compatible(&from_field.ty, &to_field.ty));
+        assert!(!is_wire_compatible(&to_field.ty, &from_field.ty));
+    }
+
+    #[test]
+    fn test_enum_type_change() {
+        let from = enumeration(RpEnumType::String, &[]);
+        let to = enumeration(RpEnumType::U32, &[]);
+
+        let violations = check_named(&from, &to);
+
+        assert_eq!(1, violations.len());
+
+        match violations[0] {
+            EnumTypeChange(_, RpEnumType::String, _, RpEnumType::U32, _) => {}
+            ref other => panic!("unexpected violation: {:?}", other),
+        }
+
+        assert!(check_named(&from, &from).is_empty());
+    }
+
+    #[test]
+    fn test_sub_type_strategy_change() {
+        let from = interface(RpSubTypeStrategy::default(), &[]);
+        let to = interface(RpSubTypeStrategy::Untagged, &[]);
+
+        let violations = check_named(&from, &to);
+
+        assert_eq!(1, violations.len());
+
+        match violations[0] {
+            SubTypeStrategyChange(_, RpSubTypeStrategy::Tagged { .. }, _, _, _) => {}
+            ref other => panic!("unexpected violation: {:?}", other),
+        }
+
+        assert!(check_named(&from, &from).is_empty());
+    }
+
+    #[test]
+    fn test_http_path_change() {
+        let from = endpoint("get", vec![segment("foo"), variable("id")], None);
+        let to = endpoint("get", vec![segment("bar"), variable("id")], None);
+
+        let violations = check_http(&from, &to);
+
+        assert_eq!(1, violations.len());
+
+        match violations[0] {
+            EndpointHttpPathChange(_, Some(ref from), _, Some(ref to), _) => {
+                assert_eq!("/foo/{id}", from);
+                assert_eq!("/bar/{id}", to);
+            }
+            ref other => panic!("unexpected violation: {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_http_path_variable_renamed() {
+        let from = endpoint("get", vec![segment("foo"), variable("id")], None);
+        let to = endpoint("get", vec![segment("foo"), variable("foo_id")], None);
+
+        assert!(check_http(&from, &to).is_empty());
+    }
+
+    #[test]
+    fn test_http_method_change() {
+        let from = endpoint("get", vec![segment("foo")], None);
+        let to = endpoint("get", vec![segment("foo")], Some(RpHttpMethod::Post));
+
+        let violations = check_http(&from, &to);
+
+        assert_eq!(1, violations.len());
+
+        match violations[0] {
+            EndpointHttpMethodChange(_, RpHttpMethod::Get, _, RpHttpMethod::Post, _) => {}
+            ref other => panic!("unexpected violation: {:?}", other),
+        }
+
+        // no method is the same as `GET`.
+        let explicit = endpoint("get", vec![segment("foo")], Some(RpHttpMethod::Get));
+        assert!(check_http(&from, &explicit).is_empty());
+    }
+
+    #[test]
+    fn test_endpoint_http_checked() {
+        let from = service(vec![endpoint("get", vec![segment("foo")], None)]);
+        let to = service(vec![endpoint("get", vec![segment("bar")], None)]);
+
+        let violations = check_decl(("1.0.0", from), ("1.1.0", to));
+
+        assert_eq!(1, violations.len());
+
+        match violations[0] {
+            EndpointHttpPathChange(Minor, ..) => {}
+            ref other => panic!("unexpected violation: {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_sub_type_added() {
+        let strategy = RpSubTypeStrategy::default();
+        let from = interface(strategy.clone(), &["A"]);
+        let to = interface(strategy.clone(), &["A", "B"]);
+
+        let violations = check_decl(("1.0.0", from), ("1.1.0", to));
+
+        assert_eq!(1, violations.len());
+
+        match violations[0] {
+            AddSubType(Minor, _) => {}
+            ref other => panic!("unexpected violation: {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_sub_type_removed() {
+        let strategy = RpSubTypeStrategy::default();
+        let from = interface(strategy.clone(), &["A", "B"]);
+        let to = interface(strategy.clone(), &["A"]);
+
+        let violations = check_decl(("1.0.0", from), ("1.0.1", to));
+
+        assert_eq!(1, violations.len());
+
+        match violations[0] {
+            RemoveSubType(Patch, _) => {}
+            ref other => panic!("unexpected violation: {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_variant_added() {
+        let from = enumeration(RpEnumType::String, &[("A", "a")]);
+        let to = enumeration(RpEnumType::String, &[("A", "a"), ("B", "b")]);
+
+        let violations = check_decl(("1.0.0", from), ("1.0.1", to));
+
+        assert_eq!(1, violations.len());
+
+        match violations[0] {
+            AddVariant(Patch, _) => {}
+            ref other => panic!("unexpected violation: {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_variant_removed() {
+        let from = enumeration(RpEnumType::String, &[("A", "a"), ("B", "b")]);
+        let to = enumeration(RpEnumType::String, &[("A", "a")]);
+
+        let violations = check_decl(("1.0.0", from), ("1.1.0", to));
+
+        assert_eq!(1, violations.len());
+
+        match violations[0] {
+            RemoveVariant(Minor, _) => {}
+            ref other => panic!("unexpected violation: {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_tuple_field_moved() {
+        let from = tuple(vec![
+            field("a", None, unsigned(32)),
+            field("b", None, RpType::String),
+        ]);
+
+        let to = tuple(vec![
+            field("b", None, RpType::String),
+            field("a", None, unsigned(32)),
+        ]);
+
+        let violations = check_decl(("1.0.0", from), ("1.0.1", to));
+
+        let mut indexes = violations
+            .iter()
+            .map(|v| match *v {
+                FieldIndexChange(Patch, from, _, to, _) => (from, to),
+                ref other => panic!("unexpected violation: {:?}", other),
+            })
+            .collect::<Vec<_>>();
+
+        indexes.sort();
+        assert_eq!(vec![(0, 1), (1, 0)], indexes);
+    }
+
+    #[test]
+    fn test_tuple_field_renamed() {
+        let from = tuple(vec![field("a", None, unsigned(32))]);
+        let to = tuple(vec![field("b", Some("a"), unsigned(32))]);
+
+        assert!(check_decl(("1.0.0", from), ("1.0.1", to)).is_empty());
+    }
+}
@@ -2,10 +2,12 @@
 
 use core::errors::Result;
 use core::flavored::{RpAccept, RpChannel, RpEndpointArgument, RpEndpointHttp, RpHttpMethod,
-                     RpPathSpec, RpValue};
-use core::{self, Attributes, Context, Loc, Span, WithSpan};
+                     RpPathSpec, RpType, RpValue};
+use core::{self, Attributes, BigInt, Context, Loc, RpDeprecated, RpNumber, RpValidate, Span,
+           WithSpan};
 use into_model::IntoModel;
 use path_parser;
+use regex::Regex;
 use scope::Scope;
 use std::collections::HashMap;
 
@@ -31,6 +33,205 @@ pub fn reserved(scope: &Scope, attributes: &mut Attributes) -> Result<HashMap<St
     Ok(reserved)
 }
 
+/// The largest number that can be pinned using `#[number(..)]`.
+const MAX_NUMBER: u32 = 536_870_911;
+
+/// `#[number(..)]` attribute for fields and sub-types.
+///
+/// Pins the number used to identify the item in formats which identify fields by number.
+pub fn number(scope: &Scope, attributes: &mut Attributes) -> Result<Option<u32>> {
+    let selection = match attributes.take_selection("number") {
+        None => return Ok(None),
+        Some(selection) => selection,
+    };
+
+    let (mut selection, span) = Loc::take_pair(selection);
+
+    let number = match selection.take_word() {
+        Some(number) => number,
+        None => return Err("expected number, like: `#[number(1)]`".into()).with_span(&span),
+    };
+
+    let (number, span) = Loc::take_pair(number);
+    let number = number.as_number().with_span(&span)?;
+
+    let number = match number.to_u32() {
+        Some(n) if number.decimal == 0 && n >= 1 && n <= MAX_NUMBER => n,
+        _ => {
+            return Err(format!("number must be within 1 to {} (inclusive)", MAX_NUMBER).into())
+                .with_span(&span)
+        }
+    };
+
+    check_selection!(scope.ctx(), selection);
+
+    Ok(Some(number))
+}
+
+/// `#[deprecated]` or `#[deprecated("message")]` attribute.
+///
+/// Marks declarations, fields, variants, and endpoints as deprecated, with an optional message
+/// describing what to use instead.
+pub fn deprecated(scope: &Scope, attributes: &mut Attributes) -> Result<Option<RpDeprecated>> {
+    if attributes.take_word("deprecated") {
+        return Ok(Some(RpDeprecated::default()));
+    }
+
+    let selection = match attributes.take_selection("deprecated") {
+        None => return Ok(None),
+        Some(selection) => selection,
+    };
+
+    let (mut selection, span) = Loc::take_pair(selection);
+
+    let message = match selection.take_word() {
+        Some(message) => message,
+        None => {
+            return Err("expected message, like: `#[deprecated(\"use foo instead\")]`".into())
+                .with_span(&span)
+        }
+    };
+
+    let (message, span) = Loc::take_pair(message);
+    let message = message.as_string().with_span(&span)?.to_string();
+
+    check_selection!(scope.ctx(), selection);
+
+    Ok(Some(RpDeprecated {
+        message: Some(message),
+    }))
+}
+
+/// `#[validate(..)]` attribute for fields.
+///
+/// Constrains the value of the field, which is only permitted for the types where the
+/// constraint has a meaning.
+pub fn validate(
+    scope: &Scope,
+    attributes: &mut Attributes,
+    ty: &RpType,
+) -> Result<Option<RpValidate>> {
+    let selection = match attributes.take_selection("validate") {
+        None => return Ok(None),
+        Some(selection) => selection,
+    };
+
+    let (mut selection, span) = Loc::take_pair(selection);
+
+    // Does the type support bounds, and must those be whole, non-negative numbers.
+    let (bounded, counted, whole) = match *ty {
+        core::RpType::Signed { .. } | core::RpType::Unsigned { .. } => (true, false, true),
+        core::RpType::Float | core::RpType::Double => (true, false, false),
+        core::RpType::String | core::RpType::Array { .. } | core::RpType::Map { .. } => {
+            (true, true, true)
+        }
+        _ => (false, false, false),
+    };
+
+    let mut validate = RpValidate::default();
+
+    let min = bound(selection.take("min"), bounded, counted, whole, ty)?;
+    let max = bound(selection.take("max"), bounded, counted, whole, ty)?;
+
+    if let (Some(&(ref min, _)), Some(&(ref max, ref max_span))) = (min.as_ref(), max.as_ref()) {
+        if min.to_f64() > max.to_f64() {
+            return Err(format!("`max` must not be less than `min` ({})", min).into())
+                .with_span(max_span);
+        }
+    }
+
+    validate.min = min.map(|(min, _)| min);
+    validate.max = max.map(|(max, _)| max);
+
+    if let Some(pattern) = selection.take("pattern") {
+        let (pattern, span) = Loc::take_pair(pattern);
+
+        if *ty != core::RpType::String {
+            return Err(format!("`pattern` is not supported for type `{}`", ty).into())
+                .with_span(&span);
+        }
+
+        let pattern = pattern.as_string().with_span(&span)?;
+
+        if let Err(e) = Regex::new(pattern) {
+            // NB: the last line of a syntax error describes what is wrong with the pattern.
+            let e = e.to_string();
+            let reason = e.lines().last().unwrap_or("").trim_left_matches("error: ");
+
+            return Err(format!("`pattern` is not a valid regular expression: {}", reason).into())
+                .with_span(&span);
+        }
+
+        validate.pattern = Some(pattern.to_string());
+    }
+
+    check_selection!(scope.ctx(), selection);
+
+    if validate.is_empty() {
+        return Err(
+            "expected constraints, like: `#[validate(min = 1, max = 10)]`".into(),
+        ).with_span(&span);
+    }
+
+    return Ok(Some(validate));
+
+    /// Parse a single bound.
+    fn bound(
+        value: Option<Loc<RpValue>>,
+        bounded: bool,
+        counted: bool,
+        whole: bool,
+        ty: &RpType,
+    ) -> Result<Option<(RpNumber, Span)>> {
+        let (value, span) = match value {
+            Some(value) => Loc::take_pair(value),
+            None => return Ok(None),
+        };
+
+        if !bounded {
+            return Err(format!("`min` and `max` are not supported for type `{}`", ty).into())
+                .with_span(&span);
+        }
+
+        let number = value.as_number().with_span(&span)?.clone();
+
+        if whole && number.to_bigint().is_none() {
+            return Err("expected a whole number".into()).with_span(&span);
+        }
+
+        if counted && number.digits < BigInt::from(0) {
+            return Err("expected a non-negative number".into()).with_span(&span);
+        }
+
+        if let Some((lower, upper)) = range(ty) {
+            if number.digits < lower || number.digits > upper {
+                let message = format!(
+                    "number must be within {} to {} (inclusive) for type `{}`",
+                    lower, upper, ty
+                );
+
+                return Err(message.into()).with_span(&span);
+            }
+        }
+
+        Ok(Some((number, span)))
+    }
+
+    /// The range of values which can be represented by an integer type.
+    fn range(ty: &RpType) -> Option<(BigInt, BigInt)> {
+        let one = BigInt::from(1);
+
+        match *ty {
+            core::RpType::Signed { size } => {
+                let bound = one.clone() << (size - 1);
+                Some((-bound.clone(), bound - one))
+            }
+            core::RpType::Unsigned { size } => Some((BigInt::from(0), (one.clone() << size) - one)),
+            _ => None,
+        }
+    }
+}
+
 /// `#[http(..)]` attribute for endpoints.
 pub fn endpoint_http(
     scope: &Scope,
@@ -0,0 +1,423 @@
+//! Expansion of generic declarations.
+//!
+//! A generic declaration, like `type Page<T> { .. }`, is a template which is not part of the
+//! model by itself. Every unique use of it, like `Page<User>`, adds a declaration to the file
+//! where the type parameters have been replaced with the given type arguments. The declaration is
+//! named after the template and its arguments, like `PageOfUser`.
+//!
+//! Since expansion happens before the model is built, every backend sees plain declarations.
+
+use ast::{Channel, Decl, ErrorRecovery, Name, ServiceMember, Type, TypeMember};
+use core::errors::Result;
+use core::{Loc, Span, WithSpan};
+use naming::{self, Naming};
+use std::borrow::Cow;
+use std::collections::{HashMap, HashSet, VecDeque};
+use std::mem;
+
+/// The maximum number of instances of generic declarations in a single file.
+///
+/// Guards against declarations which would expand forever, like
+/// `type Nested<T> { nested?: Nested<[T]>; }`.
+const MAX_INSTANCES: usize = 1000;
+
+/// Expand all uses of generic declarations among the given declarations.
+///
+/// Generic declarations are removed, and instances are added after all other declarations in the
+/// order in which they are first used.
+pub fn expand<'input>(decls: Vec<Decl<'input>>) -> Result<Vec<Decl<'input>>> {
+    let mut templates = HashMap::new();
+    let mut names = HashSet::new();
+    let mut out = Vec::new();
+
+    for decl in decls {
+        let name = decl.name().to_string();
+
+        if templates.contains_key(&name) || names.contains(&name) {
+            return Err("conflicting declaration".into()).with_span(span(&decl));
+        }
+
+        if decl.generics().is_empty() {
+            names.insert(name);
+            out.push(decl);
+        } else {
+            templates.insert(name, decl);
+        }
+    }
+
+    let mut expander = Expander {
+        templates: templates,
+        names: names,
+        instances: HashMap::new(),
+        queue: VecDeque::new(),
+    };
+
+    for decl in &mut out {
+        for_each_type(decl, &mut |ty, span| expander.ty(ty, span))?;
+    }
+
+    while let Some(mut decl) = expander.queue.pop_front() {
+        for_each_type(&mut decl, &mut |ty, span| expander.ty(ty, span))?;
+        out.push(decl);
+    }
+
+    Ok(out)
+}
+
+struct Expander<'input> {
+    /// Generic declarations, by name.
+    templates: HashMap<String, Decl<'input>>,
+    /// Names of declarations which are not generic.
+    names: HashSet<String>,
+    /// Instances which have already been added, and the declaration and arguments they are an
+    /// instance of.
+    instances: HashMap<String, (String, Vec<Type>)>,
+    /// Instances which have not yet been expanded themselves.
+    queue: VecDeque<Decl<'input>>,
+}
+
+impl<'input> Expander<'input> {
+    /// Replace any use of a generic declaration in the given type with its instance.
+    fn ty(&mut self, ty: &mut Type, span: &Span) -> Result<()> {
+        let instance = match *ty {
+            Type::Array { ref mut inner } => return self.ty(inner, span),
+            Type::Map {
+                ref mut key,
+                ref mut value,
+            } => {
+                self.ty(key, span)?;
+                return self.ty(value, span);
+            }
+            Type::Name {
+                ref name,
+                ref mut arguments,
+            } => {
+                for argument in arguments.iter_mut() {
+                    let span = Loc::span(argument).clone();
+                    self.ty(argument, &span)?;
+                }
+
+                let ident = match local(name) {
+                    Some(ident) if self.templates.contains_key(ident) => ident.to_string(),
+                    _ => {
+                        if !arguments.is_empty() {
+                            return Err("type arguments can only be used with generic \
+                                        declarations in the same file"
+                                .into())
+                                .with_span(span);
+                        }
+
+                        return Ok(());
+                    }
+                };
+
+                let arguments = mem::replace(arguments, Vec::new());
+                self.instance(&ident, arguments, span)?
+            }
+            _ => return Ok(()),
+        };
+
+        *ty = Type::Name {
+            name: Name::Absolute {
+                prefix: None,
+                parts: Loc::new(vec![instance].into(), span.clone()),
+            },
+            arguments: Vec::new(),
+        };
+
+        Ok(())
+    }
+
+    /// Get the name of the instance of a generic declaration, adding it if needed.
+    fn instance(&mut self, ident: &str, arguments: Vec<Loc<Type>>, span: &Span) -> Result<String> {
+        let generics = match self.templates.get(ident) {
+            Some(template) => template
+                .generics()
+                .iter()
+                .map(|g| g.to_string())
+                .collect::<Vec<_>>(),
+            None => return Err(format!("no generic declaration named `{}`", ident).into()),
+        };
+
+        if generics.len() != arguments.len() {
+            return Err(format!(
+                "`{}` expects {} type argument(s), but {} were given",
+                ident,
+                generics.len(),
+                arguments.len()
+            ).into())
+                .with_span(span);
+        }
+
+        let mut names = Vec::new();
+
+        for argument in &arguments {
+            names.push(type_name(argument).with_span(Loc::span(argument))?);
+        }
+
+        let instance = format!("{}Of{}", ident, names.join("And"));
+
+        let key = (
+            ident.to_string(),
+            arguments.iter().map(|a| (**a).clone()).collect::<Vec<_>>(),
+        );
+
+        if let Some(existing) = self.instances.get(&instance) {
+            if *existing == key {
+                return Ok(instance);
+            }
+
+            return Err(format!(
+                "instance of `{}` is named `{}`, which conflicts with another instance",
+                ident, instance
+            ).into())
+                .with_span(span);
+        }
+
+        if self.names.contains(&instance) {
+            return Err(format!(
+                "instance of `{}` is named `{}`, which conflicts with an existing declaration",
+                ident, instance
+            ).into())
+                .with_span(span);
+        }
+
+        if self.instances.len() >= MAX_INSTANCES {
+            return Err(format!(
+                "too many instances of generic declarations, does `{}` expand forever?",
+                ident
+            ).into())
+                .with_span(span);
+        }
+
+        let params = generics
+            .into_iter()
+            .zip(arguments.into_iter().map(Loc::take))
+            .collect::<HashMap<_, _>>();
+
+        let mut decl = match self.templates.get(ident) {
+            Some(template) => template.clone(),
+            None => return Err(format!("no generic declaration named `{}`", ident).into()),
+        };
+
+        rename(&mut decl, &instance);
+
+        for_each_type(&mut decl, &mut |ty, _| {
+            substitute(ty, &params);
+            Ok(())
+        })?;
+
+        self.instances.insert(instance.clone(), key);
+        self.queue.push_back(decl);
+        Ok(instance)
+    }
+}
+
+/// Get the identifier of a name which refers to a top-level declaration in the same package.
+fn local(name: &Name) -> Option<&str> {
+    if let Name::Absolute {
+        prefix: None,
+        ref parts,
+    } = *name
+    {
+        if let ErrorRecovery::Value(ref parts) = **parts {
+            if parts.len() == 1 {
+                return parts.first().map(|p| p.as_str());
+            }
+        }
+    }
+
+    None
+}
+
+/// Build the part of an instance name which describes a type argument.
+fn type_name(ty: &Type) -> Result<String> {
+    let name = match *ty {
+        Type::Double => "Double".to_string(),
+        Type::Float => "Float".to_string(),
+        Type::Signed { size } => format!("I{}", size),
+        Type::Unsigned { size } => format!("U{}", size),
+        Type::Boolean => "Boolean".to_string(),
+        Type::String => "String".to_string(),
+        Type::Bytes => "Bytes".to_string(),
+        Type::Any => "Any".to_string(),
+        Type::DateTime => "Datetime".to_string(),
+        Type::Date => "Date".to_string(),
+        Type::Time => "Time".to_string(),
+        Type::Duration => "Duration".to_string(),
+        Type::Uuid => "Uuid".to_string(),
+        Type::Name { ref name, .. } => match *name {
+            Name::Absolute {
+                ref prefix,
+                ref parts,
+            } => {
+                let parts = match **parts {
+                    ErrorRecovery::Value(ref parts) => parts.join(""),
+                    ErrorRecovery::Error => return Err("expected type identifier".into()),
+                };
+
+                match *prefix {
+                    Some(ref prefix) => {
+                        format!("{}{}", naming::to_upper_camel().convert(prefix), parts)
+                    }
+                    None => parts,
+                }
+            }
+            Name::Relative { .. } => {
+                return Err("relative names can't be used as type arguments".into())
+            }
+        },
+        Type::Array { ref inner } => format!("ArrayOf{}", type_name(inner)?),
+        Type::Map { ref key, ref value } => {
+            format!("MapOf{}And{}", type_name(key)?, type_name(value)?)
+        }
+    };
+
+    Ok(name)
+}
+
+/// Replace the use of type parameters with their arguments.
+fn substitute(ty: &mut Type, params: &HashMap<String, Type>) {
+    let replacement = match *ty {
+        Type::Array { ref mut inner } => {
+            substitute(inner, params);
+            None
+        }
+        Type::Map {
+            ref mut key,
+            ref mut value,
+        } => {
+            substitute(key, params);
+            substitute(value, params);
+            None
+        }
+        Type::Name {
+            ref name,
+            ref mut arguments,
+        } => {
+            for argument in arguments.iter_mut() {
+                substitute(argument, params);
+            }
+
+            if arguments.is_empty() {
+                local(name).and_then(|n| params.get(n)).cloned()
+            } else {
+                None
+            }
+        }
+        _ => None,
+    };
+
+    if let Some(replacement) = replacement {
+        *ty = replacement;
+    }
+}
+
+/// Rename an instance of a generic declaration.
+fn rename<'input>(decl: &mut Decl<'input>, name: &str) {
+    match *decl {
+        Decl::Type(ref mut body) => {
+            body.item.name = Cow::Owned(name.to_string());
+            body.item.generics.clear();
+        }
+        Decl::Interface(ref mut body) => {
+            body.item.name = Cow::Owned(name.to_string());
+            body.item.generics.clear();
+        }
+        _ => {}
+    }
+}
+
+/// Get the span of a declaration.
+fn span<'a, 'input>(decl: &'a Decl<'input>) -> &'a Span {
+    match *decl {
+        Decl::Type(ref body) => Loc::span(&body.item),
+        Decl::Tuple(ref body) => Loc::span(&body.item),
+        Decl::Interface(ref body) => Loc::span(&body.item),
+        Decl::Enum(ref body) => Loc::span(&body.item),
+        Decl::Service(ref body) => Loc::span(&body.item),
+    }
+}
+
+/// Call the given function for every type used in the declaration, including nested
+/// declarations.
+fn for_each_type<'input, F>(decl: &mut Decl<'input>, f: &mut F) -> Result<()>
+where
+    F: FnMut(&mut Type, &Span) -> Result<()>,
+{
+    match *decl {
+        Decl::Type(ref mut body) => members(&mut body.item.members, f),
+        Decl::Tuple(ref mut body) => members(&mut body.item.members, f),
+        Decl::Interface(ref mut body) => {
+            members(&mut body.item.members, f)?;
+
+            for sub_type in &mut body.item.sub_types {
+                members(&mut sub_type.item.members, f)?;
+            }
+
+            Ok(())
+        }
+        Decl::Enum(_) => Ok(()),
+        Decl::Service(ref mut body) => {
+            for member in &mut body.item.members {
+                match *member {
+                    ServiceMember::Endpoint(ref mut endpoint) => {
+                        for argument in &mut endpoint.item.arguments {
+                            channel(&mut argument.channel, f)?;
+                        }
+
+                        if let Some(ref mut response) = endpoint.item.response {
+                            channel(response, f)?;
+                        }
+                    }
+                    ServiceMember::InnerDecl(ref mut decl) => inner_decl(decl, f)?,
+                }
+            }
+
+            Ok(())
+        }
+    }
+}
+
+fn inner_decl<'input, F>(decl: &mut Decl<'input>, f: &mut F) -> Result<()>
+where
+    F: FnMut(&mut Type, &Span) -> Result<()>,
+{
+    if !decl.generics().is_empty() {
+        return Err("generic declarations must be top-level".into()).with_span(span(decl));
+    }
+
+    for_each_type(decl, f)
+}
+
+fn members<'input, F>(members: &mut [TypeMember<'input>], f: &mut F) -> Result<()>
+where
+    F: FnMut(&mut Type, &Span) -> Result<()>,
+{
+    for member in members {
+        match *member {
+            TypeMember::Field(ref mut field) => {
+                let span = Loc::span(&field.item.ty).clone();
+
+                if let ErrorRecovery::Value(ref mut ty) = *field.item.ty {
+                    f(ty, &span)?;
+                }
+            }
+            TypeMember::Code(_) => {}
+            TypeMember::InnerDecl(ref mut decl) => inner_decl(decl, f)?,
+        }
+    }
+
+    Ok(())
+}
+
+fn channel<F>(channel: &mut Loc<Channel>, f: &mut F) -> Result<()>
+where
+    F: FnMut(&mut Type, &Span) -> Result<()>,
+{
+    let span = Loc::span(channel).clone();
+
+    match **channel {
+        Channel::Unary { ref mut ty } | Channel::Streaming { ref mut ty } => f(ty, &span),
+    }
+}
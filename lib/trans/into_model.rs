@@ -1,5 +1,6 @@
 use ast::*;
 use attributes;
+use generics;
 use core::errors::{Error, Result};
 use core::flavored::*;
 use core::{self, Attributes, BigInt, Context, Loc, Selection, Span, SymbolKind, WithSpan};
@@ -84,6 +85,7 @@ pub struct SubTypeConstraint<'input> {
     reserved: &'input HashMap<String, Span>,
     field_idents: &'input HashMap<String, Span>,
     field_names: &'input HashMap<String, Span>,
+    field_numbers: &'input HashMap<String, Span>,
     untagged: &'input mut LinkedHashMap<BTreeSet<String>, Span>,
 }
 
@@ -94,6 +96,7 @@ pub struct Members {
     decls: Vec<RpDecl>,
     field_names: HashMap<String, Span>,
     field_idents: HashMap<String, Span>,
+    field_numbers: HashMap<String, Span>,
 }
 
 /// Adds a method for all types that supports conversion into core types.
@@ -240,9 +243,20 @@ impl IntoModel for Type {
             Boolean => core::RpType::Boolean,
             String => core::RpType::String,
             DateTime => core::RpType::DateTime,
-            Name { name } => core::RpType::Name {
-                name: name.into_model(scope)?,
-            },
+            Date => core::RpType::Date,
+            Time => core::RpType::Time,
+            Duration => core::RpType::Duration,
+            Uuid => core::RpType::Uuid,
+            Name { name, arguments } => {
+                // NB: uses of generic declarations have been replaced by their instances.
+                if !arguments.is_empty() {
+                    return Err("unexpected type arguments".into());
+                }
+
+                core::RpType::Name {
+                    name: name.into_model(scope)?,
+                }
+            }
             Array { inner } => core::RpType::Array {
                 inner: inner.into_model(scope)?,
             },
@@ -365,7 +379,8 @@ impl<'input> IntoModel for Item<'input, EnumBody<'input>> {
                 )
             );
 
-            let attributes = attributes.into_model(scope)?;
+            let mut attributes = attributes.into_model(scope)?;
+            let deprecated = attributes::deprecated(scope, &mut attributes)?;
             check_attributes!(scope.ctx(), attributes);
 
             return Ok(RpEnumBody {
@@ -373,6 +388,7 @@ impl<'input> IntoModel for Item<'input, EnumBody<'input>> {
                 ident: item.name.to_string(),
                 comment: Comment(&comment).into_model(scope)?,
                 decls: vec![],
+                deprecated: deprecated,
                 enum_type: enum_type,
                 variants: variants,
                 codes: codes,
@@ -480,13 +496,15 @@ where
                 default.next(&item)?
             };
 
-            let attributes = attributes.into_model(scope)?;
+            let mut attributes = attributes.into_model(scope)?;
+            let deprecated = attributes::deprecated(scope, &mut attributes)?;
             check_attributes!(ctx, attributes);
 
             Ok(RpVariant {
                 name: scope.as_name().push(item.name.to_string()),
                 ident: Loc::map(item.name.clone(), |s| s.to_string()),
                 comment: Comment(&comment).into_model(scope)?,
+                deprecated: deprecated,
                 value: value,
             })
         })
@@ -552,31 +570,101 @@ impl<'input> IntoModel for Item<'input, Field<'input>> {
                 scope.field_ident_naming(),
             );
 
-            let attributes = attributes.into_model(scope)?;
+            let ty = (
+                "expected type, like: `string`, `u32`, or `MyType`.",
+                item.ty,
+            ).into_model(scope)?;
+
+            let mut attributes = attributes.into_model(scope)?;
+            let number = attributes::number(scope, &mut attributes)?;
+            let validate = attributes::validate(scope, &mut attributes, &ty)?;
+            let deprecated = attributes::deprecated(scope, &mut attributes)?;
             check_attributes!(scope.ctx(), attributes);
 
+            let default = match item.default {
+                Some(default) => {
+                    let (default, span) = Loc::take_pair(default.into_model(scope)?);
+
+                    if item.required {
+                        return Err("only optional fields can have a default value".into())
+                            .with_span(&span);
+                    }
+
+                    Some(field_default(default, &ty).with_span(&span)?)
+                }
+                None => None,
+            };
+
             Ok(RpField {
                 required: item.required,
                 safe_ident: safe_ident,
                 ident: ident,
                 comment: Comment(&comment).into_model(scope)?,
-                ty: (
-                    "expected type, like: `string`, `u32`, or `MyType`.",
-                    item.ty,
-                ).into_model(scope)?,
+                ty: ty,
                 field_as: field_as,
+                number: number,
+                validate: validate,
+                default: default,
+                deprecated: deprecated,
             })
         })
     }
 }
 
+/// Check that the default value of a field matches its type.
+fn field_default(value: core::RpValue, ty: &RpType) -> Result<core::RpDefault> {
+    use core::RpDefault;
+
+    let default = match *ty {
+        core::RpType::Boolean => match value {
+            core::RpValue::Identifier(ref id) if id == "true" => RpDefault::Boolean(true),
+            core::RpValue::Identifier(ref id) if id == "false" => RpDefault::Boolean(false),
+            _ => return Err("expected `true` or `false`".into()),
+        },
+        core::RpType::Signed { size } => match value {
+            core::RpValue::Number(ref n) if fits(n, size, true) => RpDefault::Signed {
+                size,
+                value: n.clone(),
+            },
+            _ => return Err(format!("expected a whole number which fits in `{}`", ty).into()),
+        },
+        core::RpType::Unsigned { size } => match value {
+            core::RpValue::Number(ref n) if fits(n, size, false) => RpDefault::Unsigned {
+                size,
+                value: n.clone(),
+            },
+            _ => return Err(format!("expected a whole number which fits in `{}`", ty).into()),
+        },
+        core::RpType::Float => RpDefault::Float(value.into_number()?),
+        core::RpType::Double => RpDefault::Double(value.into_number()?),
+        core::RpType::String => RpDefault::String(value.into_string()?),
+        _ => return Err(format!("default values are not supported for type `{}`", ty).into()),
+    };
+
+    Ok(default)
+}
+
+/// Test if the number is a whole number which fits in an integer of the given size.
+fn fits(n: &core::RpNumber, size: usize, signed: bool) -> bool {
+    if n.to_bigint().is_none() {
+        return false;
+    }
+
+    match (signed, size) {
+        (true, 32) => n.to_i32().is_some(),
+        (true, _) => n.to_i64().is_some(),
+        (false, 32) => n.to_u32().is_some(),
+        (false, _) => n.to_u64().is_some(),
+    }
+}
+
 impl<'input> IntoModel for File<'input> {
     type Output = RpFile;
 
     fn into_model(self, scope: &Scope) -> Result<RpFile> {
         let mut decls = Vec::new();
 
-        for d in self.decls {
+        for d in generics::expand(self.decls)? {
             decls.push(try_loop!(d.into_model(scope)));
         }
 
@@ -602,6 +690,7 @@ impl<'input> IntoModel for Item<'input, InterfaceBody<'input>> {
             let mut attributes = attributes.into_model(scope)?;
 
             let reserved = attributes::reserved(scope, &mut attributes)?;
+            let deprecated = attributes::deprecated(scope, &mut attributes)?;
 
             let mut sub_type_strategy = RpSubTypeStrategy::default();
 
@@ -618,6 +707,7 @@ impl<'input> IntoModel for Item<'input, InterfaceBody<'input>> {
                 decls,
                 field_idents,
                 field_names,
+                field_numbers,
                 ..
             } = {
                 let constraint = MemberConstraint {
@@ -630,6 +720,7 @@ impl<'input> IntoModel for Item<'input, InterfaceBody<'input>> {
 
             let mut names = HashMap::new();
             let mut idents = HashMap::new();
+            let mut numbers = HashMap::new();
             let mut sub_types = Vec::new();
             let mut untagged = LinkedHashMap::new();
 
@@ -641,6 +732,7 @@ impl<'input> IntoModel for Item<'input, InterfaceBody<'input>> {
                     reserved: &reserved,
                     field_idents: &field_idents,
                     field_names: &field_names,
+                    field_numbers: &field_numbers,
                     untagged: &mut untagged,
                 };
 
@@ -649,6 +741,10 @@ impl<'input> IntoModel for Item<'input, InterfaceBody<'input>> {
                 check_conflict!(ctx, idents, sub_type, sub_type.ident, "sub-type");
                 check_conflict!(ctx, names, sub_type, sub_type.name(), "sub-type with name");
 
+                if let Some(number) = sub_type.number {
+                    check_conflict!(ctx, numbers, sub_type, number, "sub-type number");
+                }
+
                 sub_types.push(sub_type);
             }
 
@@ -714,6 +810,7 @@ impl<'input> IntoModel for Item<'input, InterfaceBody<'input>> {
                 ident: item.name.to_string(),
                 comment: Comment(&comment).into_model(scope)?,
                 decls: decls,
+                deprecated: deprecated,
                 fields: fields,
                 codes: codes,
                 sub_types: sub_types,
@@ -879,6 +976,7 @@ impl<'input> IntoModel for Item<'input, ServiceBody<'input>> {
             }
 
             let mut attributes = attributes.into_model(scope)?;
+            let deprecated = attributes::deprecated(scope, &mut attributes)?;
 
             let mut http = RpServiceBodyHttp::default();
 
@@ -895,6 +993,7 @@ impl<'input> IntoModel for Item<'input, ServiceBody<'input>> {
                 ident: item.name.to_string(),
                 comment: Comment(&comment).into_model(scope)?,
                 decls: decls,
+                deprecated: deprecated,
                 http: http,
                 endpoints: endpoints,
             })
@@ -985,6 +1084,7 @@ impl<'input> IntoModel for Item<'input, Endpoint<'input>> {
                 &arguments,
             )?;
 
+            let deprecated = attributes::deprecated(scope, &mut attributes)?;
             check_attributes!(scope.ctx(), attributes);
 
             Ok(RpEndpoint {
@@ -992,6 +1092,7 @@ impl<'input> IntoModel for Item<'input, Endpoint<'input>> {
                 safe_ident: safe_ident,
                 name: name,
                 comment: Comment(&comment).into_model(scope)?,
+                deprecated: deprecated,
                 attributes: attributes,
                 arguments: arguments,
                 request: request,
@@ -1033,6 +1134,7 @@ impl<'input> IntoModel for (Item<'input, SubType<'input>>, SubTypeConstraint<'in
             reserved: interface_reserved,
             field_idents,
             field_names,
+            field_numbers,
             sub_type_strategy,
             untagged,
         } = constraint;
@@ -1045,6 +1147,8 @@ impl<'input> IntoModel for (Item<'input, SubType<'input>>, SubTypeConstraint<'in
 
             let mut attributes = attributes.into_model(scope)?;
             let reserved = attributes::reserved(scope, &mut attributes)?;
+            let number = attributes::number(scope, &mut attributes)?;
+            let deprecated = attributes::deprecated(scope, &mut attributes)?;
             check_attributes!(ctx, attributes);
 
             let mut fields = Vec::new();
@@ -1054,6 +1158,7 @@ impl<'input> IntoModel for (Item<'input, SubType<'input>>, SubTypeConstraint<'in
             let mut decl_idents = HashMap::new();
             let mut field_idents = field_idents.clone();
             let mut field_names = field_names.clone();
+            let mut field_numbers = field_numbers.clone();
 
             for member in item.members {
                 match member {
@@ -1063,6 +1168,10 @@ impl<'input> IntoModel for (Item<'input, SubType<'input>>, SubTypeConstraint<'in
                         check_conflict!(ctx, field_idents, field, field.ident(), "field");
                         check_conflict!(ctx, field_names, field, field.name(), "field with name");
 
+                        if let Some(number) = field.number {
+                            check_conflict!(ctx, field_numbers, field, number, "field number");
+                        }
+
                         check_field_tag!(ctx, field, *sub_type_strategy);
 
                         check_field_reserved!(ctx, field, interface_reserved);
@@ -1109,6 +1218,8 @@ impl<'input> IntoModel for (Item<'input, SubType<'input>>, SubTypeConstraint<'in
                 fields: fields,
                 codes: codes,
                 sub_type_name: sub_type_name,
+                number: number,
+                deprecated: deprecated,
             })
         });
 
@@ -1154,7 +1265,16 @@ impl<'input> IntoModel for Item<'input, TupleBody<'input>> {
                 ..
             } = item.members.into_model(scope)?;
 
-            let attributes = attributes.into_model(scope)?;
+            // NB: tuples are encoded as arrays, where a field can't be absent.
+            for field in &fields {
+                if field.default.is_some() {
+                    return Err("default values are not supported in tuples".into())
+                        .with_span(Loc::span(field));
+                }
+            }
+
+            let mut attributes = attributes.into_model(scope)?;
+            let deprecated = attributes::deprecated(scope, &mut attributes)?;
             check_attributes!(ctx, attributes);
 
             Ok(RpTupleBody {
@@ -1162,6 +1282,7 @@ impl<'input> IntoModel for Item<'input, TupleBody<'input>> {
                 ident: item.name.to_string(),
                 comment: Comment(&comment).into_model(scope)?,
                 decls: decls,
+                deprecated: deprecated,
                 fields: fields,
                 codes: codes,
             })
@@ -1183,6 +1304,7 @@ impl<'input> IntoModel for Item<'input, TypeBody<'input>> {
 
             let mut attributes = attributes.into_model(scope)?;
             let reserved = attributes::reserved(scope, &mut attributes)?;
+            let deprecated = attributes::deprecated(scope, &mut attributes)?;
 
             check_attributes!(ctx, attributes);
 
@@ -1205,6 +1327,7 @@ impl<'input> IntoModel for Item<'input, TypeBody<'input>> {
                 ident: item.name.to_string(),
                 comment: Comment(&comment).into_model(scope)?,
                 decls: decls,
+                deprecated: deprecated,
                 fields: fields,
                 codes: codes,
             })
@@ -1242,6 +1365,7 @@ impl<'input> IntoModel for (Vec<TypeMember<'input>>, MemberConstraint<'input>) {
 
         let mut field_idents = HashMap::new();
         let mut field_names = HashMap::new();
+        let mut field_numbers = HashMap::new();
         let mut decl_idents = HashMap::new();
 
         for member in members {
@@ -1252,6 +1376,10 @@ impl<'input> IntoModel for (Vec<TypeMember<'input>>, MemberConstraint<'input>) {
                     check_conflict!(ctx, field_idents, field, field.ident(), "field");
                     check_conflict!(ctx, field_names, field, field.name(), "field with name");
 
+                    if let Some(number) = field.number {
+                        check_conflict!(ctx, field_numbers, field, number, "field number");
+                    }
+
                     if let Some(sub_type_strategy) = sub_type_strategy {
                         check_field_tag!(ctx, field, *sub_type_strategy);
                     }
@@ -1277,6 +1405,7 @@ impl<'input> IntoModel for (Vec<TypeMember<'input>>, MemberConstraint<'input>) {
             decls: decls,
             field_names: field_names,
             field_idents: field_idents,
+            field_numbers: field_numbers,
         })
     }
 }
@@ -1302,10 +1431,12 @@ impl<'input> IntoModel for Code<'input> {
                     core::RpContext::Java { imports: imports }
                 }
                 "js" => core::RpContext::Js {},
+                "kotlin" => core::RpContext::Kotlin {},
                 "python" => core::RpContext::Python {},
                 "reproto" => core::RpContext::Reproto {},
                 "rust" => core::RpContext::Rust {},
                 "swift" => core::RpContext::Swift {},
+                "typescript" => core::RpContext::TypeScript {},
                 context => {
                     let mut r = ctx.report();
                     r.err(span, format!("context `{}` not recognized", context));
@@ -1,6 +1,7 @@
 extern crate linked_hash_map;
 #[macro_use]
 extern crate log;
+extern crate regex;
 extern crate reproto_ast as ast;
 #[macro_use]
 extern crate reproto_core as core;
@@ -40,6 +41,7 @@ macro_rules! check_selection {
 
 mod attributes;
 pub mod environment;
+mod generics;
 mod into_model;
 mod scope;
 pub mod translated;
@@ -0,0 +1,255 @@
+//! Token-based authentication, and publish permissions.
+
+use config::User;
+use core::RpPackage;
+use core::errors::Result;
+use errors::{self, Error};
+use hyper::header::{Authorization, Bearer, Headers};
+use std::collections::HashMap;
+
+/// A pattern of packages which may be published.
+#[derive(Debug)]
+enum Permission {
+    /// Any package, specified as `*`.
+    Any,
+    /// Any package under the given prefix, specified like `com.example.*`.
+    Prefix(RpPackage),
+    /// Exactly the given package, specified like `com.example`.
+    Exact(RpPackage),
+}
+
+impl Permission {
+    fn parse(input: &str) -> Option<Permission> {
+        if input == "*" {
+            return Some(Permission::Any);
+        }
+
+        if input.ends_with(".*") {
+            let prefix = &input[..input.len() - 2];
+
+            if prefix.is_empty() || prefix.contains("*") {
+                return None;
+            }
+
+            return Some(Permission::Prefix(RpPackage::parse(prefix)));
+        }
+
+        if input.is_empty() || input.contains("*") {
+            return None;
+        }
+
+        Some(Permission::Exact(RpPackage::parse(input)))
+    }
+
+    fn permits(&self, package: &RpPackage) -> bool {
+        match *self {
+            Permission::Any => true,
+            Permission::Prefix(ref prefix) => package.starts_with(prefix),
+            Permission::Exact(ref exact) => package == exact,
+        }
+    }
+}
+
+/// An authenticated user.
+#[derive(Debug)]
+pub struct AuthUser {
+    pub name: String,
+    token: String,
+    publish: Vec<Permission>,
+}
+
+impl AuthUser {
+    /// Check if the user may publish anything at all.
+    pub fn may_publish_any(&self) -> bool {
+        !self.publish.is_empty()
+    }
+
+    /// Check if the user may publish the given package.
+    pub fn may_publish(&self, package: &RpPackage) -> bool {
+        self.publish.iter().any(|p| p.permits(package))
+    }
+}
+
+#[derive(Debug)]
+pub struct Auth {
+    users: Vec<AuthUser>,
+}
+
+impl Auth {
+    /// Setup authentication from the configured users.
+    ///
+    /// Every user must have a unique token, since the token identifies the user.
+    pub fn from_config(users: HashMap<String, User>) -> Result<Auth> {
+        let mut out: Vec<AuthUser> = Vec::new();
+
+        // NB: sorted to report conflicting tokens consistently.
+        let mut users = users.into_iter().collect::<Vec<_>>();
+        users.sort_by(|a, b| a.0.cmp(&b.0));
+
+        for (name, user) in users {
+            if let Some(other) = out.iter().find(|u| u.token == user.token) {
+                return Err(format!(
+                    "user `{}`: same token as user `{}`, tokens must be unique",
+                    name, other.name
+                ).into());
+            }
+
+            let mut publish = Vec::new();
+
+            for p in &user.publish {
+                let permission = Permission::parse(p)
+                    .ok_or_else(|| format!("user `{}`: bad publish permission: {}", name, p))?;
+
+                publish.push(permission);
+            }
+
+            out.push(AuthUser {
+                name: name,
+                token: user.token,
+                publish: publish,
+            });
+        }
+
+        Ok(Auth { users: out })
+    }
+
+    /// Check if authentication is enabled.
+    ///
+    /// If no users are configured, anyone may publish.
+    pub fn is_enabled(&self) -> bool {
+        !self.users.is_empty()
+    }
+
+    /// Authenticate the user making a request.
+    pub fn authenticate(&self, headers: &Headers) -> errors::Result<&AuthUser> {
+        let token = match headers.get::<Authorization<Bearer>>() {
+            Some(auth) => auth.0.token.as_str(),
+            None => return Err(Error::Unauthorized),
+        };
+
+        self.users
+            .iter()
+            .find(|u| constant_time_eq(u.token.as_bytes(), token.as_bytes()))
+            .ok_or(Error::Unauthorized)
+    }
+}
+
+/// Compare two byte slices in time that only depends on their length.
+fn constant_time_eq(a: &[u8], b: &[u8]) -> bool {
+    if a.len() != b.len() {
+        return false;
+    }
+
+    a.iter().zip(b.iter()).fold(0u8, |acc, (a, b)| acc | (a ^ b)) == 0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Auth, AuthUser, Permission};
+    use config::User;
+    use core::RpPackage;
+    use hyper::header::{Authorization, Bearer, Headers};
+    use std::collections::HashMap;
+
+    fn permits(permission: &str, package: &str) -> bool {
+        Permission::parse(permission)
+            .expect("bad permission")
+            .permits(&RpPackage::parse(package))
+    }
+
+    fn user(token: &str, publish: &[&str]) -> User {
+        User {
+            token: token.to_string(),
+            publish: publish.iter().map(|p| p.to_string()).collect(),
+        }
+    }
+
+    fn headers(token: &str) -> Headers {
+        let mut headers = Headers::new();
+
+        headers.set(Authorization(Bearer {
+            token: token.to_string(),
+        }));
+
+        headers
+    }
+
+    fn authenticate<'a>(auth: &'a Auth, token: &str) -> Option<&'a AuthUser> {
+        auth.authenticate(&headers(token)).ok()
+    }
+
+    #[test]
+    fn test_any() {
+        assert!(permits("*", "com"));
+        assert!(permits("*", "com.a.b"));
+    }
+
+    #[test]
+    fn test_prefix() {
+        assert!(permits("com.a.*", "com.a.b"));
+        assert!(permits("com.a.*", "com.a.b.c"));
+        assert!(!permits("com.a.*", "com"));
+        assert!(!permits("com.a.*", "com.ab"));
+        assert!(!permits("com.a.*", "org.a.b"));
+    }
+
+    #[test]
+    fn test_exact() {
+        assert!(permits("com.a", "com.a"));
+        assert!(!permits("com.a", "com.a.b"));
+        assert!(!permits("com.a", "com"));
+    }
+
+    #[test]
+    fn test_bad_permission() {
+        assert!(Permission::parse("").is_none());
+        assert!(Permission::parse(".*").is_none());
+        assert!(Permission::parse("com.*.a").is_none());
+        assert!(Permission::parse("com.*.*").is_none());
+        assert!(Permission::parse("com*").is_none());
+    }
+
+    #[test]
+    fn test_bad_permission_in_config() {
+        let mut users = HashMap::new();
+        users.insert("a".to_string(), user("token-a", &["com.*.a"]));
+
+        let e = Auth::from_config(users).expect_err("expected error");
+        assert_eq!("user `a`: bad publish permission: com.*.a", e.message());
+    }
+
+    #[test]
+    fn test_duplicate_token() {
+        let mut users = HashMap::new();
+        users.insert("a".to_string(), user("token", &["*"]));
+        users.insert("b".to_string(), user("token", &["com.b.*"]));
+
+        let e = Auth::from_config(users).expect_err("expected error");
+
+        assert_eq!(
+            "user `b`: same token as user `a`, tokens must be unique",
+            e.message()
+        );
+    }
+
+    #[test]
+    fn test_authenticate() {
+        let mut users = HashMap::new();
+        users.insert("a".to_string(), user("token-a", &["com.a.*"]));
+        users.insert("b".to_string(), user("token-b", &[]));
+
+        let auth = Auth::from_config(users).unwrap();
+
+        let a = authenticate(&auth, "token-a").expect("expected user");
+        assert_eq!("a", a.name);
+        assert!(a.may_publish(&RpPackage::parse("com.a.b")));
+        assert!(!a.may_publish(&RpPackage::parse("com.b")));
+
+        let b = authenticate(&auth, "token-b").expect("expected user");
+        assert_eq!("b", b.name);
+        assert!(!b.may_publish_any());
+
+        assert!(authenticate(&auth, "token-c").is_none());
+        assert!(auth.authenticate(&Headers::new()).is_err());
+    }
+}
@@ -1,9 +1,19 @@
 use core::errors::Result;
+use std::collections::HashMap;
 use std::fs::File;
 use std::io::Read;
 use std::path::{Path, PathBuf};
 use toml;
 
+#[derive(Debug, Deserialize)]
+pub struct User {
+    /// Token used to authenticate the user.
+    pub token: String,
+    /// Packages that the user is permitted to publish, like `com.example.*`.
+    #[serde(default)]
+    pub publish: Vec<String>,
+}
+
 #[derive(Debug, Deserialize)]
 pub struct Config {
     /// Address to listen to.
@@ -12,9 +22,17 @@ pub struct Config {
     /// Objects path.
     #[serde(default = "default_objects")]
     pub objects: PathBuf,
+    /// Index path.
+    #[serde(default = "default_index")]
+    pub index: PathBuf,
     /// Max file size permitted during upload.
     #[serde(default = "default_max_file_size")]
     pub max_file_size: u64,
+    /// Users permitted to publish.
+    ///
+    /// If no users are configured, anyone may publish.
+    #[serde(default)]
+    pub users: HashMap<String, User>,
 }
 
 fn default_listen_address() -> String {
@@ -25,6 +43,10 @@ fn default_objects() -> PathBuf {
     Path::new("./objects").to_owned()
 }
 
+fn default_index() -> PathBuf {
+    Path::new("./index").to_owned()
+}
+
 fn default_max_file_size() -> u64 {
     10000000u64
 }
@@ -34,7 +56,9 @@ impl Default for Config {
         Config {
             listen_address: default_listen_address(),
             objects: default_objects(),
+            index: default_index(),
             max_file_size: default_max_file_size(),
+            users: HashMap::new(),
         }
     }
 }
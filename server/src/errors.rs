@@ -7,6 +7,10 @@ pub type Result<T> = result::Result<T, Error>;
 /// Service errors.
 pub enum Error {
     BadRequest(Cow<'static, str>),
+    /// Request is missing valid credentials.
+    Unauthorized,
+    /// Credentials are valid, but not permitted to perform the request.
+    Forbidden(Cow<'static, str>),
     Other(core::Error),
 }
 
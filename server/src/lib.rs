@@ -9,9 +9,12 @@ extern crate futures_cpupool;
 extern crate hyper;
 extern crate reproto_core as core;
 extern crate reproto_repository;
+extern crate serde_json;
 extern crate tempfile;
 extern crate toml;
+extern crate url;
 
+pub mod auth;
 pub mod config;
 mod errors;
 mod io;
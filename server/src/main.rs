@@ -10,7 +10,8 @@ extern crate reproto_server as server;
 use core::errors::Result;
 use futures_cpupool::CpuPool;
 use hyper::server::Http;
-use repository::objects_from_path;
+use repository::{init_file_index, objects_from_path, FileIndex};
+use server::auth::Auth;
 use server::reproto_service;
 use std::env;
 use std::path::Path;
@@ -39,17 +40,31 @@ fn entry() -> Result<()> {
 
     let listen_address = config.listen_address.parse()?;
     let objects = config.objects;
+    let index = config.index;
     let max_file_size = config.max_file_size;
 
+    let auth = Auth::from_config(config.users)?;
+
+    if !auth.is_enabled() {
+        warn!("No users configured, anyone may publish");
+    }
+
+    let auth = Arc::new(auth);
+
     let pool = Arc::new(CpuPool::new_num_cpus());
     let setup_pool = pool.clone();
     let objects = Arc::new(Mutex::new(objects_from_path(objects)?));
 
+    init_file_index(&index)?;
+    let index = Arc::new(Mutex::new(FileIndex::new(&index)?));
+
     let setup = move || {
         Ok(reproto_service::ReprotoService {
             max_file_size: max_file_size,
             pool: setup_pool.clone(),
             objects: objects.clone(),
+            index: index.clone(),
+            auth: auth.clone(),
         })
     };
 
@@ -1,21 +1,50 @@
+use auth::Auth;
+use core::{Range, RpPackage, Version};
 use errors::{Error, Result};
 use flate2::FlateReadExt;
 use futures::future::{ok, Future};
+use futures::Stream;
 use futures_cpupool::CpuPool;
 use hyper::header::{ContentEncoding, ContentLength, ContentType, Encoding, Headers};
 use hyper::mime;
 use hyper::server::{Request, Response, Service};
 use hyper::{self, Method, StatusCode};
 use io;
-use reproto_repository::{to_checksum, Checksum, FileObjects, Objects};
+use reproto_repository::{to_checksum, Checksum, Deployment, FileIndex, FileObjects, Index,
+                         Objects};
+use serde_json;
 use std::fs::File;
 use std::io::Read;
 use std::io::{Seek, SeekFrom};
 use std::sync::{Arc, Mutex};
 use tempfile;
+use url::percent_encoding::percent_decode;
 
 const CHECKSUM_MISMATCH: &'static str = "checksum mismatch";
 const BAD_OBJECT_ID: &'static str = "bad object id";
+const BAD_VERSION: &'static str = "bad version";
+const BAD_RANGE: &'static str = "bad range";
+const BAD_PUT_VERSION: &'static str = "bad request to put version";
+const ALREADY_PUBLISHED: &'static str = "already published";
+
+/// Request to publish a version.
+#[derive(Debug, Deserialize)]
+struct PutVersion {
+    /// Checksum of the published object.
+    object: Checksum,
+    /// Replace any existing deployment of the same version.
+    #[serde(default)]
+    force: bool,
+}
+
+/// Decode a single path segment.
+fn decode_segment(segment: &str) -> Result<String> {
+    let decoded = percent_decode(segment.as_bytes())
+        .decode_utf8()
+        .map_err(|_| Error::BadRequest("bad path segment".into()))?;
+
+    Ok(decoded.into_owned())
+}
 
 /// ## Read the contents of the file into a byte-vector
 fn read_contents<'a, R: AsMut<Read + 'a>>(mut reader: R) -> Result<Vec<u8>> {
@@ -28,6 +57,8 @@ pub struct ReprotoService {
     pub max_file_size: u64,
     pub pool: Arc<CpuPool>,
     pub objects: Arc<Mutex<FileObjects>>,
+    pub index: Arc<Mutex<FileIndex>>,
+    pub auth: Arc<Auth>,
 }
 
 type EncodingFn = fn(&File) -> Result<Box<Read>>;
@@ -97,6 +128,166 @@ impl ReprotoService {
         })))
     }
 
+    /// Respond with the given deployments, encoded as JSON.
+    fn deployments(deployments: Vec<Deployment>) -> Result<Response> {
+        let bytes = serde_json::to_vec(&deployments)?;
+
+        Ok(Response::new()
+            .with_status(StatusCode::Ok)
+            .with_header(ContentLength(bytes.len() as u64))
+            .with_header(ContentType::json())
+            .with_body(bytes))
+    }
+
+    fn parse_package(package: &str) -> Result<RpPackage> {
+        Ok(RpPackage::parse(&decode_segment(package)?))
+    }
+
+    fn parse_version(version: &str) -> Result<Version> {
+        Version::parse(&decode_segment(version)?).map_err(|_| Error::BadRequest(BAD_VERSION.into()))
+    }
+
+    /// Get all packages in the index.
+    fn get_packages(&self) -> Result<Box<Future<Item = Response, Error = Error>>> {
+        let index = self.index.clone();
+
+        Ok(Box::new(self.pool.spawn_fn(move || {
+            let packages = index.lock().map_err(|_| "lock poisoned")?.packages()?;
+            let bytes = serde_json::to_vec(&packages)?;
+
+            Ok(Response::new()
+                .with_status(StatusCode::Ok)
+                .with_header(ContentLength(bytes.len() as u64))
+                .with_header(ContentType::json())
+                .with_body(bytes))
+        })))
+    }
+
+    /// Get all deployments of a package.
+    fn get_all(&self, package: &str) -> Result<Box<Future<Item = Response, Error = Error>>> {
+        let index = self.index.clone();
+        let package = Self::parse_package(package)?;
+
+        Ok(Box::new(self.pool.spawn_fn(move || {
+            let deployments = index.lock().map_err(|_| "lock poisoned")?.all(&package)?;
+            Self::deployments(deployments)
+        })))
+    }
+
+    /// Resolve all deployments of a package matching a range.
+    fn get_resolve(
+        &self,
+        package: &str,
+        range: &str,
+    ) -> Result<Box<Future<Item = Response, Error = Error>>> {
+        let index = self.index.clone();
+        let package = Self::parse_package(package)?;
+
+        let range = Range::parse(&decode_segment(range)?)
+            .map_err(|_| Error::BadRequest(BAD_RANGE.into()))?;
+
+        Ok(Box::new(self.pool.spawn_fn(move || {
+            let deployments = index
+                .lock()
+                .map_err(|_| "lock poisoned")?
+                .resolve(&package, &range)?;
+
+            Self::deployments(deployments)
+        })))
+    }
+
+    /// Get all deployments of a specific version of a package.
+    fn get_deployments(
+        &self,
+        package: &str,
+        version: &str,
+    ) -> Result<Box<Future<Item = Response, Error = Error>>> {
+        let index = self.index.clone();
+        let package = Self::parse_package(package)?;
+        let version = Self::parse_version(version)?;
+
+        Ok(Box::new(self.pool.spawn_fn(move || {
+            let deployments = index
+                .lock()
+                .map_err(|_| "lock poisoned")?
+                .get_deployments(&package, &version)?;
+
+            Self::deployments(deployments)
+        })))
+    }
+
+    /// Publish a version of a package.
+    fn put_version(
+        &self,
+        package: &str,
+        version: &str,
+        req: Request,
+    ) -> Result<Box<Future<Item = Response, Error = Error>>> {
+        let pool = self.pool.clone();
+        let index = self.index.clone();
+        let objects = self.objects.clone();
+        let package = Self::parse_package(package)?;
+        let version = Self::parse_version(version)?;
+        let max_file_size = self.max_file_size;
+
+        if self.auth.is_enabled() {
+            let user = self.auth.authenticate(req.headers())?;
+
+            if !user.may_publish(&package) {
+                return Err(Error::Forbidden(
+                    format!("not permitted to publish: {}", package).into(),
+                ));
+            }
+
+            info!("Authenticated as: {}", user.name);
+        }
+
+        let body = req.body()
+            .map_err(Error::from)
+            .fold(Vec::new(), move |mut out: Vec<u8>, chunk| {
+                out.extend(chunk.as_ref());
+
+                if out.len() as u64 > max_file_size {
+                    return Err(Error::BadRequest("request too large".into()));
+                }
+
+                Ok(out)
+            });
+
+        let put = body.and_then(move |body| {
+            pool.spawn_fn(move || {
+                let put: PutVersion = serde_json::from_slice(&body)
+                    .map_err(|_| Error::BadRequest(BAD_PUT_VERSION.into()))?;
+
+                // NB: the object must be uploaded before it can be published.
+                if objects
+                    .lock()
+                    .map_err(|_| "lock poisoned")?
+                    .get_object(&put.object)?
+                    .is_none()
+                {
+                    return Err(Error::BadRequest("no such object".into()));
+                }
+
+                let index = index.lock().map_err(|_| "lock poisoned")?;
+
+                if !put.force && !index.get_deployments(&package, &version)?.is_empty() {
+                    return Ok(Response::new()
+                        .with_status(StatusCode::Conflict)
+                        .with_header(ContentLength(ALREADY_PUBLISHED.len() as u64))
+                        .with_header(ContentType(mime::TEXT_PLAIN))
+                        .with_body(ALREADY_PUBLISHED));
+                }
+
+                info!("Publishing: {}@{}: {}", package, version, put.object);
+                index.put_version(&put.object, &package, &version, put.force)?;
+                Ok(Response::new().with_status(StatusCode::Ok))
+            })
+        });
+
+        Ok(Box::new(put))
+    }
+
     /// Put the uploaded object into the object repository.
     fn put_uploaded_object<F>(
         &self,
@@ -149,6 +340,14 @@ impl ReprotoService {
     ) -> Result<Box<Future<Item = Response, Error = Error>>> {
         let checksum = Checksum::from_str(id).map_err(|_| Error::BadRequest(BAD_OBJECT_ID.into()))?;
 
+        if self.auth.is_enabled() {
+            let user = self.auth.authenticate(req.headers())?;
+
+            if !user.may_publish_any() {
+                return Err(Error::Forbidden("not permitted to upload objects".into()));
+            }
+        }
+
         if let Some(len) = req.headers().get::<ContentLength>() {
             if len.0 > self.max_file_size {
                 return Err(Error::BadRequest("file too large".into()).into());
@@ -173,22 +372,45 @@ impl ReprotoService {
     where
         I: IntoIterator<Item = &'a str>,
     {
+        let path = path.into_iter().collect::<Vec<_>>();
+
+        // NB: empty segments, like from a trailing slash, are only permitted for the root.
+        if path.len() > 1 && path.iter().any(|segment| segment.is_empty()) {
+            return Ok(Box::new(ok(Self::not_found())));
+        }
+
         let mut it = path.into_iter();
 
         let a = it.next();
         let b = it.next();
         let c = it.next();
+        let d = it.next();
 
-        match (req.method(), a, b, c) {
-            (&Method::Get, Some(""), None, None) => {
+        match (req.method(), a, b, c, d) {
+            (&Method::Get, Some(""), None, None, None) => {
                 return self.get_index();
             }
-            (&Method::Get, Some("objects"), Some(id), None) => {
+            (&Method::Get, Some("objects"), Some(id), None, None) => {
                 return self.get_objects(id);
             }
-            (&Method::Put, Some("objects"), Some(id), None) => {
+            (&Method::Put, Some("objects"), Some(id), None, None) => {
                 return self.put_objects(id, req);
             }
+            (&Method::Get, Some("index"), None, None, None) => {
+                return self.get_packages();
+            }
+            (&Method::Get, Some("index"), Some(package), None, None) => {
+                return self.get_all(package);
+            }
+            (&Method::Get, Some("index"), Some(package), Some("resolve"), Some(range)) => {
+                return self.get_resolve(package, range);
+            }
+            (&Method::Get, Some("index"), Some(package), Some(version), None) => {
+                return self.get_deployments(package, version);
+            }
+            (&Method::Put, Some("index"), Some(package), Some(version), None) => {
+                return self.put_version(package, version, req);
+            }
             _ => {}
         }
 
@@ -204,6 +426,16 @@ impl ReprotoService {
                     .with_header(ContentType(mime::TEXT_PLAIN))
                     .with_body(message)
             }
+            Error::Unauthorized => {
+                return Response::new().with_status(StatusCode::Unauthorized);
+            }
+            Error::Forbidden(message) => {
+                return Response::new()
+                    .with_status(StatusCode::Forbidden)
+                    .with_header(ContentLength(message.len() as u64))
+                    .with_header(ContentType(mime::TEXT_PLAIN))
+                    .with_body(message)
+            }
             Error::Other(error) => {
                 error!("{}", error.message());
 
@@ -239,3 +471,183 @@ impl Service for ReprotoService {
         )
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::ReprotoService;
+    use auth::Auth;
+    use core::{RpPackage, Version};
+    use futures::{Future, Stream};
+    use futures_cpupool::CpuPool;
+    use hyper::server::{Request, Response, Service};
+    use hyper::{Method, StatusCode};
+    use reproto_repository::{init_file_index, to_checksum, Checksum, Deployment, FileIndex,
+                             FileObjects, Index, Objects};
+    use serde_json;
+    use std::collections::HashMap;
+    use std::env;
+    use std::fs;
+    use std::path::PathBuf;
+    use std::process;
+    use std::sync::{Arc, Mutex};
+
+    /// A service backed by an index and objects in a temporary directory.
+    struct Fixture {
+        path: PathBuf,
+        service: ReprotoService,
+    }
+
+    impl Fixture {
+        fn new(name: &str) -> Fixture {
+            let path = env::temp_dir().join(format!("reproto-server-{}-{}", name, process::id()));
+
+            if path.is_dir() {
+                fs::remove_dir_all(&path).unwrap();
+            }
+
+            init_file_index(&path).unwrap();
+
+            let service = ReprotoService {
+                max_file_size: 1024,
+                pool: Arc::new(CpuPool::new(1)),
+                objects: Arc::new(Mutex::new(FileObjects::new(&path.join("objects")))),
+                index: Arc::new(Mutex::new(FileIndex::new(&path).unwrap())),
+                auth: Arc::new(Auth::from_config(HashMap::new()).unwrap()),
+            };
+
+            Fixture {
+                path: path,
+                service: service,
+            }
+        }
+
+        /// Store an object with the given content, without publishing it.
+        fn put_object(&self, content: &str) -> Checksum {
+            let checksum = to_checksum(content.as_bytes()).unwrap();
+
+            self.service
+                .objects
+                .lock()
+                .unwrap()
+                .put_object(&checksum, &mut content.as_bytes(), false)
+                .unwrap();
+
+            checksum
+        }
+
+        /// Publish a version of a package with the given content.
+        fn publish(&self, package: &str, version: &str, content: &str) {
+            let checksum = self.put_object(content);
+
+            self.service
+                .index
+                .lock()
+                .unwrap()
+                .put_version(
+                    &checksum,
+                    &RpPackage::parse(package),
+                    &Version::parse(version).unwrap(),
+                    false,
+                )
+                .unwrap();
+        }
+
+        fn call(&self, method: Method, path: &str, body: Option<String>) -> Response {
+            let mut req = Request::new(method, path.parse().unwrap());
+
+            if let Some(body) = body {
+                req.set_body(body);
+            }
+
+            self.service.call(req).wait().unwrap()
+        }
+
+        fn get(&self, path: &str) -> Response {
+            self.call(Method::Get, path, None)
+        }
+
+        fn status(&self, path: &str) -> StatusCode {
+            self.get(path).status()
+        }
+
+        /// Publish the given object as version `1.0.0` of the `foo` package.
+        fn put_version(&self, object: &Checksum, force: bool) -> StatusCode {
+            let body = format!(r#"{{"object": "{}", "force": {}}}"#, object, force);
+            let response = self.call(Method::Put, "/index/foo/1.0.0", Some(body));
+            response.status()
+        }
+    }
+
+    impl Drop for Fixture {
+        fn drop(&mut self) {
+            let _ = fs::remove_dir_all(&self.path);
+        }
+    }
+
+    fn deployments(response: Response) -> Vec<Deployment> {
+        assert_eq!(StatusCode::Ok, response.status());
+        let body = response.body().concat2().wait().unwrap();
+        serde_json::from_slice(&body).unwrap()
+    }
+
+    #[test]
+    fn test_routing() {
+        let fixture = Fixture::new("routing");
+        fixture.publish("foo", "1.0.0", "type Foo {}");
+
+        assert_eq!(StatusCode::Ok, fixture.status("/"));
+        assert_eq!(StatusCode::Ok, fixture.status("/index"));
+        assert_eq!(1, deployments(fixture.get("/index/foo")).len());
+        assert_eq!(1, deployments(fixture.get("/index/foo/1.0.0")).len());
+        assert_eq!(0, deployments(fixture.get("/index/foo/2.0.0")).len());
+        assert_eq!(1, deployments(fixture.get("/index/foo/resolve/%5E1")).len());
+
+        assert_eq!(StatusCode::NotFound, fixture.status("/index/"));
+        assert_eq!(StatusCode::NotFound, fixture.status("/index/foo/"));
+        assert_eq!(StatusCode::NotFound, fixture.status("/missing"));
+
+        let status = fixture.call(Method::Delete, "/index/foo", None).status();
+        assert_eq!(StatusCode::NotFound, status);
+
+        assert_eq!(StatusCode::BadRequest, fixture.status("/index/foo/bar"));
+        assert_eq!(StatusCode::BadRequest, fixture.status("/objects/bar"));
+    }
+
+    #[test]
+    fn test_percent_decoding() {
+        let fixture = Fixture::new("percent-decoding");
+        fixture.publish("foo.bar", "1.0.0", "type Foo {}");
+
+        assert_eq!(1, deployments(fixture.get("/index/foo%2Ebar")).len());
+        assert_eq!(0, deployments(fixture.get("/index/foo%2Ebaz")).len());
+
+        let deployments = deployments(fixture.get("/index/foo%2ebar/1%2E0%2E0"));
+        assert_eq!(1, deployments.len());
+
+        assert_eq!(StatusCode::BadRequest, fixture.status("/index/foo%FF"));
+    }
+
+    #[test]
+    fn test_republish() {
+        let fixture = Fixture::new("republish");
+        let object = fixture.put_object("type Foo {}");
+
+        assert_eq!(StatusCode::Ok, fixture.put_version(&object, false));
+        assert_eq!(StatusCode::Conflict, fixture.put_version(&object, false));
+
+        // forcing replaces the existing deployment.
+        assert_eq!(StatusCode::Ok, fixture.put_version(&object, true));
+
+        assert_eq!(1, deployments(fixture.get("/index/foo/1.0.0")).len());
+    }
+
+    #[test]
+    fn test_missing_object() {
+        let fixture = Fixture::new("missing-object");
+        let object = to_checksum("type Foo {}".as_bytes()).unwrap();
+
+        assert_eq!(StatusCode::BadRequest, fixture.put_version(&object, false));
+
+        assert_eq!(0, deployments(fixture.get("/index/foo")).len());
+    }
+}
@@ -343,11 +343,16 @@ pub enum Language {
     Java,
     JavaScript,
     Json,
+    JsonSchema,
+    Kotlin,
+    OpenApi,
+    Proto,
     Python,
     Python3,
     Reproto,
     Rust,
     Swift,
+    TypeScript,
 }
 
 impl Language {
@@ -356,11 +361,21 @@ impl Language {
         use self::Language::*;
 
         match *self {
-            Json | Reproto => false,
+            Json | JsonSchema | OpenApi | Proto | Reproto => false,
             _ => true,
         }
     }
 
+    /// Is the language only tested by suites which explicitly include it?
+    pub fn is_opt_in(&self) -> bool {
+        use self::Language::*;
+
+        match *self {
+            JsonSchema | Kotlin | OpenApi | Proto | TypeScript => true,
+            _ => false,
+        }
+    }
+
     /// Get the name of the working directory.
     pub fn name(&self) -> &'static str {
         use self::Language::*;
@@ -371,11 +386,16 @@ impl Language {
             Java => "java",
             JavaScript => "js",
             Json => "json",
+            JsonSchema => "jsonschema",
+            Kotlin => "kotlin",
+            OpenApi => "openapi",
+            Proto => "proto",
             Python => "python",
             Python3 => "python3",
             Reproto => "reproto",
             Rust => "rust",
             Swift => "swift",
+            TypeScript => "typescript",
         }
     }
 
@@ -389,11 +409,16 @@ impl Language {
             Java => "java",
             JavaScript => "js",
             Json => "json",
+            JsonSchema => "jsonschema",
+            Kotlin => "kotlin",
+            OpenApi => "openapi",
+            Proto => "proto",
             Python => "python",
             Python3 => "python",
             Reproto => "reproto",
             Rust => "rust",
             Swift => "swift",
+            TypeScript => "typescript",
         }
     }
 
@@ -404,11 +429,13 @@ impl Language {
         match *self {
             Java => RelativePath::new("target/generated-sources/reproto"),
             JavaScript => RelativePath::new("generated"),
+            Kotlin => RelativePath::new("target/generated-sources/reproto"),
             Python => RelativePath::new("generated"),
             Python3 => RelativePath::new("generated"),
             Rust => RelativePath::new("src"),
             Swift => RelativePath::new("Sources/Models"),
             Go => RelativePath::new("models"),
+            TypeScript => RelativePath::new("generated"),
             _ => RelativePath::new("."),
         }
     }
@@ -1201,6 +1228,10 @@ impl<'a> Project<'a> {
                     continue;
                 }
 
+                if suite.include.is_empty() && language.is_opt_in() {
+                    continue;
+                }
+
                 let mut extra = Vec::new();
 
                 if let Some(args) = suite.arguments.get(language) {
@@ -25,7 +25,13 @@ fn test(command: &str, args: &[&str]) -> bool {
 /// Detect supported languages
 fn detect() -> HashSet<Language> {
     let mut out = HashSet::new();
-    out.extend(vec![Language::Json, Language::Reproto]);
+    out.extend(vec![
+        Language::Json,
+        Language::JsonSchema,
+        Language::OpenApi,
+        Language::Proto,
+        Language::Reproto,
+    ]);
 
     if test("mvn", &["--version"]) {
         out.insert(Language::Java);
@@ -33,6 +39,12 @@ fn detect() -> HashSet<Language> {
         println!("WARN: `mvn --version` failed, not building Java projects");
     }
 
+    if test("mvn", &["--version"]) {
+        out.insert(Language::Kotlin);
+    } else {
+        println!("WARN: `mvn --version` failed, not building Kotlin projects");
+    }
+
     if test("python", &["--version"]) {
         out.insert(Language::Python);
     } else {
@@ -77,6 +89,14 @@ fn detect() -> HashSet<Language> {
         println!("WARN: `go version` failed, not building Go projects");
     }
 
+    if test("node", &["--version"]) && test("tsc", &["--version"]) {
+        out.insert(Language::TypeScript);
+    } else {
+        println!(
+            "WARN: `node --version` or `tsc --version` failed, not building TypeScript projects"
+        );
+    }
+
     out
 }
 
@@ -142,11 +162,16 @@ fn try_main() -> Result<()> {
         it::Language::Java,
         it::Language::JavaScript,
         it::Language::Json,
+        it::Language::JsonSchema,
+        it::Language::Kotlin,
+        it::Language::OpenApi,
+        it::Language::Proto,
         it::Language::Python,
         it::Language::Python3,
         it::Language::Reproto,
         it::Language::Rust,
         it::Language::Swift,
+        it::Language::TypeScript,
     ];
 
     let mut root = env::current_dir()?;